@@ -113,6 +113,7 @@ pub fn unstake_nfts(chain: &mut Chain, sender: &SigningKey, token_ids: &[&str])
             "unstake_nfts",
             &module::msg::ExecuteMsg::Unstake {
                 token_ids: token_ids.iter().map(|s| s.to_string()).collect(),
+                collection: None,
             },
             sender,
             vec![],
@@ -126,7 +127,7 @@ pub fn claim_nfts(chain: &mut Chain, sender: &SigningKey) {
         .execute(
             CONTRACT_NAME,
             "claim_nfts",
-            &module::msg::ExecuteMsg::ClaimNfts {},
+            &module::msg::ExecuteMsg::ClaimNfts { collection: None },
             sender,
             vec![],
         )
@@ -238,6 +239,7 @@ fn cw721_stake_max_claims_works(chain: &mut Chain) {
             contract_name: CONTRACT_NAME.to_string(),
             msg: Box::new(module::msg::ExecuteMsg::Unstake {
                 token_ids: vec![token_id],
+                collection: None,
             }),
             funds: vec![],
         });
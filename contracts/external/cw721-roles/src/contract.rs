@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_json, to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
-    StdResult, SubMsg, Uint64,
+    from_json, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Response, StdResult, Storage, SubMsg, Timestamp, Uint64,
 };
 use cw4::{
     Member, MemberChangedHookMsg, MemberDiff, MemberListResponse, MemberResponse,
@@ -11,12 +11,14 @@ use cw4::{
 use cw721::{Cw721ReceiveMsg, NftInfoResponse, OwnerOfResponse};
 use cw721_base::{Cw721Contract, InstantiateMsg as Cw721BaseInstantiateMsg};
 use cw_storage_plus::Bound;
-use cw_utils::maybe_addr;
+use cw_utils::{maybe_addr, Expiration};
 use dao_cw721_extensions::roles::{ExecuteExt, MetadataExt, QueryExt};
 use std::cmp::Ordering;
 
 use crate::msg::{ExecuteMsg, QueryMsg};
-use crate::state::{MEMBERS, TOTAL};
+use crate::state::{
+    EXPIRATION_QUEUE, EXPIRED_TOKENS, MEMBERS, ROLE_MEMBERS, ROLE_TOTALS, SOULBOUND, TOTAL,
+};
 use crate::{error::RolesContractError as ContractError, state::HOOKS};
 
 // Version info for migration
@@ -55,8 +57,17 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Only owner / minter can execute
-    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    // Only owner / minter can execute, except for transfers of
+    // tokens that are not soulbound and expiring tokens
+    match &msg {
+        ExecuteMsg::TransferNft { token_id, .. } | ExecuteMsg::SendNft { token_id, .. } => {
+            assert_can_transfer(deps.as_ref(), &info.sender, token_id)?
+        }
+        ExecuteMsg::Extension {
+            msg: ExecuteExt::ExpireTokens { .. },
+        } => {}
+        _ => cw_ownable::assert_owner(deps.storage, &info.sender)?,
+    }
 
     match msg {
         ExecuteMsg::Mint {
@@ -79,6 +90,14 @@ pub fn execute(
             ExecuteExt::UpdateTokenWeight { token_id, weight } => {
                 execute_update_token_weight(deps, env, info, token_id, weight)
             }
+            ExecuteExt::RenewToken {
+                token_id,
+                expires_at,
+            } => execute_renew_token(deps, env, info, token_id, expires_at),
+            ExecuteExt::ExpireTokens { limit } => execute_expire_tokens(deps, env, limit),
+            ExecuteExt::UpdateSoulbound { soulbound } => {
+                execute_update_soulbound(deps, info, soulbound)
+            }
         },
         ExecuteMsg::TransferNft {
             recipient,
@@ -95,6 +114,73 @@ pub fn execute(
     }
 }
 
+/// Errors unless SENDER may transfer TOKEN_ID. The minter may
+/// transfer any token, and owners may transfer their own tokens if
+/// tokens are not soulbound.
+fn assert_can_transfer(deps: Deps, sender: &Addr, token_id: &str) -> Result<(), ContractError> {
+    let Err(err) = cw_ownable::assert_owner(deps.storage, sender) else {
+        return Ok(());
+    };
+    if SOULBOUND.may_load(deps.storage)?.unwrap_or(true) {
+        return Err(err.into());
+    }
+    let token = Cw721Roles::default().tokens.load(deps.storage, token_id)?;
+    if token.owner != sender {
+        return Err(ContractError::NotTokenOwner {});
+    }
+    Ok(())
+}
+
+/// Errors if a token expiring at EXPIRES_AT would already be expired
+/// or expires at a height rather than a time.
+fn validate_expiration(
+    env: &Env,
+    token_id: &str,
+    expires_at: Option<Expiration>,
+) -> Result<(), ContractError> {
+    match expires_at {
+        Some(Expiration::AtTime(time)) if time <= env.block.time => {
+            Err(ContractError::AlreadyExpired {
+                token_id: token_id.to_string(),
+            })
+        }
+        Some(Expiration::AtHeight(_)) => Err(ContractError::HeightExpiration {}),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the time at which a token with EXTENSION expires, if it
+/// expires.
+fn expiration_time(extension: &MetadataExt) -> Option<Timestamp> {
+    match extension.expires_at {
+        Some(Expiration::AtTime(time)) => Some(time),
+        _ => None,
+    }
+}
+
+/// Queues TOKEN_ID to be expired by `ExpireTokens`, if it expires.
+/// Called when the token's weight starts being counted.
+fn track_expiration(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    extension: &MetadataExt,
+) -> StdResult<()> {
+    match expiration_time(extension) {
+        Some(expires_at) => {
+            EXPIRATION_QUEUE.save(storage, (expires_at.nanos(), token_id), &Empty {})
+        }
+        None => Ok(()),
+    }
+}
+
+/// Removes TOKEN_ID from the expiration queue, if it expires. Called
+/// when the token's weight stops being counted.
+fn untrack_expiration(storage: &mut dyn Storage, token_id: &str, extension: &MetadataExt) {
+    if let Some(expires_at) = expiration_time(extension) {
+        EXPIRATION_QUEUE.remove(storage, (expires_at.nanos(), token_id));
+    }
+}
+
 pub fn execute_mint(
     deps: DepsMut,
     env: Env,
@@ -104,6 +190,8 @@ pub fn execute_mint(
     token_uri: Option<String>,
     extension: MetadataExt,
 ) -> Result<Response, ContractError> {
+    validate_expiration(&env, &token_id, extension.expires_at)?;
+
     let mut total = Uint64::from(TOTAL.load(deps.storage)?);
     let mut diff = MemberDiff::new(owner.clone(), None, None);
 
@@ -123,6 +211,16 @@ pub fn execute_mint(
         },
     )?;
     TOTAL.save(deps.storage, &total.u64(), env.block.height)?;
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    update_role_weight(
+        deps.storage,
+        env.block.height,
+        extension.role.as_deref(),
+        &owner_addr,
+        extension.weight,
+        0,
+    )?;
+    track_expiration(deps.storage, &token_id, &extension)?;
 
     let diffs = MemberChangedHookMsg { diffs: vec![diff] };
 
@@ -172,6 +270,20 @@ pub fn execute_burn(
         },
     )?)?;
 
+    // Expired tokens' weight has already been removed
+    if EXPIRED_TOKENS.has(deps.storage, &token_id) {
+        EXPIRED_TOKENS.remove(deps.storage, &token_id);
+        Cw721Roles::default()
+            .tokens
+            .remove(deps.storage, &token_id)?;
+        Cw721Roles::default().decrement_tokens(deps.storage)?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "burn")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id));
+    }
+
     let mut total = Uint64::from(TOTAL.load(deps.storage)?);
     let mut diff = MemberDiff::new(owner.owner.clone(), None, None);
 
@@ -206,6 +318,15 @@ pub fn execute_burn(
     }
 
     TOTAL.save(deps.storage, &total.u64(), env.block.height)?;
+    update_role_weight(
+        deps.storage,
+        env.block.height,
+        nft_info.extension.role.as_deref(),
+        &owner_addr,
+        0,
+        nft_info.extension.weight,
+    )?;
+    untrack_expiration(deps.storage, &token_id, &nft_info.extension);
 
     let diffs = MemberChangedHookMsg { diffs: vec![diff] };
 
@@ -230,7 +351,7 @@ pub fn execute_burn(
 
 pub fn execute_transfer(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     recipient: String,
     token_id: String,
@@ -238,12 +359,22 @@ pub fn execute_transfer(
     let contract = Cw721Roles::default();
 
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let msgs = move_token_weight(
+        deps.storage,
+        env.block.height,
+        &token_id,
+        &token.extension,
+        &token.owner,
+        &recipient_addr,
+    )?;
     // set owner and remove existing approvals
-    token.owner = deps.api.addr_validate(&recipient)?;
+    token.owner = recipient_addr;
     token.approvals = vec![];
     contract.tokens.save(deps.storage, &token_id, &token)?;
 
     Ok(Response::new()
+        .add_submessages(msgs)
         .add_attribute("action", "transfer_nft")
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient)
@@ -252,7 +383,7 @@ pub fn execute_transfer(
 
 pub fn execute_send(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     token_id: String,
     recipient_contract: String,
@@ -261,8 +392,17 @@ pub fn execute_send(
     let contract = Cw721Roles::default();
 
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
+    let recipient_addr = deps.api.addr_validate(&recipient_contract)?;
+    let msgs = move_token_weight(
+        deps.storage,
+        env.block.height,
+        &token_id,
+        &token.extension,
+        &token.owner,
+        &recipient_addr,
+    )?;
     // set owner and remove existing approvals
-    token.owner = deps.api.addr_validate(&recipient_contract)?;
+    token.owner = recipient_addr;
     token.approvals = vec![];
     contract.tokens.save(deps.storage, &token_id, &token)?;
 
@@ -274,6 +414,7 @@ pub fn execute_send(
 
     Ok(Response::new()
         .add_message(send.into_cosmos_msg(recipient_contract.clone())?)
+        .add_submessages(msgs)
         .add_attribute("action", "send_nft")
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient_contract)
@@ -308,7 +449,7 @@ pub fn execute_remove_hook(
 
 pub fn execute_update_token_role(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     token_id: String,
     role: Option<String>,
@@ -318,6 +459,26 @@ pub fn execute_update_token_role(
     // Make sure NFT exists
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
 
+    // Move the token's weight from its old role to the new one
+    if token.extension.role != role && !EXPIRED_TOKENS.has(deps.storage, &token_id) {
+        update_role_weight(
+            deps.storage,
+            env.block.height,
+            token.extension.role.as_deref(),
+            &token.owner,
+            0,
+            token.extension.weight,
+        )?;
+        update_role_weight(
+            deps.storage,
+            env.block.height,
+            role.as_deref(),
+            &token.owner,
+            token.extension.weight,
+            0,
+        )?;
+    }
+
     // Update role with new value
     token.extension.role = role.clone();
     contract.tokens.save(deps.storage, &token_id, &token)?;
@@ -363,6 +524,21 @@ pub fn execute_update_token_weight(
     // Make sure NFT exists
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
 
+    // Expired tokens' weight is not counted, so only the token changes
+    if EXPIRED_TOKENS.has(deps.storage, &token_id) {
+        if weight == token.extension.weight {
+            return Err(ContractError::NoWeightChange {});
+        }
+        token.extension.weight = weight;
+        contract.tokens.save(deps.storage, &token_id, &token)?;
+
+        return Ok(Response::default()
+            .add_attribute("action", "update_token_weight")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id)
+            .add_attribute("weight", weight.to_string()));
+    }
+
     let mut total = Uint64::from(TOTAL.load(deps.storage)?);
     let mut diff = MemberDiff::new(token.clone().owner, None, None);
 
@@ -414,6 +590,14 @@ pub fn execute_update_token_weight(
         },
     )?;
     TOTAL.save(deps.storage, &total.u64(), env.block.height)?;
+    update_role_weight(
+        deps.storage,
+        env.block.height,
+        token.extension.role.as_deref(),
+        &token.owner,
+        weight,
+        token.extension.weight,
+    )?;
 
     let diffs = MemberChangedHookMsg { diffs: vec![diff] };
 
@@ -434,11 +618,256 @@ pub fn execute_update_token_weight(
         .add_attribute("weight", weight.to_string()))
 }
 
+pub fn execute_renew_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    expires_at: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let contract = Cw721Roles::default();
+
+    // Make sure NFT exists
+    let mut token = contract.tokens.load(deps.storage, &token_id)?;
+
+    validate_expiration(&env, &token_id, expires_at)?;
+    let expired = EXPIRED_TOKENS.has(deps.storage, &token_id);
+    if !expired {
+        untrack_expiration(deps.storage, &token_id, &token.extension);
+    }
+    token.extension.expires_at = expires_at;
+    contract.tokens.save(deps.storage, &token_id, &token)?;
+    track_expiration(deps.storage, &token_id, &token.extension)?;
+
+    // Restore the weight of expired tokens
+    let mut msgs = vec![];
+    if expired {
+        EXPIRED_TOKENS.remove(deps.storage, &token_id);
+        let diff = add_member_weight(
+            deps.storage,
+            env.block.height,
+            &token.owner,
+            token.extension.weight,
+        )?;
+        update_role_weight(
+            deps.storage,
+            env.block.height,
+            token.extension.role.as_deref(),
+            &token.owner,
+            token.extension.weight,
+            0,
+        )?;
+        let diffs = MemberChangedHookMsg { diffs: vec![diff] };
+        msgs = HOOKS.prepare_hooks(deps.storage, |h| {
+            diffs.clone().into_cosmos_msg(h).map(SubMsg::new)
+        })?;
+    }
+
+    Ok(Response::default()
+        .add_submessages(msgs)
+        .add_attribute("action", "renew_token")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute(
+            "expires_at",
+            expires_at.unwrap_or(Expiration::Never {}).to_string(),
+        ))
+}
+
+pub fn execute_expire_tokens(
+    deps: DepsMut,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let contract = Cw721Roles::default();
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // The queue is ordered by expiration, so the first token that has
+    // not expired ends the expired ones
+    let expired = EXPIRATION_QUEUE
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take_while(|r| match r {
+            Ok((expires_at, _)) => *expires_at <= env.block.time.nanos(),
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|r| r.map(|(_, token_id)| token_id))
+        .collect::<StdResult<Vec<String>>>()?;
+
+    let mut diffs = Vec::with_capacity(expired.len());
+    for token_id in &expired {
+        let token = contract.tokens.load(deps.storage, token_id)?;
+        untrack_expiration(deps.storage, token_id, &token.extension);
+        EXPIRED_TOKENS.save(deps.storage, token_id, &Empty {})?;
+        diffs.push(sub_member_weight(
+            deps.storage,
+            env.block.height,
+            &token.owner,
+            token.extension.weight,
+        )?);
+        update_role_weight(
+            deps.storage,
+            env.block.height,
+            token.extension.role.as_deref(),
+            &token.owner,
+            0,
+            token.extension.weight,
+        )?;
+    }
+
+    let mut msgs = vec![];
+    if !diffs.is_empty() {
+        let diffs = MemberChangedHookMsg { diffs };
+        msgs = HOOKS.prepare_hooks(deps.storage, |h| {
+            diffs.clone().into_cosmos_msg(h).map(SubMsg::new)
+        })?;
+    }
+
+    Ok(Response::default()
+        .add_submessages(msgs)
+        .add_attribute("action", "expire_tokens")
+        .add_attribute("expired", expired.len().to_string()))
+}
+
+pub fn execute_update_soulbound(
+    deps: DepsMut,
+    info: MessageInfo,
+    soulbound: bool,
+) -> Result<Response, ContractError> {
+    SOULBOUND.save(deps.storage, &soulbound)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_soulbound")
+        .add_attribute("sender", info.sender)
+        .add_attribute("soulbound", soulbound.to_string()))
+}
+
+/// Adds WEIGHT to MEMBER's weight and the total weight, returning
+/// the change for use in hooks.
+fn add_member_weight(
+    storage: &mut dyn Storage,
+    height: u64,
+    member: &Addr,
+    weight: u64,
+) -> Result<MemberDiff, ContractError> {
+    let old = MEMBERS.may_load(storage, member)?;
+    let new = Uint64::from(old.unwrap_or_default())
+        .checked_add(Uint64::from(weight))?
+        .u64();
+    MEMBERS.save(storage, member, &new, height)?;
+
+    let total = Uint64::from(TOTAL.load(storage)?).checked_add(Uint64::from(weight))?;
+    TOTAL.save(storage, &total.u64(), height)?;
+
+    Ok(MemberDiff::new(member, old, Some(new)))
+}
+
+/// Subtracts WEIGHT from MEMBER's weight and the total weight,
+/// removing MEMBER if their weight becomes zero. Returns the change
+/// for use in hooks.
+fn sub_member_weight(
+    storage: &mut dyn Storage,
+    height: u64,
+    member: &Addr,
+    weight: u64,
+) -> Result<MemberDiff, ContractError> {
+    let old = MEMBERS.may_load(storage, member)?;
+    let new = old
+        .unwrap_or_default()
+        .checked_sub(weight)
+        .ok_or(ContractError::NegativeValue {})?;
+    let new = if new == 0 {
+        MEMBERS.remove(storage, member, height)?;
+        None
+    } else {
+        MEMBERS.save(storage, member, &new, height)?;
+        Some(new)
+    };
+
+    let total = Uint64::from(TOTAL.load(storage)?).checked_sub(Uint64::from(weight))?;
+    TOTAL.save(storage, &total.u64(), height)?;
+
+    Ok(MemberDiff::new(member, old, new))
+}
+
+/// Moves the weight of TOKEN_ID, which has metadata EXTENSION, from
+/// FROM to TO, returning hook messages for the change. Does nothing
+/// if the token has expired.
+fn move_token_weight(
+    storage: &mut dyn Storage,
+    height: u64,
+    token_id: &str,
+    extension: &MetadataExt,
+    from: &Addr,
+    to: &Addr,
+) -> Result<Vec<SubMsg>, ContractError> {
+    if from == to || EXPIRED_TOKENS.has(storage, token_id) {
+        return Ok(vec![]);
+    }
+
+    let role = extension.role.as_deref();
+    let diffs = MemberChangedHookMsg {
+        diffs: vec![
+            sub_member_weight(storage, height, from, extension.weight)?,
+            add_member_weight(storage, height, to, extension.weight)?,
+        ],
+    };
+    update_role_weight(storage, height, role, from, 0, extension.weight)?;
+    update_role_weight(storage, height, role, to, extension.weight, 0)?;
+
+    Ok(HOOKS.prepare_hooks(storage, |h| {
+        diffs.clone().into_cosmos_msg(h).map(SubMsg::new)
+    })?)
+}
+
+/// Adds ADD to and subtracts SUB from the weight MEMBER holds in
+/// tokens with ROLE, and from the role's total weight. Does nothing
+/// for tokens without a role.
+fn update_role_weight(
+    storage: &mut dyn Storage,
+    height: u64,
+    role: Option<&str>,
+    member: &Addr,
+    add: u64,
+    sub: u64,
+) -> Result<(), ContractError> {
+    let Some(role) = role else {
+        return Ok(());
+    };
+
+    // Tokens minted before roles were tracked are not counted in
+    // their role's weights, so those are floored at zero rather than
+    // erroring when such tokens are burned or changed.
+    let apply = |old: u64| -> Result<u64, ContractError> {
+        Ok(Uint64::from(old)
+            .checked_add(Uint64::from(add))?
+            .saturating_sub(Uint64::from(sub))
+            .u64())
+    };
+
+    let old = ROLE_MEMBERS
+        .may_load(storage, (role, member))?
+        .unwrap_or_default();
+    let new = apply(old)?;
+    if new == 0 {
+        ROLE_MEMBERS.remove(storage, (role, member), height)?;
+    } else {
+        ROLE_MEMBERS.save(storage, (role, member), &new, height)?;
+    }
+
+    let total = ROLE_TOTALS.may_load(storage, role)?.unwrap_or_default();
+    ROLE_TOTALS.save(storage, role, &apply(total)?, height)?;
+    Ok(())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Extension { msg } => match msg {
             QueryExt::Hooks {} => to_json_binary(&HOOKS.query_hooks(deps)?),
+            QueryExt::Soulbound {} => {
+                to_json_binary(&SOULBOUND.may_load(deps.storage)?.unwrap_or(true))
+            }
             QueryExt::ListMembers { start_after, limit } => {
                 to_json_binary(&query_list_members(deps, start_after, limit)?)
             }
@@ -448,6 +877,19 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             QueryExt::TotalWeight { at_height } => {
                 to_json_binary(&query_total_weight(deps, at_height)?)
             }
+            QueryExt::RoleTotalWeight { role, at_height } => {
+                to_json_binary(&query_role_total_weight(deps, role, at_height)?)
+            }
+            QueryExt::RoleMember {
+                role,
+                addr,
+                at_height,
+            } => to_json_binary(&query_role_member(deps, role, addr, at_height)?),
+            QueryExt::ListRoleMembers {
+                role,
+                start_after,
+                limit,
+            } => to_json_binary(&query_list_role_members(deps, role, start_after, limit)?),
         },
         _ => Cw721Roles::default().query(deps, env, msg),
     }
@@ -493,3 +935,55 @@ pub fn query_list_members(
 
     Ok(MemberListResponse { members })
 }
+
+pub fn query_role_total_weight(
+    deps: Deps,
+    role: String,
+    height: Option<u64>,
+) -> StdResult<TotalWeightResponse> {
+    let weight = match height {
+        Some(h) => ROLE_TOTALS.may_load_at_height(deps.storage, &role, h),
+        None => ROLE_TOTALS.may_load(deps.storage, &role),
+    }?
+    .unwrap_or_default();
+    Ok(TotalWeightResponse { weight })
+}
+
+pub fn query_role_member(
+    deps: Deps,
+    role: String,
+    addr: String,
+    height: Option<u64>,
+) -> StdResult<MemberResponse> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let weight = match height {
+        Some(h) => ROLE_MEMBERS.may_load_at_height(deps.storage, (role.as_str(), &addr), h),
+        None => ROLE_MEMBERS.may_load(deps.storage, (role.as_str(), &addr)),
+    }?;
+    Ok(MemberResponse { weight })
+}
+
+pub fn query_list_role_members(
+    deps: Deps,
+    role: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<MemberListResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let addr = maybe_addr(deps.api, start_after)?;
+    let start = addr.as_ref().map(Bound::exclusive);
+
+    let members = ROLE_MEMBERS
+        .prefix(role.as_str())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(addr, weight)| Member {
+                addr: addr.into(),
+                weight,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(MemberListResponse { members })
+}
@@ -26,4 +26,13 @@ pub enum RolesContractError {
 
     #[error("The submitted weight is equal to the previous value, no change will occur")]
     NoWeightChange {},
+
+    #[error("Token ({token_id}) would already be expired")]
+    AlreadyExpired { token_id: String },
+
+    #[error("Tokens may only expire at a time")]
+    HeightExpiration {},
+
+    #[error("Only the minter or the token's owner may transfer it")]
+    NotTokenOwner {},
 }
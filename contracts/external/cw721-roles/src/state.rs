@@ -1,6 +1,6 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Empty};
 use cw_controllers::Hooks;
-use cw_storage_plus::{SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 
 // Hooks to contracts that will receive staking and unstaking messages.
 pub const HOOKS: Hooks = Hooks::new("hooks");
@@ -20,3 +20,33 @@ pub const MEMBERS: SnapshotMap<&Addr, u64> = SnapshotMap::new(
     "members__changelog",
     Strategy::EveryBlock,
 );
+
+/// A historic list of the weight members hold in tokens with a given
+/// role, keyed by (role, member)
+pub const ROLE_MEMBERS: SnapshotMap<(&str, &Addr), u64> = SnapshotMap::new(
+    "role_members",
+    "role_members__checkpoints",
+    "role_members__changelog",
+    Strategy::EveryBlock,
+);
+
+/// A historic snapshot of the total weight of tokens with a given role
+pub const ROLE_TOTALS: SnapshotMap<&str, u64> = SnapshotMap::new(
+    "role_totals",
+    "role_totals__checkpoints",
+    "role_totals__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Whether tokens may only be transferred by the minter. Defaults to
+/// true if unset
+pub const SOULBOUND: Item<bool> = Item::new("soulbound");
+
+/// Tokens with an expiration whose weight is still counted, keyed by
+/// (expiration in nanoseconds, token ID) so that expired tokens come
+/// first
+pub const EXPIRATION_QUEUE: Map<(u64, &str), Empty> = Map::new("expiration_queue");
+
+/// Tokens that have expired and whose weight has been removed from
+/// their owner and the total
+pub const EXPIRED_TOKENS: Map<&str, Empty> = Map::new("expired_tokens");
@@ -1,7 +1,8 @@
 use cosmwasm_std::{to_json_binary, Addr, Binary};
 use cw4::{HooksResponse, Member, MemberListResponse, MemberResponse, TotalWeightResponse};
 use cw721::{NftInfoResponse, OwnerOfResponse};
-use cw_multi_test::{App, Executor};
+use cw_multi_test::{next_block, App, Executor};
+use cw_utils::Expiration;
 use dao_cw721_extensions::roles::{ExecuteExt, MetadataExt, QueryExt};
 use dao_testing::contracts::{cw721_roles_contract, voting_cw721_staked_contract};
 use dao_voting_cw721_staked::msg::{InstantiateMsg as Cw721StakedInstantiateMsg, NftContract};
@@ -108,6 +109,7 @@ fn test_minting_and_burning() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -125,6 +127,7 @@ fn test_minting_and_burning() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -138,6 +141,7 @@ fn test_minting_and_burning() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -208,6 +212,7 @@ fn test_minting_and_transfer_permissions() {
         extension: MetadataExt {
             role: Some("member".to_string()),
             weight: 1,
+            expires_at: None,
         },
     };
 
@@ -247,6 +252,7 @@ fn test_send_permissions() {
         extension: MetadataExt {
             role: Some("member".to_string()),
             weight: 1,
+            expires_at: None,
         },
     };
     // DAO can mint successfully as the minter
@@ -302,6 +308,7 @@ fn test_update_token_role() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -339,6 +346,7 @@ fn test_update_token_uri() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -376,6 +384,7 @@ fn test_update_token_weight() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -430,6 +439,7 @@ fn test_update_token_weight() {
         extension: MetadataExt {
             role: None,
             weight: 10,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -471,6 +481,7 @@ fn test_zero_weight_token() {
         extension: MetadataExt {
             role: None,
             weight: 0,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -497,6 +508,7 @@ fn test_hooks() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &msg, &[])
@@ -541,6 +553,7 @@ fn test_hooks() {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     };
     // Should error as the DAO is not a contract, meaning hooks fired
@@ -579,9 +592,310 @@ fn test_hooks() {
             extension: MetadataExt {
                 role: None,
                 weight: 1,
+                expires_at: None,
             },
         },
         &[],
     )
     .unwrap();
 }
+
+#[test]
+fn test_role_weights() {
+    let (mut app, cw721_addr) = setup();
+
+    let query_role_total = |app: &App, role: &str, at_height: Option<u64>| -> u64 {
+        let total: TotalWeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721_addr,
+                &QueryMsg::Extension {
+                    msg: QueryExt::RoleTotalWeight {
+                        role: role.to_string(),
+                        at_height,
+                    },
+                },
+            )
+            .unwrap();
+        total.weight
+    };
+    let query_role_member = |app: &App, role: &str, addr: &str| -> Option<u64> {
+        let member: MemberResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &cw721_addr,
+                &QueryMsg::Extension {
+                    msg: QueryExt::RoleMember {
+                        role: role.to_string(),
+                        addr: addr.to_string(),
+                        at_height: None,
+                    },
+                },
+            )
+            .unwrap();
+        member.weight
+    };
+
+    for (token_id, owner, role, weight) in [
+        ("1", ALICE, Some("core"), 2),
+        ("2", ALICE, Some("core"), 3),
+        ("3", BOB, Some("core"), 1),
+        ("4", BOB, None, 4),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(DAO),
+            cw721_addr.clone(),
+            &ExecuteMsg::Mint {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: MetadataExt {
+                    role: role.map(str::to_string),
+                    weight,
+                    expires_at: None,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    assert_eq!(query_role_total(&app, "core", None), 6);
+    assert_eq!(query_role_member(&app, "core", ALICE), Some(5));
+    assert_eq!(query_role_member(&app, "core", BOB), Some(1));
+
+    let members: MemberListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &cw721_addr,
+            &QueryMsg::Extension {
+                msg: QueryExt::ListRoleMembers {
+                    role: "core".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        members.members,
+        vec![
+            Member {
+                addr: ALICE.to_string(),
+                weight: 5
+            },
+            Member {
+                addr: BOB.to_string(),
+                weight: 1
+            }
+        ]
+    );
+
+    // Changing a token's role or weight moves its weight between roles.
+    let before = app.block_info().height;
+    app.update_block(|block| block.height += 1);
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        cw721_addr.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateTokenRole {
+                token_id: "3".to_string(),
+                role: Some("council".to_string()),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        cw721_addr.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateTokenWeight {
+                token_id: "1".to_string(),
+                weight: 4,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_role_total(&app, "core", None), 7);
+    assert_eq!(query_role_total(&app, "council", None), 1);
+    assert_eq!(query_role_member(&app, "core", BOB), None);
+    assert_eq!(query_role_member(&app, "council", BOB), Some(1));
+
+    // Role weights are snapshotted.
+    assert_eq!(query_role_total(&app, "core", Some(before + 1)), 6);
+
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        cw721_addr.clone(),
+        &ExecuteMsg::Burn {
+            token_id: "2".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_role_total(&app, "core", None), 4);
+    assert_eq!(query_role_member(&app, "core", ALICE), Some(4));
+}
+
+#[test]
+fn test_expiring_and_soulbound_roles() {
+    let (mut app, cw721_addr) = setup();
+    let height = app.block_info().height;
+    let time = app.block_info().time;
+
+    let mint = |app: &mut App, token_id: &str, owner: &str, expires_at: Option<Expiration>| {
+        app.execute_contract(
+            Addr::unchecked(DAO),
+            cw721_addr.clone(),
+            &ExecuteMsg::Mint {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: MetadataExt {
+                    role: Some("council".to_string()),
+                    weight: 2,
+                    expires_at,
+                },
+            },
+            &[],
+        )
+    };
+
+    // Can't mint a token that has already expired
+    let err: RolesContractError = mint(&mut app, "1", ALICE, Some(Expiration::AtTime(time)))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        RolesContractError::AlreadyExpired {
+            token_id: "1".to_string()
+        }
+    );
+
+    // Tokens expire at a time
+    let err: RolesContractError =
+        mint(&mut app, "1", ALICE, Some(Expiration::AtHeight(height + 5)))
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, RolesContractError::HeightExpiration {});
+
+    mint(
+        &mut app,
+        "1",
+        ALICE,
+        Some(Expiration::AtTime(time.plus_seconds(5))),
+    )
+    .unwrap();
+    mint(&mut app, "2", BOB, None).unwrap();
+    let total = query_total_weight(&app, &cw721_addr, None).unwrap();
+    assert_eq!(total.weight, 4);
+
+    // Expiring tokens before they expire does nothing
+    let expire = ExecuteMsg::Extension {
+        msg: ExecuteExt::ExpireTokens { limit: None },
+    };
+    app.execute_contract(Addr::unchecked(BOB), cw721_addr.clone(), &expire, &[])
+        .unwrap();
+    let total = query_total_weight(&app, &cw721_addr, None).unwrap();
+    assert_eq!(total.weight, 4);
+
+    // Expired tokens keep their weight, in members and the total
+    // alike, until they are expired
+    app.update_block(|block| {
+        block.height += 5;
+        block.time = block.time.plus_seconds(5);
+    });
+    let member = query_member(&app, &cw721_addr, ALICE, None).unwrap();
+    assert_eq!(member.weight, Some(2));
+    let total = query_total_weight(&app, &cw721_addr, None).unwrap();
+    assert_eq!(total.weight, 4);
+
+    // Anyone may expire tokens once they expire. Weights at past
+    // heights are unchanged.
+    app.execute_contract(Addr::unchecked(BOB), cw721_addr.clone(), &expire, &[])
+        .unwrap();
+    app.update_block(next_block);
+    let member = query_member(&app, &cw721_addr, ALICE, None).unwrap();
+    assert_eq!(member.weight, None);
+    let member = query_member(&app, &cw721_addr, ALICE, Some(height + 1)).unwrap();
+    assert_eq!(member.weight, Some(2));
+    let total = query_total_weight(&app, &cw721_addr, None).unwrap();
+    assert_eq!(total.weight, 2);
+    let total = query_total_weight(&app, &cw721_addr, Some(height + 5)).unwrap();
+    assert_eq!(total.weight, 4);
+
+    // Only the minter may renew tokens, which restores their weight
+    let renew = ExecuteMsg::Extension {
+        msg: ExecuteExt::RenewToken {
+            token_id: "1".to_string(),
+            expires_at: Some(Expiration::AtTime(time.plus_seconds(100))),
+        },
+    };
+    app.execute_contract(Addr::unchecked(ALICE), cw721_addr.clone(), &renew, &[])
+        .unwrap_err();
+    app.execute_contract(Addr::unchecked(DAO), cw721_addr.clone(), &renew, &[])
+        .unwrap();
+    let member = query_member(&app, &cw721_addr, ALICE, None).unwrap();
+    assert_eq!(member.weight, Some(2));
+    let info: NftInfoResponse<MetadataExt> = query_token_info(&app, &cw721_addr, "1").unwrap();
+    assert_eq!(
+        info.extension.expires_at,
+        Some(Expiration::AtTime(time.plus_seconds(100)))
+    );
+
+    // Tokens are soulbound by default
+    let soulbound: bool = app
+        .wrap()
+        .query_wasm_smart(
+            &cw721_addr,
+            &QueryMsg::Extension {
+                msg: QueryExt::Soulbound {},
+            },
+        )
+        .unwrap();
+    assert!(soulbound);
+    let transfer = ExecuteMsg::TransferNft {
+        recipient: BOB.to_string(),
+        token_id: "1".to_string(),
+    };
+    app.execute_contract(Addr::unchecked(ALICE), cw721_addr.clone(), &transfer, &[])
+        .unwrap_err();
+
+    // Owners may transfer their tokens when they are not soulbound,
+    // and the token's weight moves with it
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        cw721_addr.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateSoulbound { soulbound: false },
+        },
+        &[],
+    )
+    .unwrap();
+    let err: RolesContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            cw721_addr.clone(),
+            &ExecuteMsg::TransferNft {
+                recipient: ALICE.to_string(),
+                token_id: "2".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, RolesContractError::NotTokenOwner {});
+    app.execute_contract(Addr::unchecked(ALICE), cw721_addr.clone(), &transfer, &[])
+        .unwrap();
+    let member = query_member(&app, &cw721_addr, ALICE, None).unwrap();
+    assert_eq!(member.weight, None);
+    let member = query_member(&app, &cw721_addr, BOB, None).unwrap();
+    assert_eq!(member.weight, Some(4));
+    let total = query_total_weight(&app, &cw721_addr, None).unwrap();
+    assert_eq!(total.weight, 4);
+}
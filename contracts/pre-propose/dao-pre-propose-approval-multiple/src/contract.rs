@@ -0,0 +1,132 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cw2::set_contract_version;
+use dao_pre_propose_approval_single::contract::{
+    execute_add_approver_hook, execute_remove_approver_hook, execute_update_approval_timeout,
+    execute_update_approver, execute_update_committee,
+};
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
+use dao_voting::proposal::MultipleChoiceProposeMsg as ProposeMsg;
+
+use crate::msg::{
+    ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
+};
+use crate::state::{APPROVAL_TIMEOUT, APPROVER, COMMITTEE, PROPOSALS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-multiple";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessage>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, PreProposeError> {
+    let approver = deps.api.addr_validate(&msg.extension.approver)?;
+    APPROVER.save(deps.storage, &approver)?;
+    if let Some(committee) = msg.extension.committee.clone() {
+        let committee = committee.into_checked(deps.as_ref())?;
+        COMMITTEE.save(deps.storage, &committee)?;
+    }
+    if let Some(timeout) = msg.extension.approval_timeout {
+        APPROVAL_TIMEOUT.save(deps.storage, &timeout)?;
+    }
+
+    let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(resp.add_attribute("approver", approver.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, PreProposeError> {
+    match msg {
+        ExecuteMsg::Propose { msg } => execute_propose(deps, env, info, msg),
+
+        ExecuteMsg::AddProposalSubmittedHook { address } => {
+            execute_add_approver_hook(deps, info, address)
+        }
+        ExecuteMsg::RemoveProposalSubmittedHook { address } => {
+            execute_remove_approver_hook(deps, info, address)
+        }
+
+        ExecuteMsg::Extension { msg } => match msg {
+            ExecuteExt::Approve { id } => PROPOSALS.approve(deps, env, info, id),
+            ExecuteExt::Reject { id } => PROPOSALS.reject(deps, info, id),
+            ExecuteExt::UpdateApprover { address } => execute_update_approver(deps, info, address),
+            ExecuteExt::UpdateCommittee { committee } => {
+                execute_update_committee(deps, info, committee)
+            }
+            ExecuteExt::UpdateApprovalTimeout { timeout } => {
+                execute_update_approval_timeout(deps, info, timeout)
+            }
+            ExecuteExt::ExpireProposal { id } => PROPOSALS.expire_proposal(deps, env, id),
+            ExecuteExt::WithdrawProposal { id } => PROPOSALS.withdraw(deps, info, id),
+            ExecuteExt::EditProposal { id, msg } => execute_edit_proposal(deps, env, info, id, msg),
+        },
+        // Proposals made through this module are reviewed by the
+        // approver and never seek sponsors.
+        ExecuteMsg::UpdateSponsorshipConfig { config: Some(_) } => {
+            Err(PreProposeError::SponsorshipUnsupported {})
+        }
+        // Default pre-propose-base behavior for all other messages
+        _ => PrePropose::default().execute(deps, env, info, msg),
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let msg = internalize(msg, &info.sender);
+    let (title, description) = (msg.title.clone(), msg.description.clone());
+    PROPOSALS.propose(deps, env, info, msg, title, description)
+}
+
+pub fn execute_edit_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let msg = internalize(msg, &info.sender);
+    let (title, description) = (msg.title.clone(), msg.description.clone());
+    PROPOSALS.edit(deps, env, info, id, msg, title, description)
+}
+
+/// Fills in the proposer of a propose message.
+fn internalize(msg: ProposeMessage, proposer: &Addr) -> ProposeMsg {
+    match msg {
+        ProposeMessage::Propose {
+            title,
+            description,
+            choices,
+            vote,
+        } => ProposeMsg {
+            title,
+            description,
+            choices,
+            proposer: Some(proposer.to_string()),
+            vote,
+        },
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::QueryExtension { msg } => PROPOSALS.query(deps, msg.into()),
+        _ => PrePropose::default().query(deps, env, msg),
+    }
+}
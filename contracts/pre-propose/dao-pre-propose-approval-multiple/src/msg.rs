@@ -0,0 +1,170 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
+use dao_pre_propose_approval_single::msg::{QueryExt as ApprovalQueryExt, UncheckedCommittee};
+use dao_pre_propose_base::msg::{
+    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+};
+use dao_voting::{
+    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    proposal::ProposalMsgs,
+};
+
+pub use dao_pre_propose_approval_single::msg::{
+    ApproverProposeMessage, InstantiateExt, PendingProposalHookMsg,
+};
+
+#[cw_serde]
+pub enum ProposeMessage {
+    Propose {
+        title: String,
+        description: String,
+        choices: MultipleChoiceOptions,
+        vote: Option<MultipleChoiceAutoVote>,
+    },
+}
+
+impl ProposalMsgs for ProposeMessage {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        match self {
+            ProposeMessage::Propose { choices, .. } => choices
+                .options
+                .iter()
+                .map(|option| option.msgs.as_slice())
+                .collect(),
+        }
+    }
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Approve a proposal, only callable by the approver or a
+    /// committee member. If sent by a committee member, records
+    /// their approval and approves the proposal once the
+    /// committee's threshold is met.
+    Approve { id: u64 },
+    /// Reject a proposal, only callable by the approver or a
+    /// committee member. If sent by a committee member, records
+    /// their rejection and rejects the proposal once enough members
+    /// have rejected it that the threshold can no longer be met.
+    Reject { id: u64 },
+    /// Updates the approver, can only be called the current approver
+    UpdateApprover { address: String },
+    /// Sets or removes the approval committee, can only be called by
+    /// the approver or the DAO.
+    UpdateCommittee {
+        committee: Option<UncheckedCommittee>,
+    },
+    /// Sets or removes the approval timeout for proposals submitted
+    /// after this is executed, can only be called by the approver or
+    /// the DAO.
+    UpdateApprovalTimeout { timeout: Option<Duration> },
+    /// Rejects a pending proposal whose approval timeout has passed.
+    /// Callable by anyone.
+    ExpireProposal { id: u64 },
+    /// Withdraws a pending proposal, only callable by its proposer.
+    WithdrawProposal { id: u64 },
+    /// Replaces the contents of a pending proposal, only callable by
+    /// its proposer. Committee signatures for the proposal are
+    /// cleared.
+    EditProposal { id: u64, msg: ProposeMessage },
+}
+
+/// The same queries as `dao-pre-propose-approval-single`, returning
+/// proposals with multiple choice propose messages.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// List the approver address
+    #[returns(cosmwasm_std::Addr)]
+    Approver {},
+    /// How long proposals may be pending before they expire, if set
+    #[returns(::std::option::Option<cw_utils::Duration>)]
+    ApprovalTimeout {},
+    /// The approval committee, if any
+    #[returns(::std::option::Option<crate::state::Committee>)]
+    Committee {},
+    /// Approvals and rejections recorded by committee members for a
+    /// proposal
+    #[returns(Vec<crate::state::Signature>)]
+    Signatures { id: u64 },
+    /// Return whether or not the proposal is pending
+    #[returns(bool)]
+    IsPending { id: u64 },
+    /// A proposal, pending or completed.
+    #[returns(crate::state::Proposal)]
+    Proposal { id: u64 },
+    /// A pending proposal
+    #[returns(crate::state::Proposal)]
+    PendingProposal { id: u64 },
+    /// List of proposals awaiting approval
+    #[returns(Vec<crate::state::Proposal>)]
+    PendingProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::Proposal>)]
+    ReversePendingProposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// A completed proposal
+    #[returns(crate::state::Proposal)]
+    CompletedProposal { id: u64 },
+    /// List of completed proposals
+    #[returns(Vec<crate::state::Proposal>)]
+    CompletedProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::Proposal>)]
+    ReverseCompletedProposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// The completed approval ID for a created proposal ID.
+    #[returns(::std::option::Option<u64>)]
+    CompletedProposalIdForCreatedProposalId { id: u64 },
+}
+
+impl From<QueryExt> for ApprovalQueryExt {
+    fn from(msg: QueryExt) -> Self {
+        match msg {
+            QueryExt::Approver {} => Self::Approver {},
+            QueryExt::ApprovalTimeout {} => Self::ApprovalTimeout {},
+            QueryExt::Committee {} => Self::Committee {},
+            QueryExt::Signatures { id } => Self::Signatures { id },
+            QueryExt::IsPending { id } => Self::IsPending { id },
+            QueryExt::Proposal { id } => Self::Proposal { id },
+            QueryExt::PendingProposal { id } => Self::PendingProposal { id },
+            QueryExt::PendingProposals { start_after, limit } => {
+                Self::PendingProposals { start_after, limit }
+            }
+            QueryExt::ReversePendingProposals {
+                start_before,
+                limit,
+            } => Self::ReversePendingProposals {
+                start_before,
+                limit,
+            },
+            QueryExt::CompletedProposal { id } => Self::CompletedProposal { id },
+            QueryExt::CompletedProposals { start_after, limit } => {
+                Self::CompletedProposals { start_after, limit }
+            }
+            QueryExt::ReverseCompletedProposals {
+                start_before,
+                limit,
+            } => Self::ReverseCompletedProposals {
+                start_before,
+                limit,
+            },
+            QueryExt::CompletedProposalIdForCreatedProposalId { id } => {
+                Self::CompletedProposalIdForCreatedProposalId { id }
+            }
+        }
+    }
+}
+
+pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
@@ -0,0 +1,14 @@
+use dao_pre_propose_approval_single::state::ApprovalProposals;
+use dao_voting::proposal::MultipleChoiceProposeMsg as ProposeMsg;
+
+// Approval state that does not depend on the proposal module's
+// propose message is shared with `dao-pre-propose-approval-single`.
+pub use dao_pre_propose_approval_single::state::{
+    Committee, ProposalStatus, Signature, APPROVAL_TIMEOUT, APPROVER, COMMITTEE,
+    CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL, SIGNATURES,
+};
+
+pub type Proposal = dao_pre_propose_approval_single::state::Proposal<ProposeMsg>;
+
+pub const PROPOSALS: ApprovalProposals<ProposeMsg> =
+    ApprovalProposals::new("pending_proposals", "completed_proposals");
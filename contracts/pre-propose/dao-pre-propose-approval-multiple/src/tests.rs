@@ -0,0 +1,535 @@
+use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Decimal, Empty, Uint128};
+use cpm::query::ProposalResponse;
+use cw2::ContractVersion;
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_interface::state::ProposalModule;
+use dao_interface::state::{Admin, ModuleInstantiateInfo};
+use dao_pre_propose_approval_single::msg::UncheckedCommittee;
+use dao_pre_propose_base::{error::PreProposeError, state::SponsorshipConfig};
+use dao_proposal_multiple as cpm;
+use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    multiple_choice::{
+        MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
+    },
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    status::Status,
+    threshold::PercentageThreshold,
+};
+
+use crate::state::{Proposal, ProposalStatus};
+use crate::{contract::*, msg::*};
+
+fn cw_dao_proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cpm::contract::execute,
+        cpm::contract::instantiate,
+        cpm::contract::query,
+    )
+    .with_reply(cpm::contract::reply);
+    Box::new(contract)
+}
+
+fn cw_pre_propose_approval_multiple() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+fn get_default_proposal_module_instantiate(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    committee: Option<UncheckedCommittee>,
+) -> cpm::msg::InstantiateMsg {
+    let pre_propose_id = app.store_code(cw_pre_propose_approval_multiple());
+
+    cpm::msg::InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        max_voting_period: Duration::Time(86400),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&InstantiateMsg {
+                    deposit_info,
+                    open_proposal_submission: false,
+                    extension: InstantiateExt {
+                        approver: "approver".to_string(),
+                        committee,
+                        approval_timeout: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "baby's first pre-propose module".to_string(),
+            },
+        },
+        close_proposal_on_execution_failure: false,
+        veto: None,
+        winner_threshold: None,
+    }
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    proposal_multiple: Addr,
+    pre_propose: Addr,
+}
+
+fn setup_default_test(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    committee: Option<UncheckedCommittee>,
+) -> DefaultTestSetup {
+    let cpm_id = app.store_code(cw_dao_proposal_multiple_contract());
+
+    let proposal_module_instantiate =
+        get_default_proposal_module_instantiate(app, deposit_info, committee);
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        cpm_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_multiple = proposal_modules.into_iter().next().unwrap().address;
+    let proposal_creation_policy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple.clone(),
+            &cpm::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    DefaultTestSetup {
+        core_addr,
+        proposal_multiple,
+        pre_propose,
+    }
+}
+
+fn choices(titles: &[&str]) -> MultipleChoiceOptions {
+    MultipleChoiceOptions {
+        options: titles
+            .iter()
+            .map(|title| MultipleChoiceOption {
+                title: title.to_string(),
+                description: "multiple choice option".to_string(),
+                msgs: vec![],
+            })
+            .collect(),
+    }
+}
+
+fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[Coin]) -> u64 {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: choices(&["one", "two"]),
+                vote: None,
+            },
+        },
+        funds,
+    )
+    .unwrap();
+
+    // Query for pending proposal and return latest id.
+    let mut pending: Vec<Proposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+
+    pending.pop().unwrap().approval_id
+}
+
+fn execute_ext(app: &mut App, pre_propose: Addr, sender: &str, msg: ExecuteExt) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        pre_propose,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_ext_should_fail(
+    app: &mut App,
+    pre_propose: Addr,
+    sender: &str,
+    msg: ExecuteExt,
+) -> PreProposeError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        pre_propose,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+fn query_proposal(app: &App, pre_propose: Addr, id: u64) -> Proposal {
+    app.wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Proposal { id },
+            },
+        )
+        .unwrap()
+}
+
+fn mint_natives(app: &mut App, receiver: &str, coins: Vec<Coin>) {
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: receiver.to_string(),
+        amount: coins,
+    }))
+    .unwrap();
+}
+
+fn get_balance_native(app: &App, who: &str, denom: &str) -> Uint128 {
+    let res = app.wrap().query_balance(who, denom).unwrap();
+    res.amount
+}
+
+fn native_deposit(refund_policy: DepositRefundPolicy) -> Option<UncheckedDepositInfo> {
+    Some(UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+        },
+        amount: Uint128::new(10),
+        refund_policy,
+    })
+}
+
+#[test]
+fn test_approval_and_execution() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        native_deposit(DepositRefundPolicy::OnlyPassed),
+        None,
+    );
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let pre_propose_id =
+        make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+
+    let pending = query_proposal(&app, pre_propose.clone(), pre_propose_id);
+    assert_eq!(pending.status, ProposalStatus::Pending {});
+    assert_eq!(pending.msg.choices, choices(&["one", "two"]));
+    assert_eq!(pending.msg.proposer, Some("ekez".to_string()));
+
+    // Only the approver may approve.
+    let err = execute_ext_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Approve { id: pre_propose_id },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    execute_ext(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id: pre_propose_id },
+    );
+
+    let proposal_id: u64 = app
+        .wrap()
+        .query_wasm_smart(&proposal_multiple, &cpm::msg::QueryMsg::NextProposalId {})
+        .unwrap();
+    let proposal_id = proposal_id - 1;
+    assert_eq!(
+        query_proposal(&app, pre_propose.clone(), pre_propose_id).status,
+        ProposalStatus::Approved {
+            created_proposal_id: proposal_id
+        }
+    );
+    let completed: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::CompletedProposalIdForCreatedProposalId { id: proposal_id },
+            },
+        )
+        .unwrap();
+    assert_eq!(completed, Some(pre_propose_id));
+
+    // The created proposal has the proposed choices plus "none of
+    // the above" and is attributed to the proposer.
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_multiple,
+            &cpm::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    assert_eq!(proposal.proposal.choices.len(), 3);
+    assert_eq!(proposal.proposal.choices[1].title, "two");
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_multiple.clone(),
+        &cpm::msg::ExecuteMsg::Vote {
+            proposal_id,
+            vote: MultipleChoiceVote { option_id: 0 },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_multiple,
+        &cpm::msg::ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
+
+    // The proposal passed, so the deposit is refunded.
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+}
+
+#[test]
+fn test_rejection() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        native_deposit(DepositRefundPolicy::OnlyPassed),
+        None,
+    );
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let pre_propose_id =
+        make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    execute_ext(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Reject { id: pre_propose_id },
+    );
+
+    assert_eq!(
+        query_proposal(&app, pre_propose, pre_propose_id).status,
+        ProposalStatus::Rejected {}
+    );
+    // No proposal was created and the deposit went to the DAO.
+    let next_id: u64 = app
+        .wrap()
+        .query_wasm_smart(&proposal_multiple, &cpm::msg::QueryMsg::NextProposalId {})
+        .unwrap();
+    assert_eq!(next_id, 1);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn test_edit_and_withdraw() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(&mut app, native_deposit(DepositRefundPolicy::Always), None);
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let pre_propose_id =
+        make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    let edit = ExecuteExt::EditProposal {
+        id: pre_propose_id,
+        msg: ProposeMessage::Propose {
+            title: "new title".to_string(),
+            description: "description".to_string(),
+            choices: choices(&["three", "four", "five"]),
+            vote: None,
+        },
+    };
+    let err = execute_ext_should_fail(&mut app, pre_propose.clone(), "keze", edit.clone());
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute_ext(&mut app, pre_propose.clone(), "ekez", edit);
+
+    let edited = query_proposal(&app, pre_propose.clone(), pre_propose_id);
+    assert_eq!(edited.msg.title, "new title");
+    assert_eq!(edited.msg.choices, choices(&["three", "four", "five"]));
+
+    execute_ext(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::WithdrawProposal { id: pre_propose_id },
+    );
+    assert_eq!(
+        query_proposal(&app, pre_propose, pre_propose_id).status,
+        ProposalStatus::Withdrawn {}
+    );
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+}
+
+#[test]
+fn test_approval_committee() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        None,
+        Some(UncheckedCommittee {
+            approvers: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            threshold: 2,
+        }),
+    );
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+
+    execute_ext(
+        &mut app,
+        pre_propose.clone(),
+        "a",
+        ExecuteExt::Approve { id: pre_propose_id },
+    );
+    assert_eq!(
+        query_proposal(&app, pre_propose.clone(), pre_propose_id).status,
+        ProposalStatus::Pending {}
+    );
+
+    execute_ext(
+        &mut app,
+        pre_propose.clone(),
+        "b",
+        ExecuteExt::Approve { id: pre_propose_id },
+    );
+    assert_eq!(
+        query_proposal(&app, pre_propose, pre_propose_id).status,
+        ProposalStatus::Approved {
+            created_proposal_id: 1
+        }
+    );
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_multiple,
+            &cpm::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.status, Status::Open);
+}
+
+#[test]
+fn test_info_query_works() {
+    let mut app = App::default();
+    let DefaultTestSetup { pre_propose, .. } = setup_default_test(&mut app, None, None);
+    let info: ContractVersion = from_json(
+        app.wrap()
+            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
+            .unwrap()
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string()
+        },
+        info
+    )
+}
+
+#[test]
+fn test_sponsorship_unsupported() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        pre_propose,
+        ..
+    } = setup_default_test(&mut app, None, None);
+
+    let update = |app: &mut App, config: Option<SponsorshipConfig>| {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateSponsorshipConfig { config },
+            &[],
+        )
+    };
+    let err: PreProposeError = update(
+        &mut app,
+        Some(SponsorshipConfig {
+            min_sponsors: 1,
+            min_power: Uint128::new(1),
+            window: Duration::Height(10),
+        }),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::SponsorshipUnsupported {});
+
+    // Clearing the config is still allowed.
+    update(&mut app, None).unwrap();
+}
@@ -0,0 +1,518 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Response, StdResult, SubMsg, WasmMsg,
+};
+use cw_paginate_storage::paginate_map_values;
+use dao_pre_propose_base::{
+    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
+};
+use dao_voting::{proposal::ProposalMsgs, status::Status};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::msg::{
+    ApproverProposeMessage, PendingProposalHookMsg, ProposeMessageInternal, QueryExt,
+};
+use crate::state::{
+    advance_approval_id, ApprovalProposals, Proposal, ProposalStatus, Signature, APPROVAL_TIMEOUT,
+    APPROVER, COMMITTEE, CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL, SIGNATURES,
+};
+
+/// The pre-propose base contract. Only its storage is accessed here,
+/// which does not depend on its message types.
+type PrePropose = PreProposeContract<Empty, Empty, Empty, Empty>;
+
+/// Whether a committee member's approval or rejection completes a
+/// proposal.
+enum Signed {
+    /// The approver approved or rejected the proposal, or enough
+    /// committee members have.
+    Complete,
+    /// The signature was recorded but more are needed.
+    Pending { approvals: u32, rejections: u32 },
+}
+
+impl<Msg> ApprovalProposals<Msg>
+where
+    Msg: Serialize + DeserializeOwned + Clone + ProposalMsgs,
+{
+    /// Saves MSG as a pending proposal, taking the proposer's deposit
+    /// and notifying proposal submitted hook receivers.
+    pub fn propose(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Msg,
+        title: String,
+        description: String,
+    ) -> Result<Response, PreProposeError> {
+        let pre_propose_base = PrePropose::default();
+
+        pre_propose_base.check_proposal_msgs(deps.as_ref(), &msg)?;
+        pre_propose_base.check_can_submit(deps.as_ref(), &env.block, info.sender.clone())?;
+        pre_propose_base.record_submission(deps.storage, &env.block, &info.sender)?;
+
+        // Take deposit, if configured.
+        let (deposit, deposit_messages) =
+            pre_propose_base.take_deposit(deps.as_ref(), &env, &info)?;
+
+        let approval_id = advance_approval_id(deps.storage)?;
+        let expiration = APPROVAL_TIMEOUT
+            .may_load(deps.storage)?
+            .map(|timeout| timeout.after(&env.block))
+            .unwrap_or_default();
+
+        // Prepare proposal submitted hooks msg to notify approver.  Make
+        // a proposal on the approver DAO to approve this pre-proposal
+        let hooks_msgs =
+            pre_propose_base
+                .proposal_submitted_hooks
+                .prepare_hooks(deps.storage, |a| {
+                    let execute_msg = WasmMsg::Execute {
+                        contract_addr: a.into_string(),
+                        msg: to_json_binary(
+                            &ExecuteBase::<ApproverProposeMessage, Empty>::Propose {
+                                msg: ApproverProposeMessage::Propose {
+                                    title: title.clone(),
+                                    description: description.clone(),
+                                    approval_id,
+                                },
+                            },
+                        )?,
+                        funds: vec![],
+                    };
+                    Ok(SubMsg::new(execute_msg))
+                })?;
+
+        // Save the proposal and its information as pending.
+        self.pending.save(
+            deps.storage,
+            approval_id,
+            &Proposal {
+                status: ProposalStatus::Pending {},
+                approval_id,
+                proposer: info.sender,
+                msg,
+                deposit,
+                expiration,
+            },
+        )?;
+
+        Ok(Response::default()
+            .add_messages(deposit_messages)
+            .add_submessages(hooks_msgs)
+            .add_attribute("method", "pre-propose")
+            .add_attribute("id", approval_id.to_string()))
+    }
+
+    pub fn approve(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        // Expired proposals may not be approved. They are rejected
+        // instead so that an approver acting late does not error.
+        if let Some(proposal) = self.pending.may_load(deps.storage, id)? {
+            if proposal.expiration.is_expired(&env.block) {
+                return self.expire(deps, id);
+            }
+        }
+
+        if let Signed::Pending {
+            approvals,
+            rejections,
+        } = self.sign(deps.branch(), &info, id, true)?
+        {
+            return Ok(signature_recorded(id, approvals, rejections));
+        }
+
+        // Load proposal and send propose message to the proposal module
+        let proposal = self.pending.may_load(deps.storage, id)?;
+        match proposal {
+            Some(proposal) => {
+                let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
+
+                // Snapshot the deposit for the proposal that we're about
+                // to create.
+                let proposal_id = deps.querier.query_wasm_smart(
+                    &proposal_module,
+                    &dao_interface::proposal::Query::NextProposalId {},
+                )?;
+                PrePropose::default().deposits.save(
+                    deps.storage,
+                    proposal_id,
+                    &(proposal.deposit.clone(), proposal.proposer.clone()),
+                )?;
+
+                let propose_messsage = WasmMsg::Execute {
+                    contract_addr: proposal_module.into_string(),
+                    msg: to_json_binary(&ProposeMessageInternal::Propose(proposal.msg.clone()))?,
+                    funds: vec![],
+                };
+
+                self.completed.save(
+                    deps.storage,
+                    id,
+                    &Proposal {
+                        status: ProposalStatus::Approved {
+                            created_proposal_id: proposal_id,
+                        },
+                        ..proposal
+                    },
+                )?;
+                CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL.save(deps.storage, proposal_id, &id)?;
+                self.pending.remove(deps.storage, id);
+
+                Ok(Response::default()
+                    .add_message(propose_messsage)
+                    .add_attribute("method", "proposal_approved")
+                    .add_attribute("approval_id", id.to_string())
+                    .add_attribute("proposal_id", proposal_id.to_string()))
+            }
+            None => Err(PreProposeError::ProposalNotFound {}),
+        }
+    }
+
+    pub fn reject(
+        &self,
+        mut deps: DepsMut,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        if let Signed::Pending {
+            approvals,
+            rejections,
+        } = self.sign(deps.branch(), &info, id, false)?
+        {
+            return Ok(signature_recorded(id, approvals, rejections));
+        }
+
+        let (proposal, messages) = self.close_pending(deps, id, ProposalStatus::Rejected {})?;
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_rejected")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute(
+                "deposit_info",
+                to_json_binary(&proposal.deposit)?.to_string(),
+            )
+            .add_messages(messages))
+    }
+
+    /// Rejects a pending proposal whose approval timeout has passed.
+    pub fn expire_proposal(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let proposal = self
+            .pending
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        if !proposal.expiration.is_expired(&env.block) {
+            return Err(PreProposeError::NotExpired {});
+        }
+        self.expire(deps, id)
+    }
+
+    pub fn withdraw(
+        &self,
+        mut deps: DepsMut,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let proposal = self
+            .pending
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        if proposal.proposer != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        let (proposal, messages) =
+            self.close_pending(deps.branch(), id, ProposalStatus::Withdrawn {})?;
+        let hooks = pending_proposal_hooks(
+            deps.as_ref(),
+            PendingProposalHookMsg::PendingProposalWithdrawn { approval_id: id },
+        )?;
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_withdrawn")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute(
+                "deposit_info",
+                to_json_binary(&proposal.deposit)?.to_string(),
+            )
+            .add_messages(messages)
+            .add_submessages(hooks))
+    }
+
+    /// Replaces the propose message of a pending proposal with MSG,
+    /// whose title and description are TITLE and DESCRIPTION.
+    pub fn edit(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+        msg: Msg,
+        title: String,
+        description: String,
+    ) -> Result<Response, PreProposeError> {
+        PrePropose::default().check_proposal_msgs(deps.as_ref(), &msg)?;
+        let mut proposal = self
+            .pending
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        if proposal.proposer != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+        if proposal.expiration.is_expired(&env.block) {
+            return Err(PreProposeError::Expired {});
+        }
+
+        proposal.msg = msg;
+        self.pending.save(deps.storage, id, &proposal)?;
+
+        // Committee members signed the old contents.
+        let signers = SIGNATURES
+            .prefix(id)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?;
+        for signer in signers {
+            SIGNATURES.remove(deps.storage, (id, &signer));
+        }
+
+        let hooks = pending_proposal_hooks(
+            deps.as_ref(),
+            PendingProposalHookMsg::PendingProposalEdited {
+                approval_id: id,
+                title,
+                description,
+            },
+        )?;
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_edited")
+            .add_attribute("proposal", id.to_string())
+            .add_submessages(hooks))
+    }
+
+    pub fn query(&self, deps: Deps, msg: QueryExt) -> StdResult<Binary> {
+        match msg {
+            QueryExt::Approver {} => to_json_binary(&APPROVER.load(deps.storage)?),
+            QueryExt::ApprovalTimeout {} => {
+                to_json_binary(&APPROVAL_TIMEOUT.may_load(deps.storage)?)
+            }
+            QueryExt::Committee {} => to_json_binary(&COMMITTEE.may_load(deps.storage)?),
+            QueryExt::Signatures { id } => to_json_binary(
+                &SIGNATURES
+                    .prefix(id)
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .map(|signature| {
+                        signature.map(|(approver, approved)| Signature { approver, approved })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?,
+            ),
+            QueryExt::IsPending { id } => {
+                let pending = self.pending.may_load(deps.storage, id)?.is_some();
+                // Force load completed proposal if not pending, throwing error
+                // if not found.
+                if !pending {
+                    self.completed.load(deps.storage, id)?;
+                }
+
+                to_json_binary(&pending)
+            }
+            QueryExt::Proposal { id } => {
+                if let Some(pending) = self.pending.may_load(deps.storage, id)? {
+                    to_json_binary(&pending)
+                } else {
+                    // Force load completed proposal if not pending, throwing
+                    // error if not found.
+                    to_json_binary(&self.completed.load(deps.storage, id)?)
+                }
+            }
+            QueryExt::PendingProposal { id } => {
+                to_json_binary(&self.pending.load(deps.storage, id)?)
+            }
+            QueryExt::PendingProposals { start_after, limit } => to_json_binary(
+                &paginate_map_values(deps, &self.pending, start_after, limit, Order::Ascending)?,
+            ),
+            QueryExt::ReversePendingProposals {
+                start_before,
+                limit,
+            } => to_json_binary(&paginate_map_values(
+                deps,
+                &self.pending,
+                start_before,
+                limit,
+                Order::Descending,
+            )?),
+            QueryExt::CompletedProposal { id } => {
+                to_json_binary(&self.completed.load(deps.storage, id)?)
+            }
+            QueryExt::CompletedProposals { start_after, limit } => to_json_binary(
+                &paginate_map_values(deps, &self.completed, start_after, limit, Order::Ascending)?,
+            ),
+            QueryExt::ReverseCompletedProposals {
+                start_before,
+                limit,
+            } => to_json_binary(&paginate_map_values(
+                deps,
+                &self.completed,
+                start_before,
+                limit,
+                Order::Descending,
+            )?),
+            QueryExt::CompletedProposalIdForCreatedProposalId { id } => {
+                to_json_binary(&CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL.may_load(deps.storage, id)?)
+            }
+        }
+    }
+
+    fn expire(&self, mut deps: DepsMut, id: u64) -> Result<Response, PreProposeError> {
+        let (proposal, messages) =
+            self.close_pending(deps.branch(), id, ProposalStatus::Expired {})?;
+        let hooks = pending_proposal_hooks(
+            deps.as_ref(),
+            PendingProposalHookMsg::PendingProposalExpired { approval_id: id },
+        )?;
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_expired")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute(
+                "deposit_info",
+                to_json_binary(&proposal.deposit)?.to_string(),
+            )
+            .add_messages(messages)
+            .add_submessages(hooks))
+    }
+
+    /// Checks that the sender may approve or reject the proposal
+    /// identified by ID and, if they are a committee member, records
+    /// their signature.
+    fn sign(
+        &self,
+        deps: DepsMut,
+        info: &MessageInfo,
+        id: u64,
+        approve: bool,
+    ) -> Result<Signed, PreProposeError> {
+        // The approver may approve or reject on their own.
+        let approver = APPROVER.load(deps.storage)?;
+        if approver == info.sender {
+            return Ok(Signed::Complete);
+        }
+
+        let committee = COMMITTEE
+            .may_load(deps.storage)?
+            .filter(|c| c.approvers.contains(&info.sender))
+            .ok_or(PreProposeError::Unauthorized {})?;
+        if !self.pending.has(deps.storage, id) {
+            return Err(PreProposeError::ProposalNotFound {});
+        }
+        SIGNATURES.save(deps.storage, (id, &info.sender), &approve)?;
+
+        // Only count signatures from current committee members.
+        let (approvals, rejections) = committee.approvers.iter().try_fold(
+            (0u32, 0u32),
+            |(approvals, rejections), member| -> StdResult<_> {
+                Ok(match SIGNATURES.may_load(deps.storage, (id, member))? {
+                    Some(true) => (approvals + 1, rejections),
+                    Some(false) => (approvals, rejections + 1),
+                    None => (approvals, rejections),
+                })
+            },
+        )?;
+        let members = committee.approvers.len() as u32;
+        let complete = if approve {
+            approvals >= committee.threshold
+        } else {
+            // Reject once the remaining members can not approve.
+            rejections > members - committee.threshold
+        };
+        Ok(if complete {
+            Signed::Complete
+        } else {
+            Signed::Pending {
+                approvals,
+                rejections,
+            }
+        })
+    }
+
+    /// Completes a pending proposal that will not be approved,
+    /// returning it along with the messages needed to settle its
+    /// deposit.
+    fn close_pending(
+        &self,
+        deps: DepsMut,
+        id: u64,
+        status: ProposalStatus,
+    ) -> Result<(Proposal<Msg>, Vec<CosmosMsg>), PreProposeError> {
+        let mut proposal = self
+            .pending
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        proposal.status = status;
+
+        self.completed.save(deps.storage, id, &proposal)?;
+        self.pending.remove(deps.storage, id);
+        PrePropose::default().record_completion(deps.storage, &proposal.proposer)?;
+
+        let messages = match (&proposal.deposit, &proposal.status) {
+            // Proposers withdrawing their own proposal get their
+            // deposit back.
+            (Some(deposit_info), ProposalStatus::Withdrawn {}) => {
+                deposit_info.get_return_deposit_message(&proposal.proposer)?
+            }
+            // A proposal that is not approved is settled like a
+            // proposal that was voted down and closed: refunded only
+            // if deposits are always refunded, and slashed at the
+            // rejected rate if slashing is configured.
+            (Some(deposit_info), _) => {
+                let dao = PrePropose::default().dao.load(deps.storage)?;
+                deposit_info.get_completion_messages(
+                    Status::Closed,
+                    None,
+                    &proposal.proposer,
+                    &dao,
+                )?
+            }
+            (None, _) => vec![],
+        };
+        Ok((proposal, messages))
+    }
+}
+
+fn signature_recorded(id: u64, approvals: u32, rejections: u32) -> Response {
+    Response::default()
+        .add_attribute("method", "signature_recorded")
+        .add_attribute("approval_id", id.to_string())
+        .add_attribute("approvals", approvals.to_string())
+        .add_attribute("rejections", rejections.to_string())
+}
+
+/// Notifies proposal submitted hook receivers that a pending proposal
+/// has changed.
+fn pending_proposal_hooks(deps: Deps, msg: PendingProposalHookMsg) -> StdResult<Vec<SubMsg>> {
+    PrePropose::default()
+        .proposal_submitted_hooks
+        .prepare_hooks(deps.storage, |a| {
+            Ok(SubMsg::new(WasmMsg::Execute {
+                contract_addr: a.into_string(),
+                msg: to_json_binary(&ExecuteBase::<
+                    ApproverProposeMessage,
+                    PendingProposalHookMsg,
+                >::Extension {
+                    msg: msg.clone(),
+                })?,
+                funds: vec![],
+            }))
+        })
+}
@@ -1,25 +1,16 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
-    SubMsg, WasmMsg,
-};
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::set_contract_version;
-use cw_paginate_storage::paginate_map_values;
-use dao_pre_propose_base::{
-    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
-};
-use dao_voting::deposit::DepositRefundPolicy;
+use cw_utils::Duration;
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
 
 use crate::msg::{
-    ApproverProposeMessage, ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage,
-    ProposeMessageInternal, QueryExt, QueryMsg,
-};
-use crate::state::{
-    advance_approval_id, Proposal, ProposalStatus, APPROVER, COMPLETED_PROPOSALS,
-    CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL, PENDING_PROPOSALS,
+    ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
+    UncheckedCommittee,
 };
+use crate::state::{APPROVAL_TIMEOUT, APPROVER, COMMITTEE, PROPOSALS};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -35,6 +26,13 @@ pub fn instantiate(
 ) -> Result<Response, PreProposeError> {
     let approver = deps.api.addr_validate(&msg.extension.approver)?;
     APPROVER.save(deps.storage, &approver)?;
+    if let Some(committee) = msg.extension.committee.clone() {
+        let committee = committee.into_checked(deps.as_ref())?;
+        COMMITTEE.save(deps.storage, &committee)?;
+    }
+    if let Some(timeout) = msg.extension.approval_timeout {
+        APPROVAL_TIMEOUT.save(deps.storage, &timeout)?;
+    }
 
     let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -59,10 +57,24 @@ pub fn execute(
         }
 
         ExecuteMsg::Extension { msg } => match msg {
-            ExecuteExt::Approve { id } => execute_approve(deps, info, id),
-            ExecuteExt::Reject { id } => execute_reject(deps, info, id),
+            ExecuteExt::Approve { id } => PROPOSALS.approve(deps, env, info, id),
+            ExecuteExt::Reject { id } => PROPOSALS.reject(deps, info, id),
             ExecuteExt::UpdateApprover { address } => execute_update_approver(deps, info, address),
+            ExecuteExt::UpdateCommittee { committee } => {
+                execute_update_committee(deps, info, committee)
+            }
+            ExecuteExt::UpdateApprovalTimeout { timeout } => {
+                execute_update_approval_timeout(deps, info, timeout)
+            }
+            ExecuteExt::ExpireProposal { id } => PROPOSALS.expire_proposal(deps, env, id),
+            ExecuteExt::WithdrawProposal { id } => PROPOSALS.withdraw(deps, info, id),
+            ExecuteExt::EditProposal { id, msg } => execute_edit_proposal(deps, env, info, id, msg),
         },
+        // Proposals made through this module are reviewed by the
+        // approver and never seek sponsors.
+        ExecuteMsg::UpdateSponsorshipConfig { config: Some(_) } => {
+            Err(PreProposeError::SponsorshipUnsupported {})
+        }
         // Default pre-propose-base behavior for all other messages
         _ => PrePropose::default().execute(deps, env, info, msg),
     }
@@ -74,22 +86,26 @@ pub fn execute_propose(
     info: MessageInfo,
     msg: ProposeMessage,
 ) -> Result<Response, PreProposeError> {
-    let pre_propose_base = PrePropose::default();
-    let config = pre_propose_base.config.load(deps.storage)?;
-
-    pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
-
-    // Take deposit, if configured.
-    let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-        deposit_info.check_native_deposit_paid(&info)?;
-        deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
-    } else {
-        vec![]
-    };
+    let msg = internalize(msg, &info.sender);
+    let (title, description) = (msg.title.clone(), msg.description.clone());
+    PROPOSALS.propose(deps, env, info, msg, title, description)
+}
 
-    let approval_id = advance_approval_id(deps.storage)?;
+pub fn execute_edit_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let msg = internalize(msg, &info.sender);
+    let (title, description) = (msg.title.clone(), msg.description.clone());
+    PROPOSALS.edit(deps, env, info, id, msg, title, description)
+}
 
-    let propose_msg_internal = match msg {
+/// Fills in the proposer of a propose message.
+fn internalize(msg: ProposeMessage, proposer: &Addr) -> ProposeMsg {
+    match msg {
         ProposeMessage::Propose {
             title,
             description,
@@ -99,55 +115,16 @@ pub fn execute_propose(
             title,
             description,
             msgs,
-            proposer: Some(info.sender.to_string()),
+            proposer: Some(proposer.to_string()),
             vote,
         },
-    };
-
-    // Prepare proposal submitted hooks msg to notify approver.  Make
-    // a proposal on the approver DAO to approve this pre-proposal
-    let hooks_msgs =
-        pre_propose_base
-            .proposal_submitted_hooks
-            .prepare_hooks(deps.storage, |a| {
-                let execute_msg = WasmMsg::Execute {
-                    contract_addr: a.into_string(),
-                    msg: to_json_binary(&ExecuteBase::<ApproverProposeMessage, Empty>::Propose {
-                        msg: ApproverProposeMessage::Propose {
-                            title: propose_msg_internal.title.clone(),
-                            description: propose_msg_internal.description.clone(),
-                            approval_id,
-                        },
-                    })?,
-                    funds: vec![],
-                };
-                Ok(SubMsg::new(execute_msg))
-            })?;
-
-    // Save the proposal and its information as pending.
-    PENDING_PROPOSALS.save(
-        deps.storage,
-        approval_id,
-        &Proposal {
-            status: ProposalStatus::Pending {},
-            approval_id,
-            proposer: info.sender,
-            msg: propose_msg_internal,
-            deposit: config.deposit_info,
-        },
-    )?;
-
-    Ok(Response::default()
-        .add_messages(deposit_messages)
-        .add_submessages(hooks_msgs)
-        .add_attribute("method", "pre-propose")
-        .add_attribute("id", approval_id.to_string()))
+    }
 }
 
-pub fn execute_approve(
+pub fn execute_update_approver(
     deps: DepsMut,
     info: MessageInfo,
-    id: u64,
+    address: String,
 ) -> Result<Response, PreProposeError> {
     // Check sender is the approver
     let approver = APPROVER.load(deps.storage)?;
@@ -155,128 +132,56 @@ pub fn execute_approve(
         return Err(PreProposeError::Unauthorized {});
     }
 
-    // Load proposal and send propose message to the proposal module
-    let proposal = PENDING_PROPOSALS.may_load(deps.storage, id)?;
-    match proposal {
-        Some(proposal) => {
-            let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
-
-            // Snapshot the deposit for the proposal that we're about
-            // to create.
-            let proposal_id = deps.querier.query_wasm_smart(
-                &proposal_module,
-                &dao_interface::proposal::Query::NextProposalId {},
-            )?;
-            PrePropose::default().deposits.save(
-                deps.storage,
-                proposal_id,
-                &(proposal.deposit.clone(), proposal.proposer.clone()),
-            )?;
-
-            let propose_messsage = WasmMsg::Execute {
-                contract_addr: proposal_module.into_string(),
-                msg: to_json_binary(&ProposeMessageInternal::Propose(proposal.msg.clone()))?,
-                funds: vec![],
-            };
-
-            COMPLETED_PROPOSALS.save(
-                deps.storage,
-                id,
-                &Proposal {
-                    status: ProposalStatus::Approved {
-                        created_proposal_id: proposal_id,
-                    },
-                    approval_id: proposal.approval_id,
-                    proposer: proposal.proposer,
-                    msg: proposal.msg,
-                    deposit: proposal.deposit,
-                },
-            )?;
-            CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL.save(deps.storage, proposal_id, &id)?;
-            PENDING_PROPOSALS.remove(deps.storage, id);
-
-            Ok(Response::default()
-                .add_message(propose_messsage)
-                .add_attribute("method", "proposal_approved")
-                .add_attribute("approval_id", id.to_string())
-                .add_attribute("proposal_id", proposal_id.to_string()))
-        }
-        None => Err(PreProposeError::ProposalNotFound {}),
-    }
+    // Validate address and save new approver
+    let addr = deps.api.addr_validate(&address)?;
+    APPROVER.save(deps.storage, &addr)?;
+
+    Ok(Response::default())
 }
 
-pub fn execute_reject(
+pub fn execute_update_committee(
     deps: DepsMut,
     info: MessageInfo,
-    id: u64,
+    committee: Option<UncheckedCommittee>,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
+    let dao = PrePropose::default().dao.load(deps.storage)?;
     let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
+
+    // Check sender is the approver or the parent DAO
+    if approver != info.sender && dao != info.sender {
         return Err(PreProposeError::Unauthorized {});
     }
 
-    let Proposal {
-        approval_id,
-        proposer,
-        msg,
-        deposit,
-        ..
-    } = PENDING_PROPOSALS
-        .may_load(deps.storage, id)?
-        .ok_or(PreProposeError::ProposalNotFound {})?;
-
-    COMPLETED_PROPOSALS.save(
-        deps.storage,
-        id,
-        &Proposal {
-            status: ProposalStatus::Rejected {},
-            approval_id,
-            proposer: proposer.clone(),
-            msg: msg.clone(),
-            deposit: deposit.clone(),
-        },
-    )?;
-    PENDING_PROPOSALS.remove(deps.storage, id);
-
-    let messages = if let Some(ref deposit_info) = deposit {
-        // Refund can be issued if proposal if deposits are always
-        // refunded. `OnlyPassed` and `Never` refund deposit policies
-        // do not apply here.
-        if deposit_info.refund_policy == DepositRefundPolicy::Always {
-            deposit_info.get_return_deposit_message(&proposer)?
-        } else {
-            // If the proposer doesn't get the deposit, the DAO does.
-            let dao = PrePropose::default().dao.load(deps.storage)?;
-            deposit_info.get_return_deposit_message(&dao)?
+    match committee {
+        Some(committee) => {
+            let committee = committee.into_checked(deps.as_ref())?;
+            COMMITTEE.save(deps.storage, &committee)?;
         }
-    } else {
-        vec![]
-    };
-
-    Ok(Response::default()
-        .add_attribute("method", "proposal_rejected")
-        .add_attribute("proposal", id.to_string())
-        .add_attribute("deposit_info", to_json_binary(&deposit)?.to_string())
-        .add_messages(messages))
+        None => COMMITTEE.remove(deps.storage),
+    }
+
+    Ok(Response::default().add_attribute("method", "update_committee"))
 }
 
-pub fn execute_update_approver(
+pub fn execute_update_approval_timeout(
     deps: DepsMut,
     info: MessageInfo,
-    address: String,
+    timeout: Option<Duration>,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
+    let dao = PrePropose::default().dao.load(deps.storage)?;
     let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
+
+    // Check sender is the approver or the parent DAO
+    if approver != info.sender && dao != info.sender {
         return Err(PreProposeError::Unauthorized {});
     }
 
-    // Validate address and save new approver
-    let addr = deps.api.addr_validate(&address)?;
-    APPROVER.save(deps.storage, &addr)?;
+    match timeout {
+        Some(timeout) => APPROVAL_TIMEOUT.save(deps.storage, &timeout)?,
+        None => APPROVAL_TIMEOUT.remove(deps.storage),
+    }
 
-    Ok(Response::default())
+    Ok(Response::default().add_attribute("method", "update_approval_timeout"))
 }
 
 pub fn execute_add_approver_hook(
@@ -331,75 +236,7 @@ pub fn execute_remove_approver_hook(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::QueryExtension { msg } => match msg {
-            QueryExt::Approver {} => to_json_binary(&APPROVER.load(deps.storage)?),
-            QueryExt::IsPending { id } => {
-                let pending = PENDING_PROPOSALS.may_load(deps.storage, id)?.is_some();
-                // Force load completed proposal if not pending, throwing error
-                // if not found.
-                if !pending {
-                    COMPLETED_PROPOSALS.load(deps.storage, id)?;
-                }
-
-                to_json_binary(&pending)
-            }
-            QueryExt::Proposal { id } => {
-                if let Some(pending) = PENDING_PROPOSALS.may_load(deps.storage, id)? {
-                    to_json_binary(&pending)
-                } else {
-                    // Force load completed proposal if not pending, throwing
-                    // error if not found.
-                    to_json_binary(&COMPLETED_PROPOSALS.load(deps.storage, id)?)
-                }
-            }
-            QueryExt::PendingProposal { id } => {
-                to_json_binary(&PENDING_PROPOSALS.load(deps.storage, id)?)
-            }
-            QueryExt::PendingProposals { start_after, limit } => {
-                to_json_binary(&paginate_map_values(
-                    deps,
-                    &PENDING_PROPOSALS,
-                    start_after,
-                    limit,
-                    Order::Ascending,
-                )?)
-            }
-            QueryExt::ReversePendingProposals {
-                start_before,
-                limit,
-            } => to_json_binary(&paginate_map_values(
-                deps,
-                &PENDING_PROPOSALS,
-                start_before,
-                limit,
-                Order::Descending,
-            )?),
-            QueryExt::CompletedProposal { id } => {
-                to_json_binary(&COMPLETED_PROPOSALS.load(deps.storage, id)?)
-            }
-            QueryExt::CompletedProposals { start_after, limit } => {
-                to_json_binary(&paginate_map_values(
-                    deps,
-                    &COMPLETED_PROPOSALS,
-                    start_after,
-                    limit,
-                    Order::Ascending,
-                )?)
-            }
-            QueryExt::ReverseCompletedProposals {
-                start_before,
-                limit,
-            } => to_json_binary(&paginate_map_values(
-                deps,
-                &COMPLETED_PROPOSALS,
-                start_before,
-                limit,
-                Order::Descending,
-            )?),
-            QueryExt::CompletedProposalIdForCreatedProposalId { id } => {
-                to_json_binary(&CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL.may_load(deps.storage, id)?)
-            }
-        },
+        QueryMsg::QueryExtension { msg } => PROPOSALS.query(deps, msg),
         _ => PrePropose::default().query(deps, env, msg),
     }
 }
@@ -1,9 +1,16 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CosmosMsg, Empty};
-use dao_pre_propose_base::msg::{
-    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+use cosmwasm_std::{CosmosMsg, Deps, Empty, StdError};
+use cw_utils::Duration;
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
 };
-use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+use dao_voting::{
+    proposal::{ProposalMsgs, SingleChoiceProposeMsg as ProposeMsg},
+    voting::SingleChoiceAutoVote,
+};
+
+use crate::state::Committee;
 
 #[cw_serde]
 pub enum ApproverProposeMessage {
@@ -14,6 +21,14 @@ pub enum ApproverProposeMessage {
     },
 }
 
+impl ProposalMsgs for ApproverProposeMessage {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        // Proposals made to approve a pending proposal execute
+        // nothing.
+        vec![]
+    }
+}
+
 #[cw_serde]
 pub enum ProposeMessage {
     Propose {
@@ -24,19 +39,117 @@ pub enum ProposeMessage {
     },
 }
 
+impl ProposalMsgs for ProposeMessage {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        match self {
+            ProposeMessage::Propose { msgs, .. } => vec![msgs],
+        }
+    }
+}
+
+/// A committee of approvers, a threshold of which must approve a
+/// proposal for it to be approved.
+#[cw_serde]
+pub struct UncheckedCommittee {
+    /// The members of the committee.
+    pub approvers: Vec<String>,
+    /// The number of members that must approve a proposal. Must be
+    /// between one and the number of members.
+    pub threshold: u32,
+}
+
+impl UncheckedCommittee {
+    pub fn into_checked(self, deps: Deps) -> Result<Committee, PreProposeError> {
+        if self.threshold == 0 || self.threshold as usize > self.approvers.len() {
+            return Err(PreProposeError::Std(StdError::generic_err(
+                "committee threshold must be between one and the number of approvers",
+            )));
+        }
+        let mut approvers = self
+            .approvers
+            .iter()
+            .map(|a| deps.api.addr_validate(a))
+            .collect::<Result<Vec<_>, _>>()?;
+        approvers.sort();
+        approvers.dedup();
+        if approvers.len() != self.approvers.len() {
+            return Err(PreProposeError::Std(StdError::generic_err(
+                "committee approvers must be unique",
+            )));
+        }
+        Ok(Committee {
+            approvers,
+            threshold: self.threshold,
+        })
+    }
+}
+
 #[cw_serde]
 pub struct InstantiateExt {
     pub approver: String,
+    /// An optional committee that may approve or reject proposals
+    /// in addition to the approver.
+    #[serde(default)]
+    pub committee: Option<UncheckedCommittee>,
+    /// How long proposals may be pending before they expire. Expired
+    /// proposals are rejected and their deposits handled as if the
+    /// approver had rejected them.
+    #[serde(default)]
+    pub approval_timeout: Option<Duration>,
 }
 
 #[cw_serde]
 pub enum ExecuteExt {
-    /// Approve a proposal, only callable by approver
+    /// Approve a proposal, only callable by the approver or a
+    /// committee member. If sent by a committee member, records
+    /// their approval and approves the proposal once the
+    /// committee's threshold is met.
     Approve { id: u64 },
-    /// Reject a proposal, only callable by approver
+    /// Reject a proposal, only callable by the approver or a
+    /// committee member. If sent by a committee member, records
+    /// their rejection and rejects the proposal once enough members
+    /// have rejected it that the threshold can no longer be met.
     Reject { id: u64 },
     /// Updates the approver, can only be called the current approver
     UpdateApprover { address: String },
+    /// Sets or removes the approval committee, can only be called by
+    /// the approver or the DAO. Approvals and rejections from
+    /// addresses no longer on the committee stop counting.
+    UpdateCommittee {
+        committee: Option<UncheckedCommittee>,
+    },
+    /// Sets or removes the approval timeout for proposals submitted
+    /// after this is executed, can only be called by the approver or
+    /// the DAO.
+    UpdateApprovalTimeout { timeout: Option<Duration> },
+    /// Rejects a pending proposal whose approval timeout has passed.
+    /// Callable by anyone.
+    ExpireProposal { id: u64 },
+    /// Withdraws a pending proposal and refunds its deposit, only
+    /// callable by its proposer.
+    WithdrawProposal { id: u64 },
+    /// Replaces the contents of a pending proposal, only callable by
+    /// its proposer. Committee signatures for the proposal are
+    /// cleared.
+    EditProposal { id: u64, msg: ProposeMessage },
+}
+
+/// Messages sent to proposal submitted hook receivers, as the
+/// extension of a pre-propose execute message, when a pending
+/// proposal changes.
+#[cw_serde]
+pub enum PendingProposalHookMsg {
+    PendingProposalEdited {
+        approval_id: u64,
+        title: String,
+        description: String,
+    },
+    PendingProposalWithdrawn {
+        approval_id: u64,
+    },
+    PendingProposalExpired {
+        approval_id: u64,
+    },
 }
 
 #[cw_serde]
@@ -45,6 +158,16 @@ pub enum QueryExt {
     /// List the approver address
     #[returns(cosmwasm_std::Addr)]
     Approver {},
+    /// How long proposals may be pending before they expire, if set
+    #[returns(::std::option::Option<cw_utils::Duration>)]
+    ApprovalTimeout {},
+    /// The approval committee, if any
+    #[returns(::std::option::Option<crate::state::Committee>)]
+    Committee {},
+    /// Approvals and rejections recorded by committee members for a
+    /// proposal
+    #[returns(Vec<crate::state::Signature>)]
+    Signatures { id: u64 },
     /// Return whether or not the proposal is pending
     #[returns(bool)]
     IsPending { id: u64 },
@@ -92,6 +215,6 @@ pub type QueryMsg = QueryBase<QueryExt>;
 /// `proposer` field. The module will fill this in based on the sender
 /// of the external message.
 #[cw_serde]
-pub(crate) enum ProposeMessageInternal {
-    Propose(ProposeMsg),
+pub enum ProposeMessageInternal<Msg = ProposeMsg> {
+    Propose(Msg),
 }
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
 
 use dao_voting::deposit::CheckedDepositInfo;
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
@@ -16,10 +17,20 @@ pub enum ProposalStatus {
     },
     /// The proposal has been rejected.
     Rejected {},
+    /// The proposal was withdrawn by its proposer before it was
+    /// approved or rejected.
+    Withdrawn {},
+    /// The proposal was not approved or rejected before its approval
+    /// timeout and has been rejected.
+    Expired {},
 }
 
+/// A proposal awaiting or having completed approval. Generic over
+/// the propose message of the proposal module it will be created in
+/// so that approval modules for different proposal modules may share
+/// logic.
 #[cw_serde]
-pub struct Proposal {
+pub struct Proposal<Msg = ProposeMsg> {
     /// The status of a completed proposal.
     pub status: ProposalStatus,
     /// The approval ID used to identify this pending proposal.
@@ -28,23 +39,70 @@ pub struct Proposal {
     pub proposer: Addr,
     /// The propose message that ought to be executed on the proposal
     /// message if this proposal is approved.
-    pub msg: ProposeMsg,
+    pub msg: Msg,
     /// Snapshot of the deposit info at the time of proposal
     /// submission.
     pub deposit: Option<CheckedDepositInfo>,
+    /// When this proposal expires if it is still pending. Expired
+    /// proposals may be rejected by anyone.
+    #[serde(default)]
+    pub expiration: Expiration,
+}
+
+#[cw_serde]
+pub struct Committee {
+    /// The members of the committee.
+    pub approvers: Vec<Addr>,
+    /// The number of members that must approve a proposal.
+    pub threshold: u32,
+}
+
+#[cw_serde]
+pub struct Signature {
+    /// The committee member.
+    pub approver: Addr,
+    /// True if the member approved the proposal, false if they
+    /// rejected it.
+    pub approved: bool,
 }
 
 pub const APPROVER: Item<Addr> = Item::new("approver");
-pub const PENDING_PROPOSALS: Map<u64, Proposal> = Map::new("pending_proposals");
-pub const COMPLETED_PROPOSALS: Map<u64, Proposal> = Map::new("completed_proposals");
+pub const COMMITTEE: Item<Committee> = Item::new("committee");
+/// How long proposals may be pending before they expire. Not set if
+/// proposals never expire.
+pub const APPROVAL_TIMEOUT: Item<Duration> = Item::new("approval_timeout");
+/// Map between (approval ID, committee member) and whether the
+/// member approved the proposal.
+pub const SIGNATURES: Map<(u64, &Addr), bool> = Map::new("signatures");
+pub const PENDING_PROPOSALS: Map<u64, Proposal> = PROPOSALS.pending;
+pub const COMPLETED_PROPOSALS: Map<u64, Proposal> = PROPOSALS.completed;
+pub const PROPOSALS: ApprovalProposals<ProposeMsg> =
+    ApprovalProposals::new("pending_proposals", "completed_proposals");
 pub const CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL: Map<u64, u64> =
     Map::new("created_to_completed_proposal");
 
 /// Used internally to track the current approval_id.
 const CURRENT_ID: Item<u64> = Item::new("current_id");
 
-pub(crate) fn advance_approval_id(store: &mut dyn Storage) -> StdResult<u64> {
+pub fn advance_approval_id(store: &mut dyn Storage) -> StdResult<u64> {
     let id: u64 = CURRENT_ID.may_load(store)?.unwrap_or_default() + 1;
     CURRENT_ID.save(store, &id)?;
     Ok(id)
 }
+
+/// Storage for pending and completed proposals. The approval flow is
+/// implemented on this type (see `crate::approval`) so that it may be
+/// used with any proposal module's propose message.
+pub struct ApprovalProposals<Msg> {
+    pub pending: Map<'static, u64, Proposal<Msg>>,
+    pub completed: Map<'static, u64, Proposal<Msg>>,
+}
+
+impl<Msg> ApprovalProposals<Msg> {
+    pub const fn new(pending_key: &'static str, completed_key: &'static str) -> Self {
+        Self {
+            pending: Map::new(pending_key),
+            completed: Map::new(completed_key),
+        }
+    }
+}
@@ -1,12 +1,19 @@
-use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
-use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{DepositInfoResponse, ExecuteMsg as ExecuteBase},
+    state::{Config, SponsorshipConfig},
+};
 use dao_proposal_single::query::ProposalResponse;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
@@ -17,7 +24,7 @@ use dao_voting::{
     voting::Vote,
 };
 
-use crate::state::{Proposal, ProposalStatus};
+use crate::state::{Committee, Proposal, ProposalStatus, Signature};
 use crate::{contract::*, msg::*};
 
 fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
@@ -36,6 +43,61 @@ fn cw_pre_propose_base_proposal_single() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+/// A proposal submitted hook receiver which records the kind of each
+/// message it receives in a `received` attribute.
+fn hook_receiver_contract() -> Box<dyn Contract<Empty>> {
+    fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: ExecuteBase<ApproverProposeMessage, PendingProposalHookMsg>,
+    ) -> StdResult<Response> {
+        let received = match msg {
+            ExecuteBase::Propose { .. } => "propose",
+            ExecuteBase::Extension {
+                msg: PendingProposalHookMsg::PendingProposalEdited { .. },
+            } => "edited",
+            ExecuteBase::Extension {
+                msg: PendingProposalHookMsg::PendingProposalWithdrawn { .. },
+            } => "withdrawn",
+            ExecuteBase::Extension {
+                msg: PendingProposalHookMsg::PendingProposalExpired { .. },
+            } => "expired",
+            _ => "other",
+        };
+        Ok(Response::new().add_attribute("received", received))
+    }
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+    fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&Empty {})
+    }
+    Box::new(ContractWrapper::new(execute, instantiate, query))
+}
+
+/// The messages received by RECEIVER while handling RES.
+fn received_hooks(res: &AppResponse, receiver: &Addr) -> Vec<String> {
+    res.events
+        .iter()
+        .filter(|event| {
+            event.ty == "wasm"
+                && event
+                    .attributes
+                    .iter()
+                    .any(|a| a.key == "_contract_address" && a.value == receiver.as_str())
+        })
+        .flat_map(|event| event.attributes.iter())
+        .filter(|a| a.key == "received")
+        .map(|a| a.value.clone())
+        .collect()
+}
+
 fn cw20_base_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         cw20_base::contract::execute,
@@ -68,6 +130,8 @@ fn get_default_proposal_module_instantiate(
                     open_proposal_submission,
                     extension: InstantiateExt {
                         approver: "approver".to_string(),
+                        committee: None,
+                        approval_timeout: None,
                     },
                 })
                 .unwrap(),
@@ -1357,6 +1421,8 @@ fn test_instantiate_with_zero_native_deposit() {
                         open_proposal_submission: false,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
+                            committee: None,
+                            approval_timeout: None,
                         },
                     })
                     .unwrap(),
@@ -1422,6 +1488,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                         open_proposal_submission: false,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
+                            committee: None,
+                            approval_timeout: None,
                         },
                     })
                     .unwrap(),
@@ -1713,3 +1781,321 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+#[test]
+fn test_approval_committee() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let update_committee = |app: &mut App, threshold: u32| {
+        app.execute_contract(
+            Addr::unchecked("approver"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCommittee {
+                    committee: Some(UncheckedCommittee {
+                        approvers: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                        threshold,
+                    }),
+                },
+            },
+            &[],
+        )
+    };
+
+    // Threshold may not exceed the number of approvers.
+    let err: PreProposeError = update_committee(&mut app, 4)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Std(StdError::generic_err(
+            "committee threshold must be between one and the number of approvers"
+        ))
+    );
+    update_committee(&mut app, 2).unwrap();
+
+    let committee: Option<Committee> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Committee {},
+            },
+        )
+        .unwrap();
+    assert_eq!(committee.unwrap().threshold, 2);
+
+    let sign = |app: &mut App, sender: &str, msg: ExecuteExt| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension { msg },
+            &[],
+        )
+    };
+    let query_proposal = |app: &App, id: u64| -> Proposal {
+        app.wrap()
+            .query_wasm_smart(
+                pre_propose.clone(),
+                &QueryMsg::QueryExtension {
+                    msg: QueryExt::Proposal { id },
+                },
+            )
+            .unwrap()
+    };
+
+    // Non-members may not sign.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let err: PreProposeError = sign(&mut app, "d", ExecuteExt::Approve { id })
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // One approval is recorded but is not enough.
+    sign(&mut app, "a", ExecuteExt::Approve { id }).unwrap();
+    assert_eq!(query_proposal(&app, id).status, ProposalStatus::Pending {});
+    let signatures: Vec<Signature> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Signatures { id },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        signatures,
+        vec![Signature {
+            approver: Addr::unchecked("a"),
+            approved: true,
+        }]
+    );
+
+    // A rejection does not prevent approval.
+    sign(&mut app, "c", ExecuteExt::Reject { id }).unwrap();
+    assert_eq!(query_proposal(&app, id).status, ProposalStatus::Pending {});
+    sign(&mut app, "b", ExecuteExt::Approve { id }).unwrap();
+    assert!(matches!(
+        query_proposal(&app, id).status,
+        ProposalStatus::Approved { .. }
+    ));
+
+    // Two rejections make approval impossible.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    sign(&mut app, "a", ExecuteExt::Reject { id }).unwrap();
+    assert_eq!(query_proposal(&app, id).status, ProposalStatus::Pending {});
+    sign(&mut app, "b", ExecuteExt::Reject { id }).unwrap();
+    assert_eq!(query_proposal(&app, id).status, ProposalStatus::Rejected {});
+
+    // The approver may still act alone.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    sign(&mut app, "approver", ExecuteExt::Approve { id }).unwrap();
+    assert!(matches!(
+        query_proposal(&app, id).status,
+        ProposalStatus::Approved { .. }
+    ));
+}
+
+#[test]
+fn test_pending_proposal_expiry_and_withdrawal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+        true,
+    );
+
+    app.execute_contract(
+        Addr::unchecked("approver"),
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateApprovalTimeout {
+                timeout: Some(Duration::Height(10)),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Changes to pending proposals are sent to every proposal
+    // submitted hook receiver, not only the approver.
+    let hook_receiver_id = app.store_code(hook_receiver_contract());
+    let hook_receiver = app
+        .instantiate_contract(
+            hook_receiver_id,
+            core_addr.clone(),
+            &Empty {},
+            &[],
+            "hook receiver",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::AddProposalSubmittedHook {
+            address: hook_receiver.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    mint_natives(&mut app, "ekez", coins(30, "ujuno"));
+    let first = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let second = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let third = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    let execute = |app: &mut App, sender: &str, msg: ExecuteExt| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension { msg },
+            &[],
+        )
+    };
+    let query_proposal = |app: &App, id: u64| -> Proposal {
+        app.wrap()
+            .query_wasm_smart(
+                pre_propose.clone(),
+                &QueryMsg::QueryExtension {
+                    msg: QueryExt::Proposal { id },
+                },
+            )
+            .unwrap()
+    };
+
+    // Only the proposer may edit or withdraw.
+    let edit = ExecuteExt::EditProposal {
+        id: first,
+        msg: ProposeMessage::Propose {
+            title: "edited".to_string(),
+            description: "edited".to_string(),
+            msgs: vec![],
+            vote: None,
+        },
+    };
+    let err: PreProposeError = execute(&mut app, "keze", edit.clone())
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    let err: PreProposeError =
+        execute(&mut app, "keze", ExecuteExt::WithdrawProposal { id: first })
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    let res = execute(&mut app, "ekez", edit.clone()).unwrap();
+    assert_eq!(received_hooks(&res, &hook_receiver), vec!["edited"]);
+    let proposal = query_proposal(&app, first);
+    assert_eq!(proposal.status, ProposalStatus::Pending {});
+    assert_eq!(proposal.msg.title, "edited".to_string());
+
+    // Withdrawing refunds the deposit.
+    let res = execute(
+        &mut app,
+        "ekez",
+        ExecuteExt::WithdrawProposal { id: second },
+    )
+    .unwrap();
+    assert_eq!(received_hooks(&res, &hook_receiver), vec!["withdrawn"]);
+    assert_eq!(
+        query_proposal(&app, second).status,
+        ProposalStatus::Withdrawn {}
+    );
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+
+    // Proposals may not be expired early.
+    let err: PreProposeError =
+        execute(&mut app, "anyone", ExecuteExt::ExpireProposal { id: third })
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, PreProposeError::NotExpired {});
+
+    app.update_block(|block| block.height += 10);
+
+    // Expired proposals may not be edited.
+    let err: PreProposeError = execute(&mut app, "ekez", edit)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Expired {});
+
+    let res = execute(&mut app, "anyone", ExecuteExt::ExpireProposal { id: third }).unwrap();
+    assert_eq!(received_hooks(&res, &hook_receiver), vec!["expired"]);
+    assert_eq!(
+        query_proposal(&app, third).status,
+        ProposalStatus::Expired {}
+    );
+    // Expiring handles the deposit like a rejection.
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+
+    // Approving an expired proposal rejects it instead.
+    execute(&mut app, "approver", ExecuteExt::Approve { id: first }).unwrap();
+    assert_eq!(
+        query_proposal(&app, first).status,
+        ProposalStatus::Expired {}
+    );
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(20)
+    );
+}
+
+#[test]
+fn test_sponsorship_unsupported() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        pre_propose,
+        ..
+    } = setup_default_test(&mut app, None, true);
+
+    let update = |app: &mut App, config: Option<SponsorshipConfig>| {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateSponsorshipConfig { config },
+            &[],
+        )
+    };
+    let err: PreProposeError = update(
+        &mut app,
+        Some(SponsorshipConfig {
+            min_sponsors: 1,
+            min_power: Uint128::new(1),
+            window: Duration::Height(10),
+        }),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::SponsorshipUnsupported {});
+
+    // Clearing the config is still allowed.
+    update(&mut app, None).unwrap();
+}
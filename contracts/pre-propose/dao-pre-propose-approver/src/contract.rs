@@ -88,10 +88,25 @@ pub fn execute(
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            ..
         } => execute_proposal_completed(deps, info, proposal_id, new_status),
         ExecuteMsg::Extension { msg } => match msg {
             ExecuteExt::ResetApprover {} => execute_reset_approver(deps, env, info),
+            ExecuteExt::PendingProposalEdited {
+                approval_id,
+                title,
+                description,
+            } => execute_pending_proposal_edited(deps, info, approval_id, title, description),
+            ExecuteExt::PendingProposalWithdrawn { approval_id }
+            | ExecuteExt::PendingProposalExpired { approval_id } => {
+                execute_pending_proposal_removed(deps, info, approval_id)
+            }
         },
+        // Proposals are created by the approval module and never seek
+        // sponsors.
+        ExecuteMsg::UpdateSponsorshipConfig { config: Some(_) } => {
+            Err(PreProposeError::SponsorshipUnsupported {})
+        }
         _ => PrePropose::default().execute(deps, env, info, msg),
     }
 }
@@ -107,22 +122,28 @@ pub fn execute_propose(
         return Err(PreProposeError::Unauthorized {});
     }
 
-    // Get pre_prospose_id, transform proposal for the approver
-    // Here we make sure that there are no messages that can be executed
-    let (pre_propose_id, sanitized_msg) = match msg {
-        ApproverProposeMessage::Propose {
-            title,
-            description,
-            approval_id: pre_propose_id,
-        } => (
-            pre_propose_id,
-            ProposeMessageInternal::Propose {
-                title,
-                description,
-                msgs: vec![],
-                proposer: Some(info.sender.to_string()),
-            },
-        ),
+    let ApproverProposeMessage::Propose {
+        title,
+        description,
+        approval_id: pre_propose_id,
+    } = msg;
+    create_approver_proposal(deps, info, pre_propose_id, title, description)
+}
+
+fn create_approver_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+    pre_propose_id: u64,
+    title: String,
+    description: String,
+) -> Result<Response, PreProposeError> {
+    // Transform proposal for the approver. Here we make sure that
+    // there are no messages that can be executed
+    let sanitized_msg = ProposeMessageInternal::Propose {
+        title,
+        description,
+        msgs: vec![],
+        proposer: Some(info.sender.to_string()),
     };
 
     let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
@@ -153,8 +174,17 @@ pub fn execute_proposal_completed(
         return Err(PreProposeError::NotModule {});
     }
 
-    // Get approval pre-propose id
-    let pre_propose_id = PROPOSAL_ID_TO_PRE_PROPOSE_ID.load(deps.storage, proposal_id)?;
+    // Get approval pre-propose id. If there is none, the pending
+    // proposal was edited, withdrawn, or expired and there is
+    // nothing to approve or reject.
+    let pre_propose_id = match PROPOSAL_ID_TO_PRE_PROPOSE_ID.may_load(deps.storage, proposal_id)? {
+        Some(pre_propose_id) => pre_propose_id,
+        None => {
+            return Ok(Response::default()
+                .add_attribute("method", "execute_proposal_completed_hook")
+                .add_attribute("proposal", proposal_id.to_string()))
+        }
+    };
 
     // Get approval contract address
     let approval_contract = PRE_PROPOSE_APPROVAL_CONTRACT.load(deps.storage)?;
@@ -188,6 +218,42 @@ pub fn execute_proposal_completed(
     }
 }
 
+pub fn execute_pending_proposal_edited(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    pre_propose_id: u64,
+    title: String,
+    description: String,
+) -> Result<Response, PreProposeError> {
+    // Votes on the approver proposal were for the old contents, so
+    // replace it with a new one.
+    execute_pending_proposal_removed(deps.branch(), info.clone(), pre_propose_id)?;
+    create_approver_proposal(deps, info, pre_propose_id, title, description)
+}
+
+pub fn execute_pending_proposal_removed(
+    deps: DepsMut,
+    info: MessageInfo,
+    pre_propose_id: u64,
+) -> Result<Response, PreProposeError> {
+    // Check that this is coming from the expected approval contract
+    let approval_contract = PRE_PROPOSE_APPROVAL_CONTRACT.load(deps.storage)?;
+    if info.sender != approval_contract {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    if let Some(proposal_id) =
+        PRE_PROPOSE_ID_TO_PROPOSAL_ID.may_load(deps.storage, pre_propose_id)?
+    {
+        PROPOSAL_ID_TO_PRE_PROPOSE_ID.remove(deps.storage, proposal_id);
+        PRE_PROPOSE_ID_TO_PROPOSAL_ID.remove(deps.storage, pre_propose_id);
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "pending_proposal_removed")
+        .add_attribute("pre_propose_id", pre_propose_id.to_string()))
+}
+
 pub fn execute_reset_approver(
     deps: DepsMut,
     env: Env,
@@ -15,6 +15,22 @@ pub enum ExecuteExt {
     // Reset approver back to DAO that set up this approver contract. Only
     // callable by the DAO.
     ResetApprover {},
+    // Hooks fired by the pre-propose approval contract when a pending
+    // proposal changes. Only callable by the pre-propose approval
+    // contract. An edited proposal gets a new approver proposal, and
+    // the approver proposal for a withdrawn or expired one no longer
+    // approves or rejects anything when it completes.
+    PendingProposalEdited {
+        approval_id: u64,
+        title: String,
+        description: String,
+    },
+    PendingProposalWithdrawn {
+        approval_id: u64,
+    },
+    PendingProposalExpired {
+        approval_id: u64,
+    },
 }
 
 #[cw_serde]
@@ -11,7 +11,7 @@ use dao_pre_propose_approval_single::{
     msg::{
         ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
     },
-    state::Proposal,
+    state::{Proposal, ProposalStatus},
 };
 use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
 use dao_proposal_single as dps;
@@ -95,6 +95,8 @@ fn get_proposal_module_approval_single_instantiate(
                     open_proposal_submission,
                     extension: InstantiateExt {
                         approver: APPROVER.to_string(),
+                        committee: None,
+                        approval_timeout: None,
                     },
                 })
                 .unwrap(),
@@ -1670,3 +1672,91 @@ fn test_reset_approver() {
         .unwrap();
     assert_eq!(approver, approver_core_addr);
 }
+
+#[test]
+fn test_pending_proposal_edited_and_withdrawn() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+        approver_core_addr: _,
+        proposal_single_approver,
+        pre_propose_approver,
+    } = setup_default_test(&mut app, None, true);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let old_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+
+    // Editing the pending proposal replaces the approver proposal.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::EditProposal {
+                id: pre_propose_id,
+                msg: ProposeMessage::Propose {
+                    title: "edited".to_string(),
+                    description: "edited".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let new_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    assert_ne!(old_id, new_id);
+    let mapped: Option<u64> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose_approver.clone(),
+            &ApproverQueryMsg::QueryExtension {
+                msg: ApproverQueryExt::ApproverProposalIdForPreProposeApprovalId {
+                    id: pre_propose_id,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(mapped, Some(new_id));
+
+    // Passing the old approver proposal does not approve the edited
+    // proposal.
+    approve_proposal(&mut app, proposal_single_approver.clone(), "ekez", old_id);
+    let is_pending: bool = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::IsPending { id: pre_propose_id },
+            },
+        )
+        .unwrap();
+    assert!(is_pending);
+
+    // Once withdrawn, passing the new approver proposal does nothing.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::WithdrawProposal { id: pre_propose_id },
+        },
+        &[],
+    )
+    .unwrap();
+    approve_proposal(&mut app, proposal_single_approver, "ekez", new_id);
+
+    let proposal: Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Proposal { id: pre_propose_id },
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Withdrawn {});
+    assert_eq!(proposal.msg.title, "edited".to_string());
+    assert_eq!(get_proposals(&app, proposal_single).proposals.len(), 0);
+}
@@ -0,0 +1,337 @@
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty, Uint128,
+};
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo, ProposalModule};
+use dao_pre_propose_base::{error::PreProposeError, msg::UncheckedMessagePolicy};
+use dao_proposal_condorcet::{
+    msg::{Choice, ExecuteMsg as CondorcetExecuteMsg, InstantiateMsg as CondorcetInstantiateMsg},
+    proposal::{ProposalResponse, Status},
+    ContractError as CondorcetError,
+};
+use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    threshold::PercentageThreshold,
+};
+
+use crate::contract::*;
+
+fn condorcet_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_condorcet::contract::execute,
+        dao_proposal_condorcet::contract::instantiate,
+        dao_proposal_condorcet::contract::query,
+    )
+    .with_reply(dao_proposal_condorcet::contract::reply);
+    Box::new(contract)
+}
+
+fn pre_propose_condorcet_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    condorcet: Addr,
+    pre_propose: Addr,
+}
+
+/// Sets up a DAO whose Condorcet proposal module requires a deposit
+/// of 10 `ujuno`, refunded only if the proposal passes.
+fn setup_default_test(app: &mut App) -> DefaultTestSetup {
+    let condorcet_id = app.store_code(condorcet_contract());
+    let pre_propose_id = app.store_code(pre_propose_condorcet_contract());
+
+    let proposal_module_instantiate = CondorcetInstantiateMsg {
+        quorum: PercentageThreshold::Percent(Decimal::percent(15)),
+        voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        veto: None,
+        cycle_resolution: None,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&InstantiateMsg {
+                    deposit_info: Some(UncheckedDepositInfo {
+                        denom: DepositToken::Token {
+                            denom: UncheckedDenom::Native("ujuno".to_string()),
+                        },
+                        amount: Uint128::new(10),
+                        refund_policy: DepositRefundPolicy::OnlyPassed,
+                    }),
+                    open_proposal_submission: false,
+                    extension: Empty::default(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "pre-propose condorcet".to_string(),
+            },
+        },
+    };
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        condorcet_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let condorcet = proposal_modules.into_iter().next().unwrap().address;
+    let pre_propose = match app
+        .wrap()
+        .query_wasm_smart(
+            condorcet.clone(),
+            &dao_proposal_condorcet::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+    {
+        ProposalCreationPolicy::Module { addr } => addr,
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+    };
+
+    let proposal_module: Addr = app
+        .wrap()
+        .query_wasm_smart(pre_propose.clone(), &QueryMsg::ProposalModule {})
+        .unwrap();
+    assert_eq!(proposal_module, condorcet);
+
+    DefaultTestSetup {
+        core_addr,
+        condorcet,
+        pre_propose,
+    }
+}
+
+fn mint_natives(app: &mut App, receiver: &str, coins: Vec<Coin>) {
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: receiver.to_string(),
+        amount: coins,
+    }))
+    .unwrap();
+}
+
+fn make_proposal(
+    app: &mut App,
+    pre_propose: &Addr,
+    proposer: &str,
+    choices: Vec<Choice>,
+) -> anyhow::Result<AppResponse> {
+    mint_natives(app, proposer, coins(10, "ujuno"));
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose { choices },
+        },
+        &coins(10, "ujuno"),
+    )
+}
+
+fn vote(app: &mut App, condorcet: &Addr, voter: &str, proposal_id: u32, vote: Vec<u32>) {
+    app.execute_contract(
+        Addr::unchecked(voter),
+        condorcet.clone(),
+        &CondorcetExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn query_proposal(app: &App, condorcet: &Addr, id: u32) -> ProposalResponse {
+    app.wrap()
+        .query_wasm_smart(
+            condorcet,
+            &dao_proposal_condorcet::msg::QueryMsg::Proposal { id },
+        )
+        .unwrap()
+}
+
+fn balance(app: &App, addr: impl Into<String>) -> Uint128 {
+    app.wrap().query_balance(addr, "ujuno").unwrap().amount
+}
+
+fn spend(amount: u128) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(amount, "ujuno"),
+    }
+    .into()
+}
+
+#[test]
+fn test_deposits() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        condorcet,
+        pre_propose,
+    } = setup_default_test(&mut app);
+
+    // Proposals may only be created through the pre-propose module,
+    // which sets the proposer to the sender.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            condorcet.clone(),
+            &CondorcetExecuteMsg::Propose {
+                choices: vec![Choice { msgs: vec![] }],
+                proposer: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        CondorcetError::Unauthorized {}.to_string()
+    );
+
+    // Only members may propose.
+    let err: PreProposeError = make_proposal(
+        &mut app,
+        &pre_propose,
+        "nonmember",
+        vec![Choice { msgs: vec![] }],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::NotMember {});
+
+    // A passed proposal has its deposit refunded on execution.
+    make_proposal(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        vec![Choice { msgs: vec![] }],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal(&app, &condorcet, 1).proposal.proposer,
+        Addr::unchecked("ekez")
+    );
+    assert_eq!(balance(&app, "ekez"), Uint128::zero());
+    assert_eq!(balance(&app, &pre_propose), Uint128::new(10));
+
+    vote(&mut app, &condorcet, "ekez", 1, vec![0, 1]);
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        condorcet.clone(),
+        &CondorcetExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal(&app, &condorcet, 1).proposal.last_status(),
+        Status::Executed
+    );
+    assert_eq!(balance(&app, "ekez"), Uint128::new(10));
+
+    // A rejected proposal's deposit goes to the DAO on close.
+    make_proposal(
+        &mut app,
+        &pre_propose,
+        "keze",
+        vec![Choice { msgs: vec![] }],
+    )
+    .unwrap();
+    vote(&mut app, &condorcet, "ekez", 2, vec![1, 0]);
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked("keze"),
+        condorcet,
+        &CondorcetExecuteMsg::Close { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(balance(&app, "keze"), Uint128::zero());
+    assert_eq!(balance(&app, core_addr), Uint128::new(10));
+    assert_eq!(balance(&app, pre_propose), Uint128::zero());
+}
+
+#[test]
+fn test_message_policy_applies_to_each_choice() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        pre_propose,
+        ..
+    } = setup_default_test(&mut app);
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateMessagePolicy {
+            policy: UncheckedMessagePolicy {
+                max_msgs: Some(1),
+                ..Default::default()
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err: PreProposeError = make_proposal(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        vec![
+            Choice {
+                msgs: vec![spend(1)],
+            },
+            Choice {
+                msgs: vec![spend(1), spend(2)],
+            },
+        ],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::TooManyMessages { max: 1, count: 2 });
+
+    // Only one choice is executed, so the limit is not on the total.
+    make_proposal(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        vec![
+            Choice {
+                msgs: vec![spend(1)],
+            },
+            Choice {
+                msgs: vec![spend(2)],
+            },
+        ],
+    )
+    .unwrap();
+}
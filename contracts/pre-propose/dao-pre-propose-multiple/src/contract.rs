@@ -1,7 +1,9 @@
 use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
 use cw2::set_contract_version;
 
 use dao_pre_propose_base::{
@@ -11,7 +13,7 @@ use dao_pre_propose_base::{
 };
 use dao_voting::{
     multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
-    proposal::MultipleChoiceProposeMsg as ProposeMsg,
+    proposal::{MultipleChoiceProposeMsg as ProposeMsg, ProposalMsgs},
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-multiple";
@@ -39,6 +41,14 @@ enum ProposeMessageInternal {
     Propose(ProposeMsg),
 }
 
+impl ProposalMsgs for ProposeMessageInternal {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        match self {
+            ProposeMessageInternal::Propose(msg) => msg.proposal_msgs(),
+        }
+    }
+}
+
 type PrePropose = PreProposeContract<Empty, Empty, Empty, ProposeMessageInternal>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -92,12 +102,35 @@ pub fn execute(
             deposit_info,
             open_proposal_submission,
         },
+        ExecuteMsg::UpdateDepositOptions { options } => {
+            ExecuteInternal::UpdateDepositOptions { options }
+        }
+        ExecuteMsg::UpdateSubmissionPolicy { policy } => {
+            ExecuteInternal::UpdateSubmissionPolicy { policy }
+        }
+        ExecuteMsg::UpdateSubmissionAllowlist { to_add, to_remove } => {
+            ExecuteInternal::UpdateSubmissionAllowlist { to_add, to_remove }
+        }
+        ExecuteMsg::UpdateMessagePolicy { policy } => {
+            ExecuteInternal::UpdateMessagePolicy { policy }
+        }
+        ExecuteMsg::UpdateSponsorshipConfig { config } => {
+            ExecuteInternal::UpdateSponsorshipConfig { config }
+        }
+        ExecuteMsg::Sponsor { id } => ExecuteInternal::Sponsor { id },
+        ExecuteMsg::ExpireSponsorship { id } => ExecuteInternal::ExpireSponsorship { id },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
         }
         ExecuteMsg::RemoveProposalSubmittedHook { address } => {
             ExecuteInternal::RemoveProposalSubmittedHook { address }
         }
+        ExecuteMsg::AddSponsorshipHook { address } => {
+            ExecuteInternal::AddSponsorshipHook { address }
+        }
+        ExecuteMsg::RemoveSponsorshipHook { address } => {
+            ExecuteInternal::RemoveSponsorshipHook { address }
+        }
         ExecuteBase::ProposalCompletedHook {
             proposal_id,
             new_status,
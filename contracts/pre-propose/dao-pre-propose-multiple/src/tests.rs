@@ -78,6 +78,7 @@ fn get_default_proposal_module_instantiate(
         },
         close_proposal_on_execution_failure: false,
         veto: None,
+        winner_threshold: None,
     }
 }
 
@@ -1070,6 +1071,7 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            winner_threshold: None,
         }
     };
 
@@ -1133,6 +1135,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            winner_threshold: None,
         }
     };
 
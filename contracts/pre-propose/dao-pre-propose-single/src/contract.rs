@@ -1,21 +1,31 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 
 use dao_pre_propose_base::{
     error::PreProposeError,
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
 };
-use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+use dao_voting::{
+    proposal::{ProposalMsgs, SingleChoiceProposeMsg as ProposeMsg},
+    voting::SingleChoiceAutoVote,
+};
+
+use crate::templates::{ParamValue, TemplateResponse, UncheckedTemplate, TEMPLATES};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cw_serde]
 pub enum ProposeMessage {
     /// The propose message used to make a proposal to this
@@ -28,11 +38,47 @@ pub enum ProposeMessage {
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
     },
+    /// Makes a proposal whose messages are rendered from the
+    /// template named `template` with the parameter values in
+    /// `params`. The template's deposit is taken if it has one.
+    ProposeFromTemplate {
+        title: String,
+        description: String,
+        template: String,
+        params: Vec<ParamValue>,
+        vote: Option<SingleChoiceAutoVote>,
+    },
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Registers a template, replacing any template with the same
+    /// name. Only callable by the DAO.
+    SetTemplate {
+        name: String,
+        template: UncheckedTemplate,
+    },
+    /// Removes a template. Only callable by the DAO.
+    RemoveTemplate { name: String },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// Gets the template named `name`.
+    #[returns(crate::templates::Template)]
+    Template { name: String },
+    /// Lists registered templates by name.
+    #[returns(Vec<crate::templates::TemplateResponse>)]
+    Templates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 pub type InstantiateMsg = InstantiateBase<Empty>;
-pub type ExecuteMsg = ExecuteBase<ProposeMessage, Empty>;
-pub type QueryMsg = QueryBase<Empty>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
 
 /// Internal version of the propose message that includes the
 /// `proposer` field. The module will fill this in based on the sender
@@ -42,7 +88,15 @@ enum ProposeMessageInternal {
     Propose(ProposeMsg),
 }
 
-type PrePropose = PreProposeContract<Empty, Empty, Empty, ProposeMessageInternal>;
+impl ProposalMsgs for ProposeMessageInternal {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        match self {
+            ProposeMessageInternal::Propose(msg) => msg.proposal_msgs(),
+        }
+    }
+}
+
+type PrePropose = PreProposeContract<Empty, ExecuteExt, QueryExt, ProposeMessageInternal>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -67,7 +121,7 @@ pub fn execute(
     // message externally as that is to be set by this module. Here,
     // we transform an external message which omits that field into an
     // internal message which sets it.
-    type ExecuteInternal = ExecuteBase<ProposeMessageInternal, Empty>;
+    type ExecuteInternal = ExecuteBase<ProposeMessageInternal, ExecuteExt>;
     let internalized = match msg {
         ExecuteMsg::Propose {
             msg:
@@ -87,7 +141,35 @@ pub fn execute(
                 vote,
             }),
         },
-        ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
+        ExecuteMsg::Propose {
+            msg:
+                ProposeMessage::ProposeFromTemplate {
+                    title,
+                    description,
+                    template,
+                    params,
+                    vote,
+                },
+        } => {
+            return execute_propose_from_template(
+                deps,
+                env,
+                info,
+                title,
+                description,
+                template,
+                params,
+                vote,
+            )
+        }
+        ExecuteMsg::Extension { msg } => {
+            return match msg {
+                ExecuteExt::SetTemplate { name, template } => {
+                    execute_set_template(deps, info, name, template)
+                }
+                ExecuteExt::RemoveTemplate { name } => execute_remove_template(deps, info, name),
+            }
+        }
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
@@ -96,12 +178,35 @@ pub fn execute(
             deposit_info,
             open_proposal_submission,
         },
+        ExecuteMsg::UpdateDepositOptions { options } => {
+            ExecuteInternal::UpdateDepositOptions { options }
+        }
+        ExecuteMsg::UpdateSubmissionPolicy { policy } => {
+            ExecuteInternal::UpdateSubmissionPolicy { policy }
+        }
+        ExecuteMsg::UpdateSubmissionAllowlist { to_add, to_remove } => {
+            ExecuteInternal::UpdateSubmissionAllowlist { to_add, to_remove }
+        }
+        ExecuteMsg::UpdateMessagePolicy { policy } => {
+            ExecuteInternal::UpdateMessagePolicy { policy }
+        }
+        ExecuteMsg::UpdateSponsorshipConfig { config } => {
+            ExecuteInternal::UpdateSponsorshipConfig { config }
+        }
+        ExecuteMsg::Sponsor { id } => ExecuteInternal::Sponsor { id },
+        ExecuteMsg::ExpireSponsorship { id } => ExecuteInternal::ExpireSponsorship { id },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
         }
         ExecuteMsg::RemoveProposalSubmittedHook { address } => {
             ExecuteInternal::RemoveProposalSubmittedHook { address }
         }
+        ExecuteMsg::AddSponsorshipHook { address } => {
+            ExecuteInternal::AddSponsorshipHook { address }
+        }
+        ExecuteMsg::RemoveSponsorshipHook { address } => {
+            ExecuteInternal::RemoveSponsorshipHook { address }
+        }
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
@@ -114,7 +219,96 @@ pub fn execute(
     PrePropose::default().execute(deps, env, info, internalized)
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn execute_propose_from_template(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    template: String,
+    params: Vec<ParamValue>,
+    vote: Option<SingleChoiceAutoVote>,
+) -> Result<Response, PreProposeError> {
+    let template = TEMPLATES
+        .may_load(deps.storage, &template)?
+        .ok_or(PreProposeError::TemplateNotFound { name: template })?;
+    let msgs = template.render(deps.as_ref(), params)?;
+
+    PrePropose::default().execute_propose_with_deposit(
+        deps,
+        env,
+        info.clone(),
+        ProposeMessageInternal::Propose(ProposeMsg {
+            proposer: Some(info.sender.to_string()),
+            title,
+            description,
+            msgs,
+            vote,
+        }),
+        template.deposit,
+    )
+}
+
+pub fn execute_set_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    template: UncheckedTemplate,
+) -> Result<Response, PreProposeError> {
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(PreProposeError::NotDao {});
+    }
+
+    let template = template.into_checked(deps.as_ref(), dao)?;
+    TEMPLATES.save(deps.storage, &name, &template)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "set_template")
+        .add_attribute("name", name))
+}
+
+pub fn execute_remove_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, PreProposeError> {
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(PreProposeError::NotDao {});
+    }
+
+    if !TEMPLATES.has(deps.storage, &name) {
+        return Err(PreProposeError::TemplateNotFound { name });
+    }
+    TEMPLATES.remove(deps.storage, &name);
+
+    Ok(Response::default()
+        .add_attribute("method", "remove_template")
+        .add_attribute("name", name))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    PrePropose::default().query(deps, env, msg)
+    match msg {
+        QueryMsg::QueryExtension { msg } => match msg {
+            QueryExt::Template { name } => to_json_binary(&TEMPLATES.load(deps.storage, &name)?),
+            QueryExt::Templates { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+                let templates = TEMPLATES
+                    .range(
+                        deps.storage,
+                        start_after.as_deref().map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit as usize)
+                    .map(|item| item.map(|(name, template)| TemplateResponse { name, template }))
+                    .collect::<StdResult<Vec<_>>>()?;
+                to_json_binary(&templates)
+            }
+        },
+        _ => PrePropose::default().query(deps, env, msg),
+    }
 }
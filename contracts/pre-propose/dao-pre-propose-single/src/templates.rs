@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_string, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, Empty,
+    StdResult, Uint128, WasmMsg,
+};
+use cw_storage_plus::Map;
+use dao_pre_propose_base::error::PreProposeError;
+use dao_voting::deposit::{CheckedDepositInfo, UncheckedDepositInfo};
+
+/// Templates registered by the DAO, keyed by name.
+pub const TEMPLATES: Map<&str, Template> = Map::new("templates");
+
+/// The type of a template parameter. Values are checked against
+/// their type when a proposal is made from the template.
+#[cw_serde]
+pub enum ParamType {
+    /// An address, validated with the chain's address format.
+    Address {},
+    /// An amount of tokens, no greater than `max` if it is set.
+    Amount { max: Option<Uint128> },
+}
+
+#[cw_serde]
+pub struct TemplateParam {
+    /// The name used to refer to this parameter in the template's
+    /// messages.
+    pub name: String,
+    pub param_type: ParamType,
+}
+
+/// A value in a template message. Either fixed by the template, or
+/// filled in with the value of a parameter when a proposal is made.
+#[cw_serde]
+pub enum Placeholder<T> {
+    Value(T),
+    Param(String),
+}
+
+/// A message skeleton. Rendered into a `CosmosMsg` when a proposal
+/// is made from its template.
+#[cw_serde]
+pub enum TemplateMsg {
+    /// Sends native tokens from the DAO.
+    BankSend {
+        to_address: Placeholder<String>,
+        denom: String,
+        amount: Placeholder<Uint128>,
+    },
+    /// Transfers cw20 tokens from the DAO.
+    Cw20Transfer {
+        contract_addr: String,
+        recipient: Placeholder<String>,
+        amount: Placeholder<Uint128>,
+    },
+    /// Executes a smart contract. `msg` is the JSON message to send
+    /// in which each occurrence of `{{name}}` is replaced with the
+    /// value of the parameter `name`, escaped for use in a JSON
+    /// string. Proposals are rejected if the rendered message is not
+    /// valid JSON.
+    WasmExecute {
+        contract_addr: Placeholder<String>,
+        msg: String,
+        funds: Vec<Coin>,
+    },
+}
+
+#[cw_serde]
+pub struct UncheckedTemplate {
+    /// What proposals made from this template do.
+    pub description: String,
+    /// The parameters a proposer must provide.
+    pub params: Vec<TemplateParam>,
+    /// The messages of proposals made from this template.
+    pub msgs: Vec<TemplateMsg>,
+    /// The deposit required to make a proposal from this template.
+    /// If not set, the module's deposit is required.
+    pub deposit: Option<UncheckedDepositInfo>,
+}
+
+#[cw_serde]
+pub struct Template {
+    pub description: String,
+    pub params: Vec<TemplateParam>,
+    pub msgs: Vec<TemplateMsg>,
+    pub deposit: Option<CheckedDepositInfo>,
+}
+
+#[cw_serde]
+pub struct TemplateResponse {
+    pub name: String,
+    pub template: Template,
+}
+
+/// The value of a template parameter provided by a proposer.
+#[cw_serde]
+pub struct ParamValue {
+    pub name: String,
+    pub value: String,
+}
+
+/// A parameter value that has been checked against its type.
+enum CheckedParam {
+    Address(String),
+    Amount(Uint128),
+}
+
+impl CheckedParam {
+    /// The value escaped for use inside a JSON string.
+    fn escaped(&self) -> StdResult<String> {
+        let quoted = match self {
+            CheckedParam::Address(addr) => to_json_string(addr)?,
+            CheckedParam::Amount(amount) => to_json_string(amount)?,
+        };
+        Ok(quoted[1..quoted.len() - 1].to_string())
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PreProposeError {
+    PreProposeError::InvalidTemplate {
+        reason: reason.into(),
+    }
+}
+
+impl UncheckedTemplate {
+    /// Checks that parameter names are unique, that every
+    /// placeholder refers to a parameter of the right type, and that
+    /// fixed addresses are valid.
+    pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<Template, PreProposeError> {
+        let mut types = BTreeMap::new();
+        for param in &self.params {
+            if types
+                .insert(param.name.as_str(), &param.param_type)
+                .is_some()
+            {
+                return Err(invalid(format!("duplicate parameter ({})", param.name)));
+            }
+        }
+        if self.msgs.is_empty() {
+            return Err(invalid("a template must have at least one message"));
+        }
+
+        let check_address = |value: &Placeholder<String>| match value {
+            Placeholder::Value(addr) => deps
+                .api
+                .addr_validate(addr)
+                .map(|_| ())
+                .map_err(PreProposeError::from),
+            Placeholder::Param(name) => match types.get(name.as_str()) {
+                Some(ParamType::Address {}) => Ok(()),
+                _ => Err(invalid(format!("({name}) is not an address parameter"))),
+            },
+        };
+        let check_amount = |value: &Placeholder<Uint128>| match value {
+            Placeholder::Value(_) => Ok(()),
+            Placeholder::Param(name) => match types.get(name.as_str()) {
+                Some(ParamType::Amount { .. }) => Ok(()),
+                _ => Err(invalid(format!("({name}) is not an amount parameter"))),
+            },
+        };
+
+        for msg in &self.msgs {
+            match msg {
+                TemplateMsg::BankSend {
+                    to_address, amount, ..
+                } => {
+                    check_address(to_address)?;
+                    check_amount(amount)?;
+                }
+                TemplateMsg::Cw20Transfer {
+                    contract_addr,
+                    recipient,
+                    amount,
+                } => {
+                    deps.api.addr_validate(contract_addr)?;
+                    check_address(recipient)?;
+                    check_amount(amount)?;
+                }
+                TemplateMsg::WasmExecute {
+                    contract_addr, msg, ..
+                } => {
+                    check_address(contract_addr)?;
+                    for name in placeholders(msg)? {
+                        if !types.contains_key(name) {
+                            return Err(invalid(format!("unknown parameter ({name})")));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Template {
+            description: self.description,
+            params: self.params,
+            msgs: self.msgs,
+            deposit: self
+                .deposit
+                .map(|deposit| deposit.into_checked(deps, dao))
+                .transpose()?,
+        })
+    }
+}
+
+/// Returns the names of the `{{name}}` placeholders in MSG.
+fn placeholders(msg: &str) -> Result<Vec<&str>, PreProposeError> {
+    let mut names = vec![];
+    let mut rest = msg;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| invalid("unterminated placeholder"))?;
+        names.push(&rest[start + 2..start + end]);
+        rest = &rest[start + end + 2..];
+    }
+    Ok(names)
+}
+
+impl Template {
+    /// Renders this template's messages with the parameter values in
+    /// VALUES, checking each value against its parameter's type.
+    pub fn render(
+        &self,
+        deps: Deps,
+        values: Vec<ParamValue>,
+    ) -> Result<Vec<CosmosMsg<Empty>>, PreProposeError> {
+        let mut values: BTreeMap<String, String> = values
+            .into_iter()
+            .map(|ParamValue { name, value }| (name, value))
+            .collect();
+
+        let mut checked = BTreeMap::new();
+        for TemplateParam { name, param_type } in &self.params {
+            let value =
+                values
+                    .remove(name)
+                    .ok_or_else(|| PreProposeError::InvalidTemplateParam {
+                        name: name.clone(),
+                        reason: "missing".to_string(),
+                    })?;
+            let value = match param_type {
+                ParamType::Address {} => {
+                    CheckedParam::Address(deps.api.addr_validate(&value)?.into_string())
+                }
+                ParamType::Amount { max } => {
+                    let amount: Uint128 =
+                        value
+                            .parse()
+                            .map_err(|_| PreProposeError::InvalidTemplateParam {
+                                name: name.clone(),
+                                reason: "not an amount".to_string(),
+                            })?;
+                    if let Some(max) = max {
+                        if amount > *max {
+                            return Err(PreProposeError::InvalidTemplateParam {
+                                name: name.clone(),
+                                reason: format!("greater than the maximum of ({max})"),
+                            });
+                        }
+                    }
+                    CheckedParam::Amount(amount)
+                }
+            };
+            checked.insert(name.as_str(), value);
+        }
+        if let Some(name) = values.into_keys().next() {
+            return Err(PreProposeError::InvalidTemplateParam {
+                name,
+                reason: "not a parameter of this template".to_string(),
+            });
+        }
+
+        let param = |name: &str| {
+            checked
+                .get(name)
+                .ok_or_else(|| invalid(format!("unknown parameter ({name})")))
+        };
+        let address = |value: &Placeholder<String>| -> Result<String, PreProposeError> {
+            match value {
+                Placeholder::Value(addr) => Ok(addr.clone()),
+                Placeholder::Param(name) => match param(name)? {
+                    CheckedParam::Address(addr) => Ok(addr.clone()),
+                    CheckedParam::Amount(_) => {
+                        Err(invalid(format!("({name}) is not an address parameter")))
+                    }
+                },
+            }
+        };
+        let amount = |value: &Placeholder<Uint128>| -> Result<Uint128, PreProposeError> {
+            match value {
+                Placeholder::Value(amount) => Ok(*amount),
+                Placeholder::Param(name) => match param(name)? {
+                    CheckedParam::Amount(amount) => Ok(*amount),
+                    CheckedParam::Address(_) => {
+                        Err(invalid(format!("({name}) is not an amount parameter")))
+                    }
+                },
+            }
+        };
+
+        self.msgs
+            .iter()
+            .map(|msg| -> Result<CosmosMsg<Empty>, PreProposeError> {
+                Ok(match msg {
+                    TemplateMsg::BankSend {
+                        to_address,
+                        denom,
+                        amount: value,
+                    } => BankMsg::Send {
+                        to_address: address(to_address)?,
+                        amount: vec![Coin {
+                            denom: denom.clone(),
+                            amount: amount(value)?,
+                        }],
+                    }
+                    .into(),
+                    TemplateMsg::Cw20Transfer {
+                        contract_addr,
+                        recipient,
+                        amount: value,
+                    } => WasmMsg::Execute {
+                        contract_addr: contract_addr.clone(),
+                        msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                            recipient: address(recipient)?,
+                            amount: amount(value)?,
+                        })?,
+                        funds: vec![],
+                    }
+                    .into(),
+                    TemplateMsg::WasmExecute {
+                        contract_addr,
+                        msg,
+                        funds,
+                    } => {
+                        let mut msg = msg.clone();
+                        for (name, value) in &checked {
+                            msg = msg.replace(&format!("{{{{{name}}}}}"), &value.escaped()?);
+                        }
+                        // Values are escaped, but the template's own
+                        // JSON is only known to be valid once
+                        // rendered.
+                        from_json::<serde_cw_value::Value>(&msg)
+                            .map_err(|_| invalid("rendered message is not valid JSON"))?;
+                        WasmMsg::Execute {
+                            contract_addr: address(contract_addr)?,
+                            msg: Binary::from(msg.into_bytes()),
+                            funds: funds.clone(),
+                        }
+                        .into()
+                    }
+                })
+            })
+            .collect()
+    }
+}
@@ -1,16 +1,29 @@
-use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{
+    coin, coins, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Empty,
+    StdResult, Uint128, WasmMsg,
+};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
-use cw_denom::UncheckedDenom;
+use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{DepositInfoResponse, UncheckedMessagePolicy, UncheckedSubmissionPolicy},
+    state::{
+        Config, MessagePolicy, Sponsorship, SponsorshipConfig, SponsorshipStatus, SubmissionPolicy,
+        SubmitterInfo,
+    },
+};
 use dao_proposal_single as dps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, CheckedDepositOption, DepositError, DepositRefundPolicy, DepositToken,
+        SlashDestination, UncheckedDepositInfo, UncheckedDepositOption,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
@@ -19,6 +32,10 @@ use dao_voting::{
 use dps::query::ProposalResponse;
 
 use crate::contract::*;
+use crate::templates::{
+    ParamType, ParamValue, Placeholder, Template, TemplateMsg, TemplateParam, TemplateResponse,
+    UncheckedTemplate,
+};
 
 fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -113,10 +130,16 @@ fn setup_default_test(
     deposit_info: Option<UncheckedDepositInfo>,
     open_proposal_submission: bool,
 ) -> DefaultTestSetup {
-    let dps_id = app.store_code(cw_dao_proposal_single_contract());
-
     let proposal_module_instantiate =
         get_default_proposal_module_instantiate(app, deposit_info, open_proposal_submission);
+    setup_test(app, proposal_module_instantiate)
+}
+
+fn setup_test(
+    app: &mut App,
+    proposal_module_instantiate: dps::msg::InstantiateMsg,
+) -> DefaultTestSetup {
+    let dps_id = app.store_code(cw_dao_proposal_single_contract());
 
     let core_addr = instantiate_with_cw4_groups_governance(
         app,
@@ -934,38 +957,6 @@ fn test_no_deposit_required_members_submission() {
     assert_eq!(Status::Passed, new_status)
 }
 
-#[test]
-fn test_execute_extension_does_nothing() {
-    let mut app = App::default();
-    let DefaultTestSetup {
-        core_addr: _,
-        proposal_single: _,
-        pre_propose,
-    } = setup_default_test(
-        &mut app, None, false, // no open proposal submission.
-    );
-
-    let res = app
-        .execute_contract(
-            Addr::unchecked("ekez"),
-            pre_propose,
-            &ExecuteMsg::Extension {
-                msg: Empty::default(),
-            },
-            &[],
-        )
-        .unwrap();
-
-    // There should be one event which is the invocation of the contract.
-    assert_eq!(res.events.len(), 1);
-    assert_eq!(res.events[0].ty, "execute".to_string());
-    assert_eq!(res.events[0].attributes.len(), 1);
-    assert_eq!(
-        res.events[0].attributes[0].key,
-        "_contract_address".to_string()
-    )
-}
-
 #[test]
 #[should_panic(expected = "invalid zero deposit. set the deposit to `None` to have no deposit")]
 fn test_instantiate_with_zero_native_deposit() {
@@ -1362,3 +1353,1149 @@ fn test_hook_management() {
     let hooks = query_hooks(app, pre_propose).hooks;
     assert_eq!(hooks, vec!["two".to_string()])
 }
+
+#[test]
+fn test_deposit_options() {
+    let mut app = App::default();
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+        false,
+    );
+
+    let options = vec![
+        UncheckedDepositOption {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("uusdc".to_string()),
+            },
+            amount: Uint128::new(5),
+        },
+        UncheckedDepositOption {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
+            },
+            amount: Uint128::new(10),
+        },
+    ];
+
+    // Only the DAO may set deposit options.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateDepositOptions {
+                options: options.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateDepositOptions { options },
+        &[],
+    )
+    .unwrap();
+    let options: Vec<CheckedDepositOption> = app
+        .wrap()
+        .query_wasm_smart(pre_propose.clone(), &QueryMsg::DepositOptions {})
+        .unwrap();
+    assert_eq!(options.len(), 2);
+
+    // Unaccepted denoms and missing deposits are rejected.
+    mint_natives(&mut app, "ekez", coins(10, "uatom"));
+    let propose = ExecuteMsg::Propose {
+        msg: ProposeMessage::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+            vote: None,
+        },
+    };
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &propose,
+            &coins(10, "uatom"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::UnacceptedDenom {
+            denom: "uatom".to_string()
+        })
+    );
+    let err: PreProposeError = app
+        .execute_contract(Addr::unchecked("ekez"), pre_propose.clone(), &propose, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Deposit(DepositError::NoDepositPaid));
+
+    // Pay with the native option. The proposal fails and the DAO
+    // receives the deposit that was paid.
+    mint_natives(&mut app, "ekez", coins(5, "uusdc"));
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(5, "uusdc"),
+    );
+    assert_eq!(
+        get_deposit_info(&app, pre_propose.clone(), id).deposit_info,
+        Some(CheckedDepositInfo {
+            denom: CheckedDenom::Native("uusdc".to_string()),
+            amount: Uint128::new(5),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        })
+    );
+    let status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    assert_eq!(status, Status::Rejected);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "uusdc").u128(),
+        5
+    );
+
+    // Pay with the cw20 option. The proposal passes and the deposit
+    // is refunded in the cw20.
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(10),
+    );
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address.clone(), "ekez"),
+        Uint128::zero()
+    );
+    let status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Yes);
+    assert_eq!(status, Status::Passed);
+    execute_proposal(&mut app, proposal_single, "ekez", id);
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address, "ekez"),
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn test_slash_by_outcome() {
+    let mut app = App::default();
+
+    let mut instantiate = get_default_proposal_module_instantiate(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Slash {
+                rejected: Decimal::percent(10),
+                vetoed: Decimal::percent(50),
+                no_quorum: Decimal::one(),
+                destination: SlashDestination::Address {
+                    address: "community_pool".to_string(),
+                },
+            },
+        }),
+        false,
+    );
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(60)),
+    };
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_test(&mut app, instantiate);
+
+    // A proposal that is voted down loses the rejected percentage.
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    let status = vote(&mut app, proposal_single.clone(), "keze", id, Vote::No);
+    assert_eq!(status, Status::Rejected);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno").u128(), 19);
+    assert_eq!(
+        get_balance_native(&app, "community_pool", "ujuno").u128(),
+        1
+    );
+
+    // A proposal that expires without reaching quorum loses the
+    // no quorum percentage.
+    let id = make_proposal(
+        &mut app,
+        pre_propose,
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    app.update_block(|b| b.time = b.time.plus_seconds(86400));
+    close_proposal(&mut app, proposal_single, "ekez", id);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno").u128(), 9);
+    assert_eq!(
+        get_balance_native(&app, "community_pool", "ujuno").u128(),
+        11
+    );
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno").u128(),
+        0
+    );
+}
+
+#[test]
+fn test_submission_policy() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let policy = UncheckedSubmissionPolicy {
+        cooldown: Some(Duration::Height(10)),
+        max_open_proposals: Some(1),
+        min_voting_power: Some(Uint128::new(9)),
+        allowlist: true,
+        nft_collection: None,
+    };
+
+    // Only the DAO may update the submission policy.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateSubmissionPolicy {
+                policy: policy.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateSubmissionPolicy { policy },
+        &[],
+    )
+    .unwrap();
+    let policy: SubmissionPolicy = app
+        .wrap()
+        .query_wasm_smart(pre_propose.clone(), &QueryMsg::SubmissionPolicy {})
+        .unwrap();
+    assert_eq!(policy.min_voting_power, Some(Uint128::new(9)));
+
+    let propose = |app: &mut App, sender: &str| -> PreProposeError {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+
+    // Addresses without enough voting power may not propose, even
+    // though proposal submission is open.
+    assert_eq!(
+        propose(&mut app, "foo"),
+        PreProposeError::InsufficientVotingPower {
+            min: Uint128::new(9)
+        }
+    );
+    assert_eq!(
+        propose(&mut app, "keze"),
+        PreProposeError::InsufficientVotingPower {
+            min: Uint128::new(9)
+        }
+    );
+    assert_eq!(
+        propose(&mut app, "ekez"),
+        PreProposeError::NotAllowedToSubmit {}
+    );
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateSubmissionAllowlist {
+            to_add: vec!["ekez".to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let allowlist: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::SubmissionAllowlist {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(allowlist, vec![Addr::unchecked("ekez")]);
+
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    let height = app.block_info().height;
+    assert_eq!(
+        propose(&mut app, "ekez"),
+        PreProposeError::CooldownActive {
+            until: Expiration::AtHeight(height + 10)
+        }
+    );
+
+    app.update_block(|block| block.height += 10);
+    assert_eq!(
+        propose(&mut app, "ekez"),
+        PreProposeError::TooManyOpenProposals { max: 1 }
+    );
+
+    // Once the open proposal completes another may be created.
+    let status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Yes);
+    assert_eq!(status, Status::Passed);
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    let submitter: SubmitterInfo = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::Submitter {
+                address: "ekez".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(submitter.open_proposals, 0);
+
+    make_proposal(&mut app, pre_propose, proposal_single, "ekez", &[]);
+}
+
+#[test]
+fn test_proposal_templates() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let payout = UncheckedTemplate {
+        description: "pay a contributor".to_string(),
+        params: vec![
+            TemplateParam {
+                name: "recipient".to_string(),
+                param_type: ParamType::Address {},
+            },
+            TemplateParam {
+                name: "amount".to_string(),
+                param_type: ParamType::Amount {
+                    max: Some(Uint128::new(100)),
+                },
+            },
+        ],
+        msgs: vec![TemplateMsg::BankSend {
+            to_address: Placeholder::Param("recipient".to_string()),
+            denom: "ujuno".to_string(),
+            amount: Placeholder::Param("amount".to_string()),
+        }],
+        deposit: Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(5),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+    };
+    let set_template = |name: &str, template: UncheckedTemplate| ExecuteMsg::Extension {
+        msg: ExecuteExt::SetTemplate {
+            name: name.to_string(),
+            template,
+        },
+    };
+
+    // Only the DAO may register templates.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &set_template("payout", payout.clone()),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    // Placeholders must refer to parameters of the right type.
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &set_template(
+                "bad",
+                UncheckedTemplate {
+                    msgs: vec![TemplateMsg::BankSend {
+                        to_address: Placeholder::Param("amount".to_string()),
+                        denom: "ujuno".to_string(),
+                        amount: Placeholder::Value(Uint128::new(1)),
+                    }],
+                    ..payout.clone()
+                },
+            ),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::InvalidTemplate {
+            reason: "(amount) is not an address parameter".to_string()
+        }
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &set_template("payout", payout),
+        &[],
+    )
+    .unwrap();
+    let templates: Vec<TemplateResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Templates {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(templates.len(), 1);
+    assert_eq!(templates[0].name, "payout");
+
+    let propose = |recipient: &str, amount: &str| ExecuteMsg::Propose {
+        msg: ProposeMessage::ProposeFromTemplate {
+            title: "payout".to_string(),
+            description: "for services rendered".to_string(),
+            template: "payout".to_string(),
+            params: vec![
+                ParamValue {
+                    name: "recipient".to_string(),
+                    value: recipient.to_string(),
+                },
+                ParamValue {
+                    name: "amount".to_string(),
+                    value: amount.to_string(),
+                },
+            ],
+            vote: None,
+        },
+    };
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+
+    // Amounts are bounded by the parameter's maximum.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &propose("keze", "101"),
+            &coins(5, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::InvalidTemplateParam {
+            name: "amount".to_string(),
+            reason: "greater than the maximum of (100)".to_string()
+        }
+    );
+
+    // The template's deposit must be paid.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &propose("keze", "50"),
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::InvalidDeposit {
+            actual: Uint128::new(10),
+            expected: Uint128::new(5)
+        })
+    );
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &propose("keze", "50"),
+        &coins(5, "ujuno"),
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dps::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(
+        proposal.proposal.msgs,
+        vec![BankMsg::Send {
+            to_address: "keze".to_string(),
+            amount: coins(50, "ujuno"),
+        }
+        .into()]
+    );
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    let deposit = get_deposit_info(&app, pre_propose.clone(), 1);
+    assert_eq!(
+        deposit.deposit_info.unwrap().amount,
+        Uint128::new(5),
+        "the template's deposit is taken"
+    );
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::RemoveTemplate {
+                name: "payout".to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &propose("keze", "50"),
+            &coins(5, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::TemplateNotFound {
+            name: "payout".to_string()
+        }
+    );
+    let template: StdResult<Template> = app.wrap().query_wasm_smart(
+        pre_propose,
+        &QueryMsg::QueryExtension {
+            msg: QueryExt::Template {
+                name: "payout".to_string(),
+            },
+        },
+    );
+    assert!(template.is_err());
+}
+
+#[test]
+fn test_execute_extension() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    // Extension messages manage templates, which only the DAO may do.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::RemoveTemplate {
+                    name: "payout".to_string(),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr,
+            pre_propose,
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::RemoveTemplate {
+                    name: "payout".to_string(),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::TemplateNotFound {
+            name: "payout".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_wasm_execute_template() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let template = |msg: &str| UncheckedTemplate {
+        description: "pay through a contract".to_string(),
+        params: vec![
+            TemplateParam {
+                name: "recipient".to_string(),
+                param_type: ParamType::Address {},
+            },
+            TemplateParam {
+                name: "amount".to_string(),
+                param_type: ParamType::Amount { max: None },
+            },
+        ],
+        msgs: vec![TemplateMsg::WasmExecute {
+            contract_addr: Placeholder::Value("payroll".to_string()),
+            msg: msg.to_string(),
+            funds: vec![],
+        }],
+        deposit: None,
+    };
+    for (name, msg) in [
+        (
+            "payout",
+            r#"{"pay":{"to":"{{recipient}}","amount":"{{amount}}"}}"#,
+        ),
+        // Unquoted placeholders render to invalid JSON.
+        ("broken", r#"{"pay":{"to":{{recipient}}}}"#),
+    ] {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::SetTemplate {
+                    name: name.to_string(),
+                    template: template(msg),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let propose = |template: &str| ExecuteMsg::Propose {
+        msg: ProposeMessage::ProposeFromTemplate {
+            title: "payout".to_string(),
+            description: "for services rendered".to_string(),
+            template: template.to_string(),
+            params: vec![
+                ParamValue {
+                    name: "recipient".to_string(),
+                    value: "keze".to_string(),
+                },
+                ParamValue {
+                    name: "amount".to_string(),
+                    value: "50".to_string(),
+                },
+            ],
+            vote: None,
+        },
+    };
+
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &propose("broken"),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::InvalidTemplate {
+            reason: "rendered message is not valid JSON".to_string()
+        }
+    );
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose,
+        &propose("payout"),
+        &[],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dps::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(
+        proposal.proposal.msgs,
+        vec![WasmMsg::Execute {
+            contract_addr: "payroll".to_string(),
+            msg: br#"{"pay":{"to":"keze","amount":"50"}}"#.to_vec().into(),
+            funds: vec![],
+        }
+        .into()]
+    );
+}
+
+#[test]
+fn test_message_policy() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let policy = UncheckedMessagePolicy {
+        protected_contracts: vec!["protected".to_string()],
+        denied_contracts: vec!["denied".to_string()],
+        spend_limits: vec![coin(100, "ujuno"), coin(10, "token")],
+        max_msgs: Some(2),
+    };
+
+    // Only the DAO may update the message policy.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateMessagePolicy {
+                policy: policy.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateMessagePolicy { policy },
+        &[],
+    )
+    .unwrap();
+    let policy: MessagePolicy = app
+        .wrap()
+        .query_wasm_smart(pre_propose.clone(), &QueryMsg::MessagePolicy {})
+        .unwrap();
+    assert_eq!(policy.max_msgs, Some(2));
+
+    let propose = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs,
+                    vote: None,
+                },
+            },
+            &[],
+        )
+    };
+    let send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let execute = |contract: &str| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(&Empty {}).unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+
+    let err: PreProposeError = propose(&mut app, vec![send(1), send(1), send(1)])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::TooManyMessages { max: 2, count: 3 });
+
+    let err: PreProposeError = propose(&mut app, vec![send(60), send(50)])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(100),
+            amount: Uint128::new(110),
+        }
+    );
+
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![WasmMsg::Migrate {
+            contract_addr: "protected".to_string(),
+            new_code_id: 2,
+            msg: to_json_binary(&Empty {}).unwrap(),
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::ProtectedContract {
+            contract: Addr::unchecked("protected")
+        }
+    );
+
+    let err: PreProposeError = propose(&mut app, vec![execute("denied")])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::DeniedContract {
+            contract: Addr::unchecked("denied")
+        }
+    );
+
+    // Burns and cw20 transfers are spends too.
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![BankMsg::Burn {
+            amount: coins(101, "ujuno"),
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(100),
+            amount: Uint128::new(101),
+        }
+    );
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                recipient: "ekez".to_string(),
+                amount: Uint128::new(11),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "token".to_string(),
+            limit: Uint128::new(10),
+            amount: Uint128::new(11),
+        }
+    );
+
+    // Messages whose spend can not be determined are rejected.
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![
+            send(1),
+            CosmosMsg::Stargate {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: Binary::default(),
+            },
+        ],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::UncheckableSpend { index: 1 });
+
+    // Protected contracts may still be executed, and spending up to
+    // the limit is allowed.
+    propose(&mut app, vec![execute("protected"), send(100)]).unwrap();
+}
+
+#[test]
+fn test_sponsorship() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        }),
+        false,
+    );
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+
+    let config = SponsorshipConfig {
+        min_sponsors: 1,
+        min_power: Uint128::new(8),
+        window: Duration::Height(10),
+    };
+
+    // Only the DAO may require sponsors, and at least one sponsor
+    // must be required.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateSponsorshipConfig {
+                config: Some(config.clone()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateSponsorshipConfig {
+                config: Some(SponsorshipConfig {
+                    min_sponsors: 0,
+                    ..config.clone()
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::ZeroMinSponsors {});
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateSponsorshipConfig {
+            config: Some(config.clone()),
+        },
+        &[],
+    )
+    .unwrap();
+    let queried: Option<SponsorshipConfig> = app
+        .wrap()
+        .query_wasm_smart(pre_propose.clone(), &QueryMsg::SponsorshipConfig {})
+        .unwrap();
+    assert_eq!(queried, Some(config));
+
+    let propose = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap();
+    };
+    let sponsor = |app: &mut App, sender: &str, id: u64| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Sponsor { id },
+            &[],
+        )
+    };
+    let sponsorship = |app: &App, id: u64| -> Sponsorship {
+        app.wrap()
+            .query_wasm_smart(pre_propose.clone(), &QueryMsg::Sponsorship { id })
+            .unwrap()
+    };
+    let next_proposal_id = |app: &App| -> u64 {
+        app.wrap()
+            .query_wasm_smart(&proposal_single, &dps::msg::QueryMsg::NextProposalId {})
+            .unwrap()
+    };
+
+    // Submitted proposals seek sponsors instead of being created.
+    propose(&mut app);
+    assert_eq!(next_proposal_id(&app), 1);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(sponsorship(&app, 1).status, SponsorshipStatus::Seeking {});
+
+    let err: PreProposeError = sponsor(&mut app, "ekez", 1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::SponsorIsProposer {});
+    let err: PreProposeError = sponsor(&mut app, "foo", 1).unwrap_err().downcast().unwrap();
+    assert_eq!(err, PreProposeError::NoSponsorPower {});
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("foo"),
+            pre_propose.clone(),
+            &ExecuteMsg::ExpireSponsorship { id: 1 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotExpired {});
+
+    // Sponsoring meets the requirements and creates the proposal.
+    sponsor(&mut app, "keze", 1).unwrap();
+    let sponsored = sponsorship(&app, 1);
+    assert_eq!(
+        sponsored.status,
+        SponsorshipStatus::Sponsored { proposal_id: 1 }
+    );
+    assert_eq!(sponsored.sponsors, vec![Addr::unchecked("keze")]);
+    assert_eq!(sponsored.power, Uint128::new(8));
+    assert_eq!(next_proposal_id(&app), 2);
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    let deposit = get_deposit_info(&app, pre_propose.clone(), 1);
+    assert_eq!(deposit.proposer, Addr::unchecked("ekez"));
+
+    let err: PreProposeError = sponsor(&mut app, "keze", 1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotSeekingSponsors {});
+
+    // Proposals that are not sponsored in time expire and have their
+    // deposits returned.
+    propose(&mut app);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    app.update_block(|block| block.height += 10);
+    let err: PreProposeError = sponsor(&mut app, "keze", 2)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, PreProposeError::SponsorshipExpired { .. }));
+
+    app.execute_contract(
+        Addr::unchecked("foo"),
+        pre_propose.clone(),
+        &ExecuteMsg::ExpireSponsorship { id: 2 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(sponsorship(&app, 2).status, SponsorshipStatus::Expired {});
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(next_proposal_id(&app), 2);
+
+    let sponsorships: Vec<Sponsorship> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::Sponsorships {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(sponsorships.len(), 2);
+}
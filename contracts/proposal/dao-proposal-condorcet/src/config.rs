@@ -2,10 +2,11 @@ use cosmwasm_schema::cw_serde;
 use cw_utils::Duration;
 use dao_voting::{
     threshold::{validate_quorum, PercentageThreshold},
+    veto::VetoConfig,
     voting::validate_voting_period,
 };
 
-use crate::ContractError;
+use crate::{resolution::CycleResolution, ContractError};
 
 #[cw_serde]
 pub struct UncheckedConfig {
@@ -13,6 +14,20 @@ pub struct UncheckedConfig {
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    /// Allows voters to change their ballot until the proposal
+    /// expires. If enabled, proposals will not pass or be rejected
+    /// early as the final tally is not known until expiration.
+    #[serde(default)]
+    pub allow_revoting: bool,
+    /// Optional veto configuration. If set, passed proposals are
+    /// timelocked and may be vetoed by the vetoer.
+    #[serde(default)]
+    pub veto: Option<VetoConfig>,
+    /// Optional method for choosing a winner when a proposal ends
+    /// without a Condorcet winner. If unset, such proposals are
+    /// rejected.
+    #[serde(default)]
+    pub cycle_resolution: Option<CycleResolution>,
 }
 
 #[cw_serde]
@@ -21,6 +36,12 @@ pub(crate) struct Config {
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    #[serde(default)]
+    pub allow_revoting: bool,
+    #[serde(default)]
+    pub veto: Option<VetoConfig>,
+    #[serde(default)]
+    pub cycle_resolution: Option<CycleResolution>,
 }
 
 impl UncheckedConfig {
@@ -33,6 +54,9 @@ impl UncheckedConfig {
             close_proposals_on_execution_failure: self.close_proposals_on_execution_failure,
             voting_period,
             min_voting_period,
+            allow_revoting: self.allow_revoting,
+            veto: self.veto,
+            cycle_resolution: self.cycle_resolution,
         })
     }
 }
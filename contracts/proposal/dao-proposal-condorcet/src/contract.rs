@@ -1,20 +1,36 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdError,
+    StdResult, Storage, SubMsg,
 };
 
 use cw2::set_contract_version;
-use dao_voting::reply::TaggedReplyId;
-use dao_voting::voting::{get_total_power, get_voting_power};
+use cw_hooks::Hooks;
+use cw_storage_plus::Bound;
+use cw_utils::parse_reply_instantiate_data;
+use dao_hooks::{
+    proposal::{new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks},
+    vote::new_vote_hooks,
+};
+use dao_voting::{
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    proposal::DEFAULT_LIMIT,
+    reply::{failed_pre_propose_module_hook_id, TaggedReplyId},
+    veto::VetoError,
+    voting::{get_total_power, get_voting_power},
+};
 
-use crate::config::UncheckedConfig;
+use crate::config::{Config, UncheckedConfig};
 use crate::error::ContractError;
 use crate::msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::proposal::{Proposal, ProposalResponse, Status};
-use crate::state::{next_proposal_id, CONFIG, DAO, PROPOSAL, TALLY, VOTE};
+use crate::proposal::{Proposal, ProposalListResponse, ProposalResponse, Status};
+use crate::state::{
+    next_proposal_id, BALLOT, CONFIG, CREATION_POLICY, DAO, PROPOSAL, PROPOSAL_HOOKS, TALLY,
+    VOTE_HOOKS,
+};
 use crate::tally::Tally;
-use crate::vote::Vote;
+use crate::vote::{Ballot, Vote, VoteResponse};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-condorcet";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -28,10 +44,19 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let (config, pre_propose_info) = msg.into_parts();
+    let config = config.into_checked()?;
+    validate_veto(deps.as_ref(), &config)?;
+
+    let (initial_policy, pre_propose_messages) =
+        pre_propose_info.into_initial_policy_and_messages(info.sender.clone())?;
+
     DAO.save(deps.storage, &info.sender)?;
-    CONFIG.save(deps.storage, &msg.into_checked()?)?;
+    CONFIG.save(deps.storage, &config)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
 
     Ok(Response::default()
+        .add_submessages(pre_propose_messages)
         .add_attribute("method", "instantiate")
         .add_attribute("creator", info.sender))
 }
@@ -65,8 +90,8 @@ pub fn instantiate(
 //
 // in terms of other costs:
 //
-// propose: query_voting_power + compute_winner [2]
-// execute: query_voting_power
+// propose: query_voting_power + compute_winner [2] + resolve_cycle [3]
+// execute: query_voting_power                      + resolve_cycle
 // vote:    query_voting_power + compute_winner
 //
 // so we're good there as well.
@@ -79,6 +104,19 @@ pub fn instantiate(
 //     be voted on or executed.
 // [2] Tally::new computes the winner over the new matrix so that this
 //     is the case.
+// [3] if a cycle resolution is configured, computing the status of a
+//     proposal that has ended without a Condorcet winner runs it over
+//     the whole matrix. propose runs it once over the new matrix, and
+//     the cost of resolution depends only on the number of
+//     candidates.
+//
+// ballots also store the voter's power and an optional rationale. the
+// rationale is chosen, and paid for, by the voter, so it can not make
+// voting on a proposal impossible for anyone else. similarly, voting
+// intentionally does not load the proposal. whether revoting is allowed
+// is stored on the tally for this reason, and proposal status change
+// hooks fire when a proposal is executed, closed, or vetoed rather than
+// when a vote is cast.
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
@@ -88,12 +126,38 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Propose { choices } => execute_propose(deps, env, info, choices),
-        ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
+        ExecuteMsg::Propose { choices, proposer } => {
+            execute_propose(deps, env, info, choices, proposer)
+        }
+        ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        ExecuteMsg::UpdateRationale {
+            proposal_id,
+            rationale,
+        } => execute_update_rationale(deps, info, proposal_id, rationale),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
 
         ExecuteMsg::SetConfig(config) => execute_set_config(deps, info, config),
+        ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
+            execute_update_pre_propose_info(deps, info, new_info)
+        }
+        ExecuteMsg::AddProposalHook { address } => {
+            execute_add_hook(deps, info, PROPOSAL_HOOKS, address, "add_proposal_hook")
+        }
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_hook(deps, info, PROPOSAL_HOOKS, address, "remove_proposal_hook")
+        }
+        ExecuteMsg::AddVoteHook { address } => {
+            execute_add_hook(deps, info, VOTE_HOOKS, address, "add_vote_hook")
+        }
+        ExecuteMsg::RemoveVoteHook { address } => {
+            execute_remove_hook(deps, info, VOTE_HOOKS, address, "remove_vote_hook")
+        }
     }
 }
 
@@ -102,13 +166,32 @@ fn execute_propose(
     env: Env,
     info: MessageInfo,
     choices: Vec<Choice>,
+    proposer: Option<String>,
 ) -> Result<Response, ContractError> {
     let dao = DAO.load(deps.storage)?;
-    let sender_voting_power = get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
-    if sender_voting_power.is_zero() {
-        return Err(ContractError::ZeroVotingPower {});
+    let policy = CREATION_POLICY.load(deps.storage)?;
+    if !policy.is_permitted(&info.sender) {
+        return Err(ContractError::Unauthorized {});
     }
 
+    // a pre-propose module must tell us who the proposer is and is
+    // responsible for checking that they may propose. otherwise, the
+    // sender is the proposer and must have voting power.
+    let proposer = match (proposer, &policy) {
+        (None, ProposalCreationPolicy::Anyone {}) => {
+            let sender_voting_power =
+                get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
+            if sender_voting_power.is_zero() {
+                return Err(ContractError::ZeroVotingPower {});
+            }
+            info.sender
+        }
+        (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
+            deps.api.addr_validate(&proposer)?
+        }
+        _ => return Err(ContractError::InvalidProposer {}),
+    };
+
     let config = CONFIG.load(deps.storage)?;
 
     let id = next_proposal_id(deps.storage)?;
@@ -122,19 +205,32 @@ fn execute_propose(
     let mut choices = choices;
     choices.push(none_of_the_above);
 
-    let tally = Tally::new(
+    let mut tally = Tally::new(
         choices.len() as u32,
         total_power,
         env.block.height,
         config.voting_period.after(&env.block),
     );
+    tally.allow_revoting = config.allow_revoting;
+    if let Some(method) = config.cycle_resolution {
+        // result is discarded. see [3] above.
+        tally.resolve(method);
+    }
     TALLY.save(deps.storage, id, &tally)?;
 
-    let mut proposal = Proposal::new(&env.block, &config, info.sender, id, choices, total_power);
-    proposal.update_status(&env.block, &tally);
+    let mut proposal = Proposal::new(&env.block, &config, proposer, id, choices, total_power);
+    proposal.update_status(&env.block, &tally)?;
     PROPOSAL.save(deps.storage, id, &proposal)?;
 
+    let hooks = new_proposal_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        id as u64,
+        proposal.proposer.as_str(),
+    )?;
+
     Ok(Response::default()
+        .add_submessages(hooks)
         .add_attribute("method", "propose")
         .add_attribute("proposal_id", proposal.id.to_string())
         .add_attribute("proposer", proposal.proposer))
@@ -146,8 +242,9 @@ fn execute_vote(
     info: MessageInfo,
     proposal_id: u32,
     vote: Vec<u32>,
+    rationale: Option<String>,
 ) -> Result<Response, ContractError> {
-    let tally = TALLY.load(deps.storage, proposal_id)?;
+    let mut tally = TALLY.load(deps.storage, proposal_id)?;
     let sender_power = get_voting_power(
         deps.as_ref(),
         info.sender.clone(),
@@ -155,25 +252,89 @@ fn execute_vote(
         Some(tally.start_height),
     )?;
     if sender_power.is_zero() {
-        Err(ContractError::ZeroVotingPower {})
-    } else if VOTE.has(deps.storage, (proposal_id, info.sender.clone())) {
-        Err(ContractError::Voted {})
-    } else if tally.expired(&env.block) {
-        Err(ContractError::Expired {})
-    } else {
-        let vote = Vote::new(vote, tally.candidates())?;
-        VOTE.save(deps.storage, (proposal_id, info.sender.clone()), &vote)?;
+        return Err(ContractError::ZeroVotingPower {});
+    }
+    let previous = BALLOT.may_load(deps.storage, (proposal_id, info.sender.clone()))?;
+    if previous.is_some() && !tally.allow_revoting {
+        return Err(ContractError::Voted {});
+    }
+    if tally.expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
 
-        let mut tally = tally;
-        tally.add_vote(vote, sender_power);
-        TALLY.save(deps.storage, proposal_id, &tally)?;
+    let vote = Vote::new(vote, tally.candidates())?;
+    if previous
+        .as_ref()
+        .map_or(false, |ballot| ballot.vote == vote)
+    {
+        return Err(ContractError::AlreadyCast {});
+    }
 
-        Ok(Response::default()
-            .add_attribute("method", "vote")
-            .add_attribute("proposal_id", proposal_id.to_string())
-            .add_attribute("voter", info.sender)
-            .add_attribute("power", sender_power))
+    BALLOT.save(
+        deps.storage,
+        (proposal_id, info.sender.clone()),
+        &Ballot {
+            vote: vote.clone(),
+            power: sender_power,
+            rationale: rationale.clone(),
+        },
+    )?;
+    let position = vote.to_string();
+    match previous {
+        Some(ballot) => tally.change_vote(&ballot.vote, ballot.power, vote, sender_power),
+        None => tally.add_vote(vote, sender_power),
     }
+    TALLY.save(deps.storage, proposal_id, &tally)?;
+
+    let hooks = new_vote_hooks(
+        VOTE_HOOKS,
+        deps.storage,
+        proposal_id as u64,
+        info.sender.to_string(),
+        position.clone(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("method", "vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("power", sender_power)
+        .add_attribute("position", position)
+        .add_attribute(
+            "rationale",
+            rationale.unwrap_or_else(|| "_none".to_string()),
+        ))
+}
+
+fn execute_update_rationale(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u32,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    // info.sender can't be forged so we implicitly access control
+    // with the key.
+    BALLOT.update(
+        deps.storage,
+        (proposal_id, info.sender.clone()),
+        |ballot| match ballot {
+            Some(ballot) => Ok(Ballot {
+                rationale: rationale.clone(),
+                ..ballot
+            }),
+            None => Err(ContractError::NoSuchVote {
+                id: proposal_id,
+                voter: info.sender.to_string(),
+            }),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "update_rationale")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("rationale", rationale.as_deref().unwrap_or("_none")))
 }
 
 fn execute_execute(
@@ -190,23 +351,91 @@ fn execute_execute(
         &dao,
         Some(tally.start_height),
     )?;
-    if sender_power.is_zero() {
-        return Err(ContractError::ZeroVotingPower {});
-    }
 
     let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
-    if let Status::Passed { winner } = proposal.update_status(&env.block, &tally) {
-        let msgs = proposal.set_executed(dao, winner)?;
-        PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+    let old_status = proposal.update_status(&env.block, &tally)?;
+    let winner = match old_status {
+        Status::Passed { winner } => {
+            if sender_power.is_zero() {
+                return Err(ContractError::ZeroVotingPower {});
+            }
+            winner
+        }
+        Status::VetoTimelock { winner, .. } => {
+            let veto = proposal
+                .veto
+                .as_ref()
+                .ok_or(VetoError::NoVetoConfiguration {})?;
+            // only the vetoer may execute a timelocked proposal, and
+            // only if early execution is enabled.
+            if veto.vetoer != info.sender {
+                return Err(if sender_power.is_zero() {
+                    ContractError::ZeroVotingPower {}
+                } else {
+                    VetoError::Timelocked {}.into()
+                });
+            }
+            veto.check_early_execute_enabled()?;
+            winner
+        }
+        _ => return Err(ContractError::Unexecutable {}),
+    };
 
-        Ok(Response::default()
-            .add_attribute("method", "execute")
-            .add_attribute("proposal_id", proposal_id.to_string())
-            .add_attribute("executor", info.sender)
-            .add_submessage(msgs))
-    } else {
-        Err(ContractError::Unexecutable {})
+    let msgs = proposal.set_executed(dao, winner)?;
+    PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+
+    let hooks = completion_hooks(deps.storage, &proposal, old_status)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("executor", info.sender)
+        .add_submessage(msgs)
+        .add_submessages(hooks))
+}
+
+fn execute_veto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u32,
+) -> Result<Response, ContractError> {
+    let tally = TALLY.load(deps.storage, proposal_id)?;
+    let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+    let old_status = proposal.update_status(&env.block, &tally)?;
+
+    let veto = proposal
+        .veto
+        .as_ref()
+        .ok_or(VetoError::NoVetoConfiguration {})?;
+    veto.check_is_vetoer(&info)?;
+
+    match old_status {
+        // open proposals may only be vetoed if veto_before_passed is
+        // enabled.
+        Status::Open => veto.check_veto_before_passed_enabled()?,
+        // a passed proposal with a veto configuration has outlived
+        // its timelock.
+        Status::Passed { .. } => return Err(VetoError::TimelockExpired {}.into()),
+        Status::VetoTimelock { .. } => (),
+        _ => {
+            return Err(VetoError::InvalidProposalStatus {
+                status: old_status.to_string(),
+            }
+            .into())
+        }
     }
+
+    proposal.set_vetoed();
+    PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+
+    let hooks = completion_hooks(deps.storage, &proposal, old_status)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "veto")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("vetoer", info.sender)
+        .add_submessages(hooks))
 }
 
 fn execute_close(
@@ -217,19 +446,55 @@ fn execute_close(
 ) -> Result<Response, ContractError> {
     let tally = TALLY.load(deps.storage, proposal_id)?;
     let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
-    if let Status::Rejected = proposal.update_status(&env.block, &tally) {
+    let old_status = proposal.update_status(&env.block, &tally)?;
+    if let Status::Rejected = old_status {
         proposal.set_closed();
         PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
 
+        let hooks = completion_hooks(deps.storage, &proposal, old_status)?;
+
         Ok(Response::default()
             .add_attribute("method", "close")
             .add_attribute("proposal_id", proposal_id.to_string())
-            .add_attribute("closer", info.sender))
+            .add_attribute("closer", info.sender)
+            .add_submessages(hooks))
     } else {
         Err(ContractError::Unclosable {})
     }
 }
 
+/// Prepares the proposal status changed hooks and the pre-propose
+/// module's proposal completed hook for a proposal that has just
+/// been executed, closed, or vetoed.
+fn completion_hooks(
+    storage: &dyn Storage,
+    proposal: &Proposal,
+    old_status: Status,
+) -> StdResult<Vec<SubMsg>> {
+    let new_status = proposal.last_status();
+    let mut hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        storage,
+        proposal.id as u64,
+        old_status.to_string(),
+        new_status.to_string(),
+    )?;
+    // the pre-propose module handles deposit refunds.
+    hooks.extend(proposal_completed_hooks(
+        CREATION_POLICY.load(storage)?,
+        proposal.id as u64,
+        new_status.into(),
+    )?);
+    Ok(hooks)
+}
+
+fn validate_veto(deps: Deps, config: &Config) -> Result<(), ContractError> {
+    if let Some(veto) = &config.veto {
+        veto.validate(&deps, &config.voting_period)?;
+    }
+    Ok(())
+}
+
 fn execute_set_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -238,24 +503,120 @@ fn execute_set_config(
     if info.sender != DAO.load(deps.storage)? {
         Err(ContractError::NotDao {})
     } else {
-        CONFIG.save(deps.storage, &config.into_checked()?)?;
+        let config = config.into_checked()?;
+        validate_veto(deps.as_ref(), &config)?;
+        CONFIG.save(deps.storage, &config)?;
         Ok(Response::default()
             .add_attribute("method", "update_config")
             .add_attribute("updater", info.sender))
     }
 }
 
+fn execute_update_pre_propose_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::NotDao {});
+    }
+
+    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(dao)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("method", "update_pre_propose_info")
+        .add_attribute("updater", info.sender)
+        .add_attribute("new_policy", format!("{initial_policy:?}")))
+}
+
+fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    address: String,
+    method: &str,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::NotDao {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    hooks.add_hook(deps.storage, address.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("method", method)
+        .add_attribute("address", address))
+}
+
+fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    address: String,
+    method: &str,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::NotDao {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    hooks.remove_hook(deps.storage, address.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("method", method)
+        .add_attribute("address", address))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Proposal { id } => {
-            let mut proposal = PROPOSAL.load(deps.storage, id)?;
-            let tally = TALLY.load(deps.storage, id)?;
-            proposal.update_status(&env.block, &tally);
-            to_json_binary(&ProposalResponse { proposal, tally })
+            let proposal = PROPOSAL.load(deps.storage, id)?;
+            to_json_binary(&proposal_response(deps, &env, proposal)?)
+        }
+        QueryMsg::ListProposals { start_after, limit } => to_json_binary(&list_proposals(
+            deps,
+            &env,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+            limit,
+        )?),
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => to_json_binary(&list_proposals(
+            deps,
+            &env,
+            None,
+            start_before.map(Bound::exclusive),
+            Order::Descending,
+            limit,
+        )?),
+        QueryMsg::Vote { proposal_id, voter } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            to_json_binary(&VoteResponse {
+                ballot: BALLOT.may_load(deps.storage, (proposal_id, voter))?,
+            })
+        }
+        QueryMsg::Pairwise { proposal_id } => {
+            let proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+            let tally = TALLY.load(deps.storage, proposal_id)?;
+            to_json_binary(&tally.pairwise(proposal.cycle_resolution))
         }
         QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::ProposalCreationPolicy {} => to_json_binary(&CREATION_POLICY.load(deps.storage)?),
+        QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
         QueryMsg::NextProposalId {} => to_json_binary(&next_proposal_id(deps.storage)?),
+        QueryMsg::QuorumReached { proposal_id } => {
+            let proposal_id = u32::try_from(proposal_id)
+                .map_err(|_| StdError::generic_err("proposal id out of range"))?;
+            let proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+            let tally = TALLY.load(deps.storage, proposal_id)?;
+            to_json_binary(&proposal.quorum_reached(&tally))
+        }
         QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
         QueryMsg::Info {} => to_json_binary(&dao_interface::voting::InfoResponse {
             info: cw2::get_contract_version(deps.storage)?,
@@ -263,6 +624,28 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     }
 }
 
+fn proposal_response(deps: Deps, env: &Env, mut proposal: Proposal) -> StdResult<ProposalResponse> {
+    let tally = TALLY.load(deps.storage, proposal.id)?;
+    proposal.update_status(&env.block, &tally)?;
+    Ok(ProposalResponse { proposal, tally })
+}
+
+fn list_proposals(
+    deps: Deps,
+    env: &Env,
+    min: Option<Bound<u32>>,
+    max: Option<Bound<u32>>,
+    order: Order,
+    limit: Option<u64>,
+) -> StdResult<ProposalListResponse> {
+    let proposals: Vec<ProposalResponse> = PROPOSAL
+        .range(deps.storage, min, max, order)
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| proposal_response(deps, env, item?.1))
+        .collect::<StdResult<_>>()?;
+    Ok(ProposalListResponse { proposals })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
@@ -276,6 +659,43 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 .add_attribute("proposal_execution_failed", proposal_id.to_string())
                 .add_attribute("error", msg.result.into_result().err().unwrap_or_default()))
         }
-        _ => unimplemented!("pre-propose and hooks not yet supported"),
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::default().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::FailedVoteHook(idx) => {
+            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::default().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::PreProposeModuleInstantiation => {
+            let res = parse_reply_instantiate_data(msg)?;
+            let module = deps.api.addr_validate(&res.contract_address)?;
+            CREATION_POLICY.save(
+                deps.storage,
+                &ProposalCreationPolicy::Module { addr: module },
+            )?;
+
+            let response = Response::default()
+                .add_attribute("update_pre_propose_module", res.contract_address);
+            Ok(match res.data {
+                Some(data) => response.set_data(data),
+                None => response,
+            })
+        }
+        TaggedReplyId::FailedPreProposeModuleHook => {
+            // our pre-propose module errored while receiving a
+            // proposal completed hook. remove it so that proposals
+            // may still complete.
+            let addr = match CREATION_POLICY.load(deps.storage)? {
+                ProposalCreationPolicy::Anyone {} => {
+                    return Err(ContractError::InvalidReplyID {
+                        id: failed_pre_propose_module_hook_id(),
+                    })
+                }
+                ProposalCreationPolicy::Module { addr } => addr,
+            };
+            CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
+            Ok(Response::default().add_attribute("failed_prepropose_hook", addr))
+        }
     }
 }
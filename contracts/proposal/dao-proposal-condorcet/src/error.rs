@@ -1,5 +1,9 @@
 use cosmwasm_std::StdError;
-use dao_voting::{error::VotingError, reply::error::TagError, threshold::ThresholdError};
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::{
+    error::VotingError, reply::error::TagError, threshold::ThresholdError, veto::VetoError,
+};
 use thiserror::Error;
 
 use crate::vote::VoteError;
@@ -16,6 +20,12 @@ pub enum ContractError {
     Voting(#[from] VotingError),
     #[error(transparent)]
     Tag(#[from] TagError),
+    #[error(transparent)]
+    Hook(#[from] HookError),
+    #[error(transparent)]
+    Veto(#[from] VetoError),
+    #[error(transparent)]
+    ParseReply(#[from] ParseReplyError),
 
     #[error("non-zero voting power required to perform this action")]
     ZeroVotingPower {},
@@ -32,6 +42,21 @@ pub enum ContractError {
     #[error("already voted")]
     Voted {},
 
+    #[error("already cast a vote with that ranking. change your vote to revote")]
+    AlreadyCast {},
+
+    #[error("no vote exists for proposal ({id}) and voter ({voter})")]
+    NoSuchVote { id: u32, voter: String },
+
+    #[error("the proposal creation policy does not permit this sender to propose")]
+    Unauthorized {},
+
+    #[error("the proposer must be set if and only if a pre-propose module creates the proposal")]
+    InvalidProposer {},
+
+    #[error("received a reply failure with an invalid ID: ({id})")]
+    InvalidReplyID { id: u64 },
+
     #[error("only non-expired proposals may be voted on")]
     Expired {},
 
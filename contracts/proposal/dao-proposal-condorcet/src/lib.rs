@@ -5,6 +5,7 @@ mod error;
 mod m;
 pub mod msg;
 pub mod proposal;
+pub mod resolution;
 pub mod state;
 pub mod tally;
 
@@ -1,11 +1,53 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
 
 use dao_dao_macros::proposal_module_query;
+use dao_voting::{
+    pre_propose::PreProposeInfo, proposal::ProposalMsgs, threshold::PercentageThreshold,
+    veto::VetoConfig,
+};
 
-use crate::config::UncheckedConfig;
+use crate::{config::UncheckedConfig, resolution::CycleResolution};
 
-pub type InstantiateMsg = UncheckedConfig;
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub quorum: PercentageThreshold,
+    pub voting_period: Duration,
+    pub min_voting_period: Option<Duration>,
+    pub close_proposals_on_execution_failure: bool,
+    /// Allows voters to change their ballot until the proposal
+    /// expires.
+    #[serde(default)]
+    pub allow_revoting: bool,
+    /// Optional veto configuration for proposals created by this
+    /// module.
+    #[serde(default)]
+    pub veto: Option<VetoConfig>,
+    /// Optional method for choosing a winner when a proposal ends
+    /// without a Condorcet winner.
+    #[serde(default)]
+    pub cycle_resolution: Option<CycleResolution>,
+    /// Information about what addresses may create proposals.
+    pub pre_propose_info: PreProposeInfo,
+}
+
+impl InstantiateMsg {
+    pub(crate) fn into_parts(self) -> (UncheckedConfig, PreProposeInfo) {
+        (
+            UncheckedConfig {
+                quorum: self.quorum,
+                voting_period: self.voting_period,
+                min_voting_period: self.min_voting_period,
+                close_proposals_on_execution_failure: self.close_proposals_on_execution_failure,
+                allow_revoting: self.allow_revoting,
+                veto: self.veto,
+                cycle_resolution: self.cycle_resolution,
+            },
+            self.pre_propose_info,
+        )
+    }
+}
 
 #[cw_serde]
 pub struct Choice {
@@ -14,11 +56,79 @@ pub struct Choice {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Propose { choices: Vec<Choice> },
-    Vote { proposal_id: u32, vote: Vec<u32> },
-    Execute { proposal_id: u32 },
-    Close { proposal_id: u32 },
+    Propose {
+        choices: Vec<Choice>,
+        /// The address creating the proposal. If no pre-propose
+        /// module is attached to this module this must always be
+        /// None as the proposer is the sender of the propose
+        /// message. If a pre-propose module is attached, this must be
+        /// Some and will set the proposer of the proposal it creates.
+        #[serde(default)]
+        proposer: Option<String>,
+    },
+    Vote {
+        proposal_id: u32,
+        vote: Vec<u32>,
+        /// An optional rationale for why this vote was cast.
+        #[serde(default)]
+        rationale: Option<String>,
+    },
+    /// Updates the sender's rationale for their vote on the
+    /// specified proposal.
+    UpdateRationale {
+        proposal_id: u32,
+        rationale: Option<String>,
+    },
+    Execute {
+        proposal_id: u32,
+    },
+    /// Vetoes a proposal. Only the vetoer configured on the proposal
+    /// may do this, either while it is timelocked or, if enabled,
+    /// while it is open.
+    Veto {
+        proposal_id: u32,
+    },
+    Close {
+        proposal_id: u32,
+    },
     SetConfig(UncheckedConfig),
+    /// Updates the proposal creation policy used for this
+    /// module. Only the DAO may call this method.
+    UpdatePreProposeInfo {
+        info: PreProposeInfo,
+    },
+    /// Adds an address as a consumer of proposal hooks. Consumers of
+    /// proposal hooks have hook messages executed on them whenever
+    /// the status of a proposal changes or a proposal is created.
+    AddProposalHook {
+        address: String,
+    },
+    /// Removes a consumer of proposal hooks.
+    RemoveProposalHook {
+        address: String,
+    },
+    /// Adds an address as a consumer of vote hooks. Consumers of vote
+    /// hooks have hook messages executed on them whenever a vote is
+    /// cast.
+    AddVoteHook {
+        address: String,
+    },
+    /// Removes a consumer of vote hooks.
+    RemoveVoteHook {
+        address: String,
+    },
+}
+
+impl ProposalMsgs for ExecuteMsg {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        match self {
+            ExecuteMsg::Propose { choices, .. } => choices
+                .iter()
+                .map(|choice| choice.msgs.as_slice())
+                .collect(),
+            _ => vec![],
+        }
+    }
 }
 
 #[proposal_module_query]
@@ -27,6 +137,35 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(crate::proposal::ProposalResponse)]
     Proposal { id: u32 },
+    /// Lists proposals in ascending order of id.
+    #[returns(crate::proposal::ProposalListResponse)]
+    ListProposals {
+        start_after: Option<u32>,
+        limit: Option<u64>,
+    },
+    /// Lists proposals in descending order of id.
+    #[returns(crate::proposal::ProposalListResponse)]
+    ReverseProposals {
+        start_before: Option<u32>,
+        limit: Option<u64>,
+    },
+    /// Returns the ballot cast by a voter on a proposal, if any.
+    #[returns(crate::vote::VoteResponse)]
+    Vote { proposal_id: u32, voter: String },
+    /// Returns the pairwise margins between every candidate on a
+    /// proposal along with the strongest paths between them, so that
+    /// the outcome can be audited.
+    #[returns(crate::tally::PairwiseResponse)]
+    Pairwise { proposal_id: u32 },
     #[returns(crate::config::Config)]
     Config {},
+    /// Gets the proposal creation policy used by this module.
+    #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+    /// Lists all of the consumers of proposal hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ProposalHooks {},
+    /// Lists all of the consumers of vote hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    VoteHooks {},
 }
@@ -2,13 +2,14 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Addr, BlockInfo, StdResult, SubMsg, Uint128, WasmMsg};
 use cw_utils::Expiration;
 use dao_voting::{
-    reply::mask_proposal_execution_proposal_id, threshold::PercentageThreshold,
+    reply::mask_proposal_execution_proposal_id, threshold::PercentageThreshold, veto::VetoConfig,
     voting::does_vote_count_pass,
 };
 
 use crate::{
     config::Config,
     msg::Choice,
+    resolution::CycleResolution,
     tally::{Tally, Winner},
 };
 
@@ -23,6 +24,13 @@ pub struct Proposal {
 
     pub close_on_execution_failure: bool,
     pub total_power: Uint128,
+    /// The veto configuration for this proposal, if any.
+    #[serde(default)]
+    pub veto: Option<VetoConfig>,
+    /// The method used to pick a winner if voting ends without a
+    /// Condorcet winner. If unset, the proposal is rejected.
+    #[serde(default)]
+    pub cycle_resolution: Option<CycleResolution>,
 
     pub id: u32,
     pub choices: Vec<Choice>,
@@ -44,6 +52,33 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal has passed and is timelocked. Only the vetoer
+    /// may execute or veto it until the timelock expires.
+    VetoTimelock { winner: u32, expiration: Expiration },
+    /// The proposal has been vetoed.
+    Vetoed,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", dao_voting::status::Status::from(*self))
+    }
+}
+
+impl From<Status> for dao_voting::status::Status {
+    fn from(status: Status) -> Self {
+        use dao_voting::status::Status as S;
+        match status {
+            Status::Open => S::Open,
+            Status::Rejected => S::Rejected,
+            Status::Passed { .. } => S::Passed,
+            Status::Executed => S::Executed,
+            Status::Closed => S::Closed,
+            Status::ExecutionFailed => S::ExecutionFailed,
+            Status::VetoTimelock { expiration, .. } => S::VetoTimelock { expiration },
+            Status::Vetoed => S::Vetoed,
+        }
+    }
 }
 
 #[cw_serde]
@@ -52,61 +87,104 @@ pub struct ProposalResponse {
     pub tally: Tally,
 }
 
-fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
+#[cw_serde]
+pub struct ProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> StdResult<Status> {
     match proposal.last_status {
-        Status::Rejected
-        | Status::Passed { .. }
-        | Status::Executed
-        | Status::Closed
-        | Status::ExecutionFailed => proposal.last_status,
-        Status::Open => {
-            if proposal
-                .min_voting_period
-                .map_or(false, |min| !min.is_expired(block))
-            {
-                return Status::Open;
+        Status::VetoTimelock { winner, expiration } => {
+            // once the timelock expires the proposal is passed.
+            if expiration.is_expired(block) {
+                Ok(Status::Passed { winner })
+            } else {
+                Ok(proposal.last_status)
             }
+        }
+        Status::Open => match (open_status(block, proposal, tally), &proposal.veto) {
+            // the timelock runs from the end of the voting period,
+            // so an early pass does not shorten it.
+            (Status::Passed { winner }, Some(veto)) => {
+                let expiration = tally.expiration.add(veto.timelock_duration)?;
+                if expiration.is_expired(block) {
+                    Ok(Status::Passed { winner })
+                } else {
+                    Ok(Status::VetoTimelock { winner, expiration })
+                }
+            }
+            (status, _) => Ok(status),
+        },
+        _ => Ok(proposal.last_status),
+    }
+}
+
+/// Computes the status of an open proposal from its tally.
+fn open_status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
+    if proposal
+        .min_voting_period
+        .map_or(false, |min| !min.is_expired(block))
+    {
+        return Status::Open;
+    }
 
-            let winner = tally.winner;
-            let expired = tally.expiration.is_expired(block);
-            let quorum = does_vote_count_pass(
-                proposal.total_power - tally.power_outstanding,
-                proposal.total_power,
-                proposal.quorum,
-            );
+    let winner = tally.winner;
+    let expired = tally.expiration.is_expired(block);
+    let quorum = proposal.quorum_reached(tally);
+    // with revoting enabled votes may change until expiration, so
+    // outcomes that would otherwise be certain before then are not.
+    let settled = expired || !tally.allow_revoting;
 
-            if expired && !quorum {
-                Status::Rejected
-            } else {
-                match winner {
-                    Winner::Never => Status::Rejected,
-                    Winner::None => {
-                        if expired {
-                            Status::Rejected
-                        } else {
-                            Status::Open
-                        }
-                    }
-                    Winner::Some(winner) => {
-                        if expired && quorum {
-                            Status::Passed { winner }
-                        } else {
-                            Status::Open
-                        }
-                    }
-                    Winner::Undisputed(winner) => {
-                        if quorum {
-                            Status::Passed { winner }
-                        } else {
-                            Status::Open
-                        }
-                    }
+    if expired && !quorum {
+        Status::Rejected
+    } else {
+        match winner {
+            Winner::Never => {
+                // a cycle resolution may still pick a winner, so wait
+                // for the voting period to end.
+                if expired || (settled && proposal.cycle_resolution.is_none()) {
+                    resolve_cycle(proposal, tally)
+                } else {
+                    Status::Open
+                }
+            }
+            Winner::None => {
+                if expired {
+                    resolve_cycle(proposal, tally)
+                } else {
+                    Status::Open
+                }
+            }
+            Winner::Some(winner) => {
+                if expired && quorum {
+                    Status::Passed { winner }
+                } else {
+                    Status::Open
+                }
+            }
+            Winner::Undisputed(winner) => {
+                if quorum && settled {
+                    Status::Passed { winner }
+                } else {
+                    Status::Open
                 }
             }
         }
     }
 }
 
+/// Computes the status of a proposal that ended with no Condorcet
+/// winner.
+fn resolve_cycle(proposal: &Proposal, tally: &Tally) -> Status {
+    match proposal
+        .cycle_resolution
+        .and_then(|method| tally.resolve(method))
+    {
+        Some(winner) => Status::Passed { winner },
+        None => Status::Rejected,
+    }
+}
+
 impl Proposal {
     pub(crate) fn new(
         block: &BlockInfo,
@@ -122,6 +200,8 @@ impl Proposal {
             min_voting_period: config.min_voting_period.map(|m| m.after(block)),
             quorum: config.quorum,
             close_on_execution_failure: config.close_proposals_on_execution_failure,
+            veto: config.veto.clone(),
+            cycle_resolution: config.cycle_resolution,
 
             id,
             proposer,
@@ -130,17 +210,26 @@ impl Proposal {
         }
     }
 
-    pub(crate) fn update_status(&mut self, block: &BlockInfo, tally: &Tally) -> Status {
-        self.last_status = status(block, self, tally);
-        self.last_status
+    pub(crate) fn update_status(&mut self, block: &BlockInfo, tally: &Tally) -> StdResult<Status> {
+        self.last_status = status(block, self, tally)?;
+        Ok(self.last_status)
     }
 
-    pub fn status(&self, block: &BlockInfo, tally: &Tally) -> Status {
+    pub fn status(&self, block: &BlockInfo, tally: &Tally) -> StdResult<Status> {
         status(block, self, tally)
     }
 
-    // To test that status is updated before responding to queries.
-    #[cfg(test)]
+    /// Returns true if the voting power cast in `tally` meets this
+    /// proposal's quorum.
+    pub fn quorum_reached(&self, tally: &Tally) -> bool {
+        does_vote_count_pass(
+            self.total_power - tally.power_outstanding,
+            self.total_power,
+            self.quorum,
+        )
+    }
+
+    /// The proposal's status as of the last time it was updated.
     pub fn last_status(&self) -> Status {
         self.last_status
     }
@@ -151,10 +240,22 @@ impl Proposal {
         self.last_status = Status::Closed;
     }
 
+    pub(crate) fn set_vetoed(&mut self) {
+        debug_assert!(matches!(
+            self.last_status,
+            Status::Open | Status::VetoTimelock { .. }
+        ));
+
+        self.last_status = Status::Vetoed;
+    }
+
     /// Sets the proposal's status to executed and returns a
     /// submessage to be executed.
     pub(crate) fn set_executed(&mut self, dao: Addr, winner: u32) -> StdResult<SubMsg> {
-        debug_assert_eq!(self.last_status, Status::Passed { winner });
+        debug_assert!(matches!(
+            self.last_status,
+            Status::Passed { winner: w } | Status::VetoTimelock { winner: w, .. } if w == winner
+        ));
 
         self.last_status = Status::Executed;
 
@@ -0,0 +1,165 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// A method for choosing a winner when an election has no Condorcet
+/// winner, i.e. when the pairwise preferences contain a cycle.
+///
+/// Both methods operate on pairwise margins and always elect the
+/// Condorcet winner when one exists.
+#[cw_serde]
+#[derive(Copy)]
+pub enum CycleResolution {
+    /// The Schulze method. Candidates are compared by the strength of
+    /// the strongest path between them, where the strength of a path
+    /// is its weakest margin.
+    Schulze,
+    /// Ranked pairs (Tideman). Pairwise victories are locked in from
+    /// largest to smallest margin, skipping any that would create a
+    /// cycle. The winner is the candidate nobody is locked in over.
+    /// Equal margins are locked in order of candidate index.
+    RankedPairs,
+}
+
+impl CycleResolution {
+    /// Computes the winner from a matrix where `margins[x][y]` is the
+    /// margin by which `x` is preferred to `y`, or zero if `y` is
+    /// preferred to `x`. Returns `None` if the method results in a
+    /// tie.
+    ///
+    /// The amount of computation done does not depend on the
+    /// contents of `margins`, only its size.
+    pub fn winner(self, margins: &[Vec<Uint128>]) -> Option<u32> {
+        match self {
+            CycleResolution::Schulze => schulze_winner(&strongest_paths(margins)),
+            CycleResolution::RankedPairs => ranked_pairs_winner(margins),
+        }
+    }
+}
+
+/// Computes the strength of the strongest path from every candidate
+/// to every other candidate using the Floyd–Warshall algorithm.
+pub fn strongest_paths(margins: &[Vec<Uint128>]) -> Vec<Vec<Uint128>> {
+    let n = margins.len();
+    let mut p = margins.to_vec();
+    for i in 0..n {
+        for j in 0..n {
+            if i == j {
+                continue;
+            }
+            for k in 0..n {
+                if i != k && j != k {
+                    p[j][k] = p[j][k].max(p[j][i].min(p[i][k]));
+                }
+            }
+        }
+    }
+    p
+}
+
+/// The Schulze winner is the candidate whose strongest path to every
+/// other candidate is stronger than the reverse.
+fn schulze_winner(p: &[Vec<Uint128>]) -> Option<u32> {
+    let n = p.len();
+    (0..n)
+        .find(|&x| (0..n).all(|y| x == y || p[x][y] > p[y][x]))
+        .map(|x| x as u32)
+}
+
+fn ranked_pairs_winner(margins: &[Vec<Uint128>]) -> Option<u32> {
+    let n = margins.len();
+    let mut pairs: Vec<(Uint128, usize, usize)> = (0..n)
+        .flat_map(|x| (0..n).map(move |y| (x, y)))
+        .filter(|&(x, y)| x != y)
+        .map(|(x, y)| (margins[x][y], x, y))
+        .collect();
+    // largest margins first, ties in candidate order.
+    pairs.sort_by(|a, b| b.0.cmp(&a.0).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+    // reaches[x][y] is true if there is a path of locked victories
+    // from x to y.
+    let mut reaches = vec![vec![false; n]; n];
+    let mut beaten = vec![false; n];
+    for (margin, winner, loser) in pairs {
+        let lock = !margin.is_zero() && !reaches[loser][winner];
+        if lock {
+            beaten[loser] = true;
+        }
+        // locking winner -> loser lets everything that reaches the
+        // winner reach everything the loser reaches. the loop runs
+        // whether or not the pair is locked so that the cost of
+        // resolution is independent of the votes cast.
+        for x in 0..n {
+            for y in 0..n {
+                let via = (x == winner || reaches[x][winner]) && (y == loser || reaches[loser][y]);
+                reaches[x][y] |= lock && via;
+            }
+        }
+    }
+
+    let mut unbeaten = (0..n).filter(|&c| !beaten[c]);
+    match (unbeaten.next(), unbeaten.next()) {
+        (Some(winner), None) => Some(winner as u32),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn margins(m: &[&[u128]]) -> Vec<Vec<Uint128>> {
+        m.iter()
+            .map(|row| row.iter().map(|v| Uint128::new(*v)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_cycle() {
+        // a > b by 3, b > c by 5, c > a by 1.
+        let m = margins(&[&[0, 3, 0], &[0, 0, 5], &[1, 0, 0]]);
+
+        assert_eq!(CycleResolution::Schulze.winner(&m), Some(0));
+        assert_eq!(CycleResolution::RankedPairs.winner(&m), Some(0));
+        assert_eq!(
+            strongest_paths(&m),
+            margins(&[&[0, 3, 3], &[1, 0, 5], &[1, 1, 0]])
+        );
+    }
+
+    #[test]
+    fn test_condorcet_winner_elected() {
+        // b beats a and c.
+        let m = margins(&[&[0, 0, 2], &[1, 0, 4], &[0, 0, 0]]);
+
+        assert_eq!(CycleResolution::Schulze.winner(&m), Some(1));
+        assert_eq!(CycleResolution::RankedPairs.winner(&m), Some(1));
+    }
+
+    #[test]
+    fn test_tie() {
+        // a perfectly symmetric cycle can not be resolved.
+        let m = margins(&[&[0, 1, 0], &[0, 0, 1], &[1, 0, 0]]);
+        assert_eq!(CycleResolution::Schulze.winner(&m), None);
+
+        // nor can an election without votes.
+        let m = margins(&[&[0, 0], &[0, 0]]);
+        assert_eq!(CycleResolution::Schulze.winner(&m), None);
+        assert_eq!(CycleResolution::RankedPairs.winner(&m), None);
+    }
+
+    #[test]
+    fn test_methods_disagree() {
+        // from the Wikipedia article on the Schulze method. candidates
+        // a-e, margins computed from 45 voters. Schulze elects e.
+        let m = margins(&[
+            &[0, 0, 7, 15, 0],
+            &[5, 0, 0, 21, 0],
+            &[0, 13, 0, 0, 3],
+            &[0, 0, 11, 0, 0],
+            &[1, 9, 0, 17, 0],
+        ]);
+
+        assert_eq!(CycleResolution::Schulze.winner(&m), Some(4));
+        assert_eq!(CycleResolution::RankedPairs.winner(&m), Some(0));
+    }
+}
@@ -3,7 +3,9 @@ use cosmwasm_std::{BlockInfo, Uint128};
 use cw_utils::Expiration;
 
 use crate::{
+    cell::Cell,
     m::{Stats, M},
+    resolution::{strongest_paths, CycleResolution},
     vote::Vote,
 };
 
@@ -30,6 +32,28 @@ pub struct Tally {
     pub power_outstanding: Uint128,
     /// The current winner. Always up to date and updated on vote.
     pub winner: Winner,
+    /// Whether voters may change their votes. Stored here rather
+    /// than on the proposal so that voting need not load the
+    /// proposal.
+    #[serde(default)]
+    pub allow_revoting: bool,
+}
+
+#[cw_serde]
+pub struct PairwiseResponse {
+    /// `margins[x][y]` is the amount of voting power by which
+    /// candidate `x` is preferred to candidate `y`, or zero if `y`
+    /// is preferred to `x`.
+    pub margins: Vec<Vec<Uint128>>,
+    /// `strongest_paths[x][y]` is the strength of the strongest path
+    /// from `x` to `y` as computed by the Schulze method.
+    pub strongest_paths: Vec<Vec<Uint128>>,
+    /// The method used to pick a winner if there is no Condorcet
+    /// winner.
+    pub cycle_resolution: Option<CycleResolution>,
+    /// The winner chosen by `cycle_resolution` given the current
+    /// margins, if there is one.
+    pub resolved_winner: Option<u32>,
 }
 
 #[cw_serde]
@@ -54,6 +78,7 @@ impl Tally {
             winner: Winner::None,
             start_height,
             expiration,
+            allow_revoting: false,
         };
         // compute even though this will always be Winner::None so
         // that creating a tally has the same compute cost of adding a
@@ -94,6 +119,83 @@ impl Tally {
         self.winner = self.winner();
     }
 
+    /// Replaces a vote previously recorded with `add_vote` with a
+    /// new one. Used when a voter changes their ballot. Equivalent to
+    /// removing the old vote and adding the new one, but each
+    /// pairwise margin is updated once and the winner is recomputed
+    /// once. Pairs both votes rank the same way are left untouched
+    /// when the voting power is unchanged.
+    ///
+    /// Invariants:
+    ///
+    /// - `old` and `old_power` were previously added to this tally.
+    /// - Tally is not expired.
+    pub fn change_vote(&mut self, old: &Vote, old_power: Uint128, new: Vote, new_power: Uint128) {
+        let mut old_rank = vec![0; self.m.n as usize];
+        for (rank, candidate) in old.iter().enumerate() {
+            old_rank[*candidate as usize] = rank;
+        }
+        for (index, preference) in new.iter().enumerate() {
+            for defeat in 0..index {
+                let winner = new[defeat];
+                let cell = (*preference, winner);
+                if old_rank[winner as usize] < old_rank[*preference as usize] {
+                    // both votes prefer `winner`, so only a change
+                    // in power moves the margin.
+                    if new_power > old_power {
+                        self.m.decrement(cell, new_power - old_power)
+                    } else if old_power > new_power {
+                        self.m.increment(cell, old_power - new_power)
+                    }
+                } else {
+                    // the old vote's victory for `preference` is
+                    // undone and replaced with a defeat.
+                    self.m.decrement(cell, old_power + new_power)
+                }
+            }
+        }
+        self.power_outstanding = self.power_outstanding + old_power - new_power;
+        self.winner = self.winner();
+    }
+
+    /// Returns the pairwise margins between candidates. See
+    /// `PairwiseResponse::margins`.
+    pub fn margins(&self) -> Vec<Vec<Uint128>> {
+        let n = self.m.n;
+        (0..n)
+            .map(|x| {
+                (0..n)
+                    .map(|y| {
+                        if x == y {
+                            return Uint128::zero();
+                        }
+                        match self.m.get((x, y)) {
+                            Cell::Positive(margin) => margin,
+                            Cell::Zero | Cell::Negative(_) => Uint128::zero(),
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Picks a winner using `method`. Unlike `winner`, this will
+    /// choose a winner when the pairwise preferences contain a
+    /// cycle. Returns `None` if the method results in a tie.
+    pub fn resolve(&self, method: CycleResolution) -> Option<u32> {
+        method.winner(&self.margins())
+    }
+
+    pub fn pairwise(&self, cycle_resolution: Option<CycleResolution>) -> PairwiseResponse {
+        let margins = self.margins();
+        PairwiseResponse {
+            strongest_paths: strongest_paths(&margins),
+            resolved_winner: cycle_resolution.and_then(|method| method.winner(&margins)),
+            cycle_resolution,
+            margins,
+        }
+    }
+
     fn winner(&self) -> Winner {
         match self.m.stats(self.power_outstanding) {
             Stats::PositiveColumn { col, min_margin } => {
@@ -13,7 +13,10 @@ fn test_instantiation() {
     let suite = SuiteBuilder::default().build();
     let config = suite.query_config();
 
-    assert_eq!(config, default_config.into_checked().unwrap())
+    assert_eq!(
+        config,
+        default_config.into_parts().0.into_checked().unwrap()
+    )
 }
 
 #[test]
@@ -24,6 +27,9 @@ fn test_instantiate_conflicting_proposal_durations() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(11)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        veto: None,
+        cycle_resolution: None,
     })
     .build();
 }
@@ -38,6 +44,9 @@ fn test_instantiate_conflicting_duration_types() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Time(9)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        veto: None,
+        cycle_resolution: None,
     })
     .build();
 }
@@ -49,6 +58,9 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(10)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        veto: None,
+        cycle_resolution: None,
     })
     .build();
     SuiteBuilder::with_config(UncheckedConfig {
@@ -56,6 +68,9 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Time(10),
         min_voting_period: Some(Duration::Time(10)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        veto: None,
+        cycle_resolution: None,
     })
     .build();
 }
@@ -0,0 +1,341 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult,
+};
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{Contract, ContractWrapper};
+use dao_interface::state::{Admin, ModuleInstantiateInfo};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
+    state::PreProposeContract,
+};
+use dao_proposal_hook_counter::msg::{
+    CountResponse, InstantiateMsg as HookCounterInstantiateMsg, QueryMsg as HookCounterQueryMsg,
+};
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+};
+
+use crate::{
+    msg::{Choice, ExecuteMsg},
+    proposal::Status,
+    ContractError,
+};
+
+use super::{
+    is_error,
+    suite::{unimportant_message, Suite, SuiteBuilder},
+};
+
+/// A minimal pre-propose module for condorcet proposals which sets
+/// the proposer of proposals it creates to the sender.
+#[cw_serde]
+enum ProposeMessage {
+    Propose { choices: Vec<Choice> },
+}
+
+type PrePropose = PreProposeContract<Empty, Empty, Empty, ExecuteMsg>;
+
+fn pre_propose_instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateBase<Empty>,
+) -> Result<Response, PreProposeError> {
+    PrePropose::default().instantiate(deps, env, info, msg)
+}
+
+fn pre_propose_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteBase<ProposeMessage, Empty>,
+) -> Result<Response, PreProposeError> {
+    let internalized = match msg {
+        ExecuteBase::Propose {
+            msg: ProposeMessage::Propose { choices },
+        } => ExecuteBase::Propose {
+            msg: ExecuteMsg::Propose {
+                choices,
+                proposer: Some(info.sender.to_string()),
+            },
+        },
+        ExecuteBase::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+        } => ExecuteBase::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+        },
+        ExecuteBase::Extension { msg } => ExecuteBase::Extension { msg },
+        ExecuteBase::Withdraw { denom } => ExecuteBase::Withdraw { denom },
+        ExecuteBase::UpdateConfig {
+            deposit_info,
+            open_proposal_submission,
+        } => ExecuteBase::UpdateConfig {
+            deposit_info,
+            open_proposal_submission,
+        },
+        ExecuteBase::UpdateDepositOptions { options } => {
+            ExecuteBase::UpdateDepositOptions { options }
+        }
+        ExecuteBase::UpdateSubmissionPolicy { policy } => {
+            ExecuteBase::UpdateSubmissionPolicy { policy }
+        }
+        ExecuteBase::UpdateSubmissionAllowlist { to_add, to_remove } => {
+            ExecuteBase::UpdateSubmissionAllowlist { to_add, to_remove }
+        }
+        ExecuteBase::UpdateMessagePolicy { policy } => ExecuteBase::UpdateMessagePolicy { policy },
+        ExecuteBase::UpdateSponsorshipConfig { config } => {
+            ExecuteBase::UpdateSponsorshipConfig { config }
+        }
+        ExecuteBase::Sponsor { id } => ExecuteBase::Sponsor { id },
+        ExecuteBase::ExpireSponsorship { id } => ExecuteBase::ExpireSponsorship { id },
+        ExecuteBase::AddProposalSubmittedHook { address } => {
+            ExecuteBase::AddProposalSubmittedHook { address }
+        }
+        ExecuteBase::RemoveProposalSubmittedHook { address } => {
+            ExecuteBase::RemoveProposalSubmittedHook { address }
+        }
+        ExecuteBase::AddSponsorshipHook { address } => ExecuteBase::AddSponsorshipHook { address },
+        ExecuteBase::RemoveSponsorshipHook { address } => {
+            ExecuteBase::RemoveSponsorshipHook { address }
+        }
+    };
+    PrePropose::default().execute(deps, env, info, internalized)
+}
+
+fn pre_propose_query(deps: Deps, env: Env, msg: QueryBase<Empty>) -> StdResult<Binary> {
+    PrePropose::default().query(deps, env, msg)
+}
+
+fn pre_propose_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        pre_propose_execute,
+        pre_propose_instantiate,
+        pre_propose_query,
+    ))
+}
+
+fn hook_counter_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        dao_proposal_hook_counter::contract::execute,
+        dao_proposal_hook_counter::contract::instantiate,
+        dao_proposal_hook_counter::contract::query,
+    ))
+}
+
+/// Installs a pre-propose module requiring a deposit of 10 `ujuno`
+/// and returns its address.
+fn install_pre_propose(suite: &mut Suite) -> Addr {
+    let code_id = suite.store_code(pre_propose_contract());
+    let core = suite.core.clone();
+    suite
+        .execute_condorcet(
+            core,
+            &ExecuteMsg::UpdatePreProposeInfo {
+                info: PreProposeInfo::ModuleMayPropose {
+                    info: ModuleInstantiateInfo {
+                        code_id,
+                        msg: to_json_binary(&InstantiateBase::<Empty> {
+                            deposit_info: Some(UncheckedDepositInfo {
+                                denom: DepositToken::Token {
+                                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                                },
+                                amount: 10u128.into(),
+                                refund_policy: DepositRefundPolicy::OnlyPassed,
+                            }),
+                            open_proposal_submission: false,
+                            extension: Empty {},
+                        })
+                        .unwrap(),
+                        admin: Some(Admin::CoreModule {}),
+                        funds: vec![],
+                        label: "pre-propose".to_string(),
+                    },
+                },
+            },
+        )
+        .unwrap();
+    match suite.query_creation_policy() {
+        ProposalCreationPolicy::Module { addr } => addr,
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+    }
+}
+
+fn propose_with_deposit(suite: &mut Suite, pre_propose: &Addr, choices: Vec<Choice>) {
+    let sender = suite.sender();
+    suite.mint(sender.clone(), coins(10, "ujuno"));
+    suite
+        .execute_on(
+            sender,
+            pre_propose,
+            &ExecuteBase::<ProposeMessage, Empty>::Propose {
+                msg: ProposeMessage::Propose { choices },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_pre_propose_deposits() {
+    let mut suite = SuiteBuilder::default().build();
+    let pre_propose = install_pre_propose(&mut suite);
+
+    let err = suite.propose(suite.sender(), vec![vec![unimportant_message()]]);
+    is_error!(err, &ContractError::Unauthorized {}.to_string());
+
+    // a passed proposal has its deposit refunded on execution.
+    propose_with_deposit(&mut suite, &pre_propose, vec![Choice { msgs: vec![] }]);
+    assert_eq!(suite.query_proposal(1).proposal.proposer, suite.sender());
+    assert_eq!(suite.query_balance(suite.sender(), "ujuno"), 0);
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+    suite.execute(suite.sender(), 1).unwrap();
+    assert_eq!(suite.query_winner_and_status(1).1, Status::Executed);
+    assert_eq!(suite.query_balance(suite.sender(), "ujuno"), 10);
+
+    // a rejected proposal's deposit goes to the DAO on close.
+    propose_with_deposit(
+        &mut suite,
+        &pre_propose,
+        vec![Choice {
+            msgs: vec![unimportant_message()],
+        }],
+    );
+    suite.a_week_passes();
+    suite.close(suite.sender(), 2).unwrap();
+    assert_eq!(suite.query_balance(suite.core.clone(), "ujuno"), 10);
+    assert_eq!(suite.query_balance(suite.sender(), "ujuno"), 10);
+}
+
+#[test]
+fn test_pre_propose_proposer() {
+    let mut suite = SuiteBuilder::default().build();
+
+    // without a pre-propose module the proposer may not be set.
+    let err = suite.execute_condorcet(
+        suite.sender(),
+        &ExecuteMsg::Propose {
+            choices: vec![Choice {
+                msgs: vec![unimportant_message()],
+            }],
+            proposer: Some("someone".to_string()),
+        },
+    );
+    is_error!(err, &ContractError::InvalidProposer {}.to_string());
+
+    // and with one it must be.
+    let pre_propose = install_pre_propose(&mut suite);
+    let err = suite.execute_on(
+        pre_propose.clone(),
+        &suite.condorcet.clone(),
+        &ExecuteMsg::Propose {
+            choices: vec![Choice {
+                msgs: vec![unimportant_message()],
+            }],
+            proposer: None,
+        },
+        &[],
+    );
+    is_error!(err, &ContractError::InvalidProposer {}.to_string());
+
+    let err = suite.execute_condorcet(
+        "someone",
+        &ExecuteMsg::UpdatePreProposeInfo {
+            info: PreProposeInfo::AnyoneMayPropose {},
+        },
+    );
+    is_error!(err, &ContractError::NotDao {}.to_string());
+}
+
+#[test]
+fn test_proposal_and_vote_hooks() {
+    let mut suite = SuiteBuilder::default().build();
+    let code_id = suite.store_code(hook_counter_contract());
+    let counter = suite.instantiate(
+        code_id,
+        &HookCounterInstantiateMsg {
+            should_error: false,
+        },
+    );
+    let core = suite.core.clone();
+    suite
+        .execute_condorcet(
+            core.clone(),
+            &ExecuteMsg::AddProposalHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    suite
+        .execute_condorcet(
+            core.clone(),
+            &ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+
+    let err = suite.execute_condorcet(
+        suite.sender(),
+        &ExecuteMsg::AddVoteHook {
+            address: counter.to_string(),
+        },
+    );
+    is_error!(err, &ContractError::NotDao {}.to_string());
+
+    let id = suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    suite.vote(suite.sender(), id, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+    suite.execute(suite.sender(), id).unwrap();
+
+    let count = |suite: &Suite, query: HookCounterQueryMsg| -> u64 {
+        let response: CountResponse = suite.query_contract(&counter, &query);
+        response.count
+    };
+    assert_eq!(count(&suite, HookCounterQueryMsg::ProposalCounter {}), 1);
+    assert_eq!(count(&suite, HookCounterQueryMsg::VoteCounter {}), 1);
+    assert_eq!(
+        count(&suite, HookCounterQueryMsg::StatusChangedCounter {}),
+        1
+    );
+
+    suite
+        .execute_condorcet(
+            core,
+            &ExecuteMsg::RemoveProposalHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(suite.query_proposal_hooks().hooks.is_empty());
+}
+
+#[test]
+fn test_failing_vote_hook_is_removed() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+    let code_id = suite.store_code(hook_counter_contract());
+    let counter = suite.instantiate(code_id, &HookCounterInstantiateMsg { should_error: true });
+    let core = suite.core.clone();
+    suite
+        .execute_condorcet(
+            core,
+            &ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+
+    // the vote succeeds and the failing hook is removed.
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    assert!(suite.query_ballot(1, suite.sender()).is_some());
+    assert!(suite.query_vote_hooks().hooks.is_empty());
+}
@@ -1,12 +1,14 @@
-use cosmwasm_std::{to_json_binary, WasmMsg};
+use cosmwasm_std::{to_json_binary, Uint128, WasmMsg};
 use cw_utils::Duration;
 
 use crate::{
     config::UncheckedConfig,
     msg::ExecuteMsg,
     proposal::{ProposalResponse, Status},
+    resolution::CycleResolution,
     tally::Winner,
     testing::suite::unimportant_message,
+    vote::Vote,
     ContractError,
 };
 
@@ -176,6 +178,9 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: None,
                     close_proposals_on_execution_failure: false,
+                    allow_revoting: false,
+                    veto: None,
+                    cycle_resolution: None,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -199,6 +204,9 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: Some(Duration::Height(10)),
                     close_proposals_on_execution_failure: false,
+                    allow_revoting: false,
+                    veto: None,
+                    cycle_resolution: None,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -236,3 +244,154 @@ fn test_execution_fail_handling() {
     // no state changes get committed.
     suite.execute(suite.sender(), 1).unwrap_err();
 }
+
+#[test]
+fn test_revoting() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("blue", 10), ("violet", 10)])
+        .with_proposal(2);
+    suite.instantiate.allow_revoting = true;
+    let mut suite = suite.build();
+
+    suite.vote("blue", 1, vec![0, 1, 2]).unwrap();
+    suite.vote("violet", 1, vec![0, 2, 1]).unwrap();
+    suite.a_day_passes();
+
+    // revoting prevents proposals from passing early.
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Undisputed(0));
+    assert_eq!(status, Status::Open);
+
+    let err = suite.vote("violet", 1, vec![0, 2, 1]);
+    is_error!(err, &ContractError::AlreadyCast {}.to_string());
+
+    suite.vote("blue", 1, vec![1, 0, 2]).unwrap();
+    suite.vote("violet", 1, vec![1, 2, 0]).unwrap();
+
+    let ballot = suite.query_ballot(1, "violet").unwrap();
+    assert_eq!(ballot.vote, Vote::new(vec![1, 2, 0], 3).unwrap());
+    assert_eq!(ballot.power, Uint128::new(10));
+
+    let ProposalResponse { proposal, tally } = suite.query_proposal(1);
+    assert_eq!(tally.winner, Winner::Undisputed(1));
+    assert_eq!(tally.power_outstanding, Uint128::zero());
+    assert_eq!(proposal.last_status(), Status::Open);
+
+    suite.a_week_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 1 });
+}
+
+#[test]
+fn test_rationale() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+
+    suite
+        .execute_condorcet(
+            suite.sender(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: vec![0, 1],
+                rationale: Some("to the moon".to_string()),
+            },
+        )
+        .unwrap();
+    let ballot = suite.query_ballot(1, suite.sender()).unwrap();
+    assert_eq!(ballot.rationale, Some("to the moon".to_string()));
+
+    suite
+        .execute_condorcet(
+            suite.sender(),
+            &ExecuteMsg::UpdateRationale {
+                proposal_id: 1,
+                rationale: None,
+            },
+        )
+        .unwrap();
+    let ballot = suite.query_ballot(1, suite.sender()).unwrap();
+    assert_eq!(ballot.rationale, None);
+
+    let err = suite.execute_condorcet(
+        "nobody",
+        &ExecuteMsg::UpdateRationale {
+            proposal_id: 1,
+            rationale: Some("I didn't vote".to_string()),
+        },
+    );
+    is_error!(
+        err,
+        &ContractError::NoSuchVote {
+            id: 1,
+            voter: "nobody".to_string()
+        }
+        .to_string()
+    );
+    assert_eq!(suite.query_ballot(1, "nobody"), None);
+}
+
+#[test]
+fn test_list_proposals() {
+    let mut suite = SuiteBuilder::default().build();
+    for _ in 0..3 {
+        suite
+            .propose(suite.sender(), vec![vec![unimportant_message()]])
+            .unwrap();
+    }
+
+    let ids = |proposals: Vec<ProposalResponse>| -> Vec<u32> {
+        proposals.into_iter().map(|p| p.proposal.id).collect()
+    };
+
+    let all = suite.query_list_proposals(None, None).proposals;
+    assert_eq!(ids(all), vec![1, 2, 3]);
+    let page = suite.query_list_proposals(Some(1), Some(1)).proposals;
+    assert_eq!(ids(page), vec![2]);
+    let reversed = suite.query_reverse_proposals(None, Some(2)).proposals;
+    assert_eq!(ids(reversed), vec![3, 2]);
+    let reversed = suite.query_reverse_proposals(Some(2), None).proposals;
+    assert_eq!(ids(reversed), vec![1]);
+
+    // statuses are updated before being returned.
+    suite.a_week_passes();
+    let all = suite.query_list_proposals(None, None).proposals;
+    assert!(all
+        .iter()
+        .all(|p| p.proposal.last_status() == Status::Rejected));
+}
+
+// a condorcet winner does not exist, but the cycle is resolved once
+// voting ends.
+#[test]
+fn test_cycle_resolution() {
+    let mut builder = SuiteBuilder::default()
+        .with_voters(&[("blue", 4), ("violet", 3), ("magenta", 2)])
+        .with_proposal(2);
+    builder.instantiate.cycle_resolution = Some(CycleResolution::Schulze);
+    let mut suite = builder.build();
+
+    suite.vote("blue", 1, vec![0, 1, 2]).unwrap();
+    suite.vote("violet", 1, vec![1, 2, 0]).unwrap();
+    suite.vote("magenta", 1, vec![2, 0, 1]).unwrap();
+
+    // without a resolution this would be rejected immediately.
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Never);
+    assert_eq!(status, Status::Open);
+
+    let pairwise = suite.query_pairwise(1);
+    assert_eq!(pairwise.cycle_resolution, Some(CycleResolution::Schulze));
+    assert_eq!(pairwise.resolved_winner, Some(0));
+    assert_eq!(
+        pairwise.strongest_paths,
+        [[0, 3, 3], [1, 0, 5], [1, 1, 0]]
+            .iter()
+            .map(|row| row.iter().map(|v| Uint128::new(*v)).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    );
+
+    suite.a_week_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 0 });
+}
@@ -1,5 +1,7 @@
-use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal};
-use cw_multi_test::{next_block, App, Executor};
+use cosmwasm_schema::serde::{de::DeserializeOwned, Serialize};
+use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty};
+use cw_hooks::HooksResponse;
+use cw_multi_test::{next_block, App, BankSudo, Contract, Executor, SudoMsg};
 use cw_utils::Duration;
 use dao_interface::{
     state::{Admin, ModuleInstantiateInfo},
@@ -8,15 +10,19 @@ use dao_interface::{
 use dao_testing::contracts::{
     cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, proposal_condorcet_contract,
 };
-use dao_voting::threshold::PercentageThreshold;
+use dao_voting::{
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    threshold::PercentageThreshold,
+};
 use dao_voting_cw4::msg::GroupContract;
 
 use crate::{
     config::{Config, UncheckedConfig},
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg},
-    proposal::{ProposalResponse, Status},
-    tally::Winner,
+    proposal::{ProposalListResponse, ProposalResponse, Status},
+    tally::{PairwiseResponse, Winner},
+    vote::{Ballot, VoteResponse},
 };
 
 pub(crate) struct Suite {
@@ -35,11 +41,15 @@ pub(crate) struct SuiteBuilder {
 impl Default for SuiteBuilder {
     fn default() -> Self {
         Self {
-            instantiate: UncheckedConfig {
+            instantiate: InstantiateMsg {
                 quorum: PercentageThreshold::Percent(Decimal::percent(15)),
                 voting_period: Duration::Time(60 * 60 * 24 * 7),
                 min_voting_period: Some(Duration::Time(60 * 60 * 24)),
                 close_proposals_on_execution_failure: true,
+                allow_revoting: false,
+                veto: None,
+                cycle_resolution: None,
+                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             },
             with_proposal: None,
             with_voters: vec![("sender".to_string(), 10)],
@@ -49,9 +59,18 @@ impl Default for SuiteBuilder {
 
 impl SuiteBuilder {
     #[allow(clippy::field_reassign_with_default)]
-    pub fn with_config(instantiate: UncheckedConfig) -> Self {
+    pub fn with_config(config: UncheckedConfig) -> Self {
         let mut b = Self::default();
-        b.instantiate = instantiate;
+        b.instantiate = InstantiateMsg {
+            quorum: config.quorum,
+            voting_period: config.voting_period,
+            min_voting_period: config.min_voting_period,
+            close_proposals_on_execution_failure: config.close_proposals_on_execution_failure,
+            allow_revoting: config.allow_revoting,
+            veto: config.veto,
+            cycle_resolution: config.cycle_resolution,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        };
         b
     }
 
@@ -189,6 +208,25 @@ impl Suite {
     pub fn sender(&self) -> Addr {
         self.sender.clone()
     }
+
+    pub fn store_code(&mut self, contract: Box<dyn Contract<Empty>>) -> u64 {
+        self.app.store_code(contract)
+    }
+
+    pub fn instantiate<T: Serialize>(&mut self, code_id: u64, msg: &T) -> Addr {
+        self.app
+            .instantiate_contract(code_id, self.core.clone(), msg, &[], "contract", None)
+            .unwrap()
+    }
+
+    pub fn mint<S: Into<String>>(&mut self, addr: S, amount: Vec<Coin>) {
+        self.app
+            .sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: addr.into(),
+                amount,
+            }))
+            .unwrap();
+    }
 }
 
 // query
@@ -207,6 +245,93 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_list_proposals(
+        &self,
+        start_after: Option<u32>,
+        limit: Option<u64>,
+    ) -> ProposalListResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::ListProposals { start_after, limit },
+            )
+            .unwrap()
+    }
+
+    pub fn query_reverse_proposals(
+        &self,
+        start_before: Option<u32>,
+        limit: Option<u64>,
+    ) -> ProposalListResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::ReverseProposals {
+                    start_before,
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_ballot<S: Into<String>>(&self, proposal_id: u32, voter: S) -> Option<Ballot> {
+        let response: VoteResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.condorcet,
+                &QueryMsg::Vote {
+                    proposal_id,
+                    voter: voter.into(),
+                },
+            )
+            .unwrap();
+        response.ballot
+    }
+
+    pub fn query_pairwise(&self, proposal_id: u32) -> PairwiseResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::Pairwise { proposal_id })
+            .unwrap()
+    }
+
+    pub fn query_creation_policy(&self) -> ProposalCreationPolicy {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalCreationPolicy {})
+            .unwrap()
+    }
+
+    pub fn query_proposal_hooks(&self) -> HooksResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalHooks {})
+            .unwrap()
+    }
+
+    pub fn query_vote_hooks(&self) -> HooksResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::VoteHooks {})
+            .unwrap()
+    }
+
+    pub fn query_contract<T: DeserializeOwned, Q: Serialize>(&self, contract: &Addr, msg: &Q) -> T {
+        self.app.wrap().query_wasm_smart(contract, msg).unwrap()
+    }
+
+    pub fn query_balance<S: Into<String>>(&self, addr: S, denom: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(addr, denom)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
     pub fn query_winner_and_status(&self, id: u32) -> (Winner, Status) {
         let q = self.query_proposal(id);
         (q.tally.winner, q.proposal.last_status())
@@ -247,6 +372,7 @@ impl Suite {
             self.condorcet.clone(),
             &ExecuteMsg::Propose {
                 choices: choices.into_iter().map(|msgs| Choice { msgs }).collect(),
+                proposer: None,
             },
             &[],
         )?;
@@ -258,14 +384,43 @@ impl Suite {
         sender: S,
         proposal_id: u32,
         vote: Vec<u32>,
+    ) -> anyhow::Result<()> {
+        self.execute_condorcet(
+            sender,
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote,
+                rationale: None,
+            },
+        )
+    }
+
+    pub fn veto<S: Into<String>>(&mut self, sender: S, proposal_id: u32) -> anyhow::Result<()> {
+        self.execute_condorcet(sender, &ExecuteMsg::Veto { proposal_id })
+    }
+
+    /// Executes a message on the condorcet module.
+    pub fn execute_condorcet<S: Into<String>>(
+        &mut self,
+        sender: S,
+        msg: &ExecuteMsg,
     ) -> anyhow::Result<()> {
         self.app
-            .execute_contract(
-                Addr::unchecked(sender),
-                self.condorcet.clone(),
-                &ExecuteMsg::Vote { proposal_id, vote },
-                &[],
-            )
+            .execute_contract(Addr::unchecked(sender), self.condorcet.clone(), msg, &[])
+            .map(|_| ())
+    }
+
+    /// Executes a message on a contract other than the condorcet
+    /// module, for example a pre-propose module.
+    pub fn execute_on<S: Into<String>, T: Serialize + std::fmt::Debug>(
+        &mut self,
+        sender: S,
+        contract: &Addr,
+        msg: &T,
+        funds: &[Coin],
+    ) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(Addr::unchecked(sender), contract.clone(), msg, funds)
             .map(|_| ())
     }
 
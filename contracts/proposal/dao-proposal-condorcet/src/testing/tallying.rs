@@ -2,6 +2,7 @@ use cosmwasm_std::Uint128;
 use cw_utils::Expiration;
 
 use crate::{
+    resolution::CycleResolution,
     tally::{Tally, Winner},
     vote::Vote,
 };
@@ -151,3 +152,60 @@ fn test_winner_none() {
     // -2 -2  2  2  4  \
     assert_eq!(tally.winner, Winner::None)
 }
+
+#[test]
+fn test_change_vote() {
+    let candidates = 3;
+    let mut tally = Tally::new(candidates, Uint128::new(5), 0, Expiration::Never {});
+    tally.add_vote(
+        Vote::new(vec![0, 1, 2], candidates).unwrap(),
+        Uint128::one(),
+    );
+    let before = tally.clone();
+
+    let vote = Vote::new(vec![2, 0, 1], candidates).unwrap();
+    tally.add_vote(vote.clone(), Uint128::new(2));
+    assert_eq!(tally.winner, Winner::Some(2));
+
+    // changing a vote leaves the tally as if only the new vote had
+    // been cast, including when the voting power differs.
+    for (new, power) in [(vec![1, 0, 2], 2), (vec![2, 1, 0], 2), (vec![1, 2, 0], 3)] {
+        let new = Vote::new(new, candidates).unwrap();
+        let mut changed = tally.clone();
+        changed.change_vote(&vote, Uint128::new(2), new.clone(), Uint128::new(power));
+
+        let mut expected = before.clone();
+        expected.add_vote(new, Uint128::new(power));
+        assert_eq!(changed, expected);
+    }
+}
+
+#[test]
+fn test_resolve_cycle() {
+    let candidates = 3;
+    let mut tally = Tally::new(candidates, Uint128::new(9), 0, Expiration::Never {});
+
+    tally.add_vote(
+        Vote::new(vec![0, 1, 2], candidates).unwrap(),
+        Uint128::new(4),
+    );
+    tally.add_vote(
+        Vote::new(vec![1, 2, 0], candidates).unwrap(),
+        Uint128::new(3),
+    );
+    tally.add_vote(
+        Vote::new(vec![2, 0, 1], candidates).unwrap(),
+        Uint128::new(2),
+    );
+
+    // 0 beats 1 by 3, 1 beats 2 by 5, and 2 beats 0 by 1.
+    assert_eq!(tally.winner, Winner::Never);
+    let margins: Vec<Vec<Uint128>> = [[0, 3, 0], [0, 0, 5], [1, 0, 0]]
+        .iter()
+        .map(|row| row.iter().map(|v| Uint128::new(*v)).collect())
+        .collect();
+    assert_eq!(tally.margins(), margins);
+
+    assert_eq!(tally.resolve(CycleResolution::Schulze), Some(0));
+    assert_eq!(tally.resolve(CycleResolution::RankedPairs), Some(0));
+}
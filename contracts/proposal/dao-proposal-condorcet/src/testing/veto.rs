@@ -0,0 +1,135 @@
+use cw_utils::Duration;
+use dao_voting::veto::{VetoConfig, VetoError};
+
+use crate::{proposal::Status, ContractError};
+
+use super::{
+    is_error,
+    suite::{Suite, SuiteBuilder},
+};
+
+fn veto_config() -> VetoConfig {
+    VetoConfig {
+        timelock_duration: Duration::Time(60 * 60 * 24),
+        vetoer: "vetoer".to_string(),
+        early_execute: true,
+        veto_before_passed: false,
+    }
+}
+
+fn suite_with_veto(veto: VetoConfig) -> Suite {
+    let mut suite = SuiteBuilder::default().with_proposal(1);
+    suite.instantiate.veto = Some(veto);
+    suite.build()
+}
+
+#[test]
+fn test_veto_timelocked_proposal() {
+    let mut suite = suite_with_veto(veto_config());
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert!(matches!(status, Status::VetoTimelock { winner: 0, .. }));
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &VetoError::Timelocked {}.to_string());
+    let err = suite.veto(suite.sender(), 1);
+    is_error!(err, &VetoError::Unauthorized {}.to_string());
+
+    suite.veto("vetoer", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &ContractError::Unexecutable {}.to_string());
+}
+
+#[test]
+fn test_veto_timelock_expires() {
+    let mut suite = suite_with_veto(veto_config());
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    // the timelock starts when the voting period ends.
+    suite.a_week_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert!(matches!(status, Status::VetoTimelock { winner: 0, .. }));
+
+    suite.a_day_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 0 });
+
+    let err = suite.veto("vetoer", 1);
+    is_error!(err, &VetoError::TimelockExpired {}.to_string());
+
+    suite.execute(suite.sender(), 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_vetoer_early_execute() {
+    let mut suite = suite_with_veto(VetoConfig {
+        early_execute: false,
+        ..veto_config()
+    });
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    let err = suite.execute("vetoer", 1);
+    is_error!(err, &VetoError::NoEarlyExecute {}.to_string());
+
+    let mut suite = suite_with_veto(veto_config());
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    // the vetoer need not have voting power to execute early.
+    suite.execute("vetoer", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_veto_before_passed() {
+    let mut suite = suite_with_veto(veto_config());
+    let err = suite.veto("vetoer", 1);
+    is_error!(err, &VetoError::NoVetoBeforePassed {}.to_string());
+
+    let mut suite = suite_with_veto(VetoConfig {
+        veto_before_passed: true,
+        ..veto_config()
+    });
+    suite.veto("vetoer", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+
+    let err = suite.veto("vetoer", 1);
+    is_error!(
+        err,
+        &VetoError::InvalidProposalStatus {
+            status: "vetoed".to_string()
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_no_veto_configured() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+    let err = suite.veto("vetoer", 1);
+    is_error!(err, &VetoError::NoVetoConfiguration {}.to_string());
+}
+
+#[test]
+#[should_panic(
+    expected = "The veto timelock duration must have the same units as the max_voting_period of the proposal (height or time)."
+)]
+fn test_veto_duration_units_must_match() {
+    suite_with_veto(VetoConfig {
+        timelock_duration: Duration::Height(10),
+        ..veto_config()
+    });
+}
@@ -1,6 +1,7 @@
-use std::ops::Index;
+use std::{fmt, ops::Index};
 
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
 use thiserror::Error;
 
 #[cw_serde]
@@ -32,6 +33,13 @@ impl Vote {
     }
 }
 
+impl fmt::Display for Vote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranking: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", ranking.join(","))
+    }
+}
+
 impl Index<usize> for Vote {
     type Output = u32;
 
@@ -40,6 +48,22 @@ impl Index<usize> for Vote {
     }
 }
 
+/// A vote cast on a proposal along with the voting power behind it.
+#[cw_serde]
+pub struct Ballot {
+    /// The candidates ranked from most to least favored.
+    pub vote: Vote,
+    /// The voting power of the voter at the proposal's start height.
+    pub power: Uint128,
+    /// An optional rationale for why this vote was cast.
+    pub rationale: Option<String>,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    pub ballot: Option<Ballot>,
+}
+
 #[derive(Error, Debug, PartialEq)]
 pub enum VoteError {
     #[error("candidate ({candidate}) appears in ballot more than once")]
@@ -78,6 +102,7 @@ mod tests {
     #[test]
     fn test_vote_construction() {
         let vote = Vote::new(vec![0, 1, 2], 3).unwrap();
-        assert_eq!(vote.0, vec![0, 1, 2])
+        assert_eq!(vote.0, vec![0, 1, 2]);
+        assert_eq!(vote.to_string(), "0,1,2")
     }
 }
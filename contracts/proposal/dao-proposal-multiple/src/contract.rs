@@ -2,7 +2,7 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -15,7 +15,10 @@ use dao_hooks::proposal::{
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy},
+    multiple_choice::{
+        instant_runoff, MultiSelectVote, MultipleChoiceOptions, MultipleChoiceVote,
+        MultipleChoiceVotes, RankingTally, VotingStrategy, WinnerThreshold,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::{MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
     reply::{
@@ -30,9 +33,14 @@ use crate::{msg::MigrateMsg, state::CREATION_POLICY};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        ProposalListResponse, ProposalResponse, RankedChoiceRoundsResponse,
+        UnvotedProposalListResponse, VoteInfo, VoteListResponse, VoteResponse, VoterVoteInfo,
+        VoterVoteListResponse,
+    },
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        Ballot, Config, IndexBackfill, BALLOTS, CONFIG, INDEX_BACKFILL, MAX_RANKINGS, PROPOSALS,
+        PROPOSAL_COUNT, PROPOSAL_HOOKS, RANKINGS, RUNOFFS, VOTER_BALLOTS, VOTE_HOOKS,
     },
     ContractError,
 };
@@ -40,6 +48,14 @@ use crate::{
 pub const CONTRACT_NAME: &str = "crates.io:dao-proposal-multiple";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The number of proposals or ballots given index entries per
+/// `BackfillIndexes` message, and during migration.
+pub(crate) const BACKFILL_LIMIT: u64 = 100;
+
+/// The number of open proposals scanned per `ListUnvotedProposals`
+/// query.
+pub(crate) const MAX_UNVOTED_SCAN: usize = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -50,6 +66,7 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.voting_strategy.validate()?;
+    validate_winner_threshold(&msg.voting_strategy, &msg.winner_threshold)?;
 
     let dao = info.sender;
 
@@ -74,6 +91,7 @@ pub fn instantiate(
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        winner_threshold: msg.winner_threshold,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -102,6 +120,11 @@ pub fn execute(
             vote,
             rationale,
         } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        ExecuteMsg::VoteMultiple {
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote_multiple(deps, env, info.sender, proposal_id, vote, rationale),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
@@ -114,6 +137,7 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            winner_threshold,
         } => execute_update_config(
             deps,
             info,
@@ -125,6 +149,7 @@ pub fn execute(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            winner_threshold,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -143,6 +168,7 @@ pub fn execute(
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::BackfillIndexes { limit } => execute_backfill_indexes(deps, limit),
     }
 }
 
@@ -217,37 +243,15 @@ pub fn execute_propose(
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
             veto: config.veto,
+            winner_threshold: config.winner_threshold,
+            runoff_of: None,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
         proposal.update_status(&env.block)?;
         proposal
     };
-    let id = advance_proposal_id(deps.storage)?;
-
-    // Limit the size of proposals.
-    //
-    // The Juno mainnet has a larger limit for data that can be
-    // uploaded as part of an execute message than it does for data
-    // that can be queried as part of a query. This means that without
-    // this check it is possible to create a proposal that can not be
-    // queried.
-    //
-    // The size selected was determined by uploading versions of this
-    // contract to the Juno mainnet until queries worked within a
-    // reasonable margin of error.
-    //
-    // `to_vec` is the method used by cosmwasm to convert a struct
-    // into it's byte representation in storage.
-    let proposal_size = cosmwasm_std::to_json_vec(&proposal)?.len() as u64;
-    if proposal_size > MAX_PROPOSAL_SIZE {
-        return Err(ContractError::ProposalTooLarge {
-            size: proposal_size,
-            max: MAX_PROPOSAL_SIZE,
-        });
-    }
-
-    PROPOSALS.save(deps.storage, id, &proposal)?;
+    let id = save_new_proposal(deps.storage, &proposal)?;
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
@@ -290,6 +294,56 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+/// Saves a newly created proposal under the next proposal ID and
+/// returns that ID.
+fn save_new_proposal(
+    storage: &mut dyn Storage,
+    proposal: &MultipleChoiceProposal,
+) -> Result<u64, ContractError> {
+    let id = advance_proposal_id(storage)?;
+
+    // Limit the size of proposals.
+    //
+    // The Juno mainnet has a larger limit for data that can be
+    // uploaded as part of an execute message than it does for data
+    // that can be queried as part of a query. This means that without
+    // this check it is possible to create a proposal that can not be
+    // queried.
+    //
+    // The size selected was determined by uploading versions of this
+    // contract to the Juno mainnet until queries worked within a
+    // reasonable margin of error.
+    //
+    // `to_vec` is the method used by cosmwasm to convert a struct
+    // into it's byte representation in storage.
+    let proposal_size = cosmwasm_std::to_json_vec(proposal)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    PROPOSALS.save(storage, id, proposal)?;
+    Ok(id)
+}
+
+/// Winner thresholds only apply to single choice voting: approval
+/// voting may have several winners, and the instant runoff winner
+/// already has a majority of the remaining votes.
+fn validate_winner_threshold(
+    voting_strategy: &VotingStrategy,
+    winner_threshold: &Option<WinnerThreshold>,
+) -> Result<(), ContractError> {
+    if let Some(winner_threshold) = winner_threshold {
+        if !matches!(voting_strategy, VotingStrategy::SingleChoice { .. }) {
+            return Err(ContractError::WinnerThresholdUnsupported {});
+        }
+        winner_threshold.validate()?;
+    }
+    Ok(())
+}
+
 pub fn execute_veto(
     deps: DepsMut,
     env: Env,
@@ -353,8 +407,11 @@ pub fn execute_veto(
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    let proposal_completed_hooks = proposal_completed_hooks(
+        proposal_creation_policy,
+        prop.deposit_proposal_id(proposal_id),
+        prop.status,
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "veto")
@@ -370,6 +427,42 @@ pub fn execute_vote(
     proposal_id: u64,
     vote: MultipleChoiceVote,
     rationale: Option<String>,
+) -> Result<Response<Empty>, ContractError> {
+    let vote = MultiSelectVote {
+        option_ids: vec![vote.option_id],
+    };
+    cast_vote(deps, env, sender, proposal_id, vote, rationale, "vote")
+}
+
+pub fn execute_vote_multiple(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    vote: MultiSelectVote,
+    rationale: Option<String>,
+) -> Result<Response<Empty>, ContractError> {
+    cast_vote(
+        deps,
+        env,
+        sender,
+        proposal_id,
+        vote,
+        rationale,
+        "vote_multiple",
+    )
+}
+
+/// Casts a vote selecting one or more options. Single choice
+/// proposals only accept votes for exactly one option.
+fn cast_vote(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    vote: MultiSelectVote,
+    rationale: Option<String>,
+    action: &str,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let mut prop = PROPOSALS
@@ -377,7 +470,9 @@ pub fn execute_vote(
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // Check that this is a valid vote.
-    if vote.option_id as usize >= prop.choices.len() {
+    let strategy = prop.voting_strategy.clone();
+    let multi_select = strategy.is_multi_select();
+    if !vote.validate(&strategy, &prop.choices) || (!multi_select && vote.option_ids.len() != 1) {
         return Err(ContractError::InvalidVote {});
     }
 
@@ -402,10 +497,20 @@ pub fn execute_vote(
         return Err(ContractError::NotRegistered {});
     }
 
+    let ballot = Ballot {
+        power: vote_power,
+        vote: MultipleChoiceVote {
+            option_id: vote.option_ids[0],
+        },
+        rationale: rationale.clone(),
+        option_ids: multi_select.then(|| vote.option_ids.clone()),
+    };
+    let mut replaced = None;
     BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
-                if current_ballot.vote == vote {
+                let current_vote = current_ballot.selection();
+                if current_vote.selects_same(&strategy, &vote) {
                     // Don't allow casting the same vote more than
                     // once. This seems liable to be confusing
                     // behavior.
@@ -413,27 +518,36 @@ pub fn execute_vote(
                 } else {
                     // Remove the old vote if this is a re-vote.
                     prop.votes
-                        .remove_vote(current_ballot.vote, current_ballot.power)?;
-                    Ok(Ballot {
-                        power: vote_power,
-                        vote,
-                        rationale: rationale.clone(),
-                    })
+                        .remove_ballot(&strategy, &current_vote, current_ballot.power)?;
+                    replaced = Some((current_vote, current_ballot.power));
+                    Ok(ballot)
                 }
             } else {
                 Err(ContractError::AlreadyVoted {})
             }
         }
-        None => Ok(Ballot {
-            vote,
-            power: vote_power,
-            rationale: rationale.clone(),
-        }),
+        None => Ok(ballot),
     })?;
+    VOTER_BALLOTS.save(deps.storage, (&sender, proposal_id), &Empty {})?;
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power)?;
+    prop.votes.add_ballot(&strategy, &vote, vote_power)?;
+    if let VotingStrategy::RankedChoice { .. } = strategy {
+        if let Some((current_vote, power)) = replaced {
+            remove_ranking(deps.storage, proposal_id, &current_vote, power)?;
+        }
+        add_ranking(deps.storage, proposal_id, &vote, vote_power)?;
+        // Running the instant runoff requires every ranking, so it
+        // is run once here rather than on every status check.
+        let rankings = load_rankings(deps.storage, proposal_id)?;
+        if rankings.len() > MAX_RANKINGS {
+            return Err(ContractError::TooManyRankings { max: MAX_RANKINGS });
+        }
+        prop.votes.ranked_choice_winner = instant_runoff(prop.choices.len(), &rankings)
+            .last()
+            .and_then(|round| round.winner);
+    }
     prop.update_status(&env.block)?;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
     let new_status = prop.status;
@@ -454,7 +568,7 @@ pub fn execute_vote(
     Ok(Response::default()
         .add_submessages(change_hooks)
         .add_submessages(vote_hooks)
-        .add_attribute("action", "vote")
+        .add_attribute("action", action)
         .add_attribute("sender", sender)
         .add_attribute("proposal_id", proposal_id.to_string())
         .add_attribute("position", vote.to_string())
@@ -465,8 +579,57 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Adds POWER to the ranked ballots that share VOTE's ranking.
+fn add_ranking(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    vote: &MultiSelectVote,
+    power: Uint128,
+) -> StdResult<()> {
+    RANKINGS.update(
+        storage,
+        (proposal_id, &vote.to_string()),
+        |tally| -> StdResult<_> {
+            let mut tally = tally.unwrap_or_else(|| RankingTally {
+                ranking: vote.option_ids.clone(),
+                power: Uint128::zero(),
+            });
+            tally.power = tally.power.checked_add(power).map_err(StdError::overflow)?;
+            Ok(tally)
+        },
+    )?;
+    Ok(())
+}
+
+/// Removes POWER from the ranked ballots that share VOTE's ranking,
+/// dropping the ranking once no power is left behind it.
+fn remove_ranking(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    vote: &MultiSelectVote,
+    power: Uint128,
+) -> StdResult<()> {
+    let key = vote.to_string();
+    let mut tally = RANKINGS.load(storage, (proposal_id, &key))?;
+    tally.power = tally.power.checked_sub(power).map_err(StdError::overflow)?;
+    if tally.power.is_zero() {
+        RANKINGS.remove(storage, (proposal_id, &key));
+    } else {
+        RANKINGS.save(storage, (proposal_id, &key), &tally)?;
+    }
+    Ok(())
+}
+
+fn load_rankings(storage: &dyn Storage, proposal_id: u64) -> StdResult<Vec<RankingTally>> {
+    RANKINGS
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, tally)| tally))
+        .collect()
+}
+
 pub fn execute_execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
@@ -534,57 +697,72 @@ pub fn execute_execute(
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
     let vote_result = prop.calculate_vote_result()?;
-    match vote_result {
-        VoteResult::Tie => Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
-        VoteResult::SingleWinner(winning_choice) => {
-            let response = if !winning_choice.msgs.is_empty() {
-                let execute_message = WasmMsg::Execute {
-                    contract_addr: config.dao.to_string(),
-                    msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
-                        msgs: winning_choice.msgs,
-                    })?,
-                    funds: vec![],
-                };
-                match config.close_proposal_on_execution_failure {
-                    true => {
-                        let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                        Response::default().add_submessage(SubMsg::reply_on_error(
-                            execute_message,
-                            masked_proposal_id,
-                        ))
-                    }
-                    false => Response::default().add_message(execute_message),
-                }
-            } else {
+    let msgs = match vote_result {
+        VoteResult::Tie => return Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
+        VoteResult::SingleWinner(winning_choice) => winning_choice.msgs,
+        // Winning options of an approval vote are executed in order
+        // of most to least approved.
+        VoteResult::MultipleWinners(winning_choices) => winning_choices
+            .into_iter()
+            .flat_map(|choice| choice.msgs)
+            .collect(),
+    };
+
+    let response = if !msgs.is_empty() {
+        let execute_message = WasmMsg::Execute {
+            contract_addr: config.dao.to_string(),
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+            funds: vec![],
+        };
+        match config.close_proposal_on_execution_failure {
+            true => {
+                let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
                 Response::default()
-            };
+                    .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
+            }
+            false => Response::default().add_message(execute_message),
+        }
+    } else {
+        Response::default()
+    };
 
-            let proposal_status_changed_hooks = proposal_status_changed_hooks(
-                PROPOSAL_HOOKS,
-                deps.storage,
-                proposal_id,
-                old_status.to_string(),
-                prop.status.to_string(),
-            )?;
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
 
-            // Add prepropose / deposit module hook which will handle deposit refunds.
-            let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-            let proposal_completed_hooks =
-                proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
-
-            Ok(response
-                .add_submessages(proposal_status_changed_hooks)
-                .add_submessages(proposal_completed_hooks)
-                .add_attribute("action", "execute")
-                .add_attribute("sender", info.sender)
-                .add_attribute("proposal_id", proposal_id.to_string())
-                .add_attribute("dao", config.dao))
-        }
-    }
+    let runoff = start_runoff(deps.branch(), &env, proposal_id, &prop)?;
+
+    // Add prepropose / deposit module hook which will handle deposit
+    // refunds. If a runoff was started, the deposit is held for it.
+    let proposal_completed_hooks = if runoff.is_none() {
+        let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+        proposal_completed_hooks(
+            proposal_creation_policy,
+            prop.deposit_proposal_id(proposal_id),
+            prop.status,
+        )?
+    } else {
+        vec![]
+    };
+
+    let (runoff_id, runoff_hooks) = runoff.unzip();
+    Ok(response
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_submessages(runoff_hooks.unwrap_or_default())
+        .add_attribute("action", "execute")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao)
+        .add_attributes(runoff_id.map(|id| ("runoff_proposal_id", id.to_string()))))
 }
 
 pub fn execute_close(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     proposal_id: u64,
@@ -602,6 +780,8 @@ pub fn execute_close(
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
+    let runoff = start_runoff(deps.branch(), &env, proposal_id, &prop)?;
+
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
         deps.storage,
@@ -610,17 +790,79 @@ pub fn execute_close(
         prop.status.to_string(),
     )?;
 
-    // Add prepropose / deposit module hook which will handle deposit refunds.
-    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    // Add prepropose / deposit module hook which will handle deposit
+    // refunds. If a runoff was started, the deposit is held for it.
+    let proposal_completed_hooks = if runoff.is_none() {
+        let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+        proposal_completed_hooks(
+            proposal_creation_policy,
+            prop.deposit_proposal_id(proposal_id),
+            prop.status,
+        )?
+    } else {
+        vec![]
+    };
 
+    let (runoff_id, runoff_hooks) = runoff.unzip();
     Ok(Response::default()
         .add_submessages(proposal_status_changed_hooks)
         .add_submessages(proposal_completed_hooks)
+        .add_submessages(runoff_hooks.unwrap_or_default())
         .add_attribute("action", "close")
         .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attributes(runoff_id.map(|id| ("runoff_proposal_id", id.to_string()))))
+}
+
+/// If PROP was rejected only because its winner did not reach the
+/// winner threshold and it asks for a runoff, creates a runoff
+/// between its top two options. Returns the runoff's ID and new
+/// proposal hooks if one was created.
+fn start_runoff(
+    deps: DepsMut,
+    env: &Env,
+    proposal_id: u64,
+    prop: &MultipleChoiceProposal,
+) -> Result<Option<(u64, Vec<SubMsg>)>, ContractError> {
+    let wants_runoff = prop.winner_threshold.as_ref().map_or(false, |t| t.runoff);
+    if !wants_runoff || !prop.missed_winner_threshold()? {
+        return Ok(None);
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let choices = MultipleChoiceOptions {
+        options: prop.runoff_options(),
+    }
+    .into_checked()?
+    .options;
+    let mut runoff = MultipleChoiceProposal {
+        title: prop.title.clone(),
+        description: prop.description.clone(),
+        proposer: prop.proposer.clone(),
+        start_height: env.block.height,
+        min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+        expiration: config.max_voting_period.after(&env.block),
+        voting_strategy: prop.voting_strategy.clone(),
+        total_power: get_total_power(deps.as_ref(), &config.dao, None)?,
+        status: Status::Open,
+        votes: MultipleChoiceVotes::zero(choices.len()),
+        allow_revoting: config.allow_revoting,
+        choices,
+        veto: config.veto,
+        // With only two options left the plurality winner is
+        // final, so runoffs do not themselves have runoffs.
+        winner_threshold: None,
+        runoff_of: Some(proposal_id),
+    };
+    runoff.update_status(&env.block)?;
+    let runoff_id = save_new_proposal(deps.storage, &runoff)?;
+    RUNOFFS.save(deps.storage, proposal_id, &runoff_id)?;
+    let hooks = new_proposal_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        runoff_id,
+        runoff.proposer.as_str(),
+    )?;
+    Ok(Some((runoff_id, hooks)))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -635,6 +877,7 @@ pub fn execute_update_config(
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    winner_threshold: Option<WinnerThreshold>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -644,6 +887,7 @@ pub fn execute_update_config(
     }
 
     voting_strategy.validate()?;
+    validate_winner_threshold(&voting_strategy, &winner_threshold)?;
 
     let dao = deps.api.addr_validate(&dao)?;
 
@@ -666,6 +910,7 @@ pub fn execute_update_config(
             dao,
             close_proposal_on_execution_failure,
             veto,
+            winner_threshold,
         },
     )?;
 
@@ -849,6 +1094,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_list_proposals(deps, env, start_after, limit)
         }
         QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::QuorumReached { proposal_id } => query_quorum_reached(deps, proposal_id),
         QueryMsg::ProposalCount {} => query_proposal_count(deps),
         QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
         QueryMsg::ListVotes {
@@ -856,11 +1102,34 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => query_list_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::ListVotesByVoter {
+            voter,
+            start_after,
+            limit,
+        } => query_list_votes_by_voter(deps, voter, start_after, limit),
+        QueryMsg::ListProposalsByProposer {
+            proposer,
+            start_after,
+            limit,
+        } => query_list_proposals_by_proposer(deps, env, proposer, start_after, limit),
+        QueryMsg::ListProposalsByStatus {
+            status,
+            start_after,
+            limit,
+        } => query_list_proposals_by_status(deps, env, status, start_after, limit),
+        QueryMsg::ListUnvotedProposals {
+            voter,
+            start_after,
+            limit,
+        } => query_list_unvoted_proposals(deps, env, voter, start_after, limit),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
             limit,
         } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::RankedChoiceRounds { proposal_id } => {
+            query_ranked_choice_rounds(deps, proposal_id)
+        }
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
@@ -883,6 +1152,17 @@ pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
     to_json_binary(&proposal.into_response(&env.block, id)?)
 }
 
+pub fn query_ranked_choice_rounds(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let rounds = match proposal.voting_strategy {
+        VotingStrategy::RankedChoice { .. } => {
+            instant_runoff(proposal.choices.len(), &load_rankings(deps.storage, id)?)
+        }
+        _ => vec![],
+    };
+    to_json_binary(&RankedChoiceRoundsResponse { rounds })
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_json_binary(&policy)
@@ -930,6 +1210,16 @@ pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&next_proposal_id(deps.storage)?)
 }
 
+/// Reports on the proposal's runoff if it had one, as the runoff's
+/// outcome settles the deposit paid for it.
+pub fn query_quorum_reached(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let proposal_id = RUNOFFS
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or(proposal_id);
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    to_json_binary(&proposal.quorum_reached())
+}
+
 pub fn query_proposal_count(deps: Deps) -> StdResult<Binary> {
     let proposal_count = PROPOSAL_COUNT.load(deps.storage)?;
     to_json_binary(&proposal_count)
@@ -943,6 +1233,7 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
         vote: ballot.vote,
         power: ballot.power,
         rationale: ballot.rationale,
+        option_ids: ballot.option_ids,
     });
     to_json_binary(&VoteResponse { vote })
 }
@@ -970,6 +1261,7 @@ pub fn query_list_votes(
                 vote: ballot.vote,
                 power: ballot.power,
                 rationale: ballot.rationale,
+                option_ids: ballot.option_ids,
             })
         })
         .collect::<StdResult<Vec<_>>>()?;
@@ -977,6 +1269,164 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_votes_by_voter(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(Bound::exclusive);
+
+    let votes = VOTER_BALLOTS
+        .prefix(&voter)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|proposal_id| {
+            let proposal_id = proposal_id?;
+            let ballot = BALLOTS.load(deps.storage, (proposal_id, &voter))?;
+            Ok(VoterVoteInfo {
+                proposal_id,
+                vote: VoteInfo {
+                    voter: voter.clone(),
+                    vote: ballot.vote,
+                    power: ballot.power,
+                    rationale: ballot.rationale,
+                    option_ids: ballot.option_ids,
+                },
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&VoterVoteListResponse { votes })
+}
+
+pub fn query_list_proposals_by_proposer(
+    deps: Deps,
+    env: Env,
+    proposer: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let min = start_after.map(Bound::exclusive);
+
+    let props = PROPOSALS
+        .idx
+        .proposer
+        .prefix(proposer)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            proposal.into_response(&env.block, id)
+        })
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_list_proposals_by_status(
+    deps: Deps,
+    env: Env,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+
+    // A proposal's stored status may be out of date, so we check
+    // every status it may be stored with and keep only the proposals
+    // whose current status matches. Each of these lists is ordered
+    // by proposal ID and contains at most `limit` proposals, so
+    // merging them and taking the first `limit` gives a correct page.
+    let mut props = status
+        .stored_index_keys()
+        .into_iter()
+        .map(|key| {
+            PROPOSALS
+                .idx
+                .status
+                .prefix(key.to_string())
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .map(|item| {
+                    let (id, proposal) = item?;
+                    proposal.into_response(&env.block, id)
+                })
+                .filter(|response| {
+                    response.as_ref().map_or(true, |response| {
+                        response.proposal.status.index_key() == status.index_key()
+                    })
+                })
+                .take(limit)
+                .collect::<StdResult<Vec<ProposalResponse>>>()
+        })
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<ProposalResponse>>();
+    props.sort_by_key(|response| response.id);
+    props.truncate(limit);
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_list_unvoted_proposals(
+    deps: Deps,
+    env: Env,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(Bound::exclusive);
+
+    // Proposals the voter has voted on are skipped, so a bounded
+    // number of open proposals are scanned and the last one scanned
+    // is returned for the next query to start after.
+    let mut open = PROPOSALS
+        .idx
+        .status
+        .prefix(Status::Open.index_key().to_string())
+        .range(deps.storage, min, None, Order::Ascending);
+    let mut proposals = vec![];
+    let mut last_scanned = None;
+    for _ in 0..MAX_UNVOTED_SCAN {
+        if proposals.len() == limit {
+            break;
+        }
+        let Some(item) = open.next() else {
+            // Every open proposal has been scanned.
+            last_scanned = None;
+            break;
+        };
+        let (id, proposal) = item?;
+        last_scanned = Some(id);
+        if BALLOTS.has(deps.storage, (id, &voter)) {
+            continue;
+        }
+        // Proposals are stored as open until they are updated, even
+        // if they have since expired.
+        let response = proposal.into_response(&env.block, id)?;
+        if response.proposal.status == Status::Open {
+            proposals.push(response);
+        }
+    }
+
+    to_json_binary(&UnvotedProposalListResponse {
+        proposals,
+        last_scanned,
+    })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1050,5 +1500,82 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Proposals and ballots saved before indexed queries were
+    // supported have no index entries.
+    INDEX_BACKFILL.save(
+        deps.storage,
+        &IndexBackfill::Proposals { start_after: None },
+    )?;
+    backfill_indexes(deps.storage, BACKFILL_LIMIT)?;
+
     Ok(Response::default())
 }
+
+pub fn execute_backfill_indexes(
+    deps: DepsMut,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let complete = backfill_indexes(deps.storage, limit.unwrap_or(BACKFILL_LIMIT))?;
+    Ok(Response::default()
+        .add_attribute("action", "backfill_indexes")
+        .add_attribute("complete", complete.to_string()))
+}
+
+/// Writes index entries for up to LIMIT of the proposals and ballots
+/// saved before they were indexed. Writing an index entry that
+/// already exists is a no-op, so entries saved since do no
+/// harm. Returns true once every entry has been written.
+pub(crate) fn backfill_indexes(storage: &mut dyn Storage, limit: u64) -> StdResult<bool> {
+    let limit = limit.max(1) as usize;
+    let next = match INDEX_BACKFILL.may_load(storage)? {
+        None => return Ok(true),
+        Some(IndexBackfill::Proposals { start_after }) => {
+            let proposals = PROPOSALS
+                .range(
+                    storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<(u64, MultipleChoiceProposal)>>>()?;
+            for (id, proposal) in &proposals {
+                PROPOSALS.replace(storage, *id, Some(proposal), None)?;
+            }
+            match proposals.last() {
+                Some((id, _)) if proposals.len() == limit => IndexBackfill::Proposals {
+                    start_after: Some(*id),
+                },
+                _ => IndexBackfill::Ballots { start_after: None },
+            }
+        }
+        Some(IndexBackfill::Ballots { start_after }) => {
+            let ballots = BALLOTS
+                .keys(
+                    storage,
+                    start_after
+                        .as_ref()
+                        .map(|(id, voter)| Bound::exclusive((*id, voter))),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<(u64, Addr)>>>()?;
+            for (id, voter) in &ballots {
+                VOTER_BALLOTS.save(storage, (voter, *id), &Empty {})?;
+            }
+            match ballots.last() {
+                Some(last) if ballots.len() == limit => IndexBackfill::Ballots {
+                    start_after: Some(last.clone()),
+                },
+                _ => {
+                    INDEX_BACKFILL.remove(storage);
+                    return Ok(true);
+                }
+            }
+        }
+    };
+    INDEX_BACKFILL.save(storage, &next)?;
+    Ok(false)
+}
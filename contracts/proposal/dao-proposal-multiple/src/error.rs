@@ -59,6 +59,9 @@ pub enum ContractError {
     #[error("Proposal is in a tie: two or more options have the same number of votes.")]
     Tie {},
 
+    #[error("A winner threshold may only be used with single choice voting.")]
+    WinnerThresholdUnsupported {},
+
     #[error("Proposal is not expired.")]
     NotExpired {},
 
@@ -80,6 +83,9 @@ pub enum ContractError {
     #[error("Invalid vote selected.")]
     InvalidVote {},
 
+    #[error("Ranked choice proposals may have at most {max} distinct rankings. Rank the options the same as an existing ballot.")]
+    TooManyRankings { max: usize },
+
     #[error("Must have voting power to propose.")]
     MustHaveVotingPower {},
 
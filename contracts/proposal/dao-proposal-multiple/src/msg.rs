@@ -2,9 +2,10 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{MultiSelectVote, MultipleChoiceVote, VotingStrategy, WinnerThreshold},
     pre_propose::PreProposeInfo,
     proposal::MultipleChoiceProposeMsg,
+    status::Status,
     veto::VetoConfig,
 };
 
@@ -45,6 +46,12 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// Optional minimum share of the votes cast that the winning
+    /// option must receive, with an optional runoff between the top
+    /// two options when it is not reached. Only supported with
+    /// single choice voting.
+    #[serde(default)]
+    pub winner_threshold: Option<WinnerThreshold>,
 }
 
 #[cw_serde]
@@ -63,6 +70,21 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Votes for several options at once on a proposal that uses
+    /// approval or ranked choice voting. With approval voting each
+    /// selected option receives the sender's full voting power.
+    VoteMultiple {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The options the sender approves of, or for ranked choice
+        /// proposals, the sender's ranking from most to least
+        /// preferred.
+        vote: MultiSelectVote,
+        /// An optional rationale for why this vote was cast. This can
+        /// be updated, set, or removed later by the address casting
+        /// the vote.
+        rationale: Option<String>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -119,6 +141,11 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// Optional minimum share of the votes cast that the winning
+        /// option must receive. This will only apply to proposals
+        /// created after the config update.
+        #[serde(default)]
+        winner_threshold: Option<WinnerThreshold>,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -143,6 +170,15 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Writes index entries for up to LIMIT proposals or ballots
+    /// saved before this module indexed them, continuing from where
+    /// the last call left off. Until every entry has been written the
+    /// voter, proposer, and status queries may omit older proposals
+    /// and votes. Migrating writes the first page. May be called by
+    /// anyone.
+    BackfillIndexes {
+        limit: Option<u64>,
+    },
 }
 
 #[proposal_module_query]
@@ -178,6 +214,48 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u64>,
     },
+    /// Lists all of the votes that have been cast by an address
+    /// across proposals in ascending order of proposal ID.
+    #[returns(crate::query::VoterVoteListResponse)]
+    ListVotesByVoter {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals created by an address in ascending
+    /// order of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByProposer {
+        proposer: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals with a status in ascending order of
+    /// proposal ID. Statuses are compared by variant, so any
+    /// `VetoTimelock` status matches all timelocked proposals
+    /// regardless of their timelock expiration.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByStatus {
+        status: Status,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Lists the open proposals that an address has not yet voted on
+    /// in ascending order of proposal ID. A limited number of open
+    /// proposals are scanned per query, so fewer than `limit` may be
+    /// returned before the end is reached. Listing continues after
+    /// the returned `last_scanned` until it is `None`.
+    #[returns(crate::query::UnvotedProposalListResponse)]
+    ListUnvotedProposals {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u64>,
+    },
+    /// Returns the rounds of the instant runoff for a ranked choice
+    /// proposal, computed from the votes cast so far. Empty for
+    /// proposals that do not use ranked choice voting.
+    #[returns(crate::query::RankedChoiceRoundsResponse)]
+    RankedChoiceRounds { proposal_id: u64 },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
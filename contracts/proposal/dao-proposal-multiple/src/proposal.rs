@@ -5,7 +5,8 @@ use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Uint128};
 use cw_utils::Expiration;
 use dao_voting::{
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
+        CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
+        MultipleChoiceVotes, VotingStrategy, WinnerThreshold,
     },
     status::Status,
     veto::VetoConfig,
@@ -51,10 +52,24 @@ pub struct MultipleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Optional minimum share of the votes cast that the winning
+    /// option must receive for the proposal to pass.
+    #[serde(default)]
+    pub winner_threshold: Option<WinnerThreshold>,
+    /// If this is a runoff proposal, the ID of the proposal whose
+    /// winner did not reach the winner threshold. The deposit paid
+    /// for that proposal is refunded or kept once the runoff
+    /// completes.
+    #[serde(default)]
+    pub runoff_of: Option<u64>,
 }
 
 pub enum VoteResult {
     SingleWinner(CheckedMultipleChoiceOption),
+    /// The winning options of an approval vote, ordered from most to
+    /// least approved. Empty if no option has more approvals than
+    /// "None of the above".
+    MultipleWinners(Vec<CheckedMultipleChoiceOption>),
     Tie,
 }
 
@@ -112,6 +127,23 @@ impl MultipleChoiceProposal {
         Ok(())
     }
 
+    /// Returns true if the votes cast on this proposal meet its
+    /// quorum.
+    pub fn quorum_reached(&self) -> bool {
+        does_vote_count_pass(
+            self.votes.participation(&self.voting_strategy),
+            self.total_power,
+            self.voting_strategy.get_quorum(),
+        )
+    }
+
+    /// The ID of the proposal whose deposit this proposal's outcome
+    /// settles. A runoff settles the deposit paid for the proposal it
+    /// is a runoff of, which is held until the runoff completes.
+    pub fn deposit_proposal_id(&self, id: u64) -> u64 {
+        self.runoff_of.unwrap_or(id)
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail). Passing in the case of multiple choice proposals
@@ -135,6 +167,14 @@ impl MultipleChoiceProposal {
             }
         }
 
+        match self.voting_strategy {
+            VotingStrategy::Approval { winners, .. } => {
+                return self.is_approval_passed(block, winners)
+            }
+            VotingStrategy::RankedChoice { .. } => return self.is_ranked_choice_passed(block),
+            VotingStrategy::SingleChoice { .. } => (),
+        }
+
         // Proposal can only pass if quorum has been met.
         if does_vote_count_pass(
             self.votes.total(),
@@ -144,17 +184,23 @@ impl MultipleChoiceProposal {
             let vote_result = self.calculate_vote_result()?;
             match vote_result {
                 // Proposal is not passed if there is a tie.
-                VoteResult::Tie => return Ok(false),
+                VoteResult::Tie | VoteResult::MultipleWinners(_) => return Ok(false),
                 VoteResult::SingleWinner(winning_choice) => {
                     // Proposal is not passed if winning choice is None.
                     if winning_choice.option_type != MultipleChoiceOptionType::None {
-                        // If proposal is expired, quorum has been reached, and winning choice is neither tied nor None, then proposal is passed.
+                        // If proposal is expired, quorum has been reached, and winning choice is neither tied nor None, then proposal is passed
+                        // so long as the winning choice reached the winner threshold.
                         if self.expiration.is_expired(block) {
-                            return Ok(true);
+                            return Ok(
+                                self.meets_winner_threshold(&winning_choice, self.votes.total())
+                            );
                         } else {
                             // If the proposal is not expired but the leading choice cannot
                             // possibly be outwon by any other choices, the proposal has passed.
-                            return self.is_choice_unbeatable(&winning_choice);
+                            // The winner threshold must hold even if all remaining voting power
+                            // is cast for other choices.
+                            return Ok(self.is_choice_unbeatable(&winning_choice)?
+                                && self.meets_winner_threshold(&winning_choice, self.total_power));
                         }
                     }
                 }
@@ -170,6 +216,12 @@ impl MultipleChoiceProposal {
             return Ok(false);
         }
 
+        match self.voting_strategy {
+            VotingStrategy::Approval { .. } => return self.is_approval_rejected(block),
+            VotingStrategy::RankedChoice { .. } => return self.is_ranked_choice_rejected(block),
+            VotingStrategy::SingleChoice { .. } => (),
+        }
+
         let vote_result = self.calculate_vote_result()?;
         match vote_result {
             // Proposal is rejected if there is a tie, and either the proposal is expired or
@@ -179,6 +231,8 @@ impl MultipleChoiceProposal {
                     self.expiration.is_expired(block) || self.total_power == self.votes.total();
                 Ok(rejected)
             }
+            // Only returned for approval voting, handled above.
+            VoteResult::MultipleWinners(_) => Ok(false),
             VoteResult::SingleWinner(winning_choice) => {
                 match (
                     does_vote_count_pass(
@@ -194,7 +248,8 @@ impl MultipleChoiceProposal {
                         if winning_choice.option_type == MultipleChoiceOptionType::None {
                             return Ok(true);
                         }
-                        Ok(false)
+                        // Or if the winning option did not reach the winner threshold.
+                        Ok(!self.meets_winner_threshold(&winning_choice, self.votes.total()))
                     }
                     // Proposal is not expired, quorum is either is met or unmet.
                     (true, false) | (false, false) => {
@@ -244,9 +299,269 @@ impl MultipleChoiceProposal {
                 }
                 Err(StdError::not_found("max vote weight"))
             }
+            VotingStrategy::Approval { winners, .. } => {
+                let none_power = self.none_option_power()?;
+                // Options approved by more voting power than "None of
+                // the above", from most to least approved. The sort
+                // is stable so equally approved options keep their
+                // order.
+                let mut approved: Vec<&CheckedMultipleChoiceOption> = self
+                    .choices
+                    .iter()
+                    .filter(|c| {
+                        c.option_type == MultipleChoiceOptionType::Standard
+                            && self.votes.vote_weights[c.index as usize] > none_power
+                    })
+                    .collect();
+                approved.sort_by(|a, b| {
+                    self.votes.vote_weights[b.index as usize]
+                        .cmp(&self.votes.vote_weights[a.index as usize])
+                });
+
+                let winners = winners as usize;
+                if approved.len() > winners {
+                    // If the last winning option is tied with the first
+                    // losing option, there is no way to decide between
+                    // them.
+                    let last_winner = self.votes.vote_weights[approved[winners - 1].index as usize];
+                    let first_loser = self.votes.vote_weights[approved[winners].index as usize];
+                    if last_winner == first_loser {
+                        return Ok(VoteResult::Tie);
+                    }
+                    approved.truncate(winners);
+                }
+
+                Ok(VoteResult::MultipleWinners(
+                    approved.into_iter().cloned().collect(),
+                ))
+            }
+            VotingStrategy::RankedChoice { .. } => match self.votes.ranked_choice_winner {
+                Some(winner) => Ok(VoteResult::SingleWinner(
+                    self.choices[winner as usize].clone(),
+                )),
+                None => Ok(VoteResult::Tie),
+            },
+        }
+    }
+
+    /// The voting power that approved the "None of the above" option.
+    fn none_option_power(&self) -> StdResult<Uint128> {
+        self.choices
+            .iter()
+            .find(|c| c.option_type == MultipleChoiceOptionType::None)
+            .map(|c| self.votes.vote_weights[c.index as usize])
+            .ok_or_else(|| StdError::not_found("none of the above option"))
+    }
+
+    /// Voting power that has not yet been cast on an approval or
+    /// ranked choice vote. For approval voting every voter may
+    /// approve every option, so this is the most any single option's
+    /// approval may still grow by.
+    fn remaining_multi_select_power(&self) -> Uint128 {
+        self.total_power
+            .saturating_sub(self.votes.participation(&self.voting_strategy))
+    }
+
+    /// An approval vote has passed if quorum has been met, at least
+    /// one option has more approvals than "None of the above", and
+    /// there is no tie for the last winning spot. Before expiration,
+    /// the proposal passes only if no remaining votes could change
+    /// the set of winning options.
+    fn is_approval_passed(&self, block: &BlockInfo, winners: u32) -> StdResult<bool> {
+        if !does_vote_count_pass(
+            self.votes.participation(&self.voting_strategy),
+            self.total_power,
+            self.voting_strategy.get_quorum(),
+        ) {
+            return Ok(false);
+        }
+        let winning_choices = match self.calculate_vote_result()? {
+            VoteResult::MultipleWinners(winning_choices) if !winning_choices.is_empty() => {
+                winning_choices
+            }
+            _ => return Ok(false),
+        };
+        if self.expiration.is_expired(block) {
+            return Ok(true);
+        }
+
+        let remaining = self.remaining_multi_select_power();
+        let none_power = self.none_option_power()?;
+        let weakest_winner = winning_choices
+            .iter()
+            .map(|c| self.votes.vote_weights[c.index as usize])
+            .min()
+            .unwrap_or_default();
+        let strongest_loser = self
+            .choices
+            .iter()
+            .filter(|c| {
+                c.option_type == MultipleChoiceOptionType::Standard
+                    && !winning_choices.iter().any(|w| w.index == c.index)
+            })
+            .map(|c| self.votes.vote_weights[c.index as usize])
+            .max()
+            .unwrap_or_default();
+
+        // No winner may fall to or below "None of the above".
+        if weakest_winner <= none_power + remaining {
+            return Ok(false);
+        }
+        if winning_choices.len() < winners as usize {
+            // With open winning spots, a losing option may not be
+            // able to overtake "None of the above".
+            Ok(strongest_loser + remaining <= none_power)
+        } else {
+            // With all spots filled, a losing option may not be able
+            // to catch up with the weakest winner.
+            Ok(strongest_loser + remaining < weakest_winner)
+        }
+    }
+
+    /// Returns true if quorum has been met and the instant runoff
+    /// winner is not "None of the above". Before expiration, the
+    /// proposal passes early only if no remaining votes could change
+    /// the winner: either all voting power has been cast, or a
+    /// standard option is ranked first by a majority of all voting
+    /// power and so wins the first round regardless.
+    fn is_ranked_choice_passed(&self, block: &BlockInfo) -> StdResult<bool> {
+        if !does_vote_count_pass(
+            self.votes.participation(&self.voting_strategy),
+            self.total_power,
+            self.voting_strategy.get_quorum(),
+        ) {
+            return Ok(false);
+        }
+        if self.expiration.is_expired(block) || self.remaining_multi_select_power().is_zero() {
+            return Ok(matches!(
+                self.calculate_vote_result()?,
+                VoteResult::SingleWinner(winner)
+                    if winner.option_type == MultipleChoiceOptionType::Standard
+            ));
+        }
+        Ok(self.has_first_round_majority(MultipleChoiceOptionType::Standard))
+    }
+
+    /// The counterpart of `is_ranked_choice_passed`. A ranked choice
+    /// proposal is rejected once settled if quorum was not met or the
+    /// winner is "None of the above" or there is a tie, and early if
+    /// "None of the above" is ranked first by a majority of all
+    /// voting power.
+    fn is_ranked_choice_rejected(&self, block: &BlockInfo) -> StdResult<bool> {
+        if self.expiration.is_expired(block) || self.remaining_multi_select_power().is_zero() {
+            let quorum = does_vote_count_pass(
+                self.votes.participation(&self.voting_strategy),
+                self.total_power,
+                self.voting_strategy.get_quorum(),
+            );
+            let standard_winner = matches!(
+                self.calculate_vote_result()?,
+                VoteResult::SingleWinner(winner)
+                    if winner.option_type == MultipleChoiceOptionType::Standard
+            );
+            return Ok(!quorum || !standard_winner);
+        }
+        Ok(self.has_first_round_majority(MultipleChoiceOptionType::None))
+    }
+
+    /// Returns true if an option of the given type is ranked first by
+    /// more than half of the total voting power.
+    fn has_first_round_majority(&self, option_type: MultipleChoiceOptionType) -> bool {
+        self.choices.iter().any(|c| {
+            let first_preferences = self.votes.vote_weights[c.index as usize];
+            c.option_type == option_type
+                && first_preferences > self.total_power.saturating_sub(first_preferences)
+        })
+    }
+
+    /// An approval vote is rejected once it has expired without
+    /// passing, or early if no standard option can get more approvals
+    /// than "None of the above", or if there is a tie and no voting
+    /// power left to break it.
+    fn is_approval_rejected(&self, block: &BlockInfo) -> StdResult<bool> {
+        if self.expiration.is_expired(block) {
+            let quorum = does_vote_count_pass(
+                self.votes.participation(&self.voting_strategy),
+                self.total_power,
+                self.voting_strategy.get_quorum(),
+            );
+            let has_winners = matches!(
+                self.calculate_vote_result()?,
+                VoteResult::MultipleWinners(winning_choices) if !winning_choices.is_empty()
+            );
+            return Ok(!quorum || !has_winners);
+        }
+
+        let remaining = self.remaining_multi_select_power();
+        let none_power = self.none_option_power()?;
+        let any_can_win = self.choices.iter().any(|c| {
+            c.option_type == MultipleChoiceOptionType::Standard
+                && self.votes.vote_weights[c.index as usize] + remaining > none_power
+        });
+        if !any_can_win {
+            return Ok(true);
+        }
+        Ok(remaining.is_zero() && matches!(self.calculate_vote_result()?, VoteResult::Tie))
+    }
+
+    /// Returns true if `choice` has at least the winner threshold
+    /// share of `cast` votes, or if there is no winner threshold.
+    fn meets_winner_threshold(&self, choice: &CheckedMultipleChoiceOption, cast: Uint128) -> bool {
+        match &self.winner_threshold {
+            Some(winner_threshold) => {
+                winner_threshold.is_met(self.votes.vote_weights[choice.index as usize], cast)
+            }
+            None => true,
+        }
+    }
+
+    /// Returns true if quorum was met and a standard option won, but
+    /// it did not receive the winner threshold share of the votes
+    /// cast. Only meaningful once voting has ended.
+    pub fn missed_winner_threshold(&self) -> StdResult<bool> {
+        if self.winner_threshold.is_none()
+            || !does_vote_count_pass(
+                self.votes.total(),
+                self.total_power,
+                self.voting_strategy.get_quorum(),
+            )
+        {
+            return Ok(false);
+        }
+        match self.calculate_vote_result()? {
+            VoteResult::SingleWinner(winning_choice)
+                if winning_choice.option_type == MultipleChoiceOptionType::Standard =>
+            {
+                Ok(!self.meets_winner_threshold(&winning_choice, self.votes.total()))
+            }
+            _ => Ok(false),
         }
     }
 
+    /// The two standard options with the most votes, most voted
+    /// first, to be voted on again in a runoff. Options with equal
+    /// votes keep their original order.
+    pub fn runoff_options(&self) -> Vec<MultipleChoiceOption> {
+        let mut standard: Vec<&CheckedMultipleChoiceOption> = self
+            .choices
+            .iter()
+            .filter(|c| c.option_type == MultipleChoiceOptionType::Standard)
+            .collect();
+        standard.sort_by(|a, b| {
+            self.votes.vote_weights[b.index as usize]
+                .cmp(&self.votes.vote_weights[a.index as usize])
+        });
+        standard
+            .into_iter()
+            .take(2)
+            .map(|c| MultipleChoiceOption {
+                title: c.title.clone(),
+                description: c.description.clone(),
+                msgs: c.msgs.clone(),
+            })
+            .collect()
+    }
+
     /// Ensure that with the remaining vote power, the choice with the second highest votes
     /// cannot overtake the first choice.
     fn is_choice_unbeatable(
@@ -336,6 +651,8 @@ mod tests {
             allow_revoting,
             min_voting_period: None,
             veto: None,
+            winner_threshold: None,
+            runoff_of: None,
         }
     }
 
@@ -348,6 +665,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -365,6 +683,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(1)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -381,6 +700,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -397,6 +717,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -413,6 +734,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -429,6 +751,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -455,6 +778,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -472,6 +796,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(0), Uint128::new(1)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -488,6 +813,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -504,6 +830,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(1), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -520,6 +847,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -536,6 +864,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(50), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -561,6 +890,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(0), Uint128::new(50), Uint128::new(500)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -586,6 +916,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -609,6 +940,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(999999), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -632,6 +964,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(9888889), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -657,6 +990,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(6)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -694,6 +1028,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(7), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
         let prop = create_proposal(
             &env.block,
@@ -732,6 +1067,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(6), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -767,6 +1103,7 @@ mod tests {
         };
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(5), Uint128::new(5), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -809,6 +1146,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(81), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -847,6 +1185,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(90), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -862,6 +1201,7 @@ mod tests {
 
         let votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(50), Uint128::new(0), Uint128::new(0)],
+            ..Default::default()
         };
 
         let prop = create_proposal(
@@ -875,4 +1215,326 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    fn approval_votes(vote_weights: [u128; 3], multi_select_power: u128) -> MultipleChoiceVotes {
+        MultipleChoiceVotes {
+            vote_weights: vote_weights.into_iter().map(Uint128::new).collect(),
+            multi_select_power: Uint128::new(multi_select_power),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_approval_early_pass() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            winners: 1,
+        };
+
+        // 40 power remains, which can not bring the second option
+        // level with the first.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            approval_votes([60, 10, 0], 60),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        // 50 power remains, which can.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            approval_votes([60, 10, 0], 60),
+            Uint128::new(110),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_approval_multiple_winners() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            winners: 2,
+        };
+
+        // Only one option has been approved so far, and the remaining
+        // power could approve the other.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            approval_votes([60, 0, 0], 60),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+
+        // Once expired, the single approved option wins.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            approval_votes([60, 0, 0], 60),
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::MultipleWinners(winners) => {
+                assert_eq!(winners.len(), 1);
+                assert_eq!(winners[0].index, 0);
+            }
+            _ => panic!("expected multiple winners result"),
+        }
+
+        // Both options approved, most approved first.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            approval_votes([40, 70, 0], 80),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::MultipleWinners(winners) => {
+                let indexes: Vec<u32> = winners.iter().map(|w| w.index).collect();
+                assert_eq!(indexes, vec![1, 0]);
+            }
+            _ => panic!("expected multiple winners result"),
+        }
+    }
+
+    #[test]
+    fn test_approval_rejection() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            winners: 1,
+        };
+
+        // No option can overtake "None of the above".
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            approval_votes([0, 0, 60], 60),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Tied for the only winning spot with no power remaining.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            approval_votes([50, 50, 0], 100),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Quorum is not met by the time the proposal expires. The
+        // option weights sum past quorum, but participation does not.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            approval_votes([30, 30, 0], 30),
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    fn ranked_votes(rankings: Vec<(Vec<u32>, u128)>) -> MultipleChoiceVotes {
+        let mut votes = MultipleChoiceVotes::zero(3);
+        let mut tallies = vec![];
+        for (option_ids, power) in rankings {
+            votes
+                .add_ranked_vote(
+                    &dao_voting::multiple_choice::MultiSelectVote {
+                        option_ids: option_ids.clone(),
+                    },
+                    Uint128::new(power),
+                )
+                .unwrap();
+            tallies.push(dao_voting::multiple_choice::RankingTally {
+                ranking: option_ids,
+                power: Uint128::new(power),
+            });
+        }
+        votes.ranked_choice_winner = dao_voting::multiple_choice::instant_runoff(3, &tallies)
+            .last()
+            .and_then(|round| round.winner);
+        votes
+    }
+
+    #[test]
+    fn test_ranked_choice_pass() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::RankedChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+
+        // A majority of all voting power ranks option 0 first.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            ranked_votes(vec![(vec![0], 60)]),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        // "None of the above" leads the first round but is beaten
+        // once option 0 is eliminated.
+        let votes = ranked_votes(vec![(vec![2], 40), (vec![1, 0], 35), (vec![0, 1], 25)]);
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            votes.clone(),
+            Uint128::new(110),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(110),
+            true,
+            false,
+        );
+        assert!(prop.is_passed(&env.block).unwrap());
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::SingleWinner(winner) => assert_eq!(winner.index, 1),
+            _ => panic!("expected single winner"),
+        }
+    }
+
+    #[test]
+    fn test_ranked_choice_rejection() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::RankedChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+
+        // A majority of all voting power ranks "None of the above"
+        // first.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            ranked_votes(vec![(vec![2], 55), (vec![0, 1], 45)]),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // All voting power has been cast and the last two options
+        // are tied.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            ranked_votes(vec![(vec![0], 50), (vec![1], 50)]),
+            Uint128::new(100),
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Quorum is not met.
+        let prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            ranked_votes(vec![(vec![0, 1], 20)]),
+            Uint128::new(100),
+            true,
+            false,
+        );
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_winner_threshold() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+        let winner_threshold = |percent: u64| {
+            Some(WinnerThreshold {
+                threshold: dao_voting::threshold::PercentageThreshold::Percent(
+                    cosmwasm_std::Decimal::percent(percent),
+                ),
+                runoff: true,
+            })
+        };
+
+        // Option 0 wins with 45% of the votes cast.
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy.clone(),
+            MultipleChoiceVotes {
+                vote_weights: vec![Uint128::new(35), Uint128::new(45), Uint128::new(20)],
+                ..Default::default()
+            },
+            Uint128::new(100),
+            true,
+            false,
+        );
+        prop.winner_threshold = winner_threshold(50);
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+        assert!(prop.missed_winner_threshold().unwrap());
+        let runoff = prop.runoff_options();
+        assert_eq!(runoff.len(), 2);
+        assert_eq!(runoff[0].description, "multiple choice option 2");
+        assert_eq!(runoff[1].description, "multiple choice option 1");
+
+        prop.winner_threshold = winner_threshold(45);
+        assert!(prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+        assert!(!prop.missed_winner_threshold().unwrap());
+
+        // Before expiration the threshold must hold even if all
+        // remaining voting power goes to other options.
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            MultipleChoiceVotes {
+                vote_weights: vec![Uint128::new(60), Uint128::new(10), Uint128::zero()],
+                ..Default::default()
+            },
+            Uint128::new(100),
+            false,
+            false,
+        );
+        prop.winner_threshold = winner_threshold(60);
+        assert!(prop.is_passed(&env.block).unwrap());
+        prop.winner_threshold = winner_threshold(70);
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+    }
 }
@@ -2,13 +2,24 @@ use crate::{proposal::MultipleChoiceProposal, state::Config};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 
-use dao_voting::multiple_choice::MultipleChoiceVote;
+use dao_voting::multiple_choice::{MultipleChoiceVote, RankedChoiceRound};
 
 #[cw_serde]
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
 
+/// Open proposals that an address has not voted on, returned by
+/// `ListUnvotedProposals`.
+#[cw_serde]
+pub struct UnvotedProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+    /// The ID of the last proposal scanned, to be passed as
+    /// `start_after` to continue listing. `None` once every open
+    /// proposal has been scanned.
+    pub last_scanned: Option<u64>,
+}
+
 /// Information about a proposal returned by proposal queries.
 #[cw_serde]
 pub struct ProposalResponse {
@@ -27,6 +38,10 @@ pub struct VoteInfo {
     pub power: Uint128,
     /// The rationale behind the vote.
     pub rationale: Option<String>,
+    /// The selected options, set for votes cast on approval or
+    /// ranked choice voting proposals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option_ids: Option<Vec<u32>>,
 }
 
 #[cw_serde]
@@ -39,6 +54,26 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
+/// Information about a vote cast by an address on a proposal.
+#[cw_serde]
+pub struct VoterVoteInfo {
+    /// The ID of the proposal that was voted on.
+    pub proposal_id: u64,
+    /// The vote that was cast.
+    pub vote: VoteInfo,
+}
+
+#[cw_serde]
+pub struct VoterVoteListResponse {
+    pub votes: Vec<VoterVoteInfo>,
+}
+
+/// The rounds of an instant runoff returned by `RankedChoiceRounds`.
+#[cw_serde]
+pub struct RankedChoiceRoundsResponse {
+    pub rounds: Vec<RankedChoiceRound>,
+}
+
 #[cw_serde]
 pub struct VoterResponse {
     pub weight: Option<Uint128>,
@@ -1,11 +1,13 @@
 use crate::proposal::MultipleChoiceProposal;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use cw_utils::Duration;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, VotingStrategy},
+    multiple_choice::{
+        MultiSelectVote, MultipleChoiceVote, RankingTally, VotingStrategy, WinnerThreshold,
+    },
     pre_propose::ProposalCreationPolicy,
     veto::VetoConfig,
 };
@@ -47,6 +49,10 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Optional minimum share of the votes cast that the winning
+    /// option of a single choice proposal must receive.
+    #[serde(default)]
+    pub winner_threshold: Option<WinnerThreshold>,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
@@ -54,17 +60,56 @@ pub struct Config {
 pub struct Ballot {
     /// The amount of voting power behind the vote.
     pub power: Uint128,
-    /// The position.
+    /// The position. For ballots cast on approval or ranked choice
+    /// voting proposals this is the first selected option.
     pub vote: MultipleChoiceVote,
     /// An optional rationale for why this vote was cast.
     pub rationale: Option<String>,
+    /// The selected options, set for ballots cast on approval or
+    /// ranked choice voting proposals. Ranked ballots list options
+    /// from most to least preferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub option_ids: Option<Vec<u32>>,
+}
+
+impl Ballot {
+    /// The options selected by this ballot.
+    pub fn selection(&self) -> MultiSelectVote {
+        MultiSelectVote {
+            option_ids: self
+                .option_ids
+                .clone()
+                .unwrap_or_else(|| vec![self.vote.option_id]),
+        }
+    }
 }
 
 /// The current top level config for the module.
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
-pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
+pub const PROPOSALS: IndexedMap<u64, MultipleChoiceProposal, ProposalIndexes> = IndexedMap::new(
+    "proposals",
+    ProposalIndexes {
+        proposer: MultiIndex::new(proposal_proposer_idx, "proposals", "proposals__proposer"),
+        status: MultiIndex::new(proposal_status_idx, "proposals", "proposals__status"),
+    },
+);
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The proposals each address has voted on. The existence of an
+/// `(address, proposal_id)` pair implies that `address` has a ballot
+/// for `proposal_id`.
+pub const VOTER_BALLOTS: Map<(&Addr, u64), Empty> = Map::new("voter_ballots");
+/// The ballots cast on ranked choice proposals, grouped by ranking
+/// and keyed by the ranking's `MultiSelectVote` string. The
+/// proposal's `votes` hold only first preferences and the cached
+/// instant runoff winner.
+pub const RANKINGS: Map<(u64, &str), RankingTally> = Map::new("rankings");
+/// The maximum number of distinct rankings a ranked choice proposal
+/// may have. Every ranking is loaded to run the instant runoff when a
+/// ballot is cast, so this bounds the cost of voting.
+pub const MAX_RANKINGS: usize = 50;
+/// Maps proposals that were sent to a runoff to the runoff's ID.
+pub const RUNOFFS: Map<u64, u64> = Map::new("runoffs");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -72,3 +117,41 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// Progress writing index entries for proposals and ballots saved
+/// before they were indexed. Removed once every entry is written.
+pub const INDEX_BACKFILL: Item<IndexBackfill> = Item::new("index_backfill");
+
+/// The next page of entries to write index entries for.
+#[cw_serde]
+pub enum IndexBackfill {
+    Proposals { start_after: Option<u64> },
+    Ballots { start_after: Option<(u64, Addr)> },
+}
+
+/// Secondary indexes over proposals.
+pub struct ProposalIndexes<'a> {
+    /// Proposals indexed by the address that created them.
+    pub proposer: MultiIndex<'a, Addr, MultipleChoiceProposal, u64>,
+    /// Proposals indexed by the status they were last saved
+    /// with. Statuses are only saved on vote, execute, close, and veto
+    /// events, so entries here may be out of date. See
+    /// `Status::stored_index_keys`.
+    pub status: MultiIndex<'a, String, MultipleChoiceProposal, u64>,
+}
+
+impl<'a> IndexList<MultipleChoiceProposal> for ProposalIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<MultipleChoiceProposal>> + '_> {
+        let v: Vec<&dyn Index<MultipleChoiceProposal>> = vec![&self.proposer, &self.status];
+        Box::new(v.into_iter())
+    }
+}
+
+fn proposal_proposer_idx(_pk: &[u8], proposal: &MultipleChoiceProposal) -> Addr {
+    proposal.proposer.clone()
+}
+
+fn proposal_status_idx(_pk: &[u8], proposal: &MultipleChoiceProposal) -> String {
+    proposal.status.index_key().to_string()
+}
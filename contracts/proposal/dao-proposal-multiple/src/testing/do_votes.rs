@@ -131,6 +131,7 @@ where
         close_proposal_on_execution_failure: true,
         pre_propose_info,
         veto: None,
+        winner_threshold: None,
     };
 
     let governance_addr = setup_governance(&mut app, instantiate, Some(initial_balances));
@@ -276,6 +277,7 @@ where
                             _ => weight,
                         },
                         rationale: None,
+                        option_ids: None,
                     }),
                 };
                 assert_eq!(vote, expected)
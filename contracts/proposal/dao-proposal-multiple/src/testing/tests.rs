@@ -1,5 +1,5 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg,
+    coins, to_json_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg,
 };
 use cw20::Cw20Coin;
 use cw_denom::{CheckedDenom, UncheckedDenom};
@@ -16,9 +16,9 @@ use dao_voting::{
         VotingModuleTokenType,
     },
     multiple_choice::{
-        CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
-        MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
-        MAX_NUM_CHOICES,
+        CheckedMultipleChoiceOption, MultiSelectVote, MultipleChoiceOption,
+        MultipleChoiceOptionType, MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes,
+        RankedChoiceRound, VotingStrategy, WinnerThreshold, MAX_NUM_CHOICES,
     },
     pre_propose::PreProposeInfo,
     proposal::MultipleChoiceProposeMsg as ProposeMsg,
@@ -31,8 +31,12 @@ use std::panic;
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::MultipleChoiceProposal,
-    query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::Config,
+    query::{
+        ProposalListResponse, ProposalResponse, RankedChoiceRoundsResponse,
+        UnvotedProposalListResponse, VoteInfo, VoteListResponse, VoteResponse, VoterVoteInfo,
+        VoterVoteListResponse,
+    },
+    state::{Config, MAX_RANKINGS},
     testing::{
         do_votes::do_test_votes_cw20_balances,
         execute::make_proposal,
@@ -131,6 +135,7 @@ fn test_propose() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -147,6 +152,7 @@ fn test_propose() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        winner_threshold: None,
     };
     assert_eq!(config, expected);
 
@@ -184,10 +190,13 @@ fn test_propose() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
+            ..Default::default()
         },
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        winner_threshold: None,
+        runoff_of: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -213,6 +222,7 @@ fn test_propose_wrong_num_choices() {
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -229,6 +239,7 @@ fn test_propose_wrong_num_choices() {
         dao: core_addr,
         voting_strategy,
         veto: None,
+        winner_threshold: None,
     };
     assert_eq!(config, expected);
 
@@ -293,6 +304,7 @@ fn test_proposal_count_initialized_to_zero() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, msg, None);
 
@@ -332,6 +344,7 @@ fn test_propose_auto_vote_winner() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -348,6 +361,7 @@ fn test_propose_auto_vote_winner() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        winner_threshold: None,
     };
     assert_eq!(config, expected);
 
@@ -394,10 +408,13 @@ fn test_propose_auto_vote_winner() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(100_000_000), Uint128::zero(), Uint128::zero()],
+            ..Default::default()
         },
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        winner_threshold: None,
+        runoff_of: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -423,6 +440,7 @@ fn test_propose_auto_vote_reject() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -439,6 +457,7 @@ fn test_propose_auto_vote_reject() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        winner_threshold: None,
     };
     assert_eq!(config, expected);
 
@@ -485,10 +504,13 @@ fn test_propose_auto_vote_reject() {
         total_power: Uint128::new(100_000_000),
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(), Uint128::zero(), Uint128::new(100_000_000)],
+            ..Default::default()
         },
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        winner_threshold: None,
+        runoff_of: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -515,6 +537,7 @@ fn test_propose_non_member_auto_vote_fail() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -531,6 +554,7 @@ fn test_propose_non_member_auto_vote_fail() {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
         veto: None,
+        winner_threshold: None,
     };
     assert_eq!(config, expected);
 
@@ -577,6 +601,7 @@ fn test_no_early_pass_with_min_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -673,6 +698,7 @@ fn test_propose_with_messages() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -710,6 +736,7 @@ fn test_propose_with_messages() {
         allow_revoting: false,
         dao: "dao".to_string(),
         veto: None,
+        winner_threshold: None,
     };
 
     let wasm_msg = WasmMsg::Execute {
@@ -795,6 +822,7 @@ fn test_min_duration_units_missmatch() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -828,6 +856,7 @@ fn test_min_duration_larger_than_proposal_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
     instantiate_with_staked_balances_governance(
         &mut app,
@@ -860,6 +889,7 @@ fn test_min_duration_same_as_proposal_duration() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -984,6 +1014,7 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1060,6 +1091,7 @@ fn test_different_token_proposal_deposit() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1122,6 +1154,7 @@ fn test_bad_token_proposal_deposit() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1155,6 +1188,7 @@ fn test_take_proposal_deposit() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1263,6 +1297,7 @@ fn test_take_native_proposal_deposit() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_native_staked_balances_governance(
@@ -1357,6 +1392,7 @@ fn test_native_proposal_deposit() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1640,18 +1676,1014 @@ fn test_query_list_votes() {
             vote: MultipleChoiceVote { option_id: 0 },
             power: Uint128::new(10),
             rationale: None,
+            option_ids: None,
         },
         VoteInfo {
             voter: Addr::unchecked("note"),
             vote: MultipleChoiceVote { option_id: 1 },
             power: Uint128::new(20),
             rationale: None,
+            option_ids: None,
         },
     ];
 
     assert_eq!(list_votes.votes, expected)
 }
 
+#[test]
+fn test_query_indexed_lists() {
+    let (app, core_addr) = do_test_votes_cw20_balances(
+        vec![
+            TestMultipleChoiceVote {
+                voter: "blue".to_string(),
+                position: MultipleChoiceVote { option_id: 0 },
+                weight: Uint128::new(10),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestMultipleChoiceVote {
+                voter: "note".to_string(),
+                position: MultipleChoiceVote { option_id: 0 },
+                weight: Uint128::new(20),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Passed,
+        None,
+        None,
+        true,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let votes: VoterVoteListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListVotesByVoter {
+                voter: "note".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        votes.votes,
+        vec![VoterVoteInfo {
+            proposal_id: 1,
+            vote: VoteInfo {
+                voter: Addr::unchecked("note"),
+                vote: MultipleChoiceVote { option_id: 0 },
+                power: Uint128::new(20),
+                rationale: None,
+                option_ids: None,
+            }
+        }]
+    );
+    let votes: VoterVoteListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListVotesByVoter {
+                voter: "note".to_string(),
+                start_after: Some(1),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(votes.votes, vec![]);
+
+    let proposals: ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListProposalsByProposer {
+                proposer: "blue".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposals.proposals.len(), 1);
+    assert_eq!(proposals.proposals[0].id, 1);
+
+    let proposals: ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListProposalsByStatus {
+                status: Status::Passed,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposals.proposals.len(), 1);
+    assert_eq!(proposals.proposals[0].proposal.status, Status::Passed);
+
+    let proposals: ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListProposalsByStatus {
+                status: Status::Open,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposals.proposals, vec![]);
+
+    // The proposal has passed, so there is nothing left to vote on.
+    let proposals: UnvotedProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::ListUnvotedProposals {
+                voter: "filler".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposals.proposals, vec![]);
+    assert_eq!(proposals.last_scanned, None);
+}
+
+#[test]
+fn test_approval_voting() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            winners: 2,
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: None,
+    };
+
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![
+            Cw20Coin {
+                address: "blue".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "orange".to_string(),
+                amount: Uint128::new(30),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let update_config = |voting_strategy: VotingStrategy, max_voting_period: u64| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: govmod.to_string(),
+            msg: to_json_binary(&ExecuteMsg::UpdateConfig {
+                voting_strategy,
+                min_voting_period: None,
+                close_proposal_on_execution_failure: true,
+                max_voting_period: Duration::Height(max_voting_period),
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                veto: None,
+                winner_threshold: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    };
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![update_config(
+                VotingStrategy::Approval {
+                    quorum: PercentageThreshold::Majority {},
+                    winners: 1,
+                },
+                20,
+            )],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![update_config(
+                VotingStrategy::SingleChoice {
+                    quorum: PercentageThreshold::Majority {},
+                },
+                30,
+            )],
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 3".to_string(),
+            msgs: vec![],
+            title: "title".to_string(),
+        },
+    ];
+    make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions { options },
+        None,
+    );
+
+    // Duplicate options, unknown options, and "None of the above"
+    // alongside other options are not valid selections.
+    for option_ids in [vec![0, 0], vec![0, 4], vec![1, 3], vec![]] {
+        let err: ContractError = app
+            .execute_contract(
+                Addr::unchecked("blue"),
+                govmod.clone(),
+                &ExecuteMsg::VoteMultiple {
+                    proposal_id: 1,
+                    vote: MultiSelectVote { option_ids },
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidVote {});
+    }
+
+    // A single choice vote approves of one option.
+    app.execute_contract(
+        Addr::unchecked("blue"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: MultipleChoiceVote { option_id: 2 },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    app.execute_contract(
+        Addr::unchecked("whale"),
+        govmod.clone(),
+        &ExecuteMsg::VoteMultiple {
+            proposal_id: 1,
+            vote: MultiSelectVote {
+                option_ids: vec![1, 0],
+            },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Options 1 and 2 each have 60 of 100 approvals and option 3 has
+    // 10. The remaining 30 can not change the winners.
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(
+        proposal.proposal.votes,
+        MultipleChoiceVotes {
+            vote_weights: vec![
+                Uint128::new(60),
+                Uint128::new(60),
+                Uint128::new(10),
+                Uint128::zero()
+            ],
+            multi_select_power: Uint128::new(70),
+            ..Default::default()
+        }
+    );
+
+    let vote: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &govmod,
+            &QueryMsg::GetVote {
+                proposal_id: 1,
+                voter: "whale".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        vote.vote,
+        Some(VoteInfo {
+            voter: Addr::unchecked("whale"),
+            vote: MultipleChoiceVote { option_id: 1 },
+            power: Uint128::new(60),
+            rationale: None,
+            option_ids: Some(vec![1, 0]),
+        })
+    );
+
+    // Both winning options are executed, most approved first and
+    // ties broken by option order.
+    app.execute_contract(
+        Addr::unchecked("blue"),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let config = query_proposal_config(&app, &govmod);
+    assert_eq!(config.max_voting_period, Duration::Height(30));
+    assert_eq!(
+        config.voting_strategy,
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        }
+    );
+
+    // Single choice proposals do not accept multiple selections.
+    make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions {
+            options: vec![
+                MultipleChoiceOption {
+                    description: "multiple choice option 1".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+                MultipleChoiceOption {
+                    description: "multiple choice option 2".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+            ],
+        },
+        None,
+    );
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("whale"),
+            govmod,
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 2,
+                vote: MultiSelectVote {
+                    option_ids: vec![0, 1],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidVote {});
+}
+
+#[test]
+fn test_approval_voting_quorum_counts_voters_once() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            winners: 1,
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: None,
+    };
+
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: "orange".to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = (0..3)
+        .map(|i| MultipleChoiceOption {
+            description: format!("multiple choice option {i}"),
+            msgs: vec![],
+            title: "title".to_string(),
+        })
+        .collect();
+    make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions { options },
+        None,
+    );
+
+    // Approving of three options adds 120 to the option weights, but
+    // only 40 of 100 voting power has turned out.
+    app.execute_contract(
+        Addr::unchecked("orange"),
+        govmod.clone(),
+        &ExecuteMsg::VoteMultiple {
+            proposal_id: 1,
+            vote: MultiSelectVote {
+                option_ids: vec![0, 1, 2],
+            },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let quorum_reached: bool = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::QuorumReached { proposal_id: 1 })
+        .unwrap();
+    assert!(!quorum_reached);
+
+    app.update_block(|block| block.height += 10);
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    app.execute_contract(
+        Addr::unchecked("orange"),
+        govmod.clone(),
+        &ExecuteMsg::Close { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+}
+
+#[test]
+fn test_ranked_choice_voting() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::RankedChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![
+            Cw20Coin {
+                address: "blue".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(45),
+            },
+            Cw20Coin {
+                address: "orange".to_string(),
+                amount: Uint128::new(45),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |description: &str| MultipleChoiceOption {
+        description: description.to_string(),
+        msgs: vec![],
+        title: "title".to_string(),
+    };
+    make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions {
+            options: vec![option("first"), option("second"), option("third")],
+        },
+        None,
+    );
+
+    // Rankings may not repeat options.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("blue"),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                vote: MultiSelectVote {
+                    option_ids: vec![2, 1, 2],
+                },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidVote {});
+
+    for (voter, option_ids) in [
+        ("whale", vec![1, 0]),
+        // "None of the above" may be ranked alongside other options.
+        ("orange", vec![0, 3, 1]),
+        ("blue", vec![2, 1]),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                vote: MultiSelectVote { option_ids },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // "None of the above" and then the third option are eliminated,
+    // after which blue's vote goes to the second option.
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(proposal.proposal.votes.ranked_choice_winner, Some(1));
+
+    let rounds: RankedChoiceRoundsResponse = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::RankedChoiceRounds { proposal_id: 1 })
+        .unwrap();
+    let first_round = vec![
+        Uint128::new(45),
+        Uint128::new(45),
+        Uint128::new(10),
+        Uint128::zero(),
+    ];
+    assert_eq!(
+        rounds.rounds,
+        vec![
+            RankedChoiceRound {
+                tallies: first_round.clone(),
+                exhausted: Uint128::zero(),
+                eliminated: vec![3],
+                winner: None,
+            },
+            RankedChoiceRound {
+                tallies: first_round,
+                exhausted: Uint128::zero(),
+                eliminated: vec![2],
+                winner: None,
+            },
+            RankedChoiceRound {
+                tallies: vec![
+                    Uint128::new(45),
+                    Uint128::new(55),
+                    Uint128::zero(),
+                    Uint128::zero(),
+                ],
+                exhausted: Uint128::zero(),
+                eliminated: vec![],
+                winner: Some(1),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_max_rankings() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::RankedChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: None,
+    };
+    let voters = (0..MAX_RANKINGS + 2)
+        .map(|i| format!("voter{i:02}"))
+        .collect::<Vec<_>>();
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(
+            voters
+                .iter()
+                .map(|voter| Cw20Coin {
+                    address: voter.clone(),
+                    amount: Uint128::new(1),
+                })
+                .collect(),
+        ),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |description: &str| MultipleChoiceOption {
+        description: description.to_string(),
+        msgs: vec![],
+        title: "title".to_string(),
+    };
+    make_proposal(
+        &mut app,
+        &govmod,
+        &voters[0],
+        MultipleChoiceOptions {
+            options: vec![option("first"), option("second"), option("third")],
+        },
+        None,
+    );
+
+    // Every ordering of any number of the four options.
+    let mut rankings = vec![];
+    for a in 0..4 {
+        rankings.push(vec![a]);
+        for b in (0..4).filter(|b| *b != a) {
+            rankings.push(vec![a, b]);
+            for c in (0..4).filter(|c| *c != a && *c != b) {
+                rankings.push(vec![a, b, c]);
+                rankings.push(vec![a, b, c, 6 - a - b - c]);
+            }
+        }
+    }
+    let vote = |app: &mut App, voter: &str, option_ids: Vec<u32>| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                vote: MultiSelectVote { option_ids },
+                rationale: None,
+            },
+            &[],
+        )
+    };
+    for (voter, ranking) in voters.iter().zip(&rankings).take(MAX_RANKINGS) {
+        vote(&mut app, voter, ranking.clone()).unwrap();
+    }
+
+    // Once the limit is reached, only existing rankings may be cast.
+    let err: ContractError = vote(
+        &mut app,
+        &voters[MAX_RANKINGS],
+        rankings[MAX_RANKINGS].clone(),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::TooManyRankings { max: MAX_RANKINGS });
+    vote(&mut app, &voters[MAX_RANKINGS], rankings[0].clone()).unwrap();
+    vote(&mut app, &voters[MAX_RANKINGS + 1], rankings[1].clone()).unwrap();
+
+    let rounds: RankedChoiceRoundsResponse = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::RankedChoiceRounds { proposal_id: 1 })
+        .unwrap();
+    let first_round: Uint128 = rounds.rounds[0].tallies.iter().sum();
+    assert_eq!(first_round, Uint128::new(MAX_RANKINGS as u128 + 2));
+}
+
+#[test]
+fn test_winner_threshold_runoff() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: Some(WinnerThreshold {
+            threshold: PercentageThreshold::Majority {},
+            runoff: true,
+        }),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![
+            Cw20Coin {
+                address: "blue".to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(35),
+            },
+            Cw20Coin {
+                address: "orange".to_string(),
+                amount: Uint128::new(25),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let option = |description: &str| MultipleChoiceOption {
+        description: description.to_string(),
+        msgs: vec![],
+        title: "title".to_string(),
+    };
+    make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions {
+            options: vec![option("first"), option("second"), option("third")],
+        },
+        None,
+    );
+    for (voter, option_id) in [("blue", 0), ("whale", 1), ("orange", 2)] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // The first option leads with 40% of the votes, short of a
+    // majority, so the proposal stays open until it expires.
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    app.update_block(|block| block.height += 10);
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked("orange"),
+            govmod.clone(),
+            &ExecuteMsg::Close { proposal_id: 1 },
+            &[],
+        )
+        .unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .iter()
+        .any(|a| a.key == "runoff_proposal_id" && a.value == "2")));
+
+    let proposal = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+
+    let runoff = query_proposal(&app, &govmod, 2).proposal;
+    assert_eq!(runoff.status, Status::Open);
+    assert_eq!(runoff.runoff_of, Some(1));
+    assert_eq!(runoff.winner_threshold, None);
+    assert_eq!(runoff.proposer, Addr::unchecked("whale"));
+    let descriptions: Vec<&str> = runoff
+        .choices
+        .iter()
+        .map(|c| c.description.as_str())
+        .collect();
+    assert_eq!(descriptions, vec!["first", "second", "None of the above"]);
+}
+
+#[test]
+fn test_winner_threshold_runoff_deposit() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(1),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+        false,
+    );
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info,
+        veto: None,
+        winner_threshold: Some(WinnerThreshold {
+            threshold: PercentageThreshold::Majority {},
+            runoff: true,
+        }),
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![
+            Cw20Coin {
+                address: "blue".to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(35),
+            },
+            Cw20Coin {
+                address: "orange".to_string(),
+                amount: Uint128::new(25),
+            },
+        ]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+    let (_, pre_propose_module) = query_deposit_config_and_pre_propose_module(&app, &govmod);
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: "whale".to_string(),
+        amount: coins(1, "ujuno"),
+    }))
+    .unwrap();
+    let option = |description: &str| MultipleChoiceOption {
+        description: description.to_string(),
+        msgs: vec![],
+        title: "title".to_string(),
+    };
+    make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions {
+            options: vec![option("first"), option("second"), option("third")],
+        },
+        None,
+    );
+    for (voter, option_id) in [("blue", 0), ("whale", 1), ("orange", 2)] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked("orange"),
+        govmod.clone(),
+        &ExecuteMsg::Close { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    // The deposit is held for the runoff rather than being kept
+    // because the proposal was rejected.
+    assert_eq!(
+        query_balance_native(&app, "whale", "ujuno"),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_balance_native(&app, pre_propose_module.as_str(), "ujuno"),
+        Uint128::new(1)
+    );
+
+    for voter in ["blue", "whale"] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 2,
+                vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.execute_contract(
+        Addr::unchecked("whale"),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    // The runoff passed, so the original deposit is refunded.
+    assert_eq!(
+        query_balance_native(&app, "whale", "ujuno"),
+        Uint128::new(1)
+    );
+    assert_eq!(
+        query_balance_native(&app, pre_propose_module.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_winner_threshold_requires_single_choice() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: None,
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, msg, None);
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            govmod,
+            &ExecuteMsg::UpdateConfig {
+                voting_strategy: VotingStrategy::Approval {
+                    quorum: PercentageThreshold::Majority {},
+                    winners: 1,
+                },
+                min_voting_period: None,
+                close_proposal_on_execution_failure: true,
+                max_voting_period: Duration::Height(10),
+                only_members_execute: false,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                veto: None,
+                winner_threshold: Some(WinnerThreshold {
+                    threshold: PercentageThreshold::Majority {},
+                    runoff: false,
+                }),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::WinnerThresholdUnsupported {});
+}
+
+#[test]
+#[should_panic(expected = "Approval voting must have at least one winner")]
+fn test_invalid_approval_winners() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            winners: 0,
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposal_on_execution_failure: true,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        veto: None,
+        winner_threshold: None,
+    };
+    instantiate_with_staked_balances_governance(&mut app, msg, None);
+}
+
 #[test]
 fn test_invalid_quorum() {
     // Create a proposal that will be rejected
@@ -1788,6 +2820,7 @@ fn test_cant_propose_zero_power() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_cw20_balances_governance(
@@ -1957,6 +2990,7 @@ fn test_cant_execute_not_member() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2049,6 +3083,7 @@ fn test_cant_execute_not_member_when_proposal_created() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2168,6 +3203,7 @@ fn test_open_proposal_submission() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
         veto: None,
+        winner_threshold: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
     let govmod = query_multiple_proposal_module(&app, &core_addr);
@@ -2236,8 +3272,11 @@ fn test_open_proposal_submission() {
         ],
         votes: MultipleChoiceVotes {
             vote_weights: vec![Uint128::zero(); 3],
+            ..Default::default()
         },
         veto: None,
+        winner_threshold: None,
+        runoff_of: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -2470,6 +3509,7 @@ fn test_execute_expired_proposal() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -2634,6 +3674,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: dao.to_string(),
             veto: None,
+            winner_threshold: None,
         },
         &[],
     )
@@ -2654,6 +3695,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
+            winner_threshold: None,
         },
         &[],
     )
@@ -2672,6 +3714,7 @@ fn test_update_config() {
         allow_revoting: false,
         dao: Addr::unchecked(CREATOR_ADDR),
         veto: None,
+        winner_threshold: None,
     };
     assert_eq!(govmod_config, expected);
 
@@ -2691,6 +3734,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
+            winner_threshold: None,
         },
         &[],
     )
@@ -2769,6 +3813,7 @@ fn test_query_list_proposals() {
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
     let gov_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -2847,10 +3892,13 @@ fn test_query_list_proposals() {
             total_power: Uint128::new(100),
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
+                ..Default::default()
             },
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            winner_threshold: None,
+            runoff_of: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2876,10 +3924,13 @@ fn test_query_list_proposals() {
             total_power: Uint128::new(100),
             votes: MultipleChoiceVotes {
                 vote_weights: vec![Uint128::zero(); 3],
+                ..Default::default()
             },
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            winner_threshold: None,
+            runoff_of: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2906,6 +3957,7 @@ fn test_hooks() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -3033,6 +4085,7 @@ fn test_active_threshold_absolute() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -3164,6 +4217,7 @@ fn test_active_threshold_percent() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     // 20% needed to be active, 20% of 100000000 is 20000000
@@ -3296,6 +4350,7 @@ fn test_active_threshold_none() {
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr =
@@ -3409,6 +4464,7 @@ fn test_revoting() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3543,6 +4599,7 @@ fn test_allow_revoting_config_changes() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3602,6 +4659,7 @@ fn test_allow_revoting_config_changes() {
             },
             close_proposal_on_execution_failure: false,
             veto: None,
+            winner_threshold: None,
         },
         &[],
     )
@@ -3698,6 +4756,7 @@ fn test_revoting_same_vote_twice() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3794,6 +4853,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3989,6 +5049,7 @@ fn test_close_failed_proposal() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(&mut app, instantiate, None, None);
@@ -4125,6 +5186,7 @@ fn test_close_failed_proposal() {
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
                                     veto: None,
+                                    winner_threshold: None,
                                 })
                                 .unwrap(),
                                 funds: vec![],
@@ -4244,6 +5306,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
             false,
         ),
         veto: None,
+        winner_threshold: None,
     };
 
     let core_addr = instantiate_with_staking_active_threshold(
@@ -4424,6 +5487,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         close_proposal_on_execution_failure: true,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
+        winner_threshold: None,
     };
     let core_addr = instantiate_with_staked_balances_governance(
         &mut app,
@@ -4517,6 +5581,7 @@ fn test_next_proposal_id() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4590,6 +5655,7 @@ fn test_vote_with_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4688,6 +5754,7 @@ fn test_revote_with_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4844,6 +5911,7 @@ fn test_update_rationale() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -4983,6 +6051,7 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5092,6 +6161,7 @@ fn test_veto_non_existing_prop_id() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5138,6 +6208,7 @@ fn test_veto_with_no_veto_configuration() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5230,6 +6301,7 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5338,6 +6410,7 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5453,6 +6526,7 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5564,6 +6638,7 @@ fn test_veto_from_catchall_state() {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5685,6 +6760,7 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5809,6 +6885,7 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -5922,6 +6999,7 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
             close_proposal_on_execution_failure: false,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
+            winner_threshold: None,
         },
         Some(vec![
             Cw20Coin {
@@ -1,7 +1,7 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply,
+    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
     Response, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
@@ -35,14 +35,28 @@ use crate::{
     error::ContractError,
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
-    query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
+    query::{ProposalListResponse, UnvotedProposalListResponse},
+    query::{
+        ProposalResponse, VoteInfo, VoteListResponse, VoteResponse, VoterVoteInfo,
+        VoterVoteListResponse,
+    },
+    state::{
+        Ballot, IndexBackfill, BALLOTS, CONFIG, INDEX_BACKFILL, PROPOSALS, PROPOSAL_COUNT,
+        PROPOSAL_HOOKS, VOTER_BALLOTS, VOTE_HOOKS,
+    },
 };
 use cw_proposal_single_v1 as v1;
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The number of proposals or ballots given index entries per
+/// `BackfillIndexes` message, and during migration.
+pub(crate) const BACKFILL_LIMIT: u64 = 100;
+
+/// The number of open proposals scanned per `ListUnvotedProposals`
+/// query.
+pub(crate) const MAX_UNVOTED_SCAN: usize = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -146,6 +160,7 @@ pub fn execute(
             execute_remove_vote_hook(deps, env, info, address)
         }
         ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
+        ExecuteMsg::BackfillIndexes { limit } => execute_backfill_indexes(deps, limit),
     }
 }
 
@@ -534,6 +549,7 @@ pub fn execute_vote(
             rationale: rationale.clone(),
         }),
     })?;
+    VOTER_BALLOTS.save(deps.storage, (&sender, proposal_id), &Empty {})?;
 
     let old_status = prop.status;
 
@@ -830,6 +846,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_list_proposals(deps, env, start_after, limit)
         }
         QueryMsg::NextProposalId {} => query_next_proposal_id(deps),
+        QueryMsg::QuorumReached { proposal_id } => query_quorum_reached(deps, proposal_id),
         QueryMsg::ProposalCount {} => query_proposal_count(deps),
         QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
         QueryMsg::ListVotes {
@@ -837,6 +854,26 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
         } => query_list_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::ListVotesByVoter {
+            voter,
+            start_after,
+            limit,
+        } => query_list_votes_by_voter(deps, voter, start_after, limit),
+        QueryMsg::ListProposalsByProposer {
+            proposer,
+            start_after,
+            limit,
+        } => query_list_proposals_by_proposer(deps, env, proposer, start_after, limit),
+        QueryMsg::ListProposalsByStatus {
+            status,
+            start_after,
+            limit,
+        } => query_list_proposals_by_status(deps, env, status, start_after, limit),
+        QueryMsg::ListUnvotedProposals {
+            voter,
+            start_after,
+            limit,
+        } => query_list_unvoted_proposals(deps, env, voter, start_after, limit),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::ReverseProposals {
             start_before,
@@ -915,6 +952,11 @@ pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&next_proposal_id(deps.storage)?)
 }
 
+pub fn query_quorum_reached(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    to_json_binary(&proposal.quorum_reached())
+}
+
 pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
     let voter = deps.api.addr_validate(&voter)?;
     let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
@@ -957,6 +999,163 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_votes_by_voter(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(Bound::exclusive);
+
+    let votes = VOTER_BALLOTS
+        .prefix(&voter)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|proposal_id| {
+            let proposal_id = proposal_id?;
+            let ballot = BALLOTS.load(deps.storage, (proposal_id, &voter))?;
+            Ok(VoterVoteInfo {
+                proposal_id,
+                vote: VoteInfo {
+                    voter: voter.clone(),
+                    vote: ballot.vote,
+                    power: ballot.power,
+                    rationale: ballot.rationale,
+                },
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&VoterVoteListResponse { votes })
+}
+
+pub fn query_list_proposals_by_proposer(
+    deps: Deps,
+    env: Env,
+    proposer: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let proposer = deps.api.addr_validate(&proposer)?;
+    let min = start_after.map(Bound::exclusive);
+
+    let props = PROPOSALS
+        .idx
+        .proposer
+        .prefix(proposer)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            proposal.into_response(&env.block, id)
+        })
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_list_proposals_by_status(
+    deps: Deps,
+    env: Env,
+    status: Status,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+
+    // A proposal's stored status may be out of date, so we check
+    // every status it may be stored with and keep only the proposals
+    // whose current status matches. Each of these lists is ordered
+    // by proposal ID and contains at most `limit` proposals, so
+    // merging them and taking the first `limit` gives a correct page.
+    let mut props = status
+        .stored_index_keys()
+        .into_iter()
+        .map(|key| {
+            PROPOSALS
+                .idx
+                .status
+                .prefix(key.to_string())
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .map(|item| {
+                    let (id, proposal) = item?;
+                    proposal.into_response(&env.block, id)
+                })
+                .filter(|response| {
+                    response.as_ref().map_or(true, |response| {
+                        response.proposal.status.index_key() == status.index_key()
+                    })
+                })
+                .take(limit)
+                .collect::<StdResult<Vec<ProposalResponse>>>()
+        })
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<ProposalResponse>>();
+    props.sort_by_key(|response| response.id);
+    props.truncate(limit);
+
+    to_json_binary(&ProposalListResponse { proposals: props })
+}
+
+pub fn query_list_unvoted_proposals(
+    deps: Deps,
+    env: Env,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT) as usize;
+    let voter = deps.api.addr_validate(&voter)?;
+    let min = start_after.map(Bound::exclusive);
+
+    // Proposals the voter has voted on are skipped, so a bounded
+    // number of open proposals are scanned and the last one scanned
+    // is returned for the next query to start after.
+    let mut open = PROPOSALS
+        .idx
+        .status
+        .prefix(Status::Open.index_key().to_string())
+        .range(deps.storage, min, None, Order::Ascending);
+    let mut proposals = vec![];
+    let mut last_scanned = None;
+    for _ in 0..MAX_UNVOTED_SCAN {
+        if proposals.len() == limit {
+            break;
+        }
+        let Some(item) = open.next() else {
+            // Every open proposal has been scanned.
+            last_scanned = None;
+            break;
+        };
+        let (id, proposal) = item?;
+        last_scanned = Some(id);
+        if BALLOTS.has(deps.storage, (id, &voter)) {
+            continue;
+        }
+        // Proposals are stored as open until they are updated, even
+        // if they have since expired.
+        let response = proposal.into_response(&env.block, id)?;
+        if response.proposal.status == Status::Open {
+            proposals.push(response);
+        }
+    }
+
+    to_json_binary(&UnvotedProposalListResponse {
+        proposals,
+        last_scanned,
+    })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1054,17 +1253,100 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         .map_err(|e| e.into())
                 })?;
 
+            // Ballots are read from the same storage location in v1
+            // and v2, but v1 ballots have no index entries.
+            INDEX_BACKFILL.save(deps.storage, &IndexBackfill::Ballots { start_after: None })?;
+            backfill_indexes(deps.storage, BACKFILL_LIMIT)?;
+
             Ok(Response::default()
                 .add_attribute("action", "migrate")
                 .add_attribute("from", "v1")
                 .add_submessages(pre_propose_messages))
         }
-        MigrateMsg::FromCompatible {} => Ok(Response::default()
-            .add_attribute("action", "migrate")
-            .add_attribute("from", "compatible")),
+        MigrateMsg::FromCompatible {} => {
+            // Proposals and ballots saved before indexed queries were
+            // supported have no index entries.
+            INDEX_BACKFILL.save(
+                deps.storage,
+                &IndexBackfill::Proposals { start_after: None },
+            )?;
+            backfill_indexes(deps.storage, BACKFILL_LIMIT)?;
+
+            Ok(Response::default()
+                .add_attribute("action", "migrate")
+                .add_attribute("from", "compatible"))
+        }
     }
 }
 
+pub fn execute_backfill_indexes(
+    deps: DepsMut,
+    limit: Option<u64>,
+) -> Result<Response, ContractError> {
+    let complete = backfill_indexes(deps.storage, limit.unwrap_or(BACKFILL_LIMIT))?;
+    Ok(Response::default()
+        .add_attribute("action", "backfill_indexes")
+        .add_attribute("complete", complete.to_string()))
+}
+
+/// Writes index entries for up to LIMIT of the proposals and ballots
+/// saved before they were indexed. Writing an index entry that
+/// already exists is a no-op, so entries saved since do no
+/// harm. Returns true once every entry has been written.
+pub(crate) fn backfill_indexes(storage: &mut dyn Storage, limit: u64) -> StdResult<bool> {
+    let limit = limit.max(1) as usize;
+    let next = match INDEX_BACKFILL.may_load(storage)? {
+        None => return Ok(true),
+        Some(IndexBackfill::Proposals { start_after }) => {
+            let proposals = PROPOSALS
+                .range(
+                    storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<(u64, SingleChoiceProposal)>>>()?;
+            for (id, proposal) in &proposals {
+                PROPOSALS.replace(storage, *id, Some(proposal), None)?;
+            }
+            match proposals.last() {
+                Some((id, _)) if proposals.len() == limit => IndexBackfill::Proposals {
+                    start_after: Some(*id),
+                },
+                _ => IndexBackfill::Ballots { start_after: None },
+            }
+        }
+        Some(IndexBackfill::Ballots { start_after }) => {
+            let ballots = BALLOTS
+                .keys(
+                    storage,
+                    start_after
+                        .as_ref()
+                        .map(|(id, voter)| Bound::exclusive((*id, voter))),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<Vec<(u64, Addr)>>>()?;
+            for (id, voter) in &ballots {
+                VOTER_BALLOTS.save(storage, (voter, *id), &Empty {})?;
+            }
+            match ballots.last() {
+                Some(last) if ballots.len() == limit => IndexBackfill::Ballots {
+                    start_after: Some(last.clone()),
+                },
+                _ => {
+                    INDEX_BACKFILL.remove(storage);
+                    return Ok(true);
+                }
+            }
+        }
+    };
+    INDEX_BACKFILL.save(storage, &next)?;
+    Ok(false)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     let repl = TaggedReplyId::new(msg.id)?;
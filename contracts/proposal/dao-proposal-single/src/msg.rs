@@ -2,8 +2,8 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
-    veto::VetoConfig, voting::Vote,
+    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, status::Status,
+    threshold::Threshold, veto::VetoConfig, voting::Vote,
 };
 
 #[cw_serde]
@@ -143,6 +143,13 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Writes index entries for up to LIMIT proposals or ballots
+    /// saved before this module indexed them, continuing from where
+    /// the last call left off. Until every entry has been written the
+    /// voter, proposer, and status queries may omit older proposals
+    /// and votes. Migrating writes the first page. May be called by
+    /// anyone.
+    BackfillIndexes { limit: Option<u64> },
 }
 
 #[proposal_module_query]
@@ -197,6 +204,62 @@ pub enum QueryMsg {
         /// query. If no limit is specified a max of 30 are returned.
         limit: Option<u64>,
     },
+    /// Lists all of the votes that have been cast by an address
+    /// across proposals in ascending order of proposal ID.
+    #[returns(crate::query::VoterVoteListResponse)]
+    ListVotesByVoter {
+        /// The address to list the votes of.
+        voter: String,
+        /// The proposal ID to start listing votes after.
+        start_after: Option<u64>,
+        /// The maximum number of votes to return in response to this
+        /// query. If no limit is specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals created by an address in ascending
+    /// order of proposal ID.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByProposer {
+        /// The address to list the proposals of.
+        proposer: String,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals with a status in ascending order of
+    /// proposal ID. Statuses are compared by variant, so any
+    /// `VetoTimelock` status matches all timelocked proposals
+    /// regardless of their timelock expiration.
+    #[returns(crate::query::ProposalListResponse)]
+    ListProposalsByStatus {
+        /// The status to list the proposals of.
+        status: Status,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists the open proposals that an address has not yet voted on
+    /// in ascending order of proposal ID. A limited number of open
+    /// proposals are scanned per query, so fewer than `limit` may be
+    /// returned before the end is reached. Listing continues after
+    /// the returned `last_scanned` until it is `None`.
+    #[returns(crate::query::UnvotedProposalListResponse)]
+    ListUnvotedProposals {
+        /// The address to list the unvoted proposals of.
+        voter: String,
+        /// The proposal ID to start listing proposals after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
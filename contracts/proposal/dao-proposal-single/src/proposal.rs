@@ -158,6 +158,17 @@ impl SingleChoiceProposal {
         }
     }
 
+    /// Returns true if the votes cast on this proposal meet its
+    /// quorum. Thresholds without a quorum are always met.
+    pub fn quorum_reached(&self) -> bool {
+        match self.threshold {
+            Threshold::ThresholdQuorum { quorum, .. } => {
+                does_vote_count_pass(self.votes.total(), self.total_power, quorum)
+            }
+            Threshold::AbsolutePercentage { .. } | Threshold::AbsoluteCount { .. } => true,
+        }
+    }
+
     /// As above for the passed check, used to check if a proposal is
     /// already rejected.
     pub fn is_rejected(&self, block: &BlockInfo) -> bool {
@@ -37,9 +37,36 @@ pub struct VoteListResponse {
     pub votes: Vec<VoteInfo>,
 }
 
-/// A list of proposals returned by `ListProposals` and
-/// `ReverseProposals`.
+/// Information about a vote cast by an address on a proposal.
+#[cw_serde]
+pub struct VoterVoteInfo {
+    /// The ID of the proposal that was voted on.
+    pub proposal_id: u64,
+    /// The vote that was cast.
+    pub vote: VoteInfo,
+}
+
+/// Information about the votes cast by an address returned by
+/// `ListVotesByVoter`.
+#[cw_serde]
+pub struct VoterVoteListResponse {
+    pub votes: Vec<VoterVoteInfo>,
+}
+
+/// A list of proposals returned by `ListProposals`,
+/// `ReverseProposals`, and the other proposal list queries.
 #[cw_serde]
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// Open proposals that an address has not voted on, returned by
+/// `ListUnvotedProposals`.
+#[cw_serde]
+pub struct UnvotedProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+    /// The ID of the last proposal scanned, to be passed as
+    /// `start_after` to continue listing. `None` once every open
+    /// proposal has been scanned.
+    pub last_scanned: Option<u64>,
+}
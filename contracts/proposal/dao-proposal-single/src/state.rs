@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, Map};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use cw_utils::Duration;
 use dao_voting::{
     pre_propose::ProposalCreationPolicy, threshold::Threshold, veto::VetoConfig, voting::Vote,
@@ -68,8 +68,22 @@ pub struct Config {
 pub const CONFIG: Item<Config> = Item::new("config_v2");
 /// The number of proposals that have been created.
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
-pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
+pub const PROPOSALS: IndexedMap<u64, SingleChoiceProposal, ProposalIndexes> = IndexedMap::new(
+    "proposals_v2",
+    ProposalIndexes {
+        proposer: MultiIndex::new(
+            proposal_proposer_idx,
+            "proposals_v2",
+            "proposals_v2__proposer",
+        ),
+        status: MultiIndex::new(proposal_status_idx, "proposals_v2", "proposals_v2__status"),
+    },
+);
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The proposals each address has voted on. The existence of an
+/// `(address, proposal_id)` pair implies that `address` has a ballot
+/// for `proposal_id`.
+pub const VOTER_BALLOTS: Map<(&Addr, u64), Empty> = Map::new("voter_ballots");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -77,3 +91,39 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// Progress writing index entries for proposals and ballots saved
+/// before they were indexed. Removed once every entry is written.
+pub const INDEX_BACKFILL: Item<IndexBackfill> = Item::new("index_backfill");
+
+/// The next page of entries to write index entries for.
+#[cw_serde]
+pub enum IndexBackfill {
+    Proposals { start_after: Option<u64> },
+    Ballots { start_after: Option<(u64, Addr)> },
+}
+
+/// Secondary indexes over proposals.
+pub struct ProposalIndexes<'a> {
+    /// Proposals indexed by the address that created them.
+    pub proposer: MultiIndex<'a, Addr, SingleChoiceProposal, u64>,
+    /// Proposals indexed by the status they were last saved
+    /// with. Statuses are only saved on vote, execute, close, and veto
+    /// events, so entries here may be out of date. See
+    /// `Status::stored_index_keys`.
+    pub status: MultiIndex<'a, String, SingleChoiceProposal, u64>,
+}
+
+impl<'a> IndexList<SingleChoiceProposal> for ProposalIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SingleChoiceProposal>> + '_> {
+        let v: Vec<&dyn Index<SingleChoiceProposal>> = vec![&self.proposer, &self.status];
+        Box::new(v.into_iter())
+    }
+}
+
+fn proposal_proposer_idx(_pk: &[u8], proposal: &SingleChoiceProposal) -> Addr {
+    proposal.proposer.clone()
+}
+
+fn proposal_status_idx(_pk: &[u8], proposal: &SingleChoiceProposal) -> String {
+    proposal.status.index_key().to_string()
+}
@@ -35,7 +35,10 @@ use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
+    query::{
+        ProposalListResponse, ProposalResponse, UnvotedProposalListResponse, VoteInfo,
+        VoterVoteInfo,
+    },
     state::Config,
     testing::{
         contracts::{pre_propose_single_contract, proposal_single_contract},
@@ -56,7 +59,9 @@ use crate::{
         queries::{
             query_balance_cw20, query_balance_native, query_creation_policy, query_dao_token,
             query_deposit_config_and_pre_propose_module, query_list_proposals,
-            query_list_proposals_reverse, query_list_votes, query_pre_proposal_single_config,
+            query_list_proposals_by_proposer, query_list_proposals_by_status,
+            query_list_proposals_reverse, query_list_unvoted_proposals, query_list_votes,
+            query_list_votes_by_voter, query_pre_proposal_single_config,
             query_pre_proposal_single_deposit_info, query_proposal, query_proposal_config,
             query_proposal_hooks, query_single_proposal_module, query_vote_hooks,
             query_voting_module,
@@ -3915,6 +3920,229 @@ fn test_query_list_votes() {
     );
 }
 
+#[test]
+fn test_query_indexed_lists() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::AbsoluteCount {
+        threshold: Uint128::new(3),
+    };
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "four".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let first = make_proposal(&mut app, &proposal_module, "one", vec![], None);
+    let second = make_proposal(&mut app, &proposal_module, "two", vec![], None);
+    let third = make_proposal(&mut app, &proposal_module, "one", vec![], None);
+
+    vote_on_proposal(&mut app, &proposal_module, "two", first, Vote::No);
+    vote_on_proposal(&mut app, &proposal_module, "one", second, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", second, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "three", second, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", third, Vote::Abstain);
+
+    let ids =
+        |res: ProposalListResponse| res.proposals.into_iter().map(|p| p.id).collect::<Vec<_>>();
+
+    // Votes by voter.
+    let votes = query_list_votes_by_voter(&app, &proposal_module, "two", None, None);
+    assert_eq!(
+        votes.votes,
+        vec![
+            VoterVoteInfo {
+                proposal_id: first,
+                vote: VoteInfo {
+                    rationale: None,
+                    voter: Addr::unchecked("two"),
+                    vote: Vote::No,
+                    power: Uint128::new(1)
+                }
+            },
+            VoterVoteInfo {
+                proposal_id: second,
+                vote: VoteInfo {
+                    rationale: None,
+                    voter: Addr::unchecked("two"),
+                    vote: Vote::Yes,
+                    power: Uint128::new(1)
+                }
+            },
+            VoterVoteInfo {
+                proposal_id: third,
+                vote: VoteInfo {
+                    rationale: None,
+                    voter: Addr::unchecked("two"),
+                    vote: Vote::Abstain,
+                    power: Uint128::new(1)
+                }
+            },
+        ]
+    );
+    let votes = query_list_votes_by_voter(&app, &proposal_module, "two", Some(first), Some(1));
+    assert_eq!(votes.votes.len(), 1);
+    assert_eq!(votes.votes[0].proposal_id, second);
+    let votes = query_list_votes_by_voter(&app, &proposal_module, "four", None, None);
+    assert_eq!(votes.votes, vec![]);
+
+    // Proposals by proposer.
+    let res = query_list_proposals_by_proposer(&app, &proposal_module, "one", None, None);
+    assert_eq!(ids(res), vec![first, third]);
+    let res = query_list_proposals_by_proposer(&app, &proposal_module, "one", Some(first), None);
+    assert_eq!(ids(res), vec![third]);
+    let res = query_list_proposals_by_proposer(&app, &proposal_module, "two", None, None);
+    assert_eq!(ids(res), vec![second]);
+
+    // Proposals by status.
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Open, None, None);
+    assert_eq!(ids(res), vec![first, third]);
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Passed, None, None);
+    assert_eq!(ids(res), vec![second]);
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Open, None, Some(1));
+    assert_eq!(ids(res), vec![first]);
+
+    // Open proposals not yet voted on. Listing ends once every open
+    // proposal has been scanned.
+    let unvoted_ids = |res: UnvotedProposalListResponse| {
+        (
+            res.proposals.into_iter().map(|p| p.id).collect::<Vec<_>>(),
+            res.last_scanned,
+        )
+    };
+    let res = query_list_unvoted_proposals(&app, &proposal_module, "two", None, None);
+    assert_eq!(unvoted_ids(res), (vec![], None));
+    let res = query_list_unvoted_proposals(&app, &proposal_module, "one", None, None);
+    assert_eq!(unvoted_ids(res), (vec![first, third], None));
+    let res = query_list_unvoted_proposals(&app, &proposal_module, "four", Some(first), None);
+    assert_eq!(unvoted_ids(res), (vec![third], None));
+    let res = query_list_unvoted_proposals(&app, &proposal_module, "four", None, Some(1));
+    assert_eq!(unvoted_ids(res), (vec![first], Some(first)));
+
+    // Once expired, open proposals are rejected even though their
+    // stored status has not been updated.
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Open, None, None);
+    assert_eq!(ids(res), vec![]);
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Rejected, None, None);
+    assert_eq!(ids(res), vec![first, third]);
+    let res = query_list_unvoted_proposals(&app, &proposal_module, "four", None, None);
+    assert_eq!(unvoted_ids(res), (vec![], None));
+
+    // Closing a proposal moves it between stored statuses.
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, first);
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Rejected, None, None);
+    assert_eq!(ids(res), vec![third]);
+    let res = query_list_proposals_by_status(&app, &proposal_module, Status::Closed, None, None);
+    assert_eq!(ids(res), vec![first]);
+}
+
+#[test]
+fn test_backfill_indexes() {
+    use crate::contract::backfill_indexes;
+    use crate::state::{Ballot, IndexBackfill, INDEX_BACKFILL, PROPOSALS, VOTER_BALLOTS};
+    use cw_storage_plus::Map;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    // Write directly to the primary storage locations, as a version
+    // of this module without indexes would have.
+    Map::<u64, SingleChoiceProposal>::new("proposals_v2")
+        .save(
+            deps.as_mut().storage,
+            1,
+            &SingleChoiceProposal {
+                title: "A simple text proposal".to_string(),
+                description: "This is a simple text proposal".to_string(),
+                proposer: Addr::unchecked(CREATOR_ADDR),
+                start_height: env.block.height,
+                expiration: cw_utils::Duration::Height(6).after(&env.block),
+                min_voting_period: None,
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                allow_revoting: false,
+                total_power: Uint128::new(100_000_000),
+                msgs: vec![],
+                status: Status::Open,
+                veto: None,
+                votes: Votes::zero(),
+            },
+        )
+        .unwrap();
+    Map::<(u64, &Addr), Ballot>::new("ballots")
+        .save(
+            deps.as_mut().storage,
+            (1, &Addr::unchecked("voter")),
+            &Ballot {
+                power: Uint128::new(1),
+                vote: Vote::Yes,
+                rationale: None,
+            },
+        )
+        .unwrap();
+
+    let voter = Addr::unchecked("voter");
+    let voter_ballots = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+        VOTER_BALLOTS
+            .prefix(&voter)
+            .keys(&deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .count()
+    };
+    let proposer_proposals = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+        PROPOSALS
+            .idx
+            .proposer
+            .prefix(Addr::unchecked(CREATOR_ADDR))
+            .keys(&deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .count()
+    };
+    assert_eq!(voter_ballots(&deps), 0);
+    assert_eq!(proposer_proposals(&deps), 0);
+
+    INDEX_BACKFILL
+        .save(
+            deps.as_mut().storage,
+            &IndexBackfill::Proposals { start_after: None },
+        )
+        .unwrap();
+
+    // One proposal per page, then an empty page, then one ballot per
+    // page, then an empty page.
+    assert!(!backfill_indexes(deps.as_mut().storage, 1).unwrap());
+    assert_eq!(proposer_proposals(&deps), 1);
+    assert!(!backfill_indexes(deps.as_mut().storage, 1).unwrap());
+    assert!(!backfill_indexes(deps.as_mut().storage, 1).unwrap());
+    assert_eq!(voter_ballots(&deps), 1);
+    assert!(backfill_indexes(deps.as_mut().storage, 1).unwrap());
+    assert!(INDEX_BACKFILL.may_load(&deps.storage).unwrap().is_none());
+
+    // Backfilling again is a no-op.
+    assert!(backfill_indexes(deps.as_mut().storage, 1).unwrap());
+    assert_eq!(voter_ballots(&deps), 1);
+    assert_eq!(proposer_proposals(&deps), 1);
+}
+
 #[test]
 fn test_update_pre_propose_module() {
     let CommonTest {
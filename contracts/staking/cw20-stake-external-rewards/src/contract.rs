@@ -219,7 +219,9 @@ pub fn execute_stake_changed(
     };
     match msg {
         StakeChangedHookMsg::Stake { addr, .. } => execute_stake(deps, env, addr),
-        StakeChangedHookMsg::Unstake { addr, .. } => execute_unstake(deps, env, addr),
+        StakeChangedHookMsg::Unstake { addr, .. } | StakeChangedHookMsg::Slash { addr, .. } => {
+            execute_unstake(deps, env, addr)
+        }
     }
 }
 
@@ -2,8 +2,8 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
-    StdError, StdResult, Uint128,
+    from_json, to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Storage, SubMsg, Uint128,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw20::{Cw20ReceiveMsg, TokenInfoResponse};
@@ -17,16 +17,16 @@ pub use cw20_base::contract::{
     query_token_info,
 };
 pub use cw20_base::enumerable::{query_all_accounts, query_owner_allowances};
-use cw_controllers::ClaimsResponse;
+use cw_controllers::{Claim, ClaimsResponse};
 use cw_utils::Duration;
-use dao_hooks::stake::{stake_hook_msgs, unstake_hook_msgs};
+use dao_hooks::stake::{slash_hook_msgs, stake_hook_msgs, unstake_hook_msgs};
 use dao_voting::duration::validate_duration;
 
 use crate::math;
 use crate::msg::{
     ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    ReceiveMsg, StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
-    TotalStakedAtHeightResponse, TotalValueResponse,
+    ReceiveMsg, SlashDestination, StakedBalanceAtHeightResponse, StakedValueResponse,
+    StakerBalanceResponse, TotalStakedAtHeightResponse, TotalValueResponse,
 };
 use crate::state::{
     Config, BALANCE, CLAIMS, CONFIG, HOOKS, MAX_CLAIMS, STAKED_BALANCES, STAKED_TOTAL,
@@ -84,8 +84,16 @@ pub fn execute(
     match msg {
         ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
         ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::TransferStake { recipient, amount } => {
+            execute_transfer_stake(deps, env, info, recipient, amount)
+        }
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
         ExecuteMsg::UpdateConfig { duration } => execute_update_config(info, deps, duration),
+        ExecuteMsg::Slash {
+            addr,
+            percent,
+            destination,
+        } => execute_slash(deps, env, info, addr, percent, destination),
         ExecuteMsg::AddHook { addr } => execute_add_hook(deps, env, info, addr),
         ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, env, info, addr),
         ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, info, env, action),
@@ -133,6 +141,10 @@ pub fn execute_receive(
     let sender = deps.api.addr_validate(&wrapper.sender)?;
     match msg {
         ReceiveMsg::Stake {} => execute_stake(deps, env, sender, wrapper.amount),
+        ReceiveMsg::StakeFor { recipient } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            execute_stake_for(deps, env, sender, recipient, wrapper.amount)
+        }
         ReceiveMsg::Fund {} => execute_fund(deps, env, &sender, wrapper.amount),
     }
 }
@@ -143,12 +155,44 @@ pub fn execute_stake(
     sender: Addr,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
+    let hook_msgs = stake(deps, env, &sender, amount)?;
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "stake")
+        .add_attribute("from", sender)
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_stake_for(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    recipient: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let hook_msgs = stake(deps, env, &recipient, amount)?;
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "stake_for")
+        .add_attribute("from", sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", amount))
+}
+
+/// Stakes AMOUNT tokens for STAKER, returning the stake hook messages
+/// to fire.
+fn stake(
+    deps: DepsMut,
+    env: Env,
+    staker: &Addr,
+    amount: Uint128,
+) -> Result<Vec<SubMsg>, ContractError> {
     let balance = BALANCE.load(deps.storage)?;
     let staked_total = STAKED_TOTAL.load(deps.storage)?;
     let amount_to_stake = math::amount_to_stake(staked_total, balance, amount);
     STAKED_BALANCES.update(
         deps.storage,
-        &sender,
+        staker,
         env.block.height,
         |bal| -> StdResult<Uint128> { Ok(bal.unwrap_or_default().checked_add(amount_to_stake)?) },
     )?;
@@ -164,12 +208,8 @@ pub fn execute_stake(
         deps.storage,
         &balance.checked_add(amount).map_err(StdError::overflow)?,
     )?;
-    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, sender.clone(), amount_to_stake)?;
-    Ok(Response::new()
-        .add_submessages(hook_msgs)
-        .add_attribute("action", "stake")
-        .add_attribute("from", sender)
-        .add_attribute("amount", amount))
+    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, staker.clone(), amount_to_stake)?;
+    Ok(hook_msgs)
 }
 
 pub fn execute_unstake(
@@ -233,17 +273,17 @@ pub fn execute_unstake(
                 .add_attribute("claim_duration", "None"))
         }
         Some(duration) => {
-            let outstanding_claims = CLAIMS.query_claims(deps.as_ref(), &info.sender)?.claims;
-            if outstanding_claims.len() + 1 > MAX_CLAIMS as usize {
+            let mut claims = CLAIMS
+                .may_load(deps.storage, &info.sender)?
+                .unwrap_or_default();
+            if claims.len() + 1 > MAX_CLAIMS as usize {
                 return Err(ContractError::TooManyClaims {});
             }
-
-            CLAIMS.create_claim(
-                deps.storage,
-                &info.sender,
-                amount_to_claim,
-                duration.after(&env.block),
-            )?;
+            claims.push(Claim {
+                amount: amount_to_claim,
+                release_at: duration.after(&env.block),
+            });
+            CLAIMS.save(deps.storage, &info.sender, &claims)?;
             Ok(Response::new()
                 .add_attribute("action", "unstake")
                 .add_submessages(hook_msgs)
@@ -254,15 +294,72 @@ pub fn execute_unstake(
     }
 }
 
+pub fn execute_transfer_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroTransfer {});
+    }
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if recipient == info.sender {
+        return Err(ContractError::SelfTransfer {});
+    }
+    STAKED_BALANCES.update(
+        deps.storage,
+        &info.sender,
+        env.block.height,
+        |bal| -> Result<Uint128, ContractError> {
+            bal.unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_| ContractError::ImpossibleTransfer {})
+        },
+    )?;
+    STAKED_BALANCES.update(
+        deps.storage,
+        &recipient,
+        env.block.height,
+        |bal| -> StdResult<Uint128> { Ok(bal.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    let mut hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), amount)?;
+    hook_msgs.extend(stake_hook_msgs(
+        HOOKS,
+        deps.storage,
+        recipient.clone(),
+        amount,
+    )?);
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "transfer_stake")
+        .add_attribute("from", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("amount", amount))
+}
+
 pub fn execute_claim(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
-    let release = CLAIMS.claim_tokens(deps.storage, &info.sender, &_env.block, None)?;
+    let mut release = Uint128::zero();
+    let mut claims = CLAIMS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    claims.retain(|claim| {
+        if claim.release_at.is_expired(&_env.block) {
+            release += claim.amount;
+            false
+        } else {
+            true
+        }
+    });
     if release.is_zero() {
         return Err(ContractError::NothingToClaim {});
     }
+    save_claims(deps.storage, &info.sender, &claims)?;
     let config = CONFIG.load(deps.storage)?;
     let cw_send_msg = cw20::Cw20ExecuteMsg::Transfer {
         recipient: info.sender.to_string(),
@@ -280,6 +377,101 @@ pub fn execute_claim(
         .add_attribute("amount", release))
 }
 
+/// Saves ADDR's outstanding claims, removing the entry if there are
+/// none left.
+fn save_claims(storage: &mut dyn Storage, addr: &Addr, claims: &Vec<Claim>) -> StdResult<()> {
+    if claims.is_empty() {
+        CLAIMS.remove(storage, addr);
+        Ok(())
+    } else {
+        CLAIMS.save(storage, addr, claims)
+    }
+}
+
+pub fn execute_slash(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addr: String,
+    percent: Decimal,
+    destination: SlashDestination,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    if percent.is_zero() || percent > Decimal::one() {
+        return Err(ContractError::InvalidSlashPercent {});
+    }
+    let addr = deps.api.addr_validate(&addr)?;
+
+    // Slash the staked balance. Tokens are valued the same way as
+    // when unstaking.
+    let balance = BALANCE.load(deps.storage)?;
+    let staked_total = STAKED_TOTAL.load(deps.storage)?;
+    let staked = STAKED_BALANCES
+        .may_load(deps.storage, &addr)?
+        .unwrap_or_default();
+    let slashed_stake = staked * percent;
+    let mut slashed = Uint128::zero();
+    if !slashed_stake.is_zero() {
+        slashed = math::amount_to_claim(staked_total, balance, slashed_stake);
+        STAKED_BALANCES.save(
+            deps.storage,
+            &addr,
+            &staked
+                .checked_sub(slashed_stake)
+                .map_err(StdError::overflow)?,
+            env.block.height,
+        )?;
+        STAKED_TOTAL.save(
+            deps.storage,
+            &staked_total
+                .checked_sub(slashed_stake)
+                .map_err(StdError::overflow)?,
+            env.block.height,
+        )?;
+        BALANCE.save(
+            deps.storage,
+            &balance.checked_sub(slashed).map_err(StdError::overflow)?,
+        )?;
+    }
+
+    // Slash outstanding claims, whether or not they have matured.
+    let mut claims = CLAIMS.may_load(deps.storage, &addr)?.unwrap_or_default();
+    for claim in claims.iter_mut() {
+        let cut = claim.amount * percent;
+        claim.amount -= cut;
+        slashed = slashed.checked_add(cut).map_err(StdError::overflow)?;
+    }
+    claims.retain(|claim| !claim.amount.is_zero());
+    save_claims(deps.storage, &addr, &claims)?;
+
+    if slashed.is_zero() {
+        return Err(ContractError::NothingToSlash {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let cw_msg = match destination {
+        SlashDestination::Owner {} => cw20::Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount: slashed,
+        },
+        SlashDestination::Burn {} => cw20::Cw20ExecuteMsg::Burn { amount: slashed },
+    };
+    let wasm_msg = cosmwasm_std::WasmMsg::Execute {
+        contract_addr: config.token_address.to_string(),
+        msg: to_json_binary(&cw_msg)?,
+        funds: vec![],
+    };
+    let hook_msgs = slash_hook_msgs(HOOKS, deps.storage, addr.clone(), slashed_stake)?;
+
+    Ok(Response::new()
+        .add_message(wasm_msg)
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "slash")
+        .add_attribute("addr", addr)
+        .add_attribute("staked_amount", slashed_stake)
+        .add_attribute("amount", slashed))
+}
+
 pub fn execute_fund(
     deps: DepsMut,
     _env: Env,
@@ -418,7 +610,10 @@ pub fn query_config(deps: Deps) -> StdResult<Config> {
 }
 
 pub fn query_claims(deps: Deps, address: String) -> StdResult<ClaimsResponse> {
-    CLAIMS.query_claims(deps, &deps.api.addr_validate(&address)?)
+    let address = deps.api.addr_validate(&address)?;
+    Ok(ClaimsResponse {
+        claims: CLAIMS.may_load(deps.storage, &address)?.unwrap_or_default(),
+    })
 }
 
 pub fn query_hooks(deps: Deps) -> StdResult<GetHooksResponse> {
@@ -27,9 +27,15 @@ pub enum ContractError {
     #[error("Can not unstake more than has been staked")]
     ImpossibleUnstake {},
 
+    #[error("Can not transfer more than has been staked")]
+    ImpossibleTransfer {},
+
     #[error("Provided cw20 errored in response to TokenInfo query")]
     InvalidCw20 {},
 
+    #[error("Slash percent must be greater than zero and at most one")]
+    InvalidSlashPercent {},
+
     #[error("Invalid token")]
     InvalidToken { received: Addr, expected: Addr },
 
@@ -39,6 +45,15 @@ pub enum ContractError {
     #[error("Nothing to unstake")]
     NothingStaked {},
 
+    #[error("Nothing to slash")]
+    NothingToSlash {},
+
     #[error("Too many outstanding claims. Claim some tokens before unstaking more.")]
     TooManyClaims {},
+
+    #[error("Can not transfer stake to oneself")]
+    SelfTransfer {},
+
+    #[error("Amount being transferred must be non-zero")]
+    ZeroTransfer {},
 }
@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 use cw_utils::Duration;
@@ -23,16 +23,50 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
-    Unstake { amount: Uint128 },
+    Unstake {
+        amount: Uint128,
+    },
+    /// Moves AMOUNT of the sender's staked balance to RECIPIENT
+    /// without unbonding it.
+    TransferStake {
+        recipient: String,
+        amount: Uint128,
+    },
     Claim {},
-    UpdateConfig { duration: Option<Duration> },
-    AddHook { addr: String },
-    RemoveHook { addr: String },
+    UpdateConfig {
+        duration: Option<Duration>,
+    },
+    /// Slashes PERCENT of ADDR's staked balance and of each of their
+    /// outstanding claims, sending the slashed tokens to
+    /// DESTINATION. Only callable by the owner.
+    Slash {
+        addr: String,
+        percent: Decimal,
+        destination: SlashDestination,
+    },
+    AddHook {
+        addr: String,
+    },
+    RemoveHook {
+        addr: String,
+    },
+}
+
+#[cw_serde]
+pub enum SlashDestination {
+    /// Sends slashed tokens to the owner, generally the DAO treasury.
+    Owner {},
+    /// Burns slashed tokens.
+    Burn {},
 }
 
 #[cw_serde]
 pub enum ReceiveMsg {
     Stake {},
+    /// Stakes the received tokens on behalf of RECIPIENT.
+    StakeFor {
+        recipient: String,
+    },
     Fund {},
 }
 
@@ -1,8 +1,8 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
-use cw_controllers::Claims;
+use cw_controllers::Claim;
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
 
 #[cw_serde]
@@ -31,7 +31,10 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
-pub const CLAIMS: Claims = Claims::new("claims");
+/// Outstanding claims. This has the same layout as
+/// `cw_controllers::Claims`, which does not support reducing a
+/// pending claim.
+pub const CLAIMS: Map<&Addr, Vec<Claim>> = Map::new("claims");
 
 pub const BALANCE: Item<Uint128> = Item::new("balance");
 
@@ -1,17 +1,22 @@
 use anyhow::Result as AnyResult;
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{to_json_binary, Addr, Empty, MessageInfo, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    Uint128, WasmMsg,
+};
 use cw20::Cw20Coin;
 use cw_controllers::{Claim, ClaimsResponse};
 use cw_multi_test::{next_block, App, AppResponse, Contract, ContractWrapper, Executor};
 use cw_ownable::{Action, Ownership, OwnershipError};
+use cw_storage_plus::Item;
 use cw_utils::Duration;
 use cw_utils::Expiration::AtHeight;
+use dao_hooks::stake::{StakeChangedExecuteMsg, StakeChangedHookMsg};
 use dao_voting::duration::UnstakingDurationError;
 use std::borrow::BorrowMut;
 
 use crate::msg::{
-    ExecuteMsg, ListStakersResponse, MigrateMsg, QueryMsg, ReceiveMsg,
+    ExecuteMsg, ListStakersResponse, MigrateMsg, QueryMsg, ReceiveMsg, SlashDestination,
     StakedBalanceAtHeightResponse, StakedValueResponse, StakerBalanceResponse,
     TotalStakedAtHeightResponse, TotalValueResponse,
 };
@@ -55,6 +60,44 @@ fn contract_cw20() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+// Records the stake change hooks it receives.
+const HOOK_MSGS: Item<Vec<StakeChangedHookMsg>> = Item::new("hook_msgs");
+
+fn hook_receiver_instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn hook_receiver_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: StakeChangedExecuteMsg,
+) -> StdResult<Response> {
+    let StakeChangedExecuteMsg::StakeChangeHook(hook) = msg;
+    let mut msgs = HOOK_MSGS.may_load(deps.storage)?.unwrap_or_default();
+    msgs.push(hook);
+    HOOK_MSGS.save(deps.storage, &msgs)?;
+    Ok(Response::new())
+}
+
+fn hook_receiver_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&HOOK_MSGS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+fn contract_hook_receiver() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        hook_receiver_execute,
+        hook_receiver_instantiate,
+        hook_receiver_query,
+    );
+    Box::new(contract)
+}
+
 fn mock_app() -> App {
     App::default()
 }
@@ -1064,6 +1107,242 @@ fn test_query_list_stakers() {
     assert_eq!(stakers, test_res)
 }
 
+#[test]
+fn test_stake_for_and_transfer_stake() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) =
+        setup_test_case(&mut app, initial_balances, Some(Duration::Height(5)));
+
+    // ADDR1 stakes on behalf of ADDR2.
+    let msg = cw20::Cw20ExecuteMsg::Send {
+        contract: staking_addr.to_string(),
+        amount: Uint128::new(60),
+        msg: to_json_binary(&ReceiveMsg::StakeFor {
+            recipient: ADDR2.to_string(),
+        })
+        .unwrap(),
+    };
+    app.execute_contract(Addr::unchecked(ADDR1), cw20_addr.clone(), &msg, &[])
+        .unwrap();
+    app.update_block(next_block);
+
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR2),
+        Uint128::new(60)
+    );
+    assert_eq!(get_balance(&app, &cw20_addr, ADDR1), Uint128::new(40));
+
+    // Can't transfer more than is staked.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR2),
+            staking_addr.clone(),
+            &ExecuteMsg::TransferStake {
+                recipient: ADDR3.to_string(),
+                amount: Uint128::new(61),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ImpossibleTransfer {});
+
+    // Can't transfer nothing, or transfer to oneself.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR2),
+            staking_addr.clone(),
+            &ExecuteMsg::TransferStake {
+                recipient: ADDR3.to_string(),
+                amount: Uint128::zero(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroTransfer {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR2),
+            staking_addr.clone(),
+            &ExecuteMsg::TransferStake {
+                recipient: ADDR2.to_string(),
+                amount: Uint128::new(25),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::SelfTransfer {});
+
+    let hook_id = app.store_code(contract_hook_receiver());
+    let hook = app
+        .instantiate_contract(
+            hook_id,
+            Addr::unchecked(OWNER),
+            &Empty {},
+            &[],
+            "hook receiver",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        staking_addr.clone(),
+        &ExecuteMsg::AddHook {
+            addr: hook.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Transfers move staked balance without unbonding it.
+    app.execute_contract(
+        Addr::unchecked(ADDR2),
+        staking_addr.clone(),
+        &ExecuteMsg::TransferStake {
+            recipient: ADDR3.to_string(),
+            amount: Uint128::new(25),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR2),
+        Uint128::new(35)
+    );
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR3),
+        Uint128::new(25)
+    );
+    assert_eq!(query_total_staked(&app, &staking_addr), Uint128::new(60));
+    assert!(query_claims(&app, &staking_addr, ADDR2).is_empty());
+
+    // Both parties' stake changes are reported to hooks.
+    let hook_msgs: Vec<StakeChangedHookMsg> =
+        app.wrap().query_wasm_smart(&hook, &Empty {}).unwrap();
+    assert_eq!(
+        hook_msgs,
+        vec![
+            StakeChangedHookMsg::Unstake {
+                addr: Addr::unchecked(ADDR2),
+                amount: Uint128::new(25),
+            },
+            StakeChangedHookMsg::Stake {
+                addr: Addr::unchecked(ADDR3),
+                amount: Uint128::new(25),
+            },
+        ]
+    );
+
+    // The recipient may unstake what was transferred to them.
+    unstake_tokens(
+        &mut app,
+        &staking_addr,
+        mock_info(ADDR3, &[]),
+        Uint128::new(25),
+    )
+    .unwrap();
+    app.update_block(|b| b.height += 5);
+    claim_tokens(&mut app, &staking_addr, mock_info(ADDR3, &[])).unwrap();
+    assert_eq!(get_balance(&app, &cw20_addr, ADDR3), Uint128::new(25));
+}
+
+#[test]
+fn test_slash() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) =
+        setup_test_case(&mut app, initial_balances, Some(Duration::Height(5)));
+
+    let info = mock_info(ADDR1, &[]);
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        info.clone(),
+        Uint128::new(60),
+    )
+    .unwrap();
+    unstake_tokens(&mut app, &staking_addr, info, Uint128::new(20)).unwrap();
+    app.update_block(next_block);
+
+    let slash = |app: &mut App, sender: &str, percent: &str, destination: SlashDestination| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            staking_addr.clone(),
+            &ExecuteMsg::Slash {
+                addr: ADDR1.to_string(),
+                percent: percent.parse().unwrap(),
+                destination,
+            },
+            &[],
+        )
+    };
+
+    // Only the owner may slash.
+    let err: ContractError = slash(&mut app, ADDR1, "0.5", SlashDestination::Owner {})
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+    let err: ContractError = slash(&mut app, OWNER, "1.5", SlashDestination::Owner {})
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSlashPercent {});
+
+    // Half of the stake and of the pending claim go to the owner.
+    slash(&mut app, OWNER, "0.5", SlashDestination::Owner {}).unwrap();
+    app.update_block(next_block);
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::new(20)
+    );
+    assert_eq!(query_total_staked(&app, &staking_addr), Uint128::new(20));
+    assert_eq!(
+        query_claims(&app, &staking_addr, ADDR1)[0].amount,
+        Uint128::new(10)
+    );
+    assert_eq!(get_balance(&app, &cw20_addr, OWNER), Uint128::new(30));
+
+    // The rest is burned.
+    slash(&mut app, OWNER, "1", SlashDestination::Burn {}).unwrap();
+    app.update_block(next_block);
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::zero()
+    );
+    assert!(query_claims(&app, &staking_addr, ADDR1).is_empty());
+    let token_info: cw20::TokenInfoResponse = app
+        .wrap()
+        .query_wasm_smart(&cw20_addr, &cw20::Cw20QueryMsg::TokenInfo {})
+        .unwrap();
+    assert_eq!(token_info.total_supply, Uint128::new(70));
+
+    let err: ContractError = slash(&mut app, OWNER, "1", SlashDestination::Burn {})
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NothingToSlash {});
+}
+
 #[test]
 fn test_ownership_transfer() {
     let mut app = App::default();
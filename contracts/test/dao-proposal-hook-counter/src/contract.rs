@@ -96,6 +96,11 @@ pub fn execute_stake_hook(
             count = count.checked_add(Uint128::new(1))?;
             STAKE_COUNTER.save(deps.storage, &count)?;
         }
+        StakeChangedHookMsg::Slash { .. } => {
+            let mut count = STAKE_COUNTER.load(deps.storage)?;
+            count = count.checked_add(Uint128::new(1))?;
+            STAKE_COUNTER.save(deps.storage, &count)?;
+        }
     }
 
     Ok(Response::new().add_attribute("action", "stake_hook"))
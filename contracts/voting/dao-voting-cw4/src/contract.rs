@@ -1,22 +1,32 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsg,
-    Uint128, WasmMsg,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw4::{MemberListResponse, MemberResponse, TotalWeightResponse};
-use cw_utils::parse_reply_instantiate_data;
+use cw_storage_plus::Bound;
+use cw_utils::{parse_reply_instantiate_data, Expiration};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, GroupContract, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::state::{DAO, GROUP_CONTRACT};
+use crate::msg::{
+    ExecuteMsg, GroupContract, InstantiateMsg, MemberUpdate, MembershipExpiration, MigrateMsg,
+    QueryMsg,
+};
+use crate::state::{
+    CLEARED_EXPIRED_WEIGHT, DAO, EXPIRATIONS, EXPIRATION_QUEUE, EXPIRED_WEIGHT, GROUP_CONTRACT,
+    MANAGERS,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-cw4";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const INSTANTIATE_GROUP_REPLY_ID: u64 = 0;
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -108,12 +118,206 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    _deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    _msg: ExecuteMsg,
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateMembers { add, remove } => {
+            execute_update_members(deps, env, info, add, remove)
+        }
+        ExecuteMsg::RemoveExpiredMembers { limit } => {
+            execute_remove_expired_members(deps, env, limit)
+        }
+        ExecuteMsg::UpdateManagers { to_add, to_remove } => {
+            execute_update_managers(deps, info, to_add, to_remove)
+        }
+    }
+}
+
+pub fn execute_update_members(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    add: Vec<MemberUpdate>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao && !MANAGERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut members = Vec::with_capacity(add.len());
+    for MemberUpdate {
+        addr,
+        weight,
+        expiration,
+    } in add
+    {
+        let addr = deps.api.addr_validate(&addr)?;
+        clear_expiration(deps.storage, env.block.height, &addr)?;
+        match expiration {
+            Some(Expiration::AtHeight(height)) => {
+                if height <= env.block.height {
+                    return Err(ContractError::AlreadyExpired {
+                        addr: addr.into_string(),
+                    });
+                }
+                EXPIRATIONS.save(deps.storage, &addr, &height, env.block.height)?;
+                EXPIRATION_QUEUE.save(deps.storage, (height, &addr), &weight)?;
+                update_expired_weight(deps.storage, height, |expired| expired + weight)?;
+            }
+            Some(Expiration::AtTime(_)) => return Err(ContractError::TimeExpiration {}),
+            Some(Expiration::Never {}) | None => (),
+        }
+        members.push(cw4::Member {
+            addr: addr.into_string(),
+            weight,
+        });
+    }
+    for addr in &remove {
+        let addr = deps.api.addr_validate(addr)?;
+        clear_expiration(deps.storage, env.block.height, &addr)?;
+    }
+
+    let group_contract = GROUP_CONTRACT.load(deps.storage)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_members")
+        .add_attribute("sender", info.sender)
+        .add_attribute("added", members.len().to_string())
+        .add_attribute("removed", remove.len().to_string())
+        .add_message(WasmMsg::Execute {
+            contract_addr: group_contract.into_string(),
+            msg: to_json_binary(&cw4_group::msg::ExecuteMsg::UpdateMembers {
+                remove,
+                add: members,
+            })?,
+            funds: vec![],
+        }))
+}
+
+pub fn execute_remove_expired_members(
+    deps: DepsMut,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    // The queue is ordered by expiration, so the first unexpired
+    // entry ends the expired ones.
+    let expired = EXPIRATION_QUEUE
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take_while(|r| match r {
+            Ok((height, _)) => *height <= env.block.height,
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .map(|r| r.map(|(_, addr)| addr))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for addr in &expired {
+        clear_expiration(deps.storage, env.block.height, addr)?;
+    }
+
+    let mut response = Response::new()
+        .add_attribute("action", "remove_expired_members")
+        .add_attribute("removed", expired.len().to_string());
+    if !expired.is_empty() {
+        let group_contract = GROUP_CONTRACT.load(deps.storage)?;
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: group_contract.into_string(),
+            msg: to_json_binary(&cw4_group::msg::ExecuteMsg::UpdateMembers {
+                remove: expired.into_iter().map(Addr::into_string).collect(),
+                add: vec![],
+            })?,
+            funds: vec![],
+        });
+    }
+    Ok(response)
+}
+
+/// Removes ADDR's membership expiration, if it has one. The caller
+/// must remove or update ADDR in the group in the same block.
+fn clear_expiration(storage: &mut dyn Storage, height: u64, addr: &Addr) -> StdResult<()> {
+    if let Some(expiration) = EXPIRATIONS.may_load(storage, addr)? {
+        let weight = EXPIRATION_QUEUE.load(storage, (expiration, addr))?;
+        EXPIRATIONS.remove(storage, addr, height)?;
+        EXPIRATION_QUEUE.remove(storage, (expiration, addr));
+        if expiration <= height {
+            // The group stops counting the expired weight from the
+            // next block.
+            let cleared = CLEARED_EXPIRED_WEIGHT
+                .may_load(storage)?
+                .unwrap_or_default();
+            CLEARED_EXPIRED_WEIGHT.save(storage, &(cleared + weight), height)?;
+        } else {
+            update_expired_weight(storage, expiration, |expired| expired - weight)?;
+        }
+    }
+    Ok(())
+}
+
+/// Applies UPDATE to the weight expired at HEIGHT and every later
+/// height at which a membership expires. HEIGHT must be after the
+/// current block, so this only updates expirations that are still
+/// pending.
+fn update_expired_weight(
+    storage: &mut dyn Storage,
+    height: u64,
+    update: impl Fn(u64) -> u64,
+) -> StdResult<()> {
+    if !EXPIRED_WEIGHT.has(storage, height) {
+        let expired = expired_weight_at(storage, height)?;
+        EXPIRED_WEIGHT.save(storage, height, &expired)?;
+    }
+    let later = EXPIRED_WEIGHT
+        .range(
+            storage,
+            Some(Bound::inclusive(height)),
+            None,
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+    for (height, expired) in later {
+        EXPIRED_WEIGHT.save(storage, height, &update(expired))?;
+    }
+    Ok(())
+}
+
+/// The total weight of memberships that had expired by HEIGHT.
+fn expired_weight_at(storage: &dyn Storage, height: u64) -> StdResult<u64> {
+    Ok(EXPIRED_WEIGHT
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map_or(0, |(_, expired)| expired))
+}
+
+pub fn execute_update_managers(
+    deps: DepsMut,
+    info: MessageInfo,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
 ) -> Result<Response, ContractError> {
-    Err(ContractError::NoExecute {})
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for addr in to_add {
+        let addr = deps.api.addr_validate(&addr)?;
+        MANAGERS.save(deps.storage, &addr, &Empty {})?;
+    }
+    for addr in to_remove {
+        let addr = deps.api.addr_validate(&addr)?;
+        MANAGERS.remove(deps.storage, &addr);
+    }
+
+    Ok(Response::new().add_attribute("action", "update_managers"))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -126,28 +330,109 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::GroupContract {} => to_json_binary(&GROUP_CONTRACT.load(deps.storage)?),
         QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Managers { start_after, limit } => query_managers(deps, start_after, limit),
+        QueryMsg::MemberExpiration { address } => {
+            let addr = deps.api.addr_validate(&address)?;
+            to_json_binary(
+                &EXPIRATIONS
+                    .may_load(deps.storage, &addr)?
+                    .map(Expiration::AtHeight),
+            )
+        }
+        QueryMsg::UpcomingExpirations { start_after, limit } => {
+            query_upcoming_expirations(deps, env, start_after, limit)
+        }
     }
 }
 
+pub fn query_managers(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let start_after = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let managers = MANAGERS
+        .keys(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    to_json_binary(&managers)
+}
+
+pub fn query_upcoming_expirations(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let start_after = start_after
+        .map(|a| -> StdResult<_> {
+            let addr = deps.api.addr_validate(&a)?;
+            let height = EXPIRATIONS.load(deps.storage, &addr)?;
+            Ok((height, addr))
+        })
+        .transpose()?;
+    let first = Addr::unchecked("");
+    let min = match start_after {
+        Some((height, ref addr)) => Some(Bound::exclusive((height, addr))),
+        // Memberships expiring at the next height are the first that
+        // have not yet expired.
+        None => Some(Bound::inclusive((env.block.height + 1, &first))),
+    };
+    let expirations = EXPIRATION_QUEUE
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|r| {
+            r.map(|(height, addr)| MembershipExpiration {
+                addr,
+                expiration: Expiration::AtHeight(height),
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&expirations)
+}
+
 pub fn query_voting_power_at_height(
     deps: Deps,
     env: Env,
     address: String,
     height: Option<u64>,
 ) -> StdResult<Binary> {
-    let addr = deps.api.addr_validate(&address)?.to_string();
+    let addr = deps.api.addr_validate(&address)?;
+    // Expired members have no power, even before they are removed
+    // from the group.
+    let expiration = match height {
+        Some(height) => EXPIRATIONS.may_load_at_height(deps.storage, &addr, height)?,
+        None => EXPIRATIONS.may_load(deps.storage, &addr)?,
+    };
+    let at = height.unwrap_or(env.block.height);
+    if expiration.map_or(false, |expiration| expiration <= at) {
+        return to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse {
+            power: Uint128::zero(),
+            height: at,
+        });
+    }
+
     let group_contract = GROUP_CONTRACT.load(deps.storage)?;
     let res: MemberResponse = deps.querier.query_wasm_smart(
         group_contract,
         &cw4_group::msg::QueryMsg::Member {
-            addr,
+            addr: addr.into_string(),
             at_height: height,
         },
     )?;
 
     to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse {
         power: res.weight.unwrap_or(0).into(),
-        height: height.unwrap_or(env.block.height),
+        height: at,
     })
 }
 
@@ -157,9 +442,18 @@ pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) ->
         group_contract,
         &cw4_group::msg::QueryMsg::TotalWeight { at_height: height },
     )?;
+    // Expired members are still counted by the group until they are
+    // removed, but have no power.
+    let at = height.unwrap_or(env.block.height);
+    let cleared = match height {
+        Some(height) => CLEARED_EXPIRED_WEIGHT.may_load_at_height(deps.storage, height)?,
+        None => CLEARED_EXPIRED_WEIGHT.may_load(deps.storage)?,
+    }
+    .unwrap_or_default();
+    let expired = expired_weight_at(deps.storage, at)?.saturating_sub(cleared);
     to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse {
-        power: res.weight.into(),
-        height: height.unwrap_or(env.block.height),
+        power: res.weight.saturating_sub(expired).into(),
+        height: at,
     })
 }
 
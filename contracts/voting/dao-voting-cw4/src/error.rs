@@ -18,8 +18,11 @@ pub enum ContractError {
     #[error("Error occured whilst instantiating group contract")]
     GroupContractInstantiateError {},
 
-    #[error("Contract only supports queries")]
-    NoExecute {},
+    #[error("Cannot add member ({addr}) with a membership that has already expired")]
+    AlreadyExpired { addr: String },
+
+    #[error("Memberships may only expire at a block height")]
+    TimeExpiration {},
 
     #[error("Cannot instantiate or use a group contract with no initial members")]
     NoMembers {},
@@ -1,4 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+use cw_utils::Expiration;
 use dao_dao_macros::voting_module_query;
 
 #[cw_serde]
@@ -17,8 +19,39 @@ pub struct InstantiateMsg {
     pub group_contract: GroupContract,
 }
 
+/// A member to add or update.
 #[cw_serde]
-pub enum ExecuteMsg {}
+pub struct MemberUpdate {
+    pub addr: String,
+    pub weight: u64,
+    /// The block height at which the membership expires. If not set,
+    /// the membership never expires. Expirations at a time are
+    /// rejected.
+    pub expiration: Option<Expiration>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Adds, updates, and removes members of the group. Updating a
+    /// member replaces their expiration. Only the DAO and membership
+    /// managers may execute this message, and this module must be the
+    /// admin of the group contract.
+    UpdateMembers {
+        add: Vec<MemberUpdate>,
+        remove: Vec<String>,
+    },
+    /// Removes up to LIMIT members whose memberships have expired
+    /// from the group. Expired members have no voting power from
+    /// their expiration height, but are counted in the total power
+    /// until they are removed. May be executed by anyone.
+    RemoveExpiredMembers { limit: Option<u32> },
+    /// Adds and removes membership managers. Only the DAO may execute
+    /// this message.
+    UpdateManagers {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+}
 
 #[voting_module_query]
 #[cw_serde]
@@ -26,6 +59,29 @@ pub enum ExecuteMsg {}
 pub enum QueryMsg {
     #[returns(cosmwasm_std::Addr)]
     GroupContract {},
+    /// Lists addresses that may update members on behalf of the DAO.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    Managers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets when ADDRESS's membership expires. Returns `None` if it
+    /// does not expire.
+    #[returns(Option<cw_utils::Expiration>)]
+    MemberExpiration { address: String },
+    /// Lists memberships that will expire but have not yet expired,
+    /// soonest first.
+    #[returns(Vec<MembershipExpiration>)]
+    UpcomingExpirations {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MembershipExpiration {
+    pub addr: Addr,
+    pub expiration: Expiration,
 }
 
 #[cw_serde]
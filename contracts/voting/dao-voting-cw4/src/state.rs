@@ -1,5 +1,35 @@
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 
 pub const GROUP_CONTRACT: Item<Addr> = Item::new("group_contract");
 pub const DAO: Item<Addr> = Item::new("dao_address");
+
+/// Addresses other than the DAO that may update members.
+pub const MANAGERS: Map<&Addr, Empty> = Map::new("membership_managers");
+/// The height at which memberships expire. Members without an entry
+/// never expire. Snapshotted so that voting power at past heights
+/// reflects the expirations in effect at those heights.
+pub const EXPIRATIONS: SnapshotMap<&Addr, u64> = SnapshotMap::new(
+    "membership_expirations",
+    "membership_expirations__checkpoints",
+    "membership_expirations__changelog",
+    Strategy::EveryBlock,
+);
+/// Members with an expiration, ordered by the height at which their
+/// memberships expire, and their weight in the group.
+pub const EXPIRATION_QUEUE: Map<(u64, &Addr), u64> = Map::new("membership_expiration_queue");
+/// The total weight of memberships expiring at or before each height
+/// at which a membership expires. Only heights after the current one
+/// are ever updated, so the weight expired at a past height is the
+/// last entry at or before it.
+pub const EXPIRED_WEIGHT: Map<u64, u64> = Map::new("expired_weight");
+/// The total weight of expired memberships that have since been
+/// removed from, or updated in, the group. Expired weight still
+/// counted in the group's total weight at a height is the difference
+/// between `EXPIRED_WEIGHT` and this at that height.
+pub const CLEARED_EXPIRED_WEIGHT: SnapshotItem<u64> = SnapshotItem::new(
+    "cleared_expired_weight",
+    "cleared_expired_weight__checkpoints",
+    "cleared_expired_weight__changelog",
+    Strategy::EveryBlock,
+);
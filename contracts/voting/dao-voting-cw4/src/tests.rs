@@ -4,13 +4,17 @@ use cosmwasm_std::{
 };
 use cw2::ContractVersion;
 use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use cw_utils::Expiration;
 use dao_interface::voting::{
     InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
 };
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
-    msg::{GroupContract, InstantiateMsg, MigrateMsg, QueryMsg},
+    msg::{
+        ExecuteMsg, GroupContract, InstantiateMsg, MemberUpdate, MembershipExpiration, MigrateMsg,
+        QueryMsg,
+    },
     ContractError,
 };
 
@@ -741,3 +745,242 @@ pub fn test_migrate_update_version() {
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
 }
+
+#[test]
+fn test_member_management() {
+    let mut app = App::default();
+    let voting_addr = setup_test_case(&mut app);
+    let group_addr: Addr = app
+        .wrap()
+        .query_wasm_smart(voting_addr.clone(), &QueryMsg::GroupContract {})
+        .unwrap();
+
+    // The voting module must be the group's admin to manage members.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        group_addr,
+        &cw4_group::msg::ExecuteMsg::UpdateAdmin {
+            admin: Some(voting_addr.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let power = |app: &App, addr: &str, height: Option<u64>| -> Uint128 {
+        let res: VotingPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                voting_addr.clone(),
+                &QueryMsg::VotingPowerAtHeight {
+                    address: addr.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        res.power
+    };
+    let total = |app: &App, height: Option<u64>| -> Uint128 {
+        let res: TotalPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                voting_addr.clone(),
+                &QueryMsg::TotalPowerAtHeight { height },
+            )
+            .unwrap();
+        res.power
+    };
+    let update = |app: &mut App, sender: &str, add: Vec<MemberUpdate>, remove: Vec<String>| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            voting_addr.clone(),
+            &ExecuteMsg::UpdateMembers { add, remove },
+            &[],
+        )
+    };
+
+    // Only the DAO and managers may update members.
+    let err: ContractError = update(&mut app, ADDR1, vec![], vec![ADDR2.to_string()])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            voting_addr.clone(),
+            &ExecuteMsg::UpdateManagers {
+                to_add: vec![ADDR1.to_string()],
+                to_remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        voting_addr.clone(),
+        &ExecuteMsg::UpdateManagers {
+            to_add: vec![ADDR1.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let managers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            voting_addr.clone(),
+            &QueryMsg::Managers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(managers, vec![Addr::unchecked(ADDR1)]);
+
+    // Memberships may not be added already expired.
+    let height = app.block_info().height;
+    let err: ContractError = update(
+        &mut app,
+        ADDR1,
+        vec![MemberUpdate {
+            addr: ADDR4.to_string(),
+            weight: 1,
+            expiration: Some(Expiration::AtHeight(height)),
+        }],
+        vec![],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::AlreadyExpired {
+            addr: ADDR4.to_string()
+        }
+    );
+
+    // Memberships may only expire at a block height.
+    let err: ContractError = update(
+        &mut app,
+        ADDR1,
+        vec![MemberUpdate {
+            addr: ADDR4.to_string(),
+            weight: 1,
+            expiration: Some(Expiration::AtTime(app.block_info().time.plus_seconds(60))),
+        }],
+        vec![],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::TimeExpiration {});
+
+    let expiration = Expiration::AtHeight(height + 10);
+    update(
+        &mut app,
+        ADDR1,
+        vec![
+            MemberUpdate {
+                addr: ADDR4.to_string(),
+                weight: 3,
+                expiration: Some(expiration),
+            },
+            MemberUpdate {
+                addr: ADDR3.to_string(),
+                weight: 1,
+                expiration: Some(Expiration::AtHeight(height + 5)),
+            },
+        ],
+        vec![ADDR2.to_string()],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert_eq!(power(&app, ADDR4, None), Uint128::new(3));
+    assert_eq!(power(&app, ADDR2, None), Uint128::zero());
+    assert_eq!(power(&app, ADDR2, Some(height)), Uint128::new(1));
+    assert_eq!(total(&app, None), Uint128::new(5));
+
+    // Updating a membership before it expires replaces its
+    // expiration.
+    update(
+        &mut app,
+        ADDR1,
+        vec![MemberUpdate {
+            addr: ADDR3.to_string(),
+            weight: 2,
+            expiration: None,
+        }],
+        vec![],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    assert_eq!(total(&app, None), Uint128::new(6));
+
+    let upcoming: Vec<MembershipExpiration> = app
+        .wrap()
+        .query_wasm_smart(
+            voting_addr.clone(),
+            &QueryMsg::UpcomingExpirations {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        upcoming,
+        vec![MembershipExpiration {
+            addr: Addr::unchecked(ADDR4),
+            expiration,
+        }]
+    );
+
+    // Nothing is removed before the membership expires.
+    let remove_expired = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked(ADDR3),
+            voting_addr.clone(),
+            &ExecuteMsg::RemoveExpiredMembers { limit: None },
+            &[],
+        )
+        .unwrap();
+    };
+    remove_expired(&mut app);
+    app.update_block(next_block);
+    assert_eq!(power(&app, ADDR4, None), Uint128::new(3));
+
+    // Expired members have no voting power and are not counted in
+    // the total power, even before they are removed.
+    app.update_block(|block| block.height += 10);
+    assert_eq!(power(&app, ADDR4, None), Uint128::zero());
+    assert_eq!(power(&app, ADDR4, Some(height + 9)), Uint128::new(3));
+    assert_eq!(power(&app, ADDR4, Some(height + 10)), Uint128::zero());
+    assert_eq!(power(&app, ADDR3, Some(height + 5)), Uint128::new(2));
+    assert_eq!(total(&app, None), Uint128::new(3));
+    assert_eq!(total(&app, Some(height + 5)), Uint128::new(6));
+    assert_eq!(total(&app, Some(height + 9)), Uint128::new(6));
+    assert_eq!(total(&app, Some(height + 10)), Uint128::new(3));
+
+    remove_expired(&mut app);
+    let removed_at = app.block_info().height;
+    app.update_block(next_block);
+    assert_eq!(power(&app, ADDR4, None), Uint128::zero());
+    assert_eq!(power(&app, ADDR4, Some(height + 9)), Uint128::new(3));
+    assert_eq!(total(&app, None), Uint128::new(3));
+    assert_eq!(total(&app, Some(removed_at)), Uint128::new(3));
+    assert_eq!(total(&app, Some(height + 9)), Uint128::new(6));
+
+    let expiration: Option<Expiration> = app
+        .wrap()
+        .query_wasm_smart(
+            voting_addr,
+            &QueryMsg::MemberExpiration {
+                address: ADDR4.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(expiration, None);
+}
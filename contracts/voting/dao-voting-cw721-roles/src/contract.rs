@@ -1,20 +1,23 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
-    SubMsg, WasmMsg,
+    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response,
+    StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw4::{MemberResponse, TotalWeightResponse};
+use cw4::{MemberListResponse, MemberResponse, TotalWeightResponse};
 use cw721_base::{
     ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg, QueryMsg as Cw721QueryMsg,
 };
 use cw_ownable::Action;
+use cw_storage_plus::Bound;
 use cw_utils::parse_reply_instantiate_data;
 use dao_cw721_extensions::roles::{ExecuteExt, MetadataExt, QueryExt};
 
-use crate::msg::{ExecuteMsg, InstantiateMsg, NftContract, QueryMsg};
-use crate::state::{Config, CONFIG, DAO, INITIAL_NFTS};
+use crate::msg::{ExecuteMsg, InstantiateMsg, NftContract, QueryMsg, RoleMultiplier};
+use crate::state::{
+    Config, CONFIG, DAO, INITIAL_NFTS, MAX_ROLE_MULTIPLIERS, MULTIPLIED_ROLES, ROLE_MULTIPLIERS,
+};
 use crate::ContractError;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-cw721-roles";
@@ -22,6 +25,9 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const INSTANTIATE_NFT_CONTRACT_REPLY_ID: u64 = 0;
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -33,6 +39,8 @@ pub fn instantiate(
 
     DAO.save(deps.storage, &info.sender)?;
 
+    update_role_multipliers(deps.storage, env.block.height, msg.role_multipliers, vec![])?;
+
     match msg.nft_contract {
         NftContract::Existing { address } => {
             let config = Config {
@@ -83,12 +91,67 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    _deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
-    _msg: ExecuteMsg,
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
 ) -> Result<Response<Empty>, ContractError> {
-    Err(ContractError::NoExecute {})
+    match msg {
+        ExecuteMsg::UpdateRoleMultipliers { set, remove } => {
+            execute_update_role_multipliers(deps, env, info, set, remove)
+        }
+    }
+}
+
+pub fn execute_update_role_multipliers(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    set: Vec<RoleMultiplier>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    update_role_multipliers(deps.storage, env.block.height, set, remove)?;
+
+    Ok(Response::new().add_attribute("action", "update_role_multipliers"))
+}
+
+/// Sets the multipliers of the roles in SET and resets those of the
+/// roles in REMOVE to one at HEIGHT. Roles with a multiplier of one
+/// have no entry.
+fn update_role_multipliers(
+    storage: &mut dyn Storage,
+    height: u64,
+    set: Vec<RoleMultiplier>,
+    remove: Vec<String>,
+) -> Result<(), ContractError> {
+    let mut roles = MULTIPLIED_ROLES.may_load(storage)?.unwrap_or_default();
+    let updates = set
+        .into_iter()
+        .map(|RoleMultiplier { role, multiplier }| (role, multiplier))
+        .chain(remove.into_iter().map(|role| (role, Uint128::one())));
+    for (role, multiplier) in updates {
+        if multiplier == Uint128::one() {
+            ROLE_MULTIPLIERS.remove(storage, &role, height)?;
+            roles.retain(|r| *r != role);
+        } else {
+            ROLE_MULTIPLIERS.save(storage, &role, &multiplier, height)?;
+            if !roles.contains(&role) {
+                roles.push(role);
+            }
+        }
+    }
+    if roles.len() as u64 > MAX_ROLE_MULTIPLIERS {
+        return Err(ContractError::TooManyRoleMultipliers {
+            max: MAX_ROLE_MULTIPLIERS,
+        });
+    }
+    MULTIPLIED_ROLES.save(storage, &roles, height)?;
+    Ok(())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -101,7 +164,58 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::TotalPowerAtHeight { height } => query_total_power_at_height(deps, env, height),
         QueryMsg::Info {} => query_info(deps),
+        QueryMsg::RoleMultipliers { start_after, limit } => {
+            query_role_multipliers(deps, start_after, limit)
+        }
+        QueryMsg::RoleMembers {
+            role,
+            start_after,
+            limit,
+        } => query_role_members(deps, role, start_after, limit),
+        QueryMsg::RoleVotingPowerAtHeight {
+            role,
+            address,
+            height,
+        } => query_role_voting_power_at_height(deps, env, role, address, height),
+        QueryMsg::TotalRolePowerAtHeight { role, height } => {
+            query_total_role_power_at_height(deps, env, role, height)
+        }
+    }
+}
+
+/// Returns the multiplier of ROLE at HEIGHT.
+fn role_multiplier(deps: Deps, role: &str, height: Option<u64>) -> StdResult<Uint128> {
+    Ok(match height {
+        Some(h) => ROLE_MULTIPLIERS.may_load_at_height(deps.storage, role, h),
+        None => ROLE_MULTIPLIERS.may_load(deps.storage, role),
+    }?
+    .unwrap_or(Uint128::one()))
+}
+
+/// Applies the role multipliers at HEIGHT to WEIGHT, the weight of a
+/// set of tokens. ROLE_WEIGHT returns how much of WEIGHT comes from
+/// tokens with a given role.
+fn apply_role_multipliers(
+    deps: Deps,
+    height: Option<u64>,
+    weight: u64,
+    role_weight: impl Fn(String) -> StdResult<u64>,
+) -> StdResult<Uint128> {
+    let roles = match height {
+        Some(h) => MULTIPLIED_ROLES.may_load_at_height(deps.storage, h)?,
+        None => MULTIPLIED_ROLES.may_load(deps.storage)?,
     }
+    .unwrap_or_default();
+
+    let mut power = Uint128::from(weight);
+    for role in roles {
+        let multiplier = role_multiplier(deps, &role, height)?;
+        let weight = Uint128::from(role_weight(role)?);
+        power = power
+            .checked_sub(weight)?
+            .checked_add(weight.checked_mul(multiplier)?)?;
+    }
+    Ok(power)
 }
 
 pub fn query_voting_power_at_height(
@@ -112,17 +226,20 @@ pub fn query_voting_power_at_height(
 ) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     let member: MemberResponse = deps.querier.query_wasm_smart(
-        config.nft_address,
+        &config.nft_address,
         &Cw721QueryMsg::<QueryExt>::Extension {
             msg: QueryExt::Member {
-                addr: address,
+                addr: address.clone(),
                 at_height,
             },
         },
     )?;
+    let power = apply_role_multipliers(deps, at_height, member.weight.unwrap_or(0), |role| {
+        query_role_member_weight(deps, &config, role, address.clone(), at_height)
+    })?;
 
     to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse {
-        power: member.weight.unwrap_or(0).into(),
+        power,
         height: at_height.unwrap_or(env.block.height),
     })
 }
@@ -134,18 +251,128 @@ pub fn query_total_power_at_height(
 ) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     let total: TotalWeightResponse = deps.querier.query_wasm_smart(
-        config.nft_address,
+        &config.nft_address,
         &Cw721QueryMsg::<QueryExt>::Extension {
             msg: QueryExt::TotalWeight { at_height },
         },
     )?;
+    let power = apply_role_multipliers(deps, at_height, total.weight, |role| {
+        query_role_total_weight(deps, &config, role, at_height)
+    })?;
 
     to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse {
-        power: total.weight.into(),
+        power,
         height: at_height.unwrap_or(env.block.height),
     })
 }
 
+fn query_role_member_weight(
+    deps: Deps,
+    config: &Config,
+    role: String,
+    addr: String,
+    at_height: Option<u64>,
+) -> StdResult<u64> {
+    let member: MemberResponse = deps.querier.query_wasm_smart(
+        &config.nft_address,
+        &Cw721QueryMsg::<QueryExt>::Extension {
+            msg: QueryExt::RoleMember {
+                role,
+                addr,
+                at_height,
+            },
+        },
+    )?;
+    Ok(member.weight.unwrap_or(0))
+}
+
+fn query_role_total_weight(
+    deps: Deps,
+    config: &Config,
+    role: String,
+    at_height: Option<u64>,
+) -> StdResult<u64> {
+    let total: TotalWeightResponse = deps.querier.query_wasm_smart(
+        &config.nft_address,
+        &Cw721QueryMsg::<QueryExt>::Extension {
+            msg: QueryExt::RoleTotalWeight { role, at_height },
+        },
+    )?;
+    Ok(total.weight)
+}
+
+pub fn query_role_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    role: String,
+    address: String,
+    at_height: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let multiplier = role_multiplier(deps, &role, at_height)?;
+    let weight = query_role_member_weight(deps, &config, role, address, at_height)?;
+
+    to_json_binary(&dao_interface::voting::VotingPowerAtHeightResponse {
+        power: Uint128::from(weight).checked_mul(multiplier)?,
+        height: at_height.unwrap_or(env.block.height),
+    })
+}
+
+pub fn query_total_role_power_at_height(
+    deps: Deps,
+    env: Env,
+    role: String,
+    at_height: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let multiplier = role_multiplier(deps, &role, at_height)?;
+    let weight = query_role_total_weight(deps, &config, role, at_height)?;
+
+    to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse {
+        power: Uint128::from(weight).checked_mul(multiplier)?,
+        height: at_height.unwrap_or(env.block.height),
+    })
+}
+
+pub fn query_role_multipliers(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let multipliers = ROLE_MULTIPLIERS
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|r| r.map(|(role, multiplier)| RoleMultiplier { role, multiplier }))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&multipliers)
+}
+
+pub fn query_role_members(
+    deps: Deps,
+    role: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let members: MemberListResponse = deps.querier.query_wasm_smart(
+        config.nft_address,
+        &Cw721QueryMsg::<QueryExt>::Extension {
+            msg: QueryExt::ListRoleMembers {
+                role,
+                start_after,
+                limit,
+            },
+        },
+    )?;
+    to_json_binary(&members)
+}
+
 pub fn query_config(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_json_binary(&config)
@@ -194,6 +421,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                                         extension: MetadataExt {
                                             role: nft.clone().extension.role,
                                             weight: nft.extension.weight,
+                                            expires_at: nft.extension.expires_at,
                                         },
                                     },
                                 )?,
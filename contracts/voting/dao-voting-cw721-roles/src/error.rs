@@ -9,15 +9,18 @@ pub enum ContractError {
     #[error("Error instantiating cw721-roles contract")]
     NftInstantiateError {},
 
-    #[error("This contract only supports queries")]
-    NoExecute {},
-
     #[error("New cw721-roles contract must be instantiated with at least one NFT")]
     NoInitialNfts {},
 
     #[error("Only the owner of this contract my execute this message")]
     NotOwner {},
 
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("At most {max} roles may have a multiplier other than one")]
+    TooManyRoleMultipliers { max: u64 },
+
     #[error("Got a submessage reply with unknown id: {id}")]
     UnknownReplyId { id: u64 },
 }
@@ -1,4 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
 use dao_cw721_extensions::roles::MetadataExt;
 use dao_dao_macros::voting_module_query;
 
@@ -37,14 +38,36 @@ pub enum NftContract {
     },
 }
 
+#[cw_serde]
+pub struct RoleMultiplier {
+    pub role: String,
+    /// The multiplier applied to the weight of tokens with ROLE. Zero
+    /// gives those tokens no voting power.
+    pub multiplier: Uint128,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// Info about the associated NFT contract
     pub nft_contract: NftContract,
+    /// Multipliers applied to the weight of tokens with the given
+    /// roles. Tokens with other roles, or no role, count for their
+    /// weight.
+    #[serde(default)]
+    pub role_multipliers: Vec<RoleMultiplier>,
 }
 
 #[cw_serde]
-pub enum ExecuteMsg {}
+pub enum ExecuteMsg {
+    /// Sets the multipliers of the roles in SET and resets those of
+    /// the roles in REMOVE to one. At most 16 roles may have a
+    /// multiplier other than one. Only callable by the DAO that
+    /// initialized this voting contract.
+    UpdateRoleMultipliers {
+        set: Vec<RoleMultiplier>,
+        remove: Vec<String>,
+    },
+}
 
 #[voting_module_query]
 #[cw_serde]
@@ -52,4 +75,30 @@ pub enum ExecuteMsg {}
 pub enum QueryMsg {
     #[returns(crate::state::Config)]
     Config {},
+    /// Lists roles with a multiplier other than one.
+    #[returns(Vec<RoleMultiplier>)]
+    RoleMultipliers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists members holding tokens with ROLE, and the weight of
+    /// those tokens before the role's multiplier is applied.
+    #[returns(cw4::MemberListResponse)]
+    RoleMembers {
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the voting power ADDRESS holds through tokens with
+    /// ROLE at HEIGHT.
+    #[returns(dao_interface::voting::VotingPowerAtHeightResponse)]
+    RoleVotingPowerAtHeight {
+        role: String,
+        address: String,
+        height: Option<u64>,
+    },
+    /// Returns the total voting power held through tokens with ROLE
+    /// at HEIGHT.
+    #[returns(dao_interface::voting::TotalPowerAtHeightResponse)]
+    TotalRolePowerAtHeight { role: String, height: Option<u64> },
 }
@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
 
 use crate::msg::NftMintMsg;
 
@@ -12,5 +12,26 @@ pub struct Config {
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const DAO: Item<Addr> = Item::new("dao");
 
+/// The multiplier applied to the weight of tokens with a given role.
+/// Roles without an entry have a multiplier of one.
+pub const ROLE_MULTIPLIERS: SnapshotMap<&str, Uint128> = SnapshotMap::new(
+    "role_multipliers",
+    "role_multipliers__checkpoints",
+    "role_multipliers__changelog",
+    Strategy::EveryBlock,
+);
+/// The roles with an entry in `ROLE_MULTIPLIERS` as a function of
+/// block height, so that voting power at past heights only looks at
+/// the roles that had a multiplier at that height.
+pub const MULTIPLIED_ROLES: SnapshotItem<Vec<String>> = SnapshotItem::new(
+    "multiplied_roles",
+    "multiplied_roles__checkpoints",
+    "multiplied_roles__changelog",
+    Strategy::EveryBlock,
+);
+/// The maximum number of roles that may have a multiplier other than
+/// one. Voting power queries query the NFT contract once per role.
+pub const MAX_ROLE_MULTIPLIERS: u64 = 16;
+
 // Holds initial NFTs messages during instantiation.
 pub const INITIAL_NFTS: Item<Vec<NftMintMsg>> = Item::new("initial_nfts");
@@ -21,6 +21,7 @@ pub fn mint_nft(
             extension: MetadataExt {
                 role: Some("admin".to_string()),
                 weight: 1,
+                expires_at: None,
             },
         },
         &[],
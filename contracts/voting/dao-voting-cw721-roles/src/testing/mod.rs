@@ -36,6 +36,7 @@ pub(crate) fn setup_test(initial_nfts: Vec<NftMintMsg>) -> CommonTest {
                     symbol: "TITLES".to_string(),
                     initial_nfts,
                 },
+                role_multipliers: vec![],
             },
             &[],
             "cw721_voting",
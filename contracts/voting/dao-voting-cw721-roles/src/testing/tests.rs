@@ -1,15 +1,18 @@
 use cosmwasm_std::{Addr, Uint128};
-use cw_multi_test::{App, Executor};
-use dao_cw721_extensions::roles::MetadataExt;
+use cw4::{Member, MemberListResponse};
+use cw_multi_test::{next_block, App, Executor};
+use dao_cw721_extensions::roles::{ExecuteExt, MetadataExt};
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
 use dao_testing::contracts::dao_voting_cw721_roles_contract;
 
 use crate::{
-    msg::{InstantiateMsg, NftContract, NftMintMsg},
-    state::Config,
+    msg::{ExecuteMsg, InstantiateMsg, NftContract, NftMintMsg, QueryMsg, RoleMultiplier},
+    state::{Config, MAX_ROLE_MULTIPLIERS},
     testing::{
         execute::mint_nft,
         queries::{query_config, query_info, query_minter, query_total_power, query_voting_power},
     },
+    ContractError,
 };
 
 use super::{instantiate::instantiate_cw721_roles, setup_test, CommonTest, CREATOR_ADDR};
@@ -25,6 +28,7 @@ fn test_info_query_works() -> anyhow::Result<()> {
         extension: MetadataExt {
             role: None,
             weight: 1,
+            expires_at: None,
         },
     }]);
     let info = query_info(&app, &module_addr)?;
@@ -52,6 +56,7 @@ fn test_use_existing_nft_contract() {
                 nft_contract: NftContract::Existing {
                     address: cw721_addr.clone().to_string(),
                 },
+                role_multipliers: vec![],
             },
             &[],
             "cw721_voting",
@@ -84,6 +89,7 @@ fn test_voting_queries() {
         extension: MetadataExt {
             role: Some("admin".to_string()),
             weight: 1,
+            expires_at: None,
         },
     }]);
 
@@ -124,3 +130,161 @@ fn test_voting_queries() {
     let vp = query_voting_power(&app, &module_addr, CREATOR_ADDR, None).unwrap();
     assert_eq!(vp.power, Uint128::new(2));
 }
+
+#[test]
+fn test_role_multipliers() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let module_id = app.store_code(dao_voting_cw721_roles_contract());
+
+    let (cw721_addr, _) = instantiate_cw721_roles(&mut app, CREATOR_ADDR, CREATOR_ADDR);
+    let module_addr = app.instantiate_contract(
+        module_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &InstantiateMsg {
+            nft_contract: NftContract::Existing {
+                address: cw721_addr.to_string(),
+            },
+            role_multipliers: vec![RoleMultiplier {
+                role: "core".to_string(),
+                multiplier: Uint128::new(3),
+            }],
+        },
+        &[],
+        "cw721_voting",
+        None,
+    )?;
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        cw721_addr.clone(),
+        &cw721_base::ExecuteMsg::<MetadataExt, ExecuteExt>::Mint {
+            token_id: "1".to_string(),
+            owner: "alice".to_string(),
+            token_uri: None,
+            extension: MetadataExt {
+                role: Some("core".to_string()),
+                weight: 2,
+                expires_at: None,
+            },
+        },
+        &[],
+    )?;
+    // Mints an NFT with the "admin" role and a weight of one.
+    mint_nft(&mut app, &cw721_addr, CREATOR_ADDR, "bob", "2")?;
+    app.update_block(next_block);
+
+    let total = query_total_power(&app, &module_addr, None)?;
+    assert_eq!(total.power, Uint128::new(7));
+    let vp = query_voting_power(&app, &module_addr, "alice", None)?;
+    assert_eq!(vp.power, Uint128::new(6));
+    let vp = query_voting_power(&app, &module_addr, "bob", None)?;
+    assert_eq!(vp.power, Uint128::new(1));
+
+    let vp: VotingPowerAtHeightResponse = app.wrap().query_wasm_smart(
+        &module_addr,
+        &QueryMsg::RoleVotingPowerAtHeight {
+            role: "core".to_string(),
+            address: "alice".to_string(),
+            height: None,
+        },
+    )?;
+    assert_eq!(vp.power, Uint128::new(6));
+    let total: TotalPowerAtHeightResponse = app.wrap().query_wasm_smart(
+        &module_addr,
+        &QueryMsg::TotalRolePowerAtHeight {
+            role: "admin".to_string(),
+            height: None,
+        },
+    )?;
+    assert_eq!(total.power, Uint128::new(1));
+
+    let members: MemberListResponse = app.wrap().query_wasm_smart(
+        &module_addr,
+        &QueryMsg::RoleMembers {
+            role: "core".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    assert_eq!(
+        members.members,
+        vec![Member {
+            addr: "alice".to_string(),
+            weight: 2
+        }]
+    );
+
+    // Only the DAO may update multipliers.
+    let update = ExecuteMsg::UpdateRoleMultipliers {
+        set: vec![RoleMultiplier {
+            role: "admin".to_string(),
+            multiplier: Uint128::zero(),
+        }],
+        remove: vec!["core".to_string()],
+    };
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked("alice"), module_addr.clone(), &update, &[])
+        .unwrap_err()
+        .downcast()?;
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let before = app.block_info().height;
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module_addr.clone(),
+        &update,
+        &[],
+    )?;
+    app.update_block(next_block);
+
+    let total = query_total_power(&app, &module_addr, None)?;
+    assert_eq!(total.power, Uint128::new(2));
+    let vp = query_voting_power(&app, &module_addr, "bob", None)?;
+    assert_eq!(vp.power, Uint128::zero());
+
+    // Past voting power uses the multipliers at the time.
+    let total = query_total_power(&app, &module_addr, Some(before))?;
+    assert_eq!(total.power, Uint128::new(7));
+
+    // The number of roles with a multiplier is bounded.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            module_addr.clone(),
+            &ExecuteMsg::UpdateRoleMultipliers {
+                set: (0..MAX_ROLE_MULTIPLIERS)
+                    .map(|i| RoleMultiplier {
+                        role: format!("role{i}"),
+                        multiplier: Uint128::new(2),
+                    })
+                    .collect(),
+                remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()?;
+    assert_eq!(
+        err,
+        ContractError::TooManyRoleMultipliers {
+            max: MAX_ROLE_MULTIPLIERS
+        }
+    );
+
+    let multipliers: Vec<RoleMultiplier> = app.wrap().query_wasm_smart(
+        &module_addr,
+        &QueryMsg::RoleMultipliers {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    assert_eq!(
+        multipliers,
+        vec![RoleMultiplier {
+            role: "admin".to_string(),
+            multiplier: Uint128::zero(),
+        }]
+    );
+
+    Ok(())
+}
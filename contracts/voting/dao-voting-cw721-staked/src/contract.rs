@@ -6,9 +6,10 @@ use cosmwasm_std::{
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw721::{Cw721QueryMsg, Cw721ReceiveMsg, NumTokensResponse};
+use cw721_controllers::{NftClaim, NftClaimsResponse};
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_execute_data, parse_reply_instantiate_data, Duration};
-use dao_hooks::nft_stake::{stake_nft_hook_msgs, unstake_nft_hook_msgs};
+use dao_hooks::nft_stake::{slash_nft_hook_msgs, stake_nft_hook_msgs, unstake_nft_hook_msgs};
 use dao_interface::state::ModuleInstantiateCallback;
 use dao_interface::{nft::NftFactoryCallback, voting::IsActiveResponse};
 use dao_voting::duration::validate_duration;
@@ -17,10 +18,17 @@ use dao_voting::threshold::{
     ActiveThresholdResponse,
 };
 
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, NftContract, QueryMsg};
+use serde::Deserialize;
+
+use crate::msg::{
+    CollectionResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NftContract, QueryMsg,
+    SlashDestination, TokenWeight, Trait, TraitWeight,
+};
 use crate::state::{
-    register_staked_nft, register_unstaked_nfts, Config, ACTIVE_THRESHOLD, CONFIG, DAO, HOOKS,
-    INITIAL_NFTS, MAX_CLAIMS, NFT_BALANCES, NFT_CLAIMS, STAKED_NFTS_PER_OWNER, TOTAL_STAKED_NFTS,
+    register_staked_nft, register_unstaked_nfts, reweigh_staked_nft, total_staked_power,
+    Collection, Config, ACTIVE_THRESHOLD, COLLECTIONS, COLLECTION_CLAIMS, COLLECTION_STAKED_NFTS,
+    CONFIG, DAO, HOOKS, INITIAL_NFTS, MAX_CLAIMS, NFT_BALANCES, NFT_CLAIMS, STAKED_NFTS_PER_OWNER,
+    TOKEN_WEIGHTS, TOTAL_STAKED_NFTS, TOTAL_STAKED_POWER, TRAIT_WEIGHTS,
 };
 use crate::ContractError;
 
@@ -31,6 +39,9 @@ const INSTANTIATE_NFT_CONTRACT_REPLY_ID: u64 = 0;
 const VALIDATE_SUPPLY_REPLY_ID: u64 = 1;
 const FACTORY_EXECUTE_REPLY_ID: u64 = 2;
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 // We multiply by this when calculating needed power for being active
 // when using active threshold with percent
 const PRECISION_FACTOR: u128 = 10u128.pow(9);
@@ -105,12 +116,14 @@ pub fn instantiate(
     }
 
     TOTAL_STAKED_NFTS.save(deps.storage, &Uint128::zero(), env.block.height)?;
+    TOTAL_STAKED_POWER.save(deps.storage, &Uint128::zero(), env.block.height)?;
 
     match msg.nft_contract {
         NftContract::Existing { address } => {
             let config = Config {
                 nft_address: deps.api.addr_validate(&address)?,
                 unstaking_duration: msg.unstaking_duration,
+                multiplier: Uint128::one(),
             };
             CONFIG.save(deps.storage, &config)?;
 
@@ -140,6 +153,7 @@ pub fn instantiate(
             let config = Config {
                 nft_address: Addr::unchecked(""),
                 unstaking_duration: msg.unstaking_duration,
+                multiplier: Uint128::one(),
             };
             CONFIG.save(deps.storage, &config)?;
 
@@ -172,6 +186,7 @@ pub fn instantiate(
                 let config = Config {
                     nft_address: Addr::unchecked(""),
                     unstaking_duration: msg.unstaking_duration,
+                    multiplier: Uint128::one(),
                 };
                 CONFIG.save(deps.storage, &config)?;
 
@@ -203,14 +218,43 @@ pub fn execute(
 ) -> Result<Response<Empty>, ContractError> {
     match msg {
         ExecuteMsg::ReceiveNft(msg) => execute_stake(deps, env, info, msg),
-        ExecuteMsg::Unstake { token_ids } => execute_unstake(deps, env, info, token_ids),
-        ExecuteMsg::ClaimNfts {} => execute_claim_nfts(deps, env, info),
+        ExecuteMsg::Unstake {
+            token_ids,
+            collection,
+        } => execute_unstake(deps, env, info, token_ids, collection),
+        ExecuteMsg::ClaimNfts { collection } => execute_claim_nfts(deps, env, info, collection),
         ExecuteMsg::UpdateConfig { duration } => execute_update_config(info, deps, duration),
+        ExecuteMsg::UpdateMultiplier { multiplier } => {
+            execute_update_multiplier(deps, info, multiplier)
+        }
         ExecuteMsg::AddHook { addr } => execute_add_hook(deps, info, addr),
         ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, info, addr),
         ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
             execute_update_active_threshold(deps, env, info, new_threshold)
         }
+        ExecuteMsg::UpdateTokenWeights { set, remove } => {
+            execute_update_token_weights(deps, info, set, remove)
+        }
+        ExecuteMsg::UpdateTraitWeights { set, remove } => {
+            execute_update_trait_weights(deps, info, set, remove)
+        }
+        ExecuteMsg::ReweighNfts {
+            staker,
+            token_ids,
+            collection,
+        } => execute_reweigh_nfts(deps, env, staker, token_ids, collection),
+        ExecuteMsg::AddCollection {
+            address,
+            multiplier,
+            unstaking_duration,
+        } => execute_add_collection(deps, info, address, multiplier, unstaking_duration),
+        ExecuteMsg::RemoveCollection { address } => execute_remove_collection(deps, info, address),
+        ExecuteMsg::Slash {
+            staker,
+            token_ids,
+            collection,
+            destination,
+        } => execute_slash(deps, env, info, staker, token_ids, collection, destination),
     }
 }
 
@@ -221,14 +265,35 @@ pub fn execute_stake(
     wrapper: Cw721ReceiveMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.nft_address {
-        return Err(ContractError::InvalidToken {
-            received: info.sender,
-            expected: config.nft_address,
-        });
-    }
+    let collection = if info.sender == config.nft_address {
+        config.collection()
+    } else {
+        match COLLECTIONS.may_load(deps.storage, &info.sender)? {
+            Some(collection) if collection.active => collection,
+            _ => {
+                return Err(ContractError::InvalidToken {
+                    received: info.sender,
+                    expected: config.nft_address,
+                })
+            }
+        }
+    };
     let staker = deps.api.addr_validate(&wrapper.sender)?;
-    register_staked_nft(deps.storage, env.block.height, &staker, &wrapper.token_id)?;
+    let power = nft_power(
+        deps.as_ref(),
+        &config,
+        &info.sender,
+        &collection,
+        &wrapper.token_id,
+    )?;
+    register_staked_nft(
+        deps.storage,
+        env.block.height,
+        &info.sender,
+        &staker,
+        &wrapper.token_id,
+        power,
+    )?;
     let hook_msgs = stake_nft_hook_msgs(
         HOOKS,
         deps.storage,
@@ -238,8 +303,53 @@ pub fn execute_stake(
     Ok(Response::default()
         .add_submessages(hook_msgs)
         .add_attribute("action", "stake")
+        .add_attribute("collection", info.sender)
         .add_attribute("from", staker)
-        .add_attribute("token_id", wrapper.token_id))
+        .add_attribute("token_id", wrapper.token_id)
+        .add_attribute("weight", power))
+}
+
+/// Resolves COLLECTION to a collection that may have been staked
+/// from, or to the collection in the config if it is `None`.
+fn staked_collection(
+    deps: Deps,
+    config: &Config,
+    collection: Option<String>,
+) -> Result<(Addr, Collection), ContractError> {
+    let Some(collection) = collection else {
+        return Ok((config.nft_address.clone(), config.collection()));
+    };
+    let address = deps.api.addr_validate(&collection)?;
+    if address == config.nft_address {
+        return Ok((address, config.collection()));
+    }
+    let collection = COLLECTIONS
+        .may_load(deps.storage, &address)?
+        .ok_or(ContractError::UnknownCollection { collection })?;
+    Ok((address, collection))
+}
+
+/// Returns messages transferring TOKEN_IDS from the collection at
+/// NFT_ADDRESS to RECIPIENT.
+fn transfer_nft_msgs(
+    nft_address: &Addr,
+    recipient: &Addr,
+    token_ids: Vec<String>,
+) -> StdResult<Vec<CosmosMsg>> {
+    token_ids
+        .into_iter()
+        .map(|token_id| -> StdResult<CosmosMsg> {
+            Ok(WasmMsg::Execute {
+                contract_addr: nft_address.to_string(),
+                msg: to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+                    recipient: recipient.to_string(),
+                    token_id,
+                })?,
+                funds: vec![],
+            }
+            .into())
+        })
+        .collect()
 }
 
 pub fn execute_unstake(
@@ -247,12 +357,22 @@ pub fn execute_unstake(
     env: Env,
     info: MessageInfo,
     token_ids: Vec<String>,
+    collection: Option<String>,
 ) -> Result<Response, ContractError> {
     if token_ids.is_empty() {
         return Err(ContractError::ZeroUnstake {});
     }
 
-    register_unstaked_nfts(deps.storage, env.block.height, &info.sender, &token_ids)?;
+    let config = CONFIG.load(deps.storage)?;
+    let (address, collection) = staked_collection(deps.as_ref(), &config, collection)?;
+
+    register_unstaked_nfts(
+        deps.storage,
+        env.block.height,
+        &address,
+        &info.sender,
+        &token_ids,
+    )?;
 
     // Provided that the backing cw721 contract is non-malicious:
     //
@@ -281,58 +401,44 @@ pub fn execute_unstake(
     // 7. (4) && (6) => none of the tokens in token_ids are in the
     //    claims queue for `info.sender`
     //
-    // (5) && (7) are the invariants for calling `create_nft_claims`
-    // so if we reach this point in execution, we may safely create
-    // claims.
+    // (5) && (7) mean the claims created below are unique, so if we
+    // reach this point in execution, we may safely create claims.
 
     let hook_msgs =
         unstake_nft_hook_msgs(HOOKS, deps.storage, info.sender.clone(), token_ids.clone())?;
 
-    let config = CONFIG.load(deps.storage)?;
-    match config.unstaking_duration {
-        None => {
-            let return_messages = token_ids
-                .into_iter()
-                .map(|token_id| -> StdResult<WasmMsg> {
-                    Ok(cosmwasm_std::WasmMsg::Execute {
-                        contract_addr: config.nft_address.to_string(),
-                        msg: to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
-                            recipient: info.sender.to_string(),
-                            token_id,
-                        })?,
-                        funds: vec![],
-                    })
-                })
-                .collect::<StdResult<Vec<_>>>()?;
-
-            Ok(Response::default()
-                .add_messages(return_messages)
-                .add_submessages(hook_msgs)
-                .add_attribute("action", "unstake")
-                .add_attribute("from", info.sender)
-                .add_attribute("claim_duration", "None"))
-        }
+    match collection.unstaking_duration {
+        None => Ok(Response::default()
+            .add_messages(transfer_nft_msgs(&address, &info.sender, token_ids)?)
+            .add_submessages(hook_msgs)
+            .add_attribute("action", "unstake")
+            .add_attribute("collection", address)
+            .add_attribute("from", info.sender)
+            .add_attribute("claim_duration", "None")),
 
         Some(duration) => {
-            let outstanding_claims = NFT_CLAIMS
-                .query_claims(deps.as_ref(), &info.sender)?
-                .nft_claims;
-            if outstanding_claims.len() + token_ids.len() > MAX_CLAIMS as usize {
+            let key = (&address, &info.sender);
+            let mut claims = COLLECTION_CLAIMS
+                .may_load(deps.storage, key)?
+                .unwrap_or_default();
+            if claims.len() + token_ids.len() > MAX_CLAIMS as usize {
                 return Err(ContractError::TooManyClaims {});
             }
 
             // Out of gas here is fine - just try again with fewer
             // tokens.
-            NFT_CLAIMS.create_nft_claims(
-                deps.storage,
-                &info.sender,
-                token_ids,
-                duration.after(&env.block),
-            )?;
+            let release_at = duration.after(&env.block);
+            claims.extend(
+                token_ids
+                    .into_iter()
+                    .map(|token_id| NftClaim::new(token_id, release_at)),
+            );
+            COLLECTION_CLAIMS.save(deps.storage, key, &claims)?;
 
             Ok(Response::default()
                 .add_attribute("action", "unstake")
                 .add_submessages(hook_msgs)
+                .add_attribute("collection", address)
                 .add_attribute("from", info.sender)
                 .add_attribute("claim_duration", format!("{duration}")))
         }
@@ -343,32 +449,31 @@ pub fn execute_claim_nfts(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    collection: Option<String>,
 ) -> Result<Response, ContractError> {
-    let nfts = NFT_CLAIMS.claim_nfts(deps.storage, &info.sender, &env.block)?;
-    if nfts.is_empty() {
-        return Err(ContractError::NothingToClaim {});
-    }
-
     let config = CONFIG.load(deps.storage)?;
+    let (address, _) = staked_collection(deps.as_ref(), &config, collection)?;
 
-    let msgs = nfts
+    let key = (&address, &info.sender);
+    let (released, pending): (Vec<_>, Vec<_>) = COLLECTION_CLAIMS
+        .may_load(deps.storage, key)?
+        .unwrap_or_default()
         .into_iter()
-        .map(|nft| -> StdResult<CosmosMsg> {
-            Ok(WasmMsg::Execute {
-                contract_addr: config.nft_address.to_string(),
-                msg: to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
-                    recipient: info.sender.to_string(),
-                    token_id: nft,
-                })?,
-                funds: vec![],
-            }
-            .into())
-        })
-        .collect::<StdResult<Vec<_>>>()?;
+        .partition(|claim| claim.release_at.is_expired(&env.block));
+    if released.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    if pending.is_empty() {
+        COLLECTION_CLAIMS.remove(deps.storage, key);
+    } else {
+        COLLECTION_CLAIMS.save(deps.storage, key, &pending)?;
+    }
 
+    let token_ids = released.into_iter().map(|claim| claim.token_id).collect();
     Ok(Response::default()
-        .add_messages(msgs)
+        .add_messages(transfer_nft_msgs(&address, &info.sender, token_ids)?)
         .add_attribute("action", "claim_nfts")
+        .add_attribute("collection", address)
         .add_attribute("from", info.sender))
 }
 
@@ -402,6 +507,31 @@ pub fn execute_update_config(
         ))
 }
 
+pub fn execute_update_multiplier(
+    deps: DepsMut,
+    info: MessageInfo,
+    multiplier: Uint128,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if multiplier.is_zero() {
+        return Err(ContractError::ZeroMultiplier {});
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            multiplier,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_multiplier")
+        .add_attribute("multiplier", multiplier))
+}
+
 pub fn execute_add_hook(
     deps: DepsMut,
     info: MessageInfo,
@@ -460,12 +590,9 @@ pub fn execute_update_active_threshold(
                 assert_valid_percentage_threshold(percent)?;
             }
             ActiveThreshold::AbsoluteCount { count } => {
-                let nft_supply: NumTokensResponse = deps
-                    .querier
-                    .query_wasm_smart(config.nft_address, &Cw721QueryMsg::NumTokens {})?;
                 assert_valid_absolute_count_threshold(
                     count,
-                    Uint128::new(nft_supply.count.into()),
+                    total_nft_supply(deps.as_ref(), &config)?,
                 )?;
             }
         }
@@ -477,6 +604,308 @@ pub fn execute_update_active_threshold(
     Ok(Response::new().add_attribute("action", "update_active_threshold"))
 }
 
+pub fn execute_update_token_weights(
+    deps: DepsMut,
+    info: MessageInfo,
+    set: Vec<TokenWeight>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for TokenWeight { token_id, weight } in set {
+        TOKEN_WEIGHTS.save(deps.storage, &token_id, &weight)?;
+    }
+    for token_id in remove {
+        TOKEN_WEIGHTS.remove(deps.storage, &token_id);
+    }
+
+    Ok(Response::new().add_attribute("action", "update_token_weights"))
+}
+
+pub fn execute_update_trait_weights(
+    deps: DepsMut,
+    info: MessageInfo,
+    set: Vec<TraitWeight>,
+    remove: Vec<Trait>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for TraitWeight {
+        trait_type,
+        value,
+        weight,
+    } in set
+    {
+        TRAIT_WEIGHTS.save(deps.storage, (&trait_type, &value), &weight)?;
+    }
+    for Trait { trait_type, value } in remove {
+        TRAIT_WEIGHTS.remove(deps.storage, (&trait_type, &value));
+    }
+
+    Ok(Response::new().add_attribute("action", "update_trait_weights"))
+}
+
+pub fn execute_reweigh_nfts(
+    deps: DepsMut,
+    env: Env,
+    staker: String,
+    token_ids: Vec<String>,
+    collection: Option<String>,
+) -> Result<Response, ContractError> {
+    let staker = deps.api.addr_validate(&staker)?;
+    let config = CONFIG.load(deps.storage)?;
+    let (address, collection) = staked_collection(deps.as_ref(), &config, collection)?;
+    for token_id in &token_ids {
+        let power = nft_power(deps.as_ref(), &config, &address, &collection, token_id)?;
+        reweigh_staked_nft(
+            deps.storage,
+            env.block.height,
+            &address,
+            &staker,
+            token_id,
+            power,
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "reweigh_nfts")
+        .add_attribute("collection", address)
+        .add_attribute("staker", staker)
+        .add_attribute("count", token_ids.len().to_string()))
+}
+
+pub fn execute_add_collection(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    multiplier: Uint128,
+    unstaking_duration: Option<Duration>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    if address == config.nft_address {
+        return Err(ContractError::ConfigCollection {
+            collection: address.into_string(),
+        });
+    }
+    if multiplier.is_zero() {
+        return Err(ContractError::ZeroMultiplier {});
+    }
+    validate_duration(unstaking_duration)?;
+
+    COLLECTIONS.save(
+        deps.storage,
+        &address,
+        &Collection {
+            multiplier,
+            unstaking_duration,
+            active: true,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_collection")
+        .add_attribute("collection", address)
+        .add_attribute("multiplier", multiplier))
+}
+
+pub fn execute_remove_collection(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let collection = deps.api.addr_validate(&address)?;
+    COLLECTIONS.update(deps.storage, &collection, |c| match c {
+        Some(c) => Ok(Collection { active: false, ..c }),
+        None => Err(ContractError::UnknownCollection {
+            collection: address,
+        }),
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_collection")
+        .add_attribute("collection", collection))
+}
+
+pub fn execute_slash(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    staker: String,
+    token_ids: Vec<String>,
+    collection: Option<String>,
+    destination: SlashDestination,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if token_ids.is_empty() {
+        return Err(ContractError::ZeroSlash {});
+    }
+
+    let staker = deps.api.addr_validate(&staker)?;
+    let config = CONFIG.load(deps.storage)?;
+    let (nft_address, _) = staked_collection(deps.as_ref(), &config, collection)?;
+
+    // Remove claims for any of the tokens. The rest must be staked.
+    let key = (&nft_address, &staker);
+    let (claimed, pending): (Vec<_>, Vec<_>) = COLLECTION_CLAIMS
+        .may_load(deps.storage, key)?
+        .unwrap_or_default()
+        .into_iter()
+        .partition(|claim| token_ids.contains(&claim.token_id));
+    if pending.is_empty() {
+        COLLECTION_CLAIMS.remove(deps.storage, key);
+    } else {
+        COLLECTION_CLAIMS.save(deps.storage, key, &pending)?;
+    }
+    let claimed: Vec<String> = claimed.into_iter().map(|claim| claim.token_id).collect();
+    let staked: Vec<String> = token_ids
+        .iter()
+        .filter(|token_id| !claimed.contains(*token_id))
+        .cloned()
+        .collect();
+    register_unstaked_nfts(
+        deps.storage,
+        env.block.height,
+        &nft_address,
+        &staker,
+        &staked,
+    )?;
+
+    let msgs = match destination {
+        SlashDestination::Dao {} => transfer_nft_msgs(&nft_address, &dao, token_ids.clone())?,
+        SlashDestination::Burn {} => token_ids
+            .iter()
+            .map(|token_id| -> StdResult<CosmosMsg> {
+                Ok(WasmMsg::Execute {
+                    contract_addr: nft_address.to_string(),
+                    msg: to_json_binary(&cw721::Cw721ExecuteMsg::Burn {
+                        token_id: token_id.clone(),
+                    })?,
+                    funds: vec![],
+                }
+                .into())
+            })
+            .collect::<StdResult<_>>()?,
+    };
+    let hook_msgs = if staked.is_empty() {
+        vec![]
+    } else {
+        slash_nft_hook_msgs(HOOKS, deps.storage, staker.clone(), staked)?
+    };
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "slash")
+        .add_attribute("collection", nft_address)
+        .add_attribute("staker", staker)
+        .add_attribute("count", token_ids.len().to_string()))
+}
+
+/// The parts of a cw721 `NftInfo` response used to weigh an NFT by
+/// its traits. Follows the on-chain metadata format of
+/// cw721-metadata-onchain.
+#[derive(Deserialize)]
+struct NftTraits {
+    #[serde(default)]
+    extension: Option<NftTraitsExtension>,
+}
+
+#[derive(Deserialize)]
+struct NftTraitsExtension {
+    #[serde(default)]
+    attributes: Option<Vec<NftTrait>>,
+}
+
+#[derive(Deserialize)]
+struct NftTrait {
+    trait_type: String,
+    value: String,
+}
+
+/// Returns the weight that TOKEN_ID, an NFT in the collection at
+/// NFT_ADDRESS, is staked with given the configured weights.
+pub fn nft_weight(deps: Deps, nft_address: &Addr, token_id: &str) -> StdResult<Uint128> {
+    if let Some(weight) = TOKEN_WEIGHTS.may_load(deps.storage, token_id)? {
+        return Ok(weight);
+    }
+    Ok(trait_weight(deps, nft_address, token_id)?.unwrap_or(Uint128::one()))
+}
+
+/// Returns the voting power TOKEN_ID, an NFT in COLLECTION at
+/// ADDRESS, is staked with. Token weights only apply to the
+/// collection in the config. NFTs from removed collections have no
+/// voting power.
+pub fn nft_power(
+    deps: Deps,
+    config: &Config,
+    address: &Addr,
+    collection: &Collection,
+    token_id: &str,
+) -> StdResult<Uint128> {
+    if !collection.active {
+        return Ok(Uint128::zero());
+    }
+    let weight = if *address == config.nft_address {
+        nft_weight(deps, address, token_id)?
+    } else {
+        trait_weight(deps, address, token_id)?.unwrap_or(Uint128::one())
+    };
+    weight
+        .checked_mul(collection.multiplier)
+        .map_err(StdError::from)
+}
+
+/// Returns the weight of TOKEN_ID's heaviest weighted trait, or
+/// `None` if none of its traits are weighted.
+fn trait_weight(deps: Deps, nft_address: &Addr, token_id: &str) -> StdResult<Option<Uint128>> {
+    if TRAIT_WEIGHTS.is_empty(deps.storage) {
+        return Ok(None);
+    }
+
+    // NFTs whose metadata is not in the expected format have no
+    // traits, rather than not being stakeable.
+    let traits = deps
+        .querier
+        .query_wasm_smart::<NftTraits>(
+            nft_address,
+            &Cw721QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            },
+        )
+        .ok()
+        .and_then(|info| info.extension)
+        .and_then(|extension| extension.attributes)
+        .unwrap_or_default();
+
+    let mut weight = None;
+    for NftTrait { trait_type, value } in traits {
+        if let Some(trait_weight) = TRAIT_WEIGHTS.may_load(deps.storage, (&trait_type, &value))? {
+            weight = weight.max(Some(trait_weight));
+        }
+    }
+    Ok(weight)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -485,20 +914,97 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Dao {} => query_dao(deps),
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::IsActive {} => query_is_active(deps, env),
-        QueryMsg::NftClaims { address } => query_nft_claims(deps, address),
+        QueryMsg::NftClaims {
+            address,
+            collection,
+        } => query_nft_claims(deps, address, collection),
         QueryMsg::Hooks {} => query_hooks(deps),
         QueryMsg::StakedNfts {
             address,
             start_after,
             limit,
-        } => query_staked_nfts(deps, address, start_after, limit),
+            collection,
+        } => query_staked_nfts(deps, address, start_after, limit, collection),
         QueryMsg::TotalPowerAtHeight { height } => query_total_power_at_height(deps, env, height),
         QueryMsg::VotingPowerAtHeight { address, height } => {
             query_voting_power_at_height(deps, env, address, height)
         }
+        QueryMsg::NftWeight {
+            token_id,
+            collection,
+        } => query_nft_weight(deps, token_id, collection),
+        QueryMsg::StakedNftWeight {
+            address,
+            token_id,
+            collection,
+        } => query_staked_nft_weight(deps, address, token_id, collection),
+        QueryMsg::TokenWeights { start_after, limit } => {
+            query_token_weights(deps, start_after, limit)
+        }
+        QueryMsg::TraitWeights { start_after, limit } => {
+            query_trait_weights(deps, start_after, limit)
+        }
+        QueryMsg::Collections { start_after, limit } => query_collections(deps, start_after, limit),
     }
 }
 
+/// Query handlers can only return `StdError`s.
+fn query_collection(deps: Deps, collection: Option<String>) -> StdResult<(Addr, Collection)> {
+    let config = CONFIG.load(deps.storage)?;
+    staked_collection(deps, &config, collection).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+pub fn query_nft_weight(
+    deps: Deps,
+    token_id: String,
+    collection: Option<String>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let (address, collection) = query_collection(deps, collection)?;
+    to_json_binary(&nft_power(deps, &config, &address, &collection, &token_id)?)
+}
+
+pub fn query_staked_nft_weight(
+    deps: Deps,
+    address: String,
+    token_id: String,
+    collection: Option<String>,
+) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let (collection, _) = query_collection(deps, collection)?;
+    to_json_binary(
+        &COLLECTION_STAKED_NFTS.may_load(deps.storage, (&collection, &address, &token_id))?,
+    )
+}
+
+pub fn query_collections(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let start_after = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let range = COLLECTIONS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .map(|c| {
+            c.map(|(address, collection)| CollectionResponse {
+                address,
+                collection,
+            })
+        });
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let collections = range
+        .take(limit as usize)
+        .collect::<StdResult<Vec<CollectionResponse>>>()?;
+    to_json_binary(&collections)
+}
+
 pub fn query_active_threshold(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&ActiveThresholdResponse {
         active_threshold: ACTIVE_THRESHOLD.may_load(deps.storage)?,
@@ -508,14 +1014,10 @@ pub fn query_active_threshold(deps: Deps) -> StdResult<Binary> {
 pub fn query_is_active(deps: Deps, env: Env) -> StdResult<Binary> {
     let threshold = ACTIVE_THRESHOLD.may_load(deps.storage)?;
     if let Some(threshold) = threshold {
-        let config = CONFIG.load(deps.storage)?;
+        // Thresholds count NFTs, regardless of their weights.
         let staked_nfts = TOTAL_STAKED_NFTS
             .may_load_at_height(deps.storage, env.block.height)?
             .unwrap_or_default();
-        let total_nfts: NumTokensResponse = deps.querier.query_wasm_smart(
-            config.nft_address,
-            &cw721_base::msg::QueryMsg::<Empty>::NumTokens {},
-        )?;
 
         match threshold {
             ActiveThreshold::AbsoluteCount { count } => to_json_binary(&IsActiveResponse {
@@ -527,17 +1029,19 @@ pub fn query_is_active(deps: Deps, env: Env) -> StdResult<Binary> {
                     return to_json_binary(&IsActiveResponse { active: false });
                 }
 
+                let config = CONFIG.load(deps.storage)?;
+                let total_nfts = total_nft_supply(deps, &config)?;
+
                 // percent is bounded between [0, 100]. decimal
                 // represents percents in u128 terms as p *
                 // 10^15. this bounds percent between [0, 10^17].
                 //
-                // total_potential_power is bounded between [0, 2^64]
-                // as it tracks the count of NFT tokens which has
-                // a max supply of 2^64.
+                // total_nfts is bounded between [0, 2^128] as it
+                // is a Uint128.
                 //
                 // with our precision factor being 10^9:
                 //
-                // total_nfts <= 2^64 * 10^9 <= 2^256
+                // total_nfts <= 2^128 * 10^9 <= 2^256
                 //
                 // so we're good to put that in a u256.
                 //
@@ -549,7 +1053,7 @@ pub fn query_is_active(deps: Deps, env: Env) -> StdResult<Binary> {
                 // rounding is rounding down, so the whole thing can
                 // be safely unwrapped at the end of the day thank you
                 // for coming to my ted talk.
-                let total_nfts_count = Uint128::from(total_nfts.count).full_mul(PRECISION_FACTOR);
+                let total_nfts_count = total_nfts.full_mul(PRECISION_FACTOR);
 
                 // under the hood decimals are `atomics / 10^decimal_places`.
                 // cosmwasm doesn't give us a Decimal * Uint256
@@ -574,6 +1078,25 @@ pub fn query_is_active(deps: Deps, env: Env) -> StdResult<Binary> {
     }
 }
 
+/// Returns the number of NFTs in circulation in the collection in
+/// CONFIG and in each active additional collection.
+fn total_nft_supply(deps: Deps, config: &Config) -> StdResult<Uint128> {
+    let supply = |address: &Addr| -> StdResult<Uint128> {
+        let res: NumTokensResponse = deps
+            .querier
+            .query_wasm_smart(address, &cw721_base::msg::QueryMsg::<Empty>::NumTokens {})?;
+        Ok(Uint128::from(res.count))
+    };
+    let mut total = supply(&config.nft_address)?;
+    for collection in COLLECTIONS.range(deps.storage, None, None, cosmwasm_std::Order::Ascending) {
+        let (address, collection) = collection?;
+        if collection.active {
+            total = total.checked_add(supply(&address)?)?;
+        }
+    }
+    Ok(total)
+}
+
 pub fn query_voting_power_at_height(
     deps: Deps,
     env: Env,
@@ -590,12 +1113,56 @@ pub fn query_voting_power_at_height(
 
 pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) -> StdResult<Binary> {
     let height = height.unwrap_or(env.block.height);
-    let power = TOTAL_STAKED_NFTS
-        .may_load_at_height(deps.storage, height)?
-        .unwrap_or_default();
+    let power = total_staked_power(deps.storage, Some(height))?;
     to_json_binary(&dao_interface::voting::TotalPowerAtHeightResponse { power, height })
 }
 
+pub fn query_token_weights(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let weights = TOKEN_WEIGHTS
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|r| r.map(|(token_id, weight)| TokenWeight { token_id, weight }))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&weights)
+}
+
+pub fn query_trait_weights(
+    deps: Deps,
+    start_after: Option<Trait>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let weights = TRAIT_WEIGHTS
+        .range(
+            deps.storage,
+            start_after
+                .as_ref()
+                .map(|t| Bound::exclusive((t.trait_type.as_str(), t.value.as_str()))),
+            None,
+            cosmwasm_std::Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|r| {
+            r.map(|((trait_type, value), weight)| TraitWeight {
+                trait_type,
+                value,
+                weight,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&weights)
+}
+
 pub fn query_config(deps: Deps) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
     to_json_binary(&config)
@@ -606,8 +1173,18 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&dao)
 }
 
-pub fn query_nft_claims(deps: Deps, address: String) -> StdResult<Binary> {
-    to_json_binary(&NFT_CLAIMS.query_claims(deps, &deps.api.addr_validate(&address)?)?)
+pub fn query_nft_claims(
+    deps: Deps,
+    address: String,
+    collection: Option<String>,
+) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let (collection, _) = query_collection(deps, collection)?;
+    to_json_binary(&NftClaimsResponse {
+        nft_claims: COLLECTION_CLAIMS
+            .may_load(deps.storage, (&collection, &address))?
+            .unwrap_or_default(),
+    })
 }
 
 pub fn query_hooks(deps: Deps) -> StdResult<Binary> {
@@ -624,14 +1201,13 @@ pub fn query_staked_nfts(
     address: String,
     start_after: Option<String>,
     limit: Option<u32>,
+    collection: Option<String>,
 ) -> StdResult<Binary> {
-    let prefix = deps.api.addr_validate(&address)?;
-    let prefix = STAKED_NFTS_PER_OWNER.prefix(&prefix);
-
-    let start_after = start_after.as_deref().map(Bound::exclusive);
-    let range = prefix.keys(
+    let address = deps.api.addr_validate(&address)?;
+    let (collection, _) = query_collection(deps, collection)?;
+    let range = COLLECTION_STAKED_NFTS.prefix((&collection, &address)).keys(
         deps.storage,
-        start_after,
+        start_after.as_deref().map(Bound::exclusive),
         None,
         cosmwasm_std::Order::Ascending,
     );
@@ -646,6 +1222,31 @@ pub fn query_staked_nfts(
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let storage_version: ContractVersion = get_contract_version(deps.storage)?;
 
+    // NFTs staked from the collection in the config, and their
+    // claims, used to be stored apart from additional collections.
+    // Move them into the collection-keyed maps. Every NFT staked
+    // before weights were introduced weighs one.
+    if let Some(config) = CONFIG.may_load(deps.storage)? {
+        let staked = STAKED_NFTS_PER_OWNER
+            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (staker, token_id) in staked {
+            COLLECTION_STAKED_NFTS.save(
+                deps.storage,
+                (&config.nft_address, &staker, &token_id),
+                &Uint128::one(),
+            )?;
+            STAKED_NFTS_PER_OWNER.remove(deps.storage, (&staker, &token_id));
+        }
+        let claims = NFT_CLAIMS
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (staker, claims) in claims {
+            COLLECTION_CLAIMS.save(deps.storage, (&config.nft_address, &staker), &claims)?;
+            NFT_CLAIMS.remove(deps.storage, &staker);
+        }
+    }
+
     // Only migrate if newer
     if storage_version.version.as_str() < CONTRACT_VERSION {
         // Set contract to version to latest
@@ -56,6 +56,18 @@ pub enum ContractError {
     #[error("Factory message must serialize to WasmMsg::Execute")]
     UnsupportedFactoryMsg {},
 
+    #[error("Collection multiplier must be greater than zero")]
+    ZeroMultiplier {},
+
+    #[error("({collection}) is already the collection in the config")]
+    ConfigCollection { collection: String },
+
+    #[error("({collection}) is not a collection that may be staked")]
+    UnknownCollection { collection: String },
+
+    #[error("Can't slash zero NFTs.")]
+    ZeroSlash {},
+
     #[error("Can't unstake zero NFTs.")]
     ZeroUnstake {},
 }
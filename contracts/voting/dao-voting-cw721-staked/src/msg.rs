@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Addr, Binary, Uint128};
 use cw721::Cw721ReceiveMsg;
 use cw_utils::Duration;
 use dao_dao_macros::{active_query, voting_module_query};
@@ -44,6 +44,26 @@ pub struct InstantiateMsg {
     pub active_threshold: Option<ActiveThreshold>,
 }
 
+#[cw_serde]
+pub struct TokenWeight {
+    pub token_id: String,
+    pub weight: Uint128,
+}
+
+/// An NFT trait, as found in the `attributes` of on-chain metadata.
+#[cw_serde]
+pub struct Trait {
+    pub trait_type: String,
+    pub value: String,
+}
+
+#[cw_serde]
+pub struct TraitWeight {
+    pub trait_type: String,
+    pub value: String,
+    pub weight: Uint128,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Used to stake NFTs. To stake a NFT send a cw721 send message
@@ -52,13 +72,30 @@ pub enum ExecuteMsg {
     ReceiveNft(Cw721ReceiveMsg),
     /// Unstakes the specified token_ids on behalf of the
     /// sender. token_ids must have unique values and have non-zero
-    /// length.
-    Unstake { token_ids: Vec<String> },
-    /// Claim NFTs that have been unstaked for the specified duration.
-    ClaimNfts {},
+    /// length. COLLECTION is the additional collection the tokens
+    /// are from, or `None` for the collection in the config.
+    Unstake {
+        token_ids: Vec<String>,
+        #[serde(default)]
+        collection: Option<String>,
+    },
+    /// Claim NFTs from COLLECTION that have been unstaked for the
+    /// specified duration. If COLLECTION is `None`, claims NFTs from
+    /// the collection in the config.
+    ClaimNfts {
+        #[serde(default)]
+        collection: Option<String>,
+    },
     /// Updates the contract configuration, namely unstaking duration.
     /// Only callable by the DAO that initialized this voting contract.
     UpdateConfig { duration: Option<Duration> },
+    /// Sets the multiplier of the collection in the config. NFTs from
+    /// it weigh as much as their token weight, their heaviest weighted
+    /// trait, or one, times MULTIPLIER. NFTs that are already staked
+    /// keep their old voting power until `ReweighNfts` is executed
+    /// for them. Only callable by the DAO that initialized this
+    /// voting contract.
+    UpdateMultiplier { multiplier: Uint128 },
     /// Adds a hook which is called on staking / unstaking events.
     /// Only callable by the DAO that initialized this voting contract.
     AddHook { addr: String },
@@ -70,6 +107,73 @@ pub enum ExecuteMsg {
     UpdateActiveThreshold {
         new_threshold: Option<ActiveThreshold>,
     },
+    /// Sets and removes the weights of individual NFTs. NFTs without
+    /// a weight weigh as much as their heaviest weighted trait, or
+    /// one if they have none. Only callable by the DAO that
+    /// initialized this voting contract.
+    ///
+    /// Weights apply to NFTs staked after they are set. NFTs that
+    /// are already staked keep their old weight until `ReweighNfts`
+    /// is executed for them.
+    UpdateTokenWeights {
+        set: Vec<TokenWeight>,
+        remove: Vec<String>,
+    },
+    /// Sets and removes the weights of NFT traits. Only callable by
+    /// the DAO that initialized this voting contract.
+    ///
+    /// As with token weights, NFTs that are already staked keep their
+    /// old weight until `ReweighNfts` is executed for them.
+    UpdateTraitWeights {
+        set: Vec<TraitWeight>,
+        remove: Vec<Trait>,
+    },
+    /// Applies the current weights to TOKEN_IDS, which must have been
+    /// staked by STAKER from COLLECTION, or from the collection in
+    /// the config if `None`. Callable by anyone.
+    ReweighNfts {
+        staker: String,
+        token_ids: Vec<String>,
+        #[serde(default)]
+        collection: Option<String>,
+    },
+    /// Adds a collection that may be staked in addition to the one in
+    /// the config, or updates it if it has already been added. NFTs
+    /// from the collection weigh as much as their heaviest weighted
+    /// trait, or one, times MULTIPLIER. Only callable by the DAO that
+    /// initialized this voting contract.
+    AddCollection {
+        address: String,
+        multiplier: Uint128,
+        unstaking_duration: Option<Duration>,
+    },
+    /// Confiscates TOKEN_IDS from STAKER and sends them to
+    /// DESTINATION. TOKEN_IDS are from COLLECTION, or from the
+    /// collection in the config if `None`, and must each be staked by
+    /// STAKER or in their claims queue. Only callable by the DAO that
+    /// initialized this voting contract.
+    Slash {
+        staker: String,
+        token_ids: Vec<String>,
+        #[serde(default)]
+        collection: Option<String>,
+        destination: SlashDestination,
+    },
+    /// Stops NFTs from an additional collection from being
+    /// staked. NFTs already staked keep their voting power until they
+    /// are unstaked, or until `ReweighNfts` is executed for them,
+    /// which leaves them with none. They may still be unstaked and
+    /// claimed. Only callable by the DAO that initialized this voting
+    /// contract.
+    RemoveCollection { address: String },
+}
+
+#[cw_serde]
+pub enum SlashDestination {
+    /// Transfers confiscated NFTs to the DAO.
+    Dao {},
+    /// Burns confiscated NFTs.
+    Burn {},
 }
 
 #[active_query]
@@ -80,7 +184,13 @@ pub enum QueryMsg {
     #[returns(crate::state::Config)]
     Config {},
     #[returns(::cw721_controllers::NftClaimsResponse)]
-    NftClaims { address: String },
+    NftClaims {
+        address: String,
+        /// The additional collection to get claims for, or `None`
+        /// for the collection in the config.
+        #[serde(default)]
+        collection: Option<String>,
+    },
     #[returns(::cw_controllers::HooksResponse)]
     Hooks {},
     // List the staked NFTs for a given address.
@@ -89,9 +199,54 @@ pub enum QueryMsg {
         address: String,
         start_after: Option<String>,
         limit: Option<u32>,
+        /// The additional collection to list staked NFTs from, or
+        /// `None` for the collection in the config.
+        #[serde(default)]
+        collection: Option<String>,
     },
     #[returns(ActiveThresholdResponse)]
     ActiveThreshold {},
+    /// Gets the voting power TOKEN_ID from COLLECTION, or from the
+    /// collection in the config if `None`, would be staked with.
+    #[returns(Uint128)]
+    NftWeight {
+        token_id: String,
+        #[serde(default)]
+        collection: Option<String>,
+    },
+    /// Gets the voting power TOKEN_ID from COLLECTION, or from the
+    /// collection in the config if `None`, was staked with by
+    /// ADDRESS, or `None` if ADDRESS has not staked it.
+    #[returns(Option<Uint128>)]
+    StakedNftWeight {
+        address: String,
+        token_id: String,
+        #[serde(default)]
+        collection: Option<String>,
+    },
+    #[returns(Vec<TokenWeight>)]
+    TokenWeights {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<TraitWeight>)]
+    TraitWeights {
+        start_after: Option<Trait>,
+        limit: Option<u32>,
+    },
+    /// Lists collections that may be staked in addition to the one in
+    /// the config, including removed ones.
+    #[returns(Vec<CollectionResponse>)]
+    Collections {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct CollectionResponse {
+    pub address: Addr,
+    pub collection: crate::state::Collection,
 }
 
 #[cw_serde]
@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Binary, Empty, StdError, StdResult, Storage, Uint128};
-use cw721_controllers::NftClaims;
+use cw721_controllers::NftClaim;
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
@@ -12,6 +12,23 @@ use crate::ContractError;
 pub struct Config {
     pub nft_address: Addr,
     pub unstaking_duration: Option<Duration>,
+    /// The voting power of an NFT from `nft_address` is its weight
+    /// times this multiplier. Configs saved before multipliers were
+    /// introduced have a multiplier of one.
+    #[serde(default = "Uint128::one")]
+    pub multiplier: Uint128,
+}
+
+impl Config {
+    /// The collection in the config, in the form additional
+    /// collections are stored in.
+    pub fn collection(&self) -> Collection {
+        Collection {
+            multiplier: self.multiplier,
+            unstaking_duration: self.unstaking_duration,
+            active: true,
+        }
+    }
 }
 
 pub const ACTIVE_THRESHOLD: Item<ActiveThreshold> = Item::new("active_threshold");
@@ -21,20 +38,21 @@ pub const DAO: Item<Addr> = Item::new("dao");
 // Holds initial NFTs messages during instantiation.
 pub const INITIAL_NFTS: Item<Vec<Binary>> = Item::new("initial_nfts");
 
-/// The set of NFTs currently staked by each address. The existence of
-/// an `(address, token_id)` pair implies that `address` has staked
-/// `token_id`.
+/// The set of NFTs from the collection in the config staked by each
+/// address before additional collections were introduced. Moved to
+/// `COLLECTION_STAKED_NFTS` on migration.
 pub const STAKED_NFTS_PER_OWNER: Map<(&Addr, &str), Empty> = Map::new("snpw");
-/// The number of NFTs staked by an address as a function of block
-/// height.
+/// The voting power of an address, the sum of the weights of the NFTs
+/// it has staked, as a function of block height. Without configured
+/// weights every NFT weighs one, so this is the number of NFTs staked.
 pub const NFT_BALANCES: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
     "nb",
     "nb__checkpoints",
     "nb__changelog",
     Strategy::EveryBlock,
 );
-/// The number of NFTs staked with this contract as a function of
-/// block height.
+/// The number of NFTs staked with this contract, from every
+/// collection, as a function of block height.
 pub const TOTAL_STAKED_NFTS: SnapshotItem<Uint128> = SnapshotItem::new(
     "tsn",
     "tsn__checkpoints",
@@ -42,66 +60,178 @@ pub const TOTAL_STAKED_NFTS: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
-/// The maximum number of claims that may be outstanding.
+/// The total voting power of NFTs staked with this contract as a
+/// function of block height. Not set at heights before weights were
+/// introduced, when it was equal to `TOTAL_STAKED_NFTS`.
+pub const TOTAL_STAKED_POWER: SnapshotItem<Uint128> = SnapshotItem::new(
+    "tsp",
+    "tsp__checkpoints",
+    "tsp__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Weights of individual NFTs, keyed by token ID. Take precedence
+/// over trait weights.
+pub const TOKEN_WEIGHTS: Map<&str, Uint128> = Map::new("token_weights");
+/// Weights of NFTs with a trait, keyed by (trait type, value). An NFT
+/// with more than one weighted trait weighs as much as its heaviest
+/// trait.
+pub const TRAIT_WEIGHTS: Map<(&str, &str), Uint128> = Map::new("trait_weights");
+
+/// A collection that may be staked in addition to the one in
+/// `Config`.
+#[cw_serde]
+pub struct Collection {
+    /// The voting power of an NFT from this collection is its weight
+    /// times this multiplier.
+    pub multiplier: Uint128,
+    pub unstaking_duration: Option<Duration>,
+    /// False if the collection has been removed. NFTs from removed
+    /// collections may not be staked and have no voting power once
+    /// reweighed, but those already staked may be unstaked and
+    /// claimed as usual.
+    pub active: bool,
+}
+
+/// Additional collections that may be staked, keyed by address.
+pub const COLLECTIONS: Map<&Addr, Collection> = Map::new("collections");
+/// Staked NFTs from every collection, including the one in the
+/// config, keyed by (collection, staker, token ID). Values are the
+/// voting power each NFT was staked with.
+pub const COLLECTION_STAKED_NFTS: Map<(&Addr, &Addr, &str), Uint128> =
+    Map::new("collection_staked_nfts");
+/// Outstanding claims for NFTs from every collection, keyed by
+/// (collection, staker).
+pub const COLLECTION_CLAIMS: Map<(&Addr, &Addr), Vec<NftClaim>> = Map::new("collection_claims");
+
+/// The maximum number of claims that may be outstanding per
+/// collection.
 pub const MAX_CLAIMS: u64 = 70;
-pub const NFT_CLAIMS: NftClaims = NftClaims::new("nft_claims");
+/// Outstanding claims for NFTs from the collection in the config
+/// made before additional collections were introduced, keyed by
+/// staker. Moved to `COLLECTION_CLAIMS` on migration.
+pub const NFT_CLAIMS: Map<&Addr, Vec<NftClaim>> = Map::new("nft_claims");
 
 // Hooks to contracts that will receive staking and unstaking
 // messages.
 pub const HOOKS: Hooks = Hooks::new("hooks");
 
-pub fn register_staked_nft(
+/// Loads the total staked voting power at HEIGHT, or the current
+/// total if HEIGHT is not set.
+pub fn total_staked_power(storage: &dyn Storage, height: Option<u64>) -> StdResult<Uint128> {
+    let power = match height {
+        Some(height) => TOTAL_STAKED_POWER.may_load_at_height(storage, height)?,
+        None => TOTAL_STAKED_POWER.may_load(storage)?,
+    };
+    match power {
+        Some(power) => Ok(power),
+        None => Ok(match height {
+            Some(height) => TOTAL_STAKED_NFTS.may_load_at_height(storage, height)?,
+            None => TOTAL_STAKED_NFTS.may_load(storage)?,
+        }
+        .unwrap_or_default()),
+    }
+}
+
+/// Adds ADD to and subtracts SUB from STAKER's voting power and the
+/// total voting power at HEIGHT.
+fn update_power(
     storage: &mut dyn Storage,
     height: u64,
     staker: &Addr,
-    token_id: &String,
+    add: Uint128,
+    sub: Uint128,
 ) -> StdResult<()> {
-    let add_one = |prev: Option<Uint128>| -> StdResult<Uint128> {
-        prev.unwrap_or_default()
-            .checked_add(Uint128::new(1))
+    let apply = |prev: Uint128| -> StdResult<Uint128> {
+        prev.checked_add(add)
+            .and_then(|p| p.checked_sub(sub))
             .map_err(StdError::overflow)
     };
+    let total_power = total_staked_power(storage, None)?;
+    TOTAL_STAKED_POWER.save(storage, &apply(total_power)?, height)?;
+    NFT_BALANCES.update(storage, staker, height, |prev| {
+        apply(prev.unwrap_or_default())
+    })?;
+    Ok(())
+}
 
-    STAKED_NFTS_PER_OWNER.save(storage, (staker, token_id), &Empty::default())?;
-    NFT_BALANCES.update(storage, staker, height, add_one)?;
+/// Registers the staking of TOKEN_ID from COLLECTION with voting
+/// power POWER.
+pub fn register_staked_nft(
+    storage: &mut dyn Storage,
+    height: u64,
+    collection: &Addr,
+    staker: &Addr,
+    token_id: &str,
+    power: Uint128,
+) -> StdResult<()> {
+    COLLECTION_STAKED_NFTS.save(storage, (collection, staker, token_id), &power)?;
+    update_power(storage, height, staker, power, Uint128::zero())?;
     TOTAL_STAKED_NFTS
-        .update(storage, height, add_one)
+        .update(storage, height, |prev| -> StdResult<Uint128> {
+            prev.unwrap_or_default()
+                .checked_add(Uint128::new(1))
+                .map_err(StdError::overflow)
+        })
         .map(|_| ())
 }
 
-/// Registers the unstaking of TOKEN_IDs in storage. Errors if:
+/// Registers the unstaking of TOKEN_IDs from COLLECTION in storage.
+/// Errors if:
 ///
 /// 1. `token_ids` is non-unique.
 /// 2. a NFT being staked has not previously been staked.
 pub fn register_unstaked_nfts(
     storage: &mut dyn Storage,
     height: u64,
+    collection: &Addr,
     staker: &Addr,
     token_ids: &[String],
 ) -> Result<(), ContractError> {
-    let subtractor = |amount: u128| {
-        move |prev: Option<Uint128>| -> StdResult<Uint128> {
-            prev.expect("unstaking that which was not staked")
-                .checked_sub(Uint128::new(amount))
-                .map_err(StdError::overflow)
-        }
-    };
-
+    let mut power = Uint128::zero();
     for token in token_ids {
-        let key = (staker, token.as_str());
-        if STAKED_NFTS_PER_OWNER.has(storage, key) {
-            STAKED_NFTS_PER_OWNER.remove(storage, key);
-        } else {
-            return Err(ContractError::NotStaked {
-                token_id: token.clone(),
-            });
+        let key = (collection, staker, token.as_str());
+        match COLLECTION_STAKED_NFTS.may_load(storage, key)? {
+            Some(staked) => {
+                COLLECTION_STAKED_NFTS.remove(storage, key);
+                power += staked;
+            }
+            None => {
+                return Err(ContractError::NotStaked {
+                    token_id: token.clone(),
+                })
+            }
         }
     }
 
     // invariant: token_ids has unique values. for loop asserts this.
 
-    let sub_n = subtractor(token_ids.len() as u128);
-    TOTAL_STAKED_NFTS.update(storage, height, sub_n)?;
-    NFT_BALANCES.update(storage, staker, height, sub_n)?;
+    TOTAL_STAKED_NFTS.update(storage, height, |prev| -> StdResult<Uint128> {
+        prev.expect("unstaking that which was not staked")
+            .checked_sub(Uint128::new(token_ids.len() as u128))
+            .map_err(StdError::overflow)
+    })?;
+    update_power(storage, height, staker, Uint128::zero(), power)?;
+    Ok(())
+}
+
+/// Changes the voting power of TOKEN_ID, staked by STAKER from
+/// COLLECTION, to POWER.
+pub fn reweigh_staked_nft(
+    storage: &mut dyn Storage,
+    height: u64,
+    collection: &Addr,
+    staker: &Addr,
+    token_id: &str,
+    power: Uint128,
+) -> Result<(), ContractError> {
+    let key = (collection, staker, token_id);
+    let old = COLLECTION_STAKED_NFTS
+        .may_load(storage, key)?
+        .ok_or_else(|| ContractError::NotStaked {
+            token_id: token_id.to_string(),
+        })?;
+    COLLECTION_STAKED_NFTS.save(storage, key, &power)?;
+    update_power(storage, height, staker, power, old)?;
     Ok(())
 }
@@ -87,6 +87,7 @@ pub fn unstake_nfts(
         module.clone(),
         &ExecuteMsg::Unstake {
             token_ids: token_ids.iter().map(|s| s.to_string()).collect(),
+            collection: None,
         },
         &[],
     )
@@ -110,7 +111,7 @@ pub fn claim_nfts(app: &mut App, module: &Addr, sender: &str) -> AnyResult<AppRe
     app.execute_contract(
         addr!(sender),
         module.clone(),
-        &ExecuteMsg::ClaimNfts {},
+        &ExecuteMsg::ClaimNfts { collection: None },
         &[],
     )
 }
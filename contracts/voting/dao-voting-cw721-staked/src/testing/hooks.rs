@@ -1,6 +1,6 @@
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
-    Addr,
+    Addr, Uint128,
 };
 use dao_hooks::nft_stake::{stake_nft_hook_msgs, unstake_nft_hook_msgs};
 
@@ -42,6 +42,7 @@ fn test_hooks() {
             &Config {
                 nft_address: Addr::unchecked("ekez-token"),
                 unstaking_duration: None,
+                multiplier: Uint128::one(),
             },
         )
         .unwrap();
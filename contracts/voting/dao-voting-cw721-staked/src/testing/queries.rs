@@ -18,6 +18,7 @@ pub fn query_claims(app: &App, module: &Addr, addr: &str) -> StdResult<NftClaims
         module,
         &QueryMsg::NftClaims {
             address: addr.to_string(),
+            collection: None,
         },
     )?;
     Ok(claims)
@@ -41,6 +42,7 @@ pub fn query_staked_nfts(
             address: addr.to_string(),
             start_after,
             limit,
+            collection: None,
         },
     )?;
     Ok(nfts)
@@ -1,8 +1,11 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{to_json_binary, Addr, Coin, Decimal, Empty, Uint128, WasmMsg};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdResult, Uint128, WasmMsg,
+};
 use cw721_base::msg::{ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg};
 use cw721_controllers::{NftClaim, NftClaimsResponse};
-use cw_multi_test::{next_block, App, BankSudo, Executor, SudoMsg};
+use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
 use cw_utils::Duration;
 use dao_interface::voting::IsActiveResponse;
 use dao_testing::contracts::{
@@ -11,9 +14,12 @@ use dao_testing::contracts::{
 use dao_voting::threshold::{ActiveThreshold, ActiveThresholdResponse};
 
 use crate::{
-    contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
-    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, NftContract, QueryMsg},
-    state::MAX_CLAIMS,
+    contract::{migrate, query, CONTRACT_NAME, CONTRACT_VERSION},
+    msg::{
+        CollectionResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NftContract, QueryMsg,
+        SlashDestination, TokenWeight, Trait, TraitWeight,
+    },
+    state::{Collection, Config, CONFIG, MAX_CLAIMS, NFT_CLAIMS, STAKED_NFTS_PER_OWNER},
     testing::{
         execute::{
             claim_nfts, mint_and_stake_nft, mint_nft, stake_nft, unstake_nfts, update_config,
@@ -1453,3 +1459,773 @@ pub fn test_migrate_update_version() {
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
 }
+
+// NFTs staked from the collection in the config and their claims
+// are moved into the collection-keyed maps on migration.
+#[test]
+pub fn test_migrate_config_collection() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, CONTRACT_NAME, "2.4.0")?;
+    let nft = Addr::unchecked("nft");
+    let staker = Addr::unchecked("staker");
+    CONFIG.save(
+        &mut deps.storage,
+        &Config {
+            nft_address: nft.clone(),
+            unstaking_duration: None,
+            multiplier: Uint128::one(),
+        },
+    )?;
+    STAKED_NFTS_PER_OWNER.save(&mut deps.storage, (&staker, "1"), &Empty {})?;
+    let claim = NftClaim::new("2".to_string(), cw_utils::Expiration::AtHeight(100));
+    NFT_CLAIMS.save(&mut deps.storage, &staker, &vec![claim.clone()])?;
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {})?;
+
+    assert!(STAKED_NFTS_PER_OWNER.is_empty(&deps.storage));
+    assert!(NFT_CLAIMS.is_empty(&deps.storage));
+    let staked: Vec<String> = from_json(query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::StakedNfts {
+            address: staker.to_string(),
+            start_after: None,
+            limit: None,
+            collection: None,
+        },
+    )?)?;
+    assert_eq!(staked, vec!["1".to_string()]);
+    let weight: Option<Uint128> = from_json(query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::StakedNftWeight {
+            address: staker.to_string(),
+            token_id: "1".to_string(),
+            collection: Some(nft.to_string()),
+        },
+    )?)?;
+    assert_eq!(weight, Some(Uint128::one()));
+    let claims: NftClaimsResponse = from_json(query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::NftClaims {
+            address: staker.to_string(),
+            collection: None,
+        },
+    )?)?;
+    assert_eq!(claims.nft_claims, vec![claim]);
+
+    Ok(())
+}
+
+// NFTs with a weight count for that much voting power. Changing a
+// weight applies to staked NFTs once they are reweighed, and past
+// voting power is unaffected.
+#[test]
+fn test_token_weights() -> anyhow::Result<()> {
+    let CommonTest {
+        mut app,
+        module,
+        nft,
+    } = setup_test(None);
+
+    let set_weight = |app: &mut App, sender: &str, token_id: &str, weight: u128| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            module.clone(),
+            &ExecuteMsg::UpdateTokenWeights {
+                set: vec![TokenWeight {
+                    token_id: token_id.to_string(),
+                    weight: Uint128::new(weight),
+                }],
+                remove: vec![],
+            },
+            &[],
+        )
+    };
+
+    // Only the DAO may set weights.
+    let res = set_weight(&mut app, "friend", "1", 5);
+    is_error!(res => "Unauthorized");
+    let res = app.execute_contract(
+        Addr::unchecked("friend"),
+        module.clone(),
+        &ExecuteMsg::UpdateTraitWeights {
+            set: vec![TraitWeight {
+                trait_type: "rarity".to_string(),
+                value: "legendary".to_string(),
+                weight: Uint128::new(10),
+            }],
+            remove: vec![],
+        },
+        &[],
+    );
+    is_error!(res => "Unauthorized");
+
+    set_weight(&mut app, CREATOR_ADDR, "1", 5)?;
+    let weights: Vec<TokenWeight> = app.wrap().query_wasm_smart(
+        &module,
+        &QueryMsg::TokenWeights {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    assert_eq!(
+        weights,
+        vec![TokenWeight {
+            token_id: "1".to_string(),
+            weight: Uint128::new(5)
+        }]
+    );
+
+    mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "1")?;
+    mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "2")?;
+    app.update_block(next_block);
+
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(6));
+    assert_eq!(personal, Uint128::new(6));
+    let staked_weight: Option<Uint128> = app.wrap().query_wasm_smart(
+        &module,
+        &QueryMsg::StakedNftWeight {
+            address: CREATOR_ADDR.to_string(),
+            token_id: "1".to_string(),
+            collection: None,
+        },
+    )?;
+    assert_eq!(staked_weight, Some(Uint128::new(5)));
+
+    // Staked NFTs keep their weight until reweighed.
+    set_weight(&mut app, CREATOR_ADDR, "2", 3)?;
+    let weight: Uint128 = app.wrap().query_wasm_smart(
+        &module,
+        &QueryMsg::NftWeight {
+            token_id: "2".to_string(),
+            collection: None,
+        },
+    )?;
+    assert_eq!(weight, Uint128::new(3));
+    app.update_block(next_block);
+    let (total, _) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(6));
+
+    let res = app.execute_contract(
+        Addr::unchecked("friend"),
+        module.clone(),
+        &ExecuteMsg::ReweighNfts {
+            staker: "friend".to_string(),
+            token_ids: vec!["2".to_string()],
+            collection: None,
+        },
+        &[],
+    );
+    is_error!(res => "Can not unstake that which you have not staked (unstaking 2)");
+
+    let before = app.block_info().height;
+    app.execute_contract(
+        Addr::unchecked("friend"),
+        module.clone(),
+        &ExecuteMsg::ReweighNfts {
+            staker: CREATOR_ADDR.to_string(),
+            token_ids: vec!["2".to_string()],
+            collection: None,
+        },
+        &[],
+    )?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(8));
+    assert_eq!(personal, Uint128::new(8));
+    let (total, personal) =
+        query_total_and_voting_power(&app, &module, CREATOR_ADDR, Some(before))?;
+    assert_eq!(total, Uint128::new(6));
+    assert_eq!(personal, Uint128::new(6));
+
+    // Unstaking removes the weight the NFT was staked with.
+    unstake_nfts(&mut app, &module, CREATOR_ADDR, &["1"])?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(3));
+    assert_eq!(personal, Uint128::new(3));
+
+    Ok(())
+}
+
+// The active threshold counts staked NFTs, not their voting power,
+// and NFTs from additional collections count towards it.
+#[test]
+fn test_active_threshold_counts_weighted_nfts() -> anyhow::Result<()> {
+    let CommonTest {
+        mut app,
+        module,
+        nft,
+    } = setup_test(None);
+    let other = instantiate_cw721_base(&mut app, CREATOR_ADDR, CREATOR_ADDR);
+
+    for token_id in ["1", "2", "3"] {
+        mint_nft(&mut app, &nft, CREATOR_ADDR, CREATOR_ADDR, token_id)?;
+    }
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateTokenWeights {
+            set: vec![TokenWeight {
+                token_id: "1".to_string(),
+                weight: Uint128::new(5),
+            }],
+            remove: vec![],
+        },
+        &[],
+    )?;
+    let update_threshold = |app: &mut App, threshold: ActiveThreshold| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            module.clone(),
+            &ExecuteMsg::UpdateActiveThreshold {
+                new_threshold: Some(threshold),
+            },
+            &[],
+        )
+    };
+    let is_active = |app: &App| -> anyhow::Result<bool> {
+        let res: IsActiveResponse = app
+            .wrap()
+            .query_wasm_smart(&module, &QueryMsg::IsActive {})?;
+        Ok(res.active)
+    };
+
+    // The count may not be more than the number of NFTs, even though
+    // their voting power is higher.
+    let res = update_threshold(
+        &mut app,
+        ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(4),
+        },
+    );
+    is_error!(res => "Absolute count threshold cannot be greater than the total token supply");
+    update_threshold(
+        &mut app,
+        ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(2),
+        },
+    )?;
+
+    // One NFT with a voting power of five is one NFT.
+    stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "1")?;
+    app.update_block(next_block);
+    let (total, _) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(5));
+    assert!(!is_active(&app)?);
+
+    stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "2")?;
+    app.update_block(next_block);
+    assert!(is_active(&app)?);
+
+    // Two of three NFTs are staked.
+    update_threshold(
+        &mut app,
+        ActiveThreshold::Percentage {
+            percent: Decimal::percent(60),
+        },
+    )?;
+    assert!(is_active(&app)?);
+    update_threshold(
+        &mut app,
+        ActiveThreshold::Percentage {
+            percent: Decimal::percent(70),
+        },
+    )?;
+    assert!(!is_active(&app)?);
+
+    // Three of four NFTs are staked once an additional collection is
+    // added and an NFT from it staked, regardless of its multiplier.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::AddCollection {
+            address: other.to_string(),
+            multiplier: Uint128::new(3),
+            unstaking_duration: None,
+        },
+        &[],
+    )?;
+    mint_and_stake_nft(&mut app, &other, &module, CREATOR_ADDR, "1")?;
+    app.update_block(next_block);
+    assert!(is_active(&app)?);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::Unstake {
+            token_ids: vec!["1".to_string()],
+            collection: Some(other.to_string()),
+        },
+        &[],
+    )?;
+    app.update_block(next_block);
+    assert!(!is_active(&app)?);
+
+    Ok(())
+}
+
+/// The metadata extension of an NFT with on-chain traits.
+#[cosmwasm_schema::cw_serde]
+struct TraitsExtension {
+    attributes: Vec<Trait>,
+}
+
+type Cw721Traits<'a> = cw721_base::Cw721Contract<'a, TraitsExtension, Empty, Empty, Empty>;
+
+fn cw721_traits_instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw721InstantiateMsg,
+) -> StdResult<Response> {
+    Cw721Traits::default().instantiate(deps, env, info, msg)
+}
+
+fn cw721_traits_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw721ExecuteMsg<TraitsExtension, Empty>,
+) -> Result<Response, cw721_base::ContractError> {
+    Cw721Traits::default().execute(deps, env, info, msg)
+}
+
+fn cw721_traits_query(
+    deps: Deps,
+    env: Env,
+    msg: cw721_base::msg::QueryMsg<Empty>,
+) -> StdResult<Binary> {
+    Cw721Traits::default().query(deps, env, msg)
+}
+
+fn cw721_traits_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        cw721_traits_execute,
+        cw721_traits_instantiate,
+        cw721_traits_query,
+    ))
+}
+
+// Changing the multiplier, token weights, or trait weights leaves
+// staked NFTs with the voting power they were staked with until
+// `ReweighNfts` is executed for them.
+#[test]
+fn test_weight_updates_apply_when_reweighed() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let cw721_id = app.store_code(cw721_traits_contract());
+    let module_id = app.store_code(voting_cw721_staked_contract());
+    let nft = app.instantiate_contract(
+        cw721_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &Cw721InstantiateMsg {
+            name: "Traits".to_string(),
+            symbol: "TRAITS".to_string(),
+            minter: CREATOR_ADDR.to_string(),
+        },
+        &[],
+        "cw721_traits",
+        None,
+    )?;
+    let module = app.instantiate_contract(
+        module_id,
+        Addr::unchecked(CREATOR_ADDR),
+        &InstantiateMsg {
+            nft_contract: NftContract::Existing {
+                address: nft.to_string(),
+            },
+            unstaking_duration: None,
+            active_threshold: None,
+        },
+        &[],
+        "cw721_voting",
+        None,
+    )?;
+
+    for (token_id, rarity) in [("1", "legendary"), ("2", "common")] {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            nft.clone(),
+            &Cw721ExecuteMsg::<TraitsExtension, Empty>::Mint {
+                token_id: token_id.to_string(),
+                owner: CREATOR_ADDR.to_string(),
+                token_uri: None,
+                extension: TraitsExtension {
+                    attributes: vec![Trait {
+                        trait_type: "rarity".to_string(),
+                        value: rarity.to_string(),
+                    }],
+                },
+            },
+            &[],
+        )?;
+        stake_nft(&mut app, &nft, &module, CREATOR_ADDR, token_id)?;
+    }
+    app.update_block(next_block);
+    let staked_at = app.block_info().height;
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateTraitWeights {
+            set: vec![TraitWeight {
+                trait_type: "rarity".to_string(),
+                value: "legendary".to_string(),
+                weight: Uint128::new(10),
+            }],
+            remove: vec![],
+        },
+        &[],
+    )?;
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateTokenWeights {
+            set: vec![TokenWeight {
+                token_id: "2".to_string(),
+                weight: Uint128::new(4),
+            }],
+            remove: vec![],
+        },
+        &[],
+    )?;
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateMultiplier {
+            multiplier: Uint128::new(2),
+        },
+        &[],
+    )?;
+    app.update_block(next_block);
+
+    // The new weights are reported, but not yet applied.
+    let nft_weight = |app: &App, token_id: &str| -> anyhow::Result<Uint128> {
+        Ok(app.wrap().query_wasm_smart(
+            &module,
+            &QueryMsg::NftWeight {
+                token_id: token_id.to_string(),
+                collection: None,
+            },
+        )?)
+    };
+    assert_eq!(nft_weight(&app, "1")?, Uint128::new(20));
+    assert_eq!(nft_weight(&app, "2")?, Uint128::new(8));
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(2));
+    assert_eq!(personal, Uint128::new(2));
+
+    let reweigh = |app: &mut App, token_id: &str| {
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            module.clone(),
+            &ExecuteMsg::ReweighNfts {
+                staker: CREATOR_ADDR.to_string(),
+                token_ids: vec![token_id.to_string()],
+                collection: None,
+            },
+            &[],
+        )
+    };
+    reweigh(&mut app, "1")?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(21));
+    assert_eq!(personal, Uint128::new(21));
+
+    reweigh(&mut app, "2")?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(28));
+    assert_eq!(personal, Uint128::new(28));
+
+    // Voting power at past heights is unchanged.
+    let (total, personal) =
+        query_total_and_voting_power(&app, &module, CREATOR_ADDR, Some(staked_at))?;
+    assert_eq!(total, Uint128::new(2));
+    assert_eq!(personal, Uint128::new(2));
+
+    Ok(())
+}
+
+// NFTs from additional collections may be staked once added by the
+// DAO, weigh their multiplier, and are unstaked and claimed using
+// the collection's own unstaking duration.
+#[test]
+fn test_multiple_collections() -> anyhow::Result<()> {
+    let CommonTest {
+        mut app,
+        module,
+        nft,
+    } = setup_test(None);
+    let other = instantiate_cw721_base(&mut app, CREATOR_ADDR, CREATOR_ADDR);
+
+    let add_collection = |app: &mut App, sender: &str, address: &Addr, multiplier: u128| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            module.clone(),
+            &ExecuteMsg::AddCollection {
+                address: address.to_string(),
+                multiplier: Uint128::new(multiplier),
+                unstaking_duration: Some(Duration::Height(1)),
+            },
+            &[],
+        )
+    };
+
+    // NFTs from other collections may not be staked until added.
+    mint_nft(&mut app, &other, CREATOR_ADDR, CREATOR_ADDR, "1")?;
+    let res = stake_nft(&mut app, &other, &module, CREATOR_ADDR, "1");
+    is_error!(res => "Invalid token.");
+
+    let res = add_collection(&mut app, "friend", &other, 3);
+    is_error!(res => "Unauthorized");
+    let res = add_collection(&mut app, CREATOR_ADDR, &other, 0);
+    is_error!(res => "Collection multiplier must be greater than zero");
+    let res = add_collection(&mut app, CREATOR_ADDR, &nft, 3);
+    is_error!(res => "is already the collection in the config");
+    add_collection(&mut app, CREATOR_ADDR, &other, 3)?;
+
+    let collections: Vec<CollectionResponse> = app.wrap().query_wasm_smart(
+        &module,
+        &QueryMsg::Collections {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    assert_eq!(
+        collections,
+        vec![CollectionResponse {
+            address: other.clone(),
+            collection: Collection {
+                multiplier: Uint128::new(3),
+                unstaking_duration: Some(Duration::Height(1)),
+                active: true,
+            },
+        }]
+    );
+
+    mint_and_stake_nft(&mut app, &nft, &module, CREATOR_ADDR, "1")?;
+    stake_nft(&mut app, &other, &module, CREATOR_ADDR, "1")?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(4));
+    assert_eq!(personal, Uint128::new(4));
+
+    let staked: Vec<String> = app.wrap().query_wasm_smart(
+        &module,
+        &QueryMsg::StakedNfts {
+            address: CREATOR_ADDR.to_string(),
+            start_after: None,
+            limit: None,
+            collection: Some(other.to_string()),
+        },
+    )?;
+    assert_eq!(staked, vec!["1".to_string()]);
+
+    // The active threshold counts NFTs from every collection.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateActiveThreshold {
+            new_threshold: Some(ActiveThreshold::AbsoluteCount {
+                count: Uint128::new(2),
+            }),
+        },
+        &[],
+    )?;
+    let is_active: IsActiveResponse = app
+        .wrap()
+        .query_wasm_smart(&module, &QueryMsg::IsActive {})?;
+    assert!(is_active.active);
+
+    // The collection in the config has a multiplier too. Staked NFTs
+    // pick it up once reweighed.
+    let res = app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateMultiplier {
+            multiplier: Uint128::zero(),
+        },
+        &[],
+    );
+    is_error!(res => "Collection multiplier must be greater than zero");
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::UpdateMultiplier {
+            multiplier: Uint128::new(2),
+        },
+        &[],
+    )?;
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        module.clone(),
+        &ExecuteMsg::ReweighNfts {
+            staker: CREATOR_ADDR.to_string(),
+            token_ids: vec!["1".to_string()],
+            collection: None,
+        },
+        &[],
+    )?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(5));
+    assert_eq!(personal, Uint128::new(5));
+
+    // Removed collections may not be staked. NFTs already staked keep
+    // their voting power until reweighed, and may still be unstaked.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::RemoveCollection {
+            address: other.to_string(),
+        },
+        &[],
+    )?;
+    mint_nft(&mut app, &other, CREATOR_ADDR, CREATOR_ADDR, "2")?;
+    let res = stake_nft(&mut app, &other, &module, CREATOR_ADDR, "2");
+    is_error!(res => "Invalid token.");
+    app.update_block(next_block);
+    let (total, _) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(5));
+
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        module.clone(),
+        &ExecuteMsg::ReweighNfts {
+            staker: CREATOR_ADDR.to_string(),
+            token_ids: vec!["1".to_string()],
+            collection: Some(other.to_string()),
+        },
+        &[],
+    )?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(2));
+    assert_eq!(personal, Uint128::new(2));
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::Unstake {
+            token_ids: vec!["1".to_string()],
+            collection: Some(other.to_string()),
+        },
+        &[],
+    )?;
+    app.update_block(next_block);
+    let (total, personal) = query_total_and_voting_power(&app, &module, CREATOR_ADDR, None)?;
+    assert_eq!(total, Uint128::new(2));
+    assert_eq!(personal, Uint128::new(2));
+
+    // The claim is for the other collection only.
+    let res = claim_nfts(&mut app, &module, CREATOR_ADDR);
+    is_error!(res => "Nothing to claim");
+    let claims: NftClaimsResponse = app.wrap().query_wasm_smart(
+        &module,
+        &QueryMsg::NftClaims {
+            address: CREATOR_ADDR.to_string(),
+            collection: Some(other.to_string()),
+        },
+    )?;
+    assert_eq!(claims.nft_claims.len(), 1);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &ExecuteMsg::ClaimNfts {
+            collection: Some(other.to_string()),
+        },
+        &[],
+    )?;
+    let owner = query_nft_owner(&app, &other, "1")?;
+    assert_eq!(owner.owner, CREATOR_ADDR.to_string());
+
+    Ok(())
+}
+
+// The DAO can confiscate staked NFTs and NFTs pending claim. Slashed
+// NFTs stop counting towards voting power and their claims are
+// removed.
+#[test]
+fn test_slash() -> anyhow::Result<()> {
+    let CommonTest {
+        mut app,
+        module,
+        nft,
+    } = setup_test(Some(Duration::Height(3)));
+
+    for token_id in ["1", "2", "3"] {
+        mint_nft(&mut app, &nft, CREATOR_ADDR, "staker", token_id)?;
+        stake_nft(&mut app, &nft, &module, "staker", token_id)?;
+    }
+    unstake_nfts(&mut app, &module, "staker", &["2"])?;
+    app.update_block(next_block);
+
+    let slash = |token_ids: &[&str], destination: SlashDestination| ExecuteMsg::Slash {
+        staker: "staker".to_string(),
+        token_ids: token_ids.iter().map(|s| s.to_string()).collect(),
+        collection: None,
+        destination,
+    };
+
+    let res = app.execute_contract(
+        Addr::unchecked("staker"),
+        module.clone(),
+        &slash(&["1"], SlashDestination::Dao {}),
+        &[],
+    );
+    is_error!(res => "Unauthorized");
+
+    let res = app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &slash(&[], SlashDestination::Dao {}),
+        &[],
+    );
+    is_error!(res => "Can't slash zero NFTs.");
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &slash(&["1", "2"], SlashDestination::Dao {}),
+        &[],
+    )?;
+    app.update_block(next_block);
+
+    let (total, personal) = query_total_and_voting_power(&app, &module, "staker", None)?;
+    assert_eq!(total, Uint128::new(1));
+    assert_eq!(personal, Uint128::new(1));
+    let claims = query_claims(&app, &module, "staker")?;
+    assert_eq!(claims.nft_claims, vec![]);
+    assert_eq!(query_nft_owner(&app, &nft, "1")?.owner, CREATOR_ADDR);
+    assert_eq!(query_nft_owner(&app, &nft, "2")?.owner, CREATOR_ADDR);
+
+    // Already slashed NFTs can not be slashed again.
+    let res = app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &slash(&["1"], SlashDestination::Burn {}),
+        &[],
+    );
+    assert!(res.is_err());
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.clone(),
+        &slash(&["3"], SlashDestination::Burn {}),
+        &[],
+    )?;
+    app.update_block(next_block);
+
+    let (total, personal) = query_total_and_voting_power(&app, &module, "staker", None)?;
+    assert_eq!(total, Uint128::zero());
+    assert_eq!(personal, Uint128::zero());
+    assert!(query_nft_owner(&app, &nft, "3").is_err());
+
+    Ok(())
+}
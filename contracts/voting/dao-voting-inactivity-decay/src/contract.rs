@@ -0,0 +1,452 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, BlockInfo, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdResult, Storage, Timestamp, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_storage_plus::Bound;
+use cw_utils::Duration;
+use dao_hooks::{proposal::ProposalHookMsg, vote::VoteHookMsg};
+use dao_interface::state::ProposalModule;
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+use dao_voting::voting::{get_total_power, get_voting_power};
+
+use crate::error::ContractError;
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, MemberActivity, MemberResponse, MigrateMsg, QueryMsg,
+};
+use crate::state::{
+    Activity, DecayConfig, InactivityPeriod, ACTIVITY, BLOCK_TIMES, DAO, DECAY_CONFIG,
+    MAX_TRACKED_MEMBERS, PROPOSAL_COUNT, TRACKED_MEMBERS, VOTING_MODULE,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-inactivity-decay";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The number of proposal modules requested per query when checking
+/// that a hook was sent by one of the DAO's proposal modules.
+const PROPOSAL_MODULES_PAGE_SIZE: u32 = 30;
+
+// Settings for query pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let voting_module = deps.api.addr_validate(&msg.voting_module)?;
+    // Make sure the voting module is one.
+    get_total_power(deps.as_ref(), &voting_module, None)?;
+
+    DAO.save(deps.storage, &info.sender)?;
+    VOTING_MODULE.save(deps.storage, &voting_module)?;
+    DECAY_CONFIG.save(deps.storage, &validate_decay(msg.decay)?, env.block.height)?;
+    PROPOSAL_COUNT.save(deps.storage, &0, env.block.height)?;
+    BLOCK_TIMES.save(deps.storage, env.block.height, &env.block.time)?;
+    TRACKED_MEMBERS.save(deps.storage, &vec![], env.block.height)?;
+    for member in &msg.members {
+        let member = deps.api.addr_validate(member)?;
+        track_member(deps.storage, &env.block, &member)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("voting_module", voting_module)
+        .add_attribute("members", msg.members.len().to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::VoteHook(msg) => execute_vote_hook(deps, env, info, msg),
+        ExecuteMsg::ProposalHook(msg) => execute_proposal_hook(deps, env, info, msg),
+        ExecuteMsg::TrackMembers { addresses } => execute_track_members(deps, env, info, addresses),
+        ExecuteMsg::UntrackMembers { addresses } => {
+            execute_untrack_members(deps, env, info, addresses)
+        }
+        ExecuteMsg::UpdateDecayConfig { decay } => {
+            execute_update_decay_config(deps, env, info, decay)
+        }
+    }
+}
+
+pub fn execute_vote_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: VoteHookMsg,
+) -> Result<Response, ContractError> {
+    assert_proposal_module(deps.as_ref(), &info.sender)?;
+
+    let VoteHookMsg::NewVote { voter, .. } = msg;
+    let voter = deps.api.addr_validate(&voter)?;
+    // Only members the DAO has chosen to track are recorded, so
+    // voters can not take up tracking slots.
+    let tracked = ACTIVITY.has(deps.storage, &voter);
+    if tracked {
+        record_activity(deps.storage, &env.block, &voter)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "vote_hook")
+        .add_attribute("voter", voter)
+        .add_attribute("tracked", tracked.to_string()))
+}
+
+pub fn execute_proposal_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposalHookMsg,
+) -> Result<Response, ContractError> {
+    assert_proposal_module(deps.as_ref(), &info.sender)?;
+
+    if let ProposalHookMsg::NewProposal { .. } = msg {
+        let count = PROPOSAL_COUNT.load(deps.storage)? + 1;
+        PROPOSAL_COUNT.save(deps.storage, &count, env.block.height)?;
+        BLOCK_TIMES.save(deps.storage, env.block.height, &env.block.time)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "proposal_hook"))
+}
+
+pub fn execute_track_members(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut tracked = 0u64;
+    for addr in addresses {
+        let addr = deps.api.addr_validate(&addr)?;
+        if !ACTIVITY.has(deps.storage, &addr) {
+            track_member(deps.storage, &env.block, &addr)?;
+            tracked += 1;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "track_members")
+        .add_attribute("tracked", tracked.to_string()))
+}
+
+pub fn execute_untrack_members(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut tracked = TRACKED_MEMBERS.load(deps.storage)?;
+    let mut untracked = 0u64;
+    for addr in addresses {
+        let addr = deps.api.addr_validate(&addr)?;
+        if ACTIVITY.has(deps.storage, &addr) {
+            ACTIVITY.remove(deps.storage, &addr, env.block.height)?;
+            tracked.retain(|member| *member != addr);
+            untracked += 1;
+        }
+    }
+    TRACKED_MEMBERS.save(deps.storage, &tracked, env.block.height)?;
+    BLOCK_TIMES.save(deps.storage, env.block.height, &env.block.time)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "untrack_members")
+        .add_attribute("untracked", untracked.to_string()))
+}
+
+pub fn execute_update_decay_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    decay: DecayConfig,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    DECAY_CONFIG.save(deps.storage, &validate_decay(decay)?, env.block.height)?;
+    BLOCK_TIMES.save(deps.storage, env.block.height, &env.block.time)?;
+
+    Ok(Response::new().add_attribute("action", "update_decay_config"))
+}
+
+fn validate_decay(decay: DecayConfig) -> Result<DecayConfig, ContractError> {
+    if decay.rate.is_zero() || decay.rate > Decimal::one() {
+        return Err(ContractError::InvalidRate {});
+    }
+    if decay.floor >= Decimal::one() {
+        return Err(ContractError::InvalidFloor {});
+    }
+    if matches!(
+        decay.period,
+        InactivityPeriod::Proposals { count: 0 }
+            | InactivityPeriod::Duration {
+                duration: Duration::Height(0) | Duration::Time(0)
+            }
+    ) {
+        return Err(ContractError::ZeroPeriod {});
+    }
+    Ok(decay)
+}
+
+/// Errors unless ADDR is one of the DAO's proposal modules.
+fn assert_proposal_module(deps: Deps, addr: &Addr) -> Result<(), ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    let mut start_after = None;
+    loop {
+        let modules: Vec<ProposalModule> = deps.querier.query_wasm_smart(
+            &dao,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after,
+                limit: Some(PROPOSAL_MODULES_PAGE_SIZE),
+            },
+        )?;
+        if modules.iter().any(|module| module.address == *addr) {
+            return Ok(());
+        }
+        match modules.last() {
+            Some(last) if modules.len() == PROPOSAL_MODULES_PAGE_SIZE as usize => {
+                start_after = Some(last.address.to_string())
+            }
+            _ => return Err(ContractError::Unauthorized {}),
+        }
+    }
+}
+
+/// Starts tracking ADDR, who must not already be tracked, as active
+/// in the current block. Errors if `MAX_TRACKED_MEMBERS` members are
+/// already tracked.
+fn track_member(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+) -> Result<(), ContractError> {
+    let mut tracked = TRACKED_MEMBERS.load(storage)?;
+    if tracked.len() as u64 >= MAX_TRACKED_MEMBERS {
+        return Err(ContractError::TooManyMembers {
+            max: MAX_TRACKED_MEMBERS,
+        });
+    }
+    tracked.push(addr.clone());
+    TRACKED_MEMBERS.save(storage, &tracked, block.height)?;
+    record_activity(storage, block, addr)?;
+    Ok(())
+}
+
+/// Marks ADDR as having been active in the current block.
+fn record_activity(storage: &mut dyn Storage, block: &BlockInfo, addr: &Addr) -> StdResult<()> {
+    let activity = Activity {
+        proposals: PROPOSAL_COUNT.load(storage)?,
+        height: block.height,
+        time: block.time,
+    };
+    ACTIVITY.save(storage, addr, &activity, block.height)?;
+    BLOCK_TIMES.save(storage, block.height, &block.time)?;
+    Ok(())
+}
+
+/// Returns the time of the block at HEIGHT, or the time of the last
+/// block before it seen by this contract if it is in the past.
+fn time_at_height(storage: &dyn Storage, env: &Env, height: u64) -> StdResult<Option<Timestamp>> {
+    if height >= env.block.height {
+        return Ok(Some(env.block.time));
+    }
+    BLOCK_TIMES
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()
+        .map(|entry| entry.map(|(_, time)| time))
+}
+
+/// Returns ADDR's activity and the fraction of their voting power
+/// kept at HEIGHT.
+pub fn decay_multiplier(
+    storage: &dyn Storage,
+    env: &Env,
+    addr: &Addr,
+    height: u64,
+) -> StdResult<(Option<Activity>, Decimal)> {
+    let activity = ACTIVITY.may_load_at_height(storage, addr, height)?;
+    let (config, last_active) = match (
+        DECAY_CONFIG.may_load_at_height(storage, height)?,
+        activity.clone(),
+    ) {
+        (Some(config), Some(last_active)) => (config, last_active),
+        _ => return Ok((activity, Decimal::one())),
+    };
+
+    let periods = match config.period {
+        InactivityPeriod::Proposals { count } => {
+            let proposals = PROPOSAL_COUNT
+                .may_load_at_height(storage, height)?
+                .unwrap_or_default();
+            proposals.saturating_sub(last_active.proposals) / count
+        }
+        InactivityPeriod::Duration {
+            duration: Duration::Height(blocks),
+        } => height.saturating_sub(last_active.height) / blocks,
+        InactivityPeriod::Duration {
+            duration: Duration::Time(seconds),
+        } => match time_at_height(storage, env, height)? {
+            Some(time) => time.seconds().saturating_sub(last_active.time.seconds()) / seconds,
+            None => 0,
+        },
+    };
+
+    let decay = config
+        .rate
+        .checked_mul(Decimal::from_ratio(periods, 1u64))
+        .unwrap_or(Decimal::MAX);
+    let multiplier = Decimal::one()
+        .checked_sub(decay)
+        .unwrap_or_default()
+        .max(config.floor);
+    Ok((activity, multiplier))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            query_voting_power_at_height(deps, env, address, height)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => query_total_power_at_height(deps, env, height),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
+        QueryMsg::VotingModule {} => to_json_binary(&VOTING_MODULE.load(deps.storage)?),
+        QueryMsg::DecayConfig {} => to_json_binary(&DECAY_CONFIG.load(deps.storage)?),
+        QueryMsg::Member { address, height } => query_member(deps, env, address, height),
+        QueryMsg::ListMembers { start_after, limit } => {
+            query_list_members(deps, start_after, limit)
+        }
+    }
+}
+
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+    let voting_module = VOTING_MODULE.load(deps.storage)?;
+    let power = get_voting_power(deps, addr.clone(), &voting_module, Some(height))?;
+    let (_, multiplier) = decay_multiplier(deps.storage, &env, &addr, height)?;
+
+    to_json_binary(&VotingPowerAtHeightResponse {
+        power: power * multiplier,
+        height,
+    })
+}
+
+/// The total power is the voting module's total power less the
+/// voting power every tracked member has lost to decay. This queries
+/// the voting module once for each decayed member, of which there
+/// are at most `MAX_TRACKED_MEMBERS`.
+pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) -> StdResult<Binary> {
+    let height = height.unwrap_or(env.block.height);
+    let voting_module = VOTING_MODULE.load(deps.storage)?;
+    let total = get_total_power(deps, &voting_module, Some(height))?;
+
+    let mut decayed = Uint128::zero();
+    let tracked = TRACKED_MEMBERS
+        .may_load_at_height(deps.storage, height)?
+        .unwrap_or_default();
+    for addr in tracked {
+        let (_, multiplier) = decay_multiplier(deps.storage, &env, &addr, height)?;
+        if multiplier < Decimal::one() {
+            let power = get_voting_power(deps, addr, &voting_module, Some(height))?;
+            decayed += power - power * multiplier;
+        }
+    }
+
+    to_json_binary(&TotalPowerAtHeightResponse {
+        power: total.saturating_sub(decayed),
+        height,
+    })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_json_binary(&dao_interface::voting::InfoResponse { info })
+}
+
+pub fn query_member(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let addr = deps.api.addr_validate(&address)?;
+    let height = height.unwrap_or(env.block.height);
+    let (activity, multiplier) = decay_multiplier(deps.storage, &env, &addr, height)?;
+    to_json_binary(&MemberResponse {
+        activity,
+        multiplier,
+        height,
+    })
+}
+
+pub fn query_list_members(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let start_after = start_after
+        .map(|a| deps.api.addr_validate(&a))
+        .transpose()?;
+    let members = ACTIVITY
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|r| r.map(|(addr, activity)| MemberActivity { addr, activity }))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&members)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
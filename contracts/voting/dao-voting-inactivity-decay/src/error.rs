@@ -0,0 +1,23 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Decay rate must be greater than zero and no more than one")]
+    InvalidRate {},
+
+    #[error("Decay floor must be less than one")]
+    InvalidFloor {},
+
+    #[error("Inactivity period must be greater than zero")]
+    ZeroPeriod {},
+
+    #[error("At most ({max}) members may be tracked")]
+    TooManyMembers { max: u64 },
+}
@@ -0,0 +1,82 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal};
+use dao_dao_macros::voting_module_query;
+use dao_hooks::{proposal::ProposalHookMsg, vote::VoteHookMsg};
+
+use crate::state::{Activity, DecayConfig};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The voting module whose voting power is decayed.
+    pub voting_module: String,
+    pub decay: DecayConfig,
+    /// Members to start tracking. Members may be tracked and untracked
+    /// later with `TrackMembers` and `UntrackMembers`, up to
+    /// `MAX_TRACKED_MEMBERS`. Untracked members' voting power does
+    /// not decay.
+    pub members: Vec<String>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Records a vote by a tracked member. Votes by untracked members
+    /// are ignored. Only callable by the DAO's proposal modules, which
+    /// must have this contract registered as a vote hook.
+    VoteHook(VoteHookMsg),
+    /// Counts new proposals. Only callable by the DAO's proposal
+    /// modules, which must have this contract registered as a
+    /// proposal hook if inactivity is measured in proposals.
+    ProposalHook(ProposalHookMsg),
+    /// Starts tracking the activity of members, as if they were
+    /// active in the current block. Members already tracked are
+    /// unchanged. Errors if this would track more than
+    /// `MAX_TRACKED_MEMBERS` members. Only callable by the DAO.
+    TrackMembers { addresses: Vec<String> },
+    /// Stops tracking the activity of members, restoring their full
+    /// voting power from the current block and freeing their slots.
+    /// Addresses not tracked are ignored. Only callable by the DAO.
+    UntrackMembers { addresses: Vec<String> },
+    /// Updates the decay config. Voting power at past heights is
+    /// unchanged. Only callable by the DAO.
+    UpdateDecayConfig { decay: DecayConfig },
+}
+
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(cosmwasm_std::Addr)]
+    VotingModule {},
+    #[returns(crate::state::DecayConfig)]
+    DecayConfig {},
+    /// Returns ADDRESS's activity and the fraction of their voting
+    /// power kept at HEIGHT.
+    #[returns(MemberResponse)]
+    Member {
+        address: String,
+        height: Option<u64>,
+    },
+    /// Lists tracked members and their activity, ordered by address.
+    #[returns(Vec<MemberActivity>)]
+    ListMembers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MemberResponse {
+    /// `None` if the member is not tracked.
+    pub activity: Option<Activity>,
+    pub multiplier: Decimal,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct MemberActivity {
+    pub addr: Addr,
+    pub activity: Activity,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,83 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
+use cw_utils::Duration;
+
+/// How inactivity is measured.
+#[cw_serde]
+pub enum InactivityPeriod {
+    /// The number of proposals created by the DAO's proposal modules
+    /// since a member last voted.
+    Proposals { count: u64 },
+    /// The number of blocks or seconds since a member last voted.
+    Duration { duration: Duration },
+}
+
+#[cw_serde]
+pub struct DecayConfig {
+    /// How long a member may go without voting before their voting
+    /// power decays. Their voting power decays again each time
+    /// another period passes without them voting.
+    pub period: InactivityPeriod,
+    /// The fraction of a member's voting power lost each period.
+    pub rate: Decimal,
+    /// The fraction of a member's voting power kept no matter how
+    /// long they have been inactive.
+    pub floor: Decimal,
+}
+
+/// When a member last voted, or started being tracked if they have
+/// not voted since.
+#[cw_serde]
+pub struct Activity {
+    /// The number of proposals that had been created.
+    pub proposals: u64,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+pub const DAO: Item<Addr> = Item::new("dao");
+/// The voting module whose voting power is decayed.
+pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
+
+/// The decay config as a function of block height, so that changing
+/// it does not change voting power at past heights.
+pub const DECAY_CONFIG: SnapshotItem<DecayConfig> = SnapshotItem::new(
+    "decay_config",
+    "decay_config__checkpoints",
+    "decay_config__changelog",
+    Strategy::EveryBlock,
+);
+/// The number of proposals created by the DAO's proposal modules
+/// since this contract was instantiated, as a function of block
+/// height.
+pub const PROPOSAL_COUNT: SnapshotItem<u64> = SnapshotItem::new(
+    "proposal_count",
+    "proposal_count__checkpoints",
+    "proposal_count__changelog",
+    Strategy::EveryBlock,
+);
+/// The activity of tracked members as a function of block
+/// height. Members without an entry are not tracked and their voting
+/// power does not decay.
+pub const ACTIVITY: SnapshotMap<&Addr, Activity> = SnapshotMap::new(
+    "activity",
+    "activity__checkpoints",
+    "activity__changelog",
+    Strategy::EveryBlock,
+);
+/// The maximum number of members that may be tracked. Computing the
+/// total power queries the voting module once for each decayed
+/// member, so this bounds the cost of that query.
+pub const MAX_TRACKED_MEMBERS: u64 = 100;
+/// The members in `ACTIVITY` as a function of block height, so that
+/// the total power at past heights includes members since untracked.
+pub const TRACKED_MEMBERS: SnapshotItem<Vec<Addr>> = SnapshotItem::new(
+    "tracked_members",
+    "tracked_members__checkpoints",
+    "tracked_members__changelog",
+    Strategy::EveryBlock,
+);
+/// Block times seen by this contract, keyed by height. Used to find
+/// the time at past heights when inactivity is measured in seconds.
+pub const BLOCK_TIMES: Map<u64, Timestamp> = Map::new("block_times");
@@ -0,0 +1,524 @@
+use cosmwasm_std::{to_json_binary, Addr, Decimal, Empty, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{next_block, App, AppResponse, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_hooks::{proposal::ProposalHookMsg, vote::VoteHookMsg};
+use dao_interface::{
+    state::ProposalModule,
+    voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse},
+};
+use dao_testing::{
+    contracts::proposal_single_contract, helpers::instantiate_with_cw4_groups_governance,
+};
+use dao_voting::{
+    pre_propose::PreProposeInfo,
+    threshold::{PercentageThreshold, Threshold},
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, MemberActivity, MemberResponse, QueryMsg},
+    state::{DecayConfig, InactivityPeriod, MAX_TRACKED_MEMBERS},
+    ContractError,
+};
+
+const WHALE: &str = "whale";
+const VOTER: &str = "voter";
+
+fn decay_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    )
+    .with_migrate(crate::contract::migrate);
+    Box::new(contract)
+}
+
+struct TestSetup {
+    app: App,
+    dao: Addr,
+    proposal_module: Addr,
+    voting_module: Addr,
+    decay_id: u64,
+}
+
+/// Creates a DAO with a cw4 voting module in which the whale has 100
+/// voting power and the voter 10.
+fn setup_test() -> TestSetup {
+    let mut app = App::default();
+    let proposal_id = app.store_code(proposal_single_contract());
+    let decay_id = app.store_code(decay_contract());
+
+    let dao = instantiate_with_cw4_groups_governance(
+        &mut app,
+        proposal_id,
+        to_json_binary(&dao_proposal_single::msg::InstantiateMsg {
+            threshold: Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Majority {},
+            },
+            max_voting_period: Duration::Height(10),
+            min_voting_period: None,
+            only_members_execute: false,
+            allow_revoting: false,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            close_proposal_on_execution_failure: true,
+            veto: None,
+        })
+        .unwrap(),
+        Some(vec![
+            Cw20Coin {
+                address: WHALE.to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: VOTER.to_string(),
+                amount: Uint128::new(10),
+            },
+        ]),
+    );
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(&dao, &dao_interface::msg::QueryMsg::VotingModule {})
+        .unwrap();
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    TestSetup {
+        app,
+        dao,
+        proposal_module: proposal_modules[0].address.clone(),
+        voting_module,
+        decay_id,
+    }
+}
+
+fn instantiate_decay(
+    app: &mut App,
+    setup: (&Addr, &Addr, u64),
+    decay: DecayConfig,
+) -> anyhow::Result<Addr> {
+    let (dao, voting_module, decay_id) = setup;
+    app.instantiate_contract(
+        decay_id,
+        dao.clone(),
+        &InstantiateMsg {
+            voting_module: voting_module.to_string(),
+            decay,
+            members: vec![WHALE.to_string(), VOTER.to_string()],
+        },
+        &[],
+        "inactivity decay",
+        None,
+    )
+}
+
+fn new_proposal(
+    app: &mut App,
+    sender: &Addr,
+    decay: &Addr,
+    id: u64,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        decay.clone(),
+        &ExecuteMsg::ProposalHook(ProposalHookMsg::NewProposal {
+            id,
+            proposer: VOTER.to_string(),
+        }),
+        &[],
+    )
+}
+
+fn vote(
+    app: &mut App,
+    sender: &Addr,
+    decay: &Addr,
+    id: u64,
+    voter: &str,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        decay.clone(),
+        &ExecuteMsg::VoteHook(VoteHookMsg::NewVote {
+            proposal_id: id,
+            voter: voter.to_string(),
+            vote: "yes".to_string(),
+        }),
+        &[],
+    )
+}
+
+fn query_powers(app: &App, decay: &Addr, height: Option<u64>) -> (Uint128, Uint128, Uint128) {
+    let voting_power = |address: &str| {
+        let res: VotingPowerAtHeightResponse = app
+            .wrap()
+            .query_wasm_smart(
+                decay,
+                &QueryMsg::VotingPowerAtHeight {
+                    address: address.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        res.power
+    };
+    let total: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(decay, &QueryMsg::TotalPowerAtHeight { height })
+        .unwrap();
+    (voting_power(WHALE), voting_power(VOTER), total.power)
+}
+
+#[test]
+fn test_decay_by_proposals() {
+    let TestSetup {
+        mut app,
+        dao,
+        proposal_module,
+        voting_module,
+        decay_id,
+    } = setup_test();
+    let decay = instantiate_decay(
+        &mut app,
+        (&dao, &voting_module, decay_id),
+        DecayConfig {
+            period: InactivityPeriod::Proposals { count: 2 },
+            rate: Decimal::percent(25),
+            floor: Decimal::percent(50),
+        },
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    // Only the DAO's proposal modules may send hooks.
+    let err: ContractError = new_proposal(&mut app, &Addr::unchecked(VOTER), &decay, 1)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = vote(&mut app, &dao, &decay, 1, VOTER)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    assert_eq!(
+        query_powers(&app, &decay, None),
+        (Uint128::new(100), Uint128::new(10), Uint128::new(110))
+    );
+
+    // The voter votes on every proposal and the whale on none.
+    for id in 1..=6 {
+        new_proposal(&mut app, &proposal_module, &decay, id).unwrap();
+        vote(&mut app, &proposal_module, &decay, id, VOTER).unwrap();
+        app.update_block(next_block);
+
+        let whale = match id {
+            1 => 100,
+            2 | 3 => 75,
+            _ => 50,
+        };
+        assert_eq!(
+            query_powers(&app, &decay, None),
+            (
+                Uint128::new(whale),
+                Uint128::new(10),
+                Uint128::new(whale + 10)
+            )
+        );
+    }
+    let decayed_at = app.block_info().height;
+
+    let member: MemberResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &decay,
+            &QueryMsg::Member {
+                address: WHALE.to_string(),
+                height: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(member.multiplier, Decimal::percent(50));
+    assert_eq!(member.activity.unwrap().proposals, 0);
+
+    // Voting restores the whale's voting power without changing
+    // voting power at past heights.
+    vote(&mut app, &proposal_module, &decay, 6, WHALE).unwrap();
+    app.update_block(next_block);
+    assert_eq!(
+        query_powers(&app, &decay, None),
+        (Uint128::new(100), Uint128::new(10), Uint128::new(110))
+    );
+    assert_eq!(
+        query_powers(&app, &decay, Some(decayed_at)),
+        (Uint128::new(50), Uint128::new(10), Uint128::new(60))
+    );
+}
+
+#[test]
+fn test_decay_by_height() {
+    let TestSetup {
+        mut app,
+        dao,
+        proposal_module,
+        voting_module,
+        decay_id,
+    } = setup_test();
+    let decay = instantiate_decay(
+        &mut app,
+        (&dao, &voting_module, decay_id),
+        DecayConfig {
+            period: InactivityPeriod::Duration {
+                duration: Duration::Height(10),
+            },
+            rate: Decimal::percent(10),
+            floor: Decimal::zero(),
+        },
+    )
+    .unwrap();
+    let start = app.block_info().height;
+
+    app.update_block(|block| block.height += 10);
+    vote(&mut app, &proposal_module, &decay, 1, VOTER).unwrap();
+    app.update_block(|block| block.height += 15);
+    assert_eq!(
+        query_powers(&app, &decay, None),
+        (Uint128::new(80), Uint128::new(9), Uint128::new(89))
+    );
+
+    // Only the DAO may update the config, and updates do not change
+    // voting power at past heights.
+    let update = ExecuteMsg::UpdateDecayConfig {
+        decay: DecayConfig {
+            period: InactivityPeriod::Duration {
+                duration: Duration::Height(100),
+            },
+            rate: Decimal::percent(10),
+            floor: Decimal::zero(),
+        },
+    };
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(WHALE), decay.clone(), &update, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(dao.clone(), decay.clone(), &update, &[])
+        .unwrap();
+    let updated_at = app.block_info().height;
+    app.update_block(next_block);
+
+    assert_eq!(
+        query_powers(&app, &decay, None),
+        (Uint128::new(100), Uint128::new(10), Uint128::new(110))
+    );
+    assert_eq!(
+        query_powers(&app, &decay, Some(updated_at)),
+        (Uint128::new(80), Uint128::new(9), Uint128::new(89))
+    );
+    assert_eq!(
+        query_powers(&app, &decay, Some(start + 10)),
+        (Uint128::new(90), Uint128::new(9), Uint128::new(99))
+    );
+}
+
+#[test]
+fn test_max_tracked_members() {
+    let TestSetup {
+        mut app,
+        dao,
+        proposal_module,
+        voting_module,
+        decay_id,
+    } = setup_test();
+    let decay = instantiate_decay(
+        &mut app,
+        (&dao, &voting_module, decay_id),
+        DecayConfig {
+            period: InactivityPeriod::Proposals { count: 1 },
+            rate: Decimal::percent(10),
+            floor: Decimal::zero(),
+        },
+    )
+    .unwrap();
+
+    // The whale and the voter are already tracked.
+    let addresses = (2..MAX_TRACKED_MEMBERS)
+        .map(|i| format!("member{i:03}"))
+        .collect::<Vec<_>>();
+    app.execute_contract(
+        dao.clone(),
+        decay.clone(),
+        &ExecuteMsg::TrackMembers {
+            addresses: addresses.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            dao.clone(),
+            decay.clone(),
+            &ExecuteMsg::TrackMembers {
+                addresses: vec!["newcomer".to_string()],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::TooManyMembers {
+            max: MAX_TRACKED_MEMBERS
+        }
+    );
+
+    // Voting does not track new members.
+    vote(&mut app, &proposal_module, &decay, 1, "newcomer").unwrap();
+    let member = |app: &App, address: &str, height: Option<u64>| {
+        let res: MemberResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &decay,
+                &QueryMsg::Member {
+                    address: address.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        res.activity
+    };
+    assert_eq!(member(&app, "newcomer", None), None);
+
+    // Only the DAO may untrack members, which frees their slots and
+    // restores their full voting power.
+    new_proposal(&mut app, &proposal_module, &decay, 1).unwrap();
+    app.update_block(next_block);
+    assert_eq!(
+        query_powers(&app, &decay, None),
+        (Uint128::new(90), Uint128::new(9), Uint128::new(99))
+    );
+    let untrack = ExecuteMsg::UntrackMembers {
+        addresses: vec![WHALE.to_string(), "unknown".to_string()],
+    };
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(WHALE), decay.clone(), &untrack, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(dao.clone(), decay.clone(), &untrack, &[])
+        .unwrap();
+    let untracked_at = app.block_info().height;
+    app.update_block(next_block);
+    assert_eq!(member(&app, WHALE, None), None);
+    assert!(member(&app, WHALE, Some(untracked_at)).is_some());
+    assert_eq!(
+        query_powers(&app, &decay, None),
+        (Uint128::new(100), Uint128::new(9), Uint128::new(109))
+    );
+    assert_eq!(
+        query_powers(&app, &decay, Some(untracked_at)),
+        (Uint128::new(90), Uint128::new(9), Uint128::new(99))
+    );
+
+    app.execute_contract(
+        dao.clone(),
+        decay.clone(),
+        &ExecuteMsg::TrackMembers {
+            addresses: vec!["newcomer".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    vote(&mut app, &proposal_module, &decay, 1, "newcomer").unwrap();
+    assert!(member(&app, "newcomer", None).is_some());
+
+    // Listing members is paginated.
+    let members: Vec<MemberActivity> = app
+        .wrap()
+        .query_wasm_smart(
+            &decay,
+            &QueryMsg::ListMembers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(members.len(), 10);
+    let members: Vec<MemberActivity> = app
+        .wrap()
+        .query_wasm_smart(
+            &decay,
+            &QueryMsg::ListMembers {
+                start_after: None,
+                limit: Some(u32::MAX),
+            },
+        )
+        .unwrap();
+    assert_eq!(members.len(), 30);
+}
+
+#[test]
+fn test_invalid_decay_config() {
+    let TestSetup {
+        mut app,
+        dao,
+        voting_module,
+        decay_id,
+        ..
+    } = setup_test();
+
+    let mut instantiate = |period: InactivityPeriod, rate: u64, floor: u64| -> ContractError {
+        instantiate_decay(
+            &mut app,
+            (&dao, &voting_module, decay_id),
+            DecayConfig {
+                period,
+                rate: Decimal::percent(rate),
+                floor: Decimal::percent(floor),
+            },
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+
+    let proposals = || InactivityPeriod::Proposals { count: 1 };
+    assert_eq!(
+        instantiate(proposals(), 0, 0),
+        ContractError::InvalidRate {}
+    );
+    assert_eq!(
+        instantiate(proposals(), 101, 0),
+        ContractError::InvalidRate {}
+    );
+    assert_eq!(
+        instantiate(proposals(), 10, 100),
+        ContractError::InvalidFloor {}
+    );
+    assert_eq!(
+        instantiate(InactivityPeriod::Proposals { count: 0 }, 10, 0),
+        ContractError::ZeroPeriod {}
+    );
+    assert_eq!(
+        instantiate(
+            InactivityPeriod::Duration {
+                duration: Duration::Time(0)
+            },
+            10,
+            0
+        ),
+        ContractError::ZeroPeriod {}
+    );
+}
@@ -2,11 +2,12 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    coins, from_json, to_json_binary, BankMsg, BankQuery, Binary, Coin, CosmosMsg, Deps, DepsMut,
-    Env, MessageInfo, Order, Reply, Response, StdResult, SubMsg, Uint128, Uint256, WasmMsg,
+    coins, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage, SubMsg,
+    Uint128, Uint256, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
-use cw_controllers::ClaimsResponse;
+use cw_controllers::{Claim, ClaimsResponse};
 use cw_storage_plus::Bound;
 use cw_tokenfactory_issuer::msg::{
     DenomUnit, ExecuteMsg as IssuerExecuteMsg, InstantiateMsg as IssuerInstantiateMsg, Metadata,
@@ -14,7 +15,7 @@ use cw_tokenfactory_issuer::msg::{
 use cw_utils::{
     maybe_addr, must_pay, parse_reply_execute_data, parse_reply_instantiate_data, Duration,
 };
-use dao_hooks::stake::{stake_hook_msgs, unstake_hook_msgs};
+use dao_hooks::stake::{slash_hook_msgs, stake_hook_msgs, unstake_hook_msgs};
 use dao_interface::{
     state::ModuleInstantiateCallback,
     token::{InitialBalance, NewTokenInfo, TokenFactoryCallback},
@@ -33,11 +34,14 @@ use dao_voting::{
 use crate::error::ContractError;
 use crate::msg::{
     ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    StakerBalanceResponse, TokenInfo,
+    StakeableDenomResponse, StakerBalanceResponse, TokenInfo,
 };
 use crate::state::{
-    Config, ACTIVE_THRESHOLD, CLAIMS, CONFIG, DAO, DENOM, HOOKS, MAX_CLAIMS, STAKED_BALANCES,
-    STAKED_TOTAL, TOKEN_INSTANTIATION_INFO, TOKEN_ISSUER_CONTRACT,
+    Config, InstantUnstakeConfig, PenaltyDestination, StakeableDenom, ACTIVE_THRESHOLD, CLAIMS,
+    CONFIG, DAO, DENOM, DENOM_CLAIMS, DENOM_STAKED_BALANCES, DENOM_STAKED_POWER,
+    DENOM_STAKED_TOTALS, HOOKS, MAX_CLAIMS, PENALTY_PER_TOKEN, PENDING_PENALTY_REWARDS,
+    STAKEABLE_DENOMS, STAKED_BALANCES, STAKED_POWER, STAKED_TOTAL, TOKEN_INSTANTIATION_INFO,
+    TOKEN_ISSUER_CONTRACT, TOTAL_POWER, USER_PENALTY_PER_TOKEN,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-token-staked";
@@ -54,6 +58,10 @@ const FACTORY_EXECUTE_REPLY_ID: u64 = 2;
 // when using active threshold with percent
 const PRECISION_FACTOR: u128 = 10u128.pow(9);
 
+// Penalties distributed to stakers are tracked per staked token and
+// multiplied by this to avoid losing precision
+const PENALTY_SCALE_FACTOR: u128 = 10u128.pow(18);
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -67,6 +75,7 @@ pub fn instantiate(
 
     let config = Config {
         unstaking_duration: msg.unstaking_duration,
+        instant_unstake: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -163,12 +172,31 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::Stake {} => execute_stake(deps, env, info),
-        ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::Unstake { amount, denom } => execute_unstake(deps, env, info, amount, denom),
         ExecuteMsg::UpdateConfig { duration } => execute_update_config(deps, info, duration),
-        ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::Claim { denom } => execute_claim(deps, env, info, denom),
+        ExecuteMsg::InstantUnstake { amount, denom } => {
+            execute_instant_unstake(deps, env, info, amount, denom)
+        }
+        ExecuteMsg::CancelClaim { amount, denom } => {
+            execute_cancel_claim(deps, env, info, amount, denom)
+        }
+        ExecuteMsg::ClaimPenaltyRewards {} => execute_claim_penalty_rewards(deps, info),
+        ExecuteMsg::UpdateInstantUnstake { config } => {
+            execute_update_instant_unstake(deps, info, config)
+        }
         ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
             execute_update_active_threshold(deps, env, info, new_threshold)
         }
+        ExecuteMsg::Slash {
+            addr,
+            percent,
+            destination,
+            denom,
+        } => execute_slash(deps, env, info, addr, percent, destination, denom),
+        ExecuteMsg::AddDenom { denom, rate } => execute_add_denom(deps, env, info, denom, rate),
+        ExecuteMsg::RemoveDenom { denom } => execute_remove_denom(deps, env, info, denom),
+        ExecuteMsg::ReweighStake { addr, denom } => execute_reweigh_stake(deps, env, addr, denom),
         ExecuteMsg::AddHook { addr } => execute_add_hook(deps, env, info, addr),
         ExecuteMsg::RemoveHook { addr } => execute_remove_hook(deps, env, info, addr),
     }
@@ -180,19 +208,21 @@ pub fn execute_stake(
     info: MessageInfo,
 ) -> Result<Response, ContractError> {
     let denom = DENOM.load(deps.storage)?;
+
+    // Stake an additional denom if one was sent
+    if let [coin] = info.funds.as_slice() {
+        if coin.denom != denom && !coin.amount.is_zero() {
+            if let Some(StakeableDenom { active: true, .. }) =
+                STAKEABLE_DENOMS.may_load(deps.storage, &coin.denom)?
+            {
+                return execute_stake_denom(deps, env, info.sender, &coin.denom, coin.amount);
+            }
+        }
+    }
+
     let amount = must_pay(&info, &denom)?;
 
-    STAKED_BALANCES.update(
-        deps.storage,
-        &info.sender,
-        env.block.height,
-        |balance| -> StdResult<Uint128> { Ok(balance.unwrap_or_default().checked_add(amount)?) },
-    )?;
-    STAKED_TOTAL.update(
-        deps.storage,
-        env.block.height,
-        |total| -> StdResult<Uint128> { Ok(total.unwrap_or_default().checked_add(amount)?) },
-    )?;
+    add_stake(deps.storage, env.block.height, &info.sender, amount)?;
 
     // Add stake hook messages
     let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), amount)?;
@@ -204,45 +234,58 @@ pub fn execute_stake(
         .add_attribute("from", info.sender))
 }
 
+fn execute_stake_denom(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    denom: &str,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let power = add_denom_stake(deps.storage, env.block.height, denom, &sender, amount)?;
+
+    // Add stake hook messages
+    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, sender.clone(), power)?;
+
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "stake")
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("from", sender))
+}
+
 pub fn execute_unstake(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    denom: Option<String>,
 ) -> Result<Response, ContractError> {
     if amount.is_zero() {
         return Err(ContractError::ZeroUnstake {});
     }
 
-    STAKED_BALANCES.update(
-        deps.storage,
-        &info.sender,
-        env.block.height,
-        |balance| -> Result<Uint128, ContractError> {
-            balance
-                .unwrap_or_default()
-                .checked_sub(amount)
-                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
-        },
-    )?;
-    STAKED_TOTAL.update(
-        deps.storage,
-        env.block.height,
-        |total| -> Result<Uint128, ContractError> {
-            total
-                .unwrap_or_default()
-                .checked_sub(amount)
-                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
-        },
-    )?;
+    let additional = additional_denom(deps.storage, denom)?;
+    let power = match &additional {
+        Some(denom) => {
+            remove_denom_stake(deps.storage, env.block.height, denom, &info.sender, amount)?
+        }
+        None => {
+            remove_stake(deps.storage, env.block.height, &info.sender, amount)?;
+            amount
+        }
+    };
 
     // Add unstake hook messages
-    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), amount)?;
+    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), power)?;
 
     let config = CONFIG.load(deps.storage)?;
-    let denom = DENOM.load(deps.storage)?;
     match config.unstaking_duration {
         None => {
+            let denom = match additional {
+                Some(denom) => denom,
+                None => DENOM.load(deps.storage)?,
+            };
             let msg = CosmosMsg::Bank(BankMsg::Send {
                 to_address: info.sender.to_string(),
                 amount: coins(amount.u128(), denom),
@@ -256,17 +299,16 @@ pub fn execute_unstake(
                 .add_attribute("claim_duration", "None"))
         }
         Some(duration) => {
-            let outstanding_claims = CLAIMS.query_claims(deps.as_ref(), &info.sender)?.claims;
-            if outstanding_claims.len() >= MAX_CLAIMS as usize {
+            let mut claims = load_claims(deps.storage, additional.as_deref(), &info.sender)?;
+            if claims.len() >= MAX_CLAIMS as usize {
                 return Err(ContractError::TooManyClaims {});
             }
-
-            CLAIMS.create_claim(
-                deps.storage,
-                &info.sender,
+            claims.push(Claim {
                 amount,
-                duration.after(&env.block),
-            )?;
+                release_at: duration.after(&env.block),
+            });
+            save_claims(deps.storage, additional.as_deref(), &info.sender, &claims)?;
+
             Ok(Response::new()
                 .add_submessages(hook_msgs)
                 .add_attribute("action", "unstake")
@@ -277,6 +319,190 @@ pub fn execute_unstake(
     }
 }
 
+pub fn execute_instant_unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroUnstake {});
+    }
+
+    let InstantUnstakeConfig {
+        penalty,
+        destination,
+    } = CONFIG
+        .load(deps.storage)?
+        .instant_unstake
+        .ok_or(ContractError::InstantUnstakeDisabled {})?;
+
+    let additional = additional_denom(deps.storage, denom)?;
+    let power = match &additional {
+        Some(denom) => {
+            remove_denom_stake(deps.storage, env.block.height, denom, &info.sender, amount)?
+        }
+        None => {
+            remove_stake(deps.storage, env.block.height, &info.sender, amount)?;
+            amount
+        }
+    };
+
+    // Add unstake hook messages
+    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), power)?;
+
+    // Penalties in additional denoms can only be sent to the DAO, as
+    // with slashing.
+    let (denom, destination) = match additional {
+        Some(denom) => (denom, PenaltyDestination::Dao {}),
+        None => (DENOM.load(deps.storage)?, destination),
+    };
+    let penalty_amount = penalty_amount(amount, penalty)?;
+    let mut msgs = distribute_penalty(deps.storage, &env, &destination, penalty_amount, &denom)?;
+
+    let released = amount - penalty_amount;
+    if !released.is_zero() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(released.u128(), denom),
+        }));
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "instant_unstake")
+        .add_attribute("from", info.sender)
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount)
+        .add_attribute("penalty", penalty_amount))
+}
+
+/// Returns PENALTY of AMOUNT, rounded up so that unstaking in small
+/// amounts does not avoid the penalty.
+fn penalty_amount(amount: Uint128, penalty: Decimal) -> StdResult<Uint128> {
+    let one = Uint256::from(Decimal::one().atomics());
+    let penalty = (amount.full_mul(penalty.atomics()) + one - Uint256::one()) / one;
+    Ok(penalty.try_into()?)
+}
+
+pub fn execute_cancel_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroCancel {});
+    }
+
+    let additional = additional_denom(deps.storage, denom)?;
+    let mut claims = load_claims(deps.storage, additional.as_deref(), &info.sender)?;
+
+    // Cancel the most recently created claims first. Claims that have
+    // already been released are left to be claimed.
+    let mut remaining = amount;
+    for claim in claims.iter_mut().rev() {
+        if remaining.is_zero() {
+            break;
+        }
+        if claim.release_at.is_expired(&env.block) {
+            continue;
+        }
+        let cancelled = claim.amount.min(remaining);
+        claim.amount -= cancelled;
+        remaining -= cancelled;
+    }
+    if !remaining.is_zero() {
+        return Err(ContractError::InvalidCancelAmount {});
+    }
+
+    claims.retain(|claim| !claim.amount.is_zero());
+    save_claims(deps.storage, additional.as_deref(), &info.sender, &claims)?;
+
+    let power = match &additional {
+        Some(denom) => {
+            add_denom_stake(deps.storage, env.block.height, denom, &info.sender, amount)?
+        }
+        None => {
+            add_stake(deps.storage, env.block.height, &info.sender, amount)?;
+            amount
+        }
+    };
+
+    // Add stake hook messages
+    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), power)?;
+
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "cancel_claim")
+        .add_attribute("from", info.sender)
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_claim_penalty_rewards(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    settle_penalty_rewards(deps.storage, &info.sender)?;
+
+    let rewards = PENDING_PENALTY_REWARDS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if rewards.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    PENDING_PENALTY_REWARDS.remove(deps.storage, &info.sender);
+
+    let denom = DENOM.load(deps.storage)?;
+    let msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(rewards.u128(), denom),
+    });
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "claim_penalty_rewards")
+        .add_attribute("from", info.sender)
+        .add_attribute("amount", rewards))
+}
+
+pub fn execute_update_instant_unstake(
+    deps: DepsMut,
+    info: MessageInfo,
+    instant_unstake: Option<InstantUnstakeConfig>,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    // Only the DAO can update the config
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(InstantUnstakeConfig {
+        penalty,
+        destination,
+    }) = &instant_unstake
+    {
+        if *penalty > Decimal::one() {
+            return Err(ContractError::InvalidPenalty {});
+        }
+        if *destination == (PenaltyDestination::Burn {})
+            && !TOKEN_ISSUER_CONTRACT.exists(deps.storage)
+        {
+            return Err(ContractError::NoTokenIssuer {});
+        }
+    }
+
+    config.instant_unstake = instant_unstake;
+
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new().add_attribute("action", "update_instant_unstake"))
+}
+
 pub fn execute_update_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -302,13 +528,29 @@ pub fn execute_claim(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    denom: Option<String>,
 ) -> Result<Response, ContractError> {
-    let release = CLAIMS.claim_tokens(deps.storage, &info.sender, &env.block, None)?;
+    let additional = additional_denom(deps.storage, denom)?;
+
+    let mut release = Uint128::zero();
+    let mut claims = load_claims(deps.storage, additional.as_deref(), &info.sender)?;
+    claims.retain(|claim| {
+        if claim.release_at.is_expired(&env.block) {
+            release += claim.amount;
+            false
+        } else {
+            true
+        }
+    });
     if release.is_zero() {
         return Err(ContractError::NothingToClaim {});
     }
+    save_claims(deps.storage, additional.as_deref(), &info.sender, &claims)?;
 
-    let denom = DENOM.load(deps.storage)?;
+    let denom = match additional {
+        Some(denom) => denom,
+        None => DENOM.load(deps.storage)?,
+    };
     let msg = CosmosMsg::Bank(BankMsg::Send {
         to_address: info.sender.to_string(),
         amount: coins(release.u128(), denom),
@@ -321,6 +563,488 @@ pub fn execute_claim(
         .add_attribute("amount", release))
 }
 
+pub fn execute_slash(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    addr: String,
+    percent: Decimal,
+    destination: PenaltyDestination,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if percent.is_zero() || percent > Decimal::one() {
+        return Err(ContractError::InvalidSlashPercent {});
+    }
+    let addr = deps.api.addr_validate(&addr)?;
+
+    let additional = additional_denom(deps.storage, denom)?;
+    if additional.is_some() && destination != (PenaltyDestination::Dao {}) {
+        return Err(ContractError::UnsupportedSlashDestination {});
+    }
+
+    // Slash the staked balance
+    let (slashed_stake, power) = match &additional {
+        Some(denom) => {
+            let staked = DENOM_STAKED_BALANCES
+                .may_load(deps.storage, (denom.as_str(), &addr))?
+                .unwrap_or_default();
+            let slashed_stake = staked * percent;
+            let power = if slashed_stake.is_zero() {
+                Uint128::zero()
+            } else {
+                remove_denom_stake(deps.storage, env.block.height, denom, &addr, slashed_stake)?
+            };
+            (slashed_stake, power)
+        }
+        None => {
+            let staked = STAKED_BALANCES
+                .may_load(deps.storage, &addr)?
+                .unwrap_or_default();
+            let slashed_stake = staked * percent;
+            if !slashed_stake.is_zero() {
+                remove_stake(deps.storage, env.block.height, &addr, slashed_stake)?;
+            }
+            (slashed_stake, slashed_stake)
+        }
+    };
+
+    // Slash outstanding claims, whether or not they have been released
+    let mut slashed = slashed_stake;
+    let mut claims = load_claims(deps.storage, additional.as_deref(), &addr)?;
+    for claim in claims.iter_mut() {
+        let cut = claim.amount * percent;
+        claim.amount -= cut;
+        slashed = slashed.checked_add(cut).map_err(StdError::overflow)?;
+    }
+    claims.retain(|claim| !claim.amount.is_zero());
+    save_claims(deps.storage, additional.as_deref(), &addr, &claims)?;
+
+    if slashed.is_zero() {
+        return Err(ContractError::NothingToSlash {});
+    }
+
+    let denom = match additional {
+        Some(denom) => denom,
+        None => DENOM.load(deps.storage)?,
+    };
+    let msgs = distribute_penalty(deps.storage, &env, &destination, slashed, &denom)?;
+    let hook_msgs = slash_hook_msgs(HOOKS, deps.storage, addr.clone(), power)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "slash")
+        .add_attribute("addr", addr)
+        .add_attribute("denom", denom)
+        .add_attribute("amount", slashed))
+}
+
+pub fn execute_add_denom(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    rate: Decimal,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if denom == DENOM.load(deps.storage)? {
+        return Err(ContractError::ConfigDenom { denom });
+    }
+    if rate.is_zero() {
+        return Err(ContractError::ZeroRate {});
+    }
+
+    STAKEABLE_DENOMS.save(
+        deps.storage,
+        &denom,
+        &StakeableDenom { rate, active: true },
+        env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_denom")
+        .add_attribute("denom", denom)
+        .add_attribute("rate", rate.to_string()))
+}
+
+pub fn execute_remove_denom(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut stakeable = STAKEABLE_DENOMS
+        .may_load(deps.storage, &denom)?
+        .ok_or_else(|| ContractError::UnknownDenom {
+            denom: denom.clone(),
+        })?;
+    stakeable.active = false;
+    STAKEABLE_DENOMS.save(deps.storage, &denom, &stakeable, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_denom")
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_reweigh_stake(
+    deps: DepsMut,
+    env: Env,
+    addr: String,
+    denom: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let denom = match additional_denom(deps.storage, Some(denom.clone()))? {
+        Some(denom) => denom,
+        None => return Err(ContractError::ConfigDenom { denom }),
+    };
+
+    let (old, new) = reweigh_denom_stake(deps.storage, env.block.height, &denom, &addr)?;
+
+    // Add stake or unstake hook messages for the change in power
+    let hook_msgs = if new > old {
+        stake_hook_msgs(HOOKS, deps.storage, addr.clone(), new - old)?
+    } else if old > new {
+        unstake_hook_msgs(HOOKS, deps.storage, addr.clone(), old - new)?
+    } else {
+        vec![]
+    };
+
+    Ok(Response::new()
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "reweigh_stake")
+        .add_attribute("addr", addr)
+        .add_attribute("denom", denom)
+        .add_attribute("power", new))
+}
+
+/// Returns DENOM if it is an additional denom, or `None` if DENOM is
+/// `None` or the denom in the config. Errors if DENOM has never been
+/// added.
+fn additional_denom(
+    storage: &dyn Storage,
+    denom: Option<String>,
+) -> Result<Option<String>, ContractError> {
+    match denom {
+        None => Ok(None),
+        Some(denom) if denom == DENOM.load(storage)? => Ok(None),
+        Some(denom) => match STAKEABLE_DENOMS.may_load(storage, &denom)? {
+            Some(_) => Ok(Some(denom)),
+            None => Err(ContractError::UnknownDenom { denom }),
+        },
+    }
+}
+
+/// Loads STAKER's outstanding claims for the additional denom DENOM,
+/// or for the denom in the config if `None`.
+fn load_claims(storage: &dyn Storage, denom: Option<&str>, staker: &Addr) -> StdResult<Vec<Claim>> {
+    let claims = match denom {
+        Some(denom) => DENOM_CLAIMS.may_load(storage, (denom, staker))?,
+        None => CLAIMS.may_load(storage, staker)?,
+    };
+    Ok(claims.unwrap_or_default())
+}
+
+fn save_claims(
+    storage: &mut dyn Storage,
+    denom: Option<&str>,
+    staker: &Addr,
+    claims: &Vec<Claim>,
+) -> StdResult<()> {
+    match (denom, claims.is_empty()) {
+        (Some(denom), true) => DENOM_CLAIMS.remove(storage, (denom, staker)),
+        (Some(denom), false) => DENOM_CLAIMS.save(storage, (denom, staker), claims)?,
+        (None, true) => CLAIMS.remove(storage, staker),
+        (None, false) => CLAIMS.save(storage, staker, claims)?,
+    }
+    Ok(())
+}
+
+/// Adds AMOUNT of the additional denom DENOM to STAKER's stake at
+/// its current rate and returns the voting power this added.
+fn add_denom_stake(
+    storage: &mut dyn Storage,
+    height: u64,
+    denom: &str,
+    staker: &Addr,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    let rate = STAKEABLE_DENOMS.load(storage, denom)?.rate;
+    let old = DENOM_STAKED_BALANCES
+        .may_load(storage, (denom, staker))?
+        .unwrap_or_default();
+    let new = old.checked_add(amount)?;
+    DENOM_STAKED_BALANCES.save(storage, (denom, staker), &new, height)?;
+    DENOM_STAKED_TOTALS.update(storage, denom, height, |total| -> StdResult<Uint128> {
+        Ok(total.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    let power = amount * rate;
+    DENOM_STAKED_POWER.update(storage, (denom, staker), |staked| -> StdResult<_> {
+        Ok(staked.unwrap_or_default().checked_add(power)?)
+    })?;
+    add_power(storage, height, staker, power)?;
+    Ok(power)
+}
+
+/// Removes AMOUNT of the additional denom DENOM from STAKER's stake
+/// and returns the voting power this removed. The remaining stake
+/// keeps the rate it was staked at.
+fn remove_denom_stake(
+    storage: &mut dyn Storage,
+    height: u64,
+    denom: &str,
+    staker: &Addr,
+    amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    let old = DENOM_STAKED_BALANCES
+        .may_load(storage, (denom, staker))?
+        .unwrap_or_default();
+    let new = old
+        .checked_sub(amount)
+        .map_err(|_e| ContractError::InvalidUnstakeAmount {})?;
+    DENOM_STAKED_BALANCES.save(storage, (denom, staker), &new, height)?;
+    DENOM_STAKED_TOTALS.update(
+        storage,
+        denom,
+        height,
+        |total| -> Result<Uint128, ContractError> {
+            total
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+        },
+    )?;
+
+    let old_power = DENOM_STAKED_POWER
+        .may_load(storage, (denom, staker))?
+        .unwrap_or_default();
+    let power = if new.is_zero() {
+        old_power
+    } else {
+        old_power.multiply_ratio(amount, old)
+    };
+    DENOM_STAKED_POWER.save(storage, (denom, staker), &(old_power - power))?;
+    remove_power(storage, height, staker, power)?;
+    Ok(power)
+}
+
+/// Applies the current rate of DENOM to STAKER's stake of it and
+/// returns their voting power from it before and after.
+fn reweigh_denom_stake(
+    storage: &mut dyn Storage,
+    height: u64,
+    denom: &str,
+    staker: &Addr,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let rate = STAKEABLE_DENOMS.load(storage, denom)?.rate;
+    let balance = DENOM_STAKED_BALANCES
+        .may_load(storage, (denom, staker))?
+        .unwrap_or_default();
+    let old = DENOM_STAKED_POWER
+        .may_load(storage, (denom, staker))?
+        .unwrap_or_default();
+    let new = balance * rate;
+    DENOM_STAKED_POWER.save(storage, (denom, staker), &new)?;
+    if new > old {
+        add_power(storage, height, staker, new - old)?;
+    } else {
+        remove_power(storage, height, staker, old - new)?;
+    }
+    Ok((old, new))
+}
+
+/// Loads STAKER's voting power, which is their staked balance of
+/// `DENOM` if it has never been tracked separately.
+fn load_power(storage: &dyn Storage, staker: &Addr) -> StdResult<Uint128> {
+    Ok(match STAKED_POWER.may_load(storage, staker)? {
+        Some(power) => power,
+        None => STAKED_BALANCES
+            .may_load(storage, staker)?
+            .unwrap_or_default(),
+    })
+}
+
+/// Loads the total voting power, which is the staked total of `DENOM`
+/// if it has never been tracked separately.
+fn load_total_power(storage: &dyn Storage) -> StdResult<Uint128> {
+    Ok(match TOTAL_POWER.may_load(storage)? {
+        Some(power) => power,
+        None => STAKED_TOTAL.may_load(storage)?.unwrap_or_default(),
+    })
+}
+
+/// Adds POWER to STAKER's voting power and the total voting power.
+/// Must be called before their staked balance of `DENOM` changes.
+fn add_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    staker: &Addr,
+    power: Uint128,
+) -> StdResult<()> {
+    let staker_power = load_power(storage, staker)?.checked_add(power)?;
+    STAKED_POWER.save(storage, staker, &staker_power, height)?;
+    let total_power = load_total_power(storage)?.checked_add(power)?;
+    TOTAL_POWER.save(storage, &total_power, height)
+}
+
+/// Removes POWER from STAKER's voting power and the total voting
+/// power. Must be called before their staked balance of `DENOM`
+/// changes.
+fn remove_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    staker: &Addr,
+    power: Uint128,
+) -> Result<(), ContractError> {
+    let staker_power = load_power(storage, staker)?
+        .checked_sub(power)
+        .map_err(|_e| ContractError::InvalidUnstakeAmount {})?;
+    STAKED_POWER.save(storage, staker, &staker_power, height)?;
+    let total_power = load_total_power(storage)?
+        .checked_sub(power)
+        .map_err(|_e| ContractError::InvalidUnstakeAmount {})?;
+    TOTAL_POWER.save(storage, &total_power, height)?;
+    Ok(())
+}
+
+/// Settles STAKER's penalty rewards and adds AMOUNT to their stake.
+fn add_stake(
+    storage: &mut dyn Storage,
+    height: u64,
+    staker: &Addr,
+    amount: Uint128,
+) -> StdResult<()> {
+    settle_penalty_rewards(storage, staker)?;
+    add_power(storage, height, staker, amount)?;
+    STAKED_BALANCES.update(storage, staker, height, |balance| -> StdResult<Uint128> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    STAKED_TOTAL.update(storage, height, |total| -> StdResult<Uint128> {
+        Ok(total.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+/// Settles STAKER's penalty rewards and removes AMOUNT from their
+/// stake.
+fn remove_stake(
+    storage: &mut dyn Storage,
+    height: u64,
+    staker: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    settle_penalty_rewards(storage, staker)?;
+    remove_power(storage, height, staker, amount)?;
+    STAKED_BALANCES.update(
+        storage,
+        staker,
+        height,
+        |balance| -> Result<Uint128, ContractError> {
+            balance
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+        },
+    )?;
+    STAKED_TOTAL.update(storage, height, |total| -> Result<Uint128, ContractError> {
+        total
+            .unwrap_or_default()
+            .checked_sub(amount)
+            .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+    })?;
+    Ok(())
+}
+
+/// Returns the penalty rewards STAKER has earned since they were last
+/// settled.
+fn unsettled_penalty_rewards(storage: &dyn Storage, staker: &Addr) -> StdResult<Uint128> {
+    let penalty_per_token = PENALTY_PER_TOKEN.may_load(storage)?.unwrap_or_default();
+    let user_penalty_per_token = USER_PENALTY_PER_TOKEN
+        .may_load(storage, staker)?
+        .unwrap_or_default();
+    let balance = STAKED_BALANCES
+        .may_load(storage, staker)?
+        .unwrap_or_default();
+    let earned = Uint256::from(balance) * (penalty_per_token - user_penalty_per_token)
+        / Uint256::from(PENALTY_SCALE_FACTOR);
+    Ok(earned.try_into()?)
+}
+
+fn settle_penalty_rewards(storage: &mut dyn Storage, staker: &Addr) -> StdResult<()> {
+    let earned = unsettled_penalty_rewards(storage, staker)?;
+    if !earned.is_zero() {
+        PENDING_PENALTY_REWARDS.update(storage, staker, |pending| -> StdResult<_> {
+            Ok(pending.unwrap_or_default().checked_add(earned)?)
+        })?;
+    }
+    let penalty_per_token = PENALTY_PER_TOKEN.may_load(storage)?.unwrap_or_default();
+    USER_PENALTY_PER_TOKEN.save(storage, staker, &penalty_per_token)
+}
+
+/// Sends PENALTY to DESTINATION. Penalties for stakers go to the DAO
+/// if nothing remains staked.
+fn distribute_penalty(
+    storage: &mut dyn Storage,
+    env: &Env,
+    destination: &PenaltyDestination,
+    penalty: Uint128,
+    denom: &str,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    if penalty.is_zero() {
+        return Ok(vec![]);
+    }
+    let send_to_dao = |storage: &dyn Storage| -> StdResult<Vec<CosmosMsg>> {
+        Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: DAO.load(storage)?.to_string(),
+            amount: coins(penalty.u128(), denom),
+        })])
+    };
+    match destination {
+        PenaltyDestination::Dao {} => Ok(send_to_dao(storage)?),
+        PenaltyDestination::Stakers {} => {
+            let total = STAKED_TOTAL.may_load(storage)?.unwrap_or_default();
+            if total.is_zero() {
+                return Ok(send_to_dao(storage)?);
+            }
+            let per_token =
+                Uint256::from(penalty) * Uint256::from(PENALTY_SCALE_FACTOR) / Uint256::from(total);
+            let current = PENALTY_PER_TOKEN.may_load(storage)?.unwrap_or_default();
+            PENALTY_PER_TOKEN.save(
+                storage,
+                &current.checked_add(per_token).map_err(StdError::overflow)?,
+            )?;
+            Ok(vec![])
+        }
+        PenaltyDestination::Burn {} => {
+            let issuer = TOKEN_ISSUER_CONTRACT
+                .may_load(storage)?
+                .ok_or(ContractError::NoTokenIssuer {})?;
+            Ok(vec![WasmMsg::Execute {
+                contract_addr: issuer.to_string(),
+                msg: to_json_binary(&IssuerExecuteMsg::Burn {
+                    from_address: env.contract.address.to_string(),
+                    amount: penalty,
+                })?,
+                funds: vec![],
+            }
+            .into()])
+        }
+    }
+}
+
 pub fn execute_update_active_threshold(
     deps: DepsMut,
     _env: Env,
@@ -338,9 +1062,10 @@ pub fn execute_update_active_threshold(
                 assert_valid_percentage_threshold(percent)?;
             }
             ActiveThreshold::AbsoluteCount { count } => {
-                let denom = DENOM.load(deps.storage)?;
-                let supply: Coin = deps.querier.query_supply(denom.to_string())?;
-                assert_valid_absolute_count_threshold(count, supply.amount)?;
+                assert_valid_absolute_count_threshold(
+                    count,
+                    total_potential_power(deps.as_ref())?,
+                )?;
             }
         }
         ACTIVE_THRESHOLD.save(deps.storage, &active_threshold)?;
@@ -398,20 +1123,28 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::Info {} => query_info(deps),
         QueryMsg::Dao {} => query_dao(deps),
-        QueryMsg::Claims { address } => to_json_binary(&query_claims(deps, address)?),
+        QueryMsg::Claims { address, denom } => to_json_binary(&query_claims(deps, address, denom)?),
         QueryMsg::GetConfig {} => to_json_binary(&CONFIG.load(deps.storage)?),
         QueryMsg::Denom {} => to_json_binary(&DenomResponse {
             denom: DENOM.load(deps.storage)?,
         }),
-        QueryMsg::ListStakers { start_after, limit } => {
-            query_list_stakers(deps, start_after, limit)
-        }
+        QueryMsg::ListStakers {
+            start_after,
+            limit,
+            denom,
+        } => query_list_stakers(deps, start_after, limit, denom),
         QueryMsg::IsActive {} => query_is_active(deps),
         QueryMsg::ActiveThreshold {} => query_active_threshold(deps),
         QueryMsg::GetHooks {} => to_json_binary(&query_hooks(deps)?),
         QueryMsg::TokenContract {} => {
             to_json_binary(&TOKEN_ISSUER_CONTRACT.may_load(deps.storage)?)
         }
+        QueryMsg::PendingPenaltyRewards { address } => {
+            to_json_binary(&query_pending_penalty_rewards(deps, address)?)
+        }
+        QueryMsg::StakeableDenoms { start_after, limit } => {
+            to_json_binary(&query_stakeable_denoms(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -423,9 +1156,12 @@ pub fn query_voting_power_at_height(
 ) -> StdResult<VotingPowerAtHeightResponse> {
     let height = height.unwrap_or(env.block.height);
     let address = deps.api.addr_validate(&address)?;
-    let power = STAKED_BALANCES
-        .may_load_at_height(deps.storage, &address, height)?
-        .unwrap_or_default();
+    let power = match STAKED_POWER.may_load_at_height(deps.storage, &address, height)? {
+        Some(power) => power,
+        None => STAKED_BALANCES
+            .may_load_at_height(deps.storage, &address, height)?
+            .unwrap_or_default(),
+    };
     Ok(VotingPowerAtHeightResponse { power, height })
 }
 
@@ -435,9 +1171,12 @@ pub fn query_total_power_at_height(
     height: Option<u64>,
 ) -> StdResult<TotalPowerAtHeightResponse> {
     let height = height.unwrap_or(env.block.height);
-    let power = STAKED_TOTAL
-        .may_load_at_height(deps.storage, height)?
-        .unwrap_or_default();
+    let power = match TOTAL_POWER.may_load_at_height(deps.storage, height)? {
+        Some(power) => power,
+        None => STAKED_TOTAL
+            .may_load_at_height(deps.storage, height)?
+            .unwrap_or_default(),
+    };
     Ok(TotalPowerAtHeightResponse { power, height })
 }
 
@@ -451,21 +1190,49 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&dao)
 }
 
-pub fn query_claims(deps: Deps, address: String) -> StdResult<ClaimsResponse> {
-    CLAIMS.query_claims(deps, &deps.api.addr_validate(&address)?)
+fn query_denom(deps: Deps, denom: Option<String>) -> StdResult<Option<String>> {
+    additional_denom(deps.storage, denom).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+pub fn query_claims(
+    deps: Deps,
+    address: String,
+    denom: Option<String>,
+) -> StdResult<ClaimsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let denom = query_denom(deps, denom)?;
+    Ok(ClaimsResponse {
+        claims: load_claims(deps.storage, denom.as_deref(), &address)?,
+    })
+}
+
+pub fn query_pending_penalty_rewards(deps: Deps, address: String) -> StdResult<Uint128> {
+    let address = deps.api.addr_validate(&address)?;
+    let pending = PENDING_PENALTY_REWARDS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    Ok(pending.checked_add(unsettled_penalty_rewards(deps.storage, &address)?)?)
 }
 
 pub fn query_list_stakers(
     deps: Deps,
     start_after: Option<String>,
     limit: Option<u32>,
+    denom: Option<String>,
 ) -> StdResult<Binary> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
     let addr = maybe_addr(deps.api, start_after)?;
     let start = addr.as_ref().map(Bound::exclusive);
 
-    let stakers = STAKED_BALANCES
-        .range(deps.storage, start, None, Order::Ascending)
+    let balances = match query_denom(deps, denom)? {
+        Some(denom) => {
+            DENOM_STAKED_BALANCES
+                .prefix(&denom)
+                .range(deps.storage, start, None, Order::Ascending)
+        }
+        None => STAKED_BALANCES.range(deps.storage, start, None, Order::Ascending),
+    };
+    let stakers = balances
         .take(limit)
         .map(|item| {
             item.map(|(address, balance)| StakerBalanceResponse {
@@ -478,11 +1245,33 @@ pub fn query_list_stakers(
     to_json_binary(&ListStakersResponse { stakers })
 }
 
+pub fn query_stakeable_denoms(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<StakeableDenomResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    STAKEABLE_DENOMS
+        .range(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            item.map(|(denom, stakeable_denom)| StakeableDenomResponse {
+                denom,
+                stakeable_denom,
+            })
+        })
+        .collect()
+}
+
 pub fn query_is_active(deps: Deps) -> StdResult<Binary> {
     let threshold = ACTIVE_THRESHOLD.may_load(deps.storage)?;
     if let Some(threshold) = threshold {
-        let denom = DENOM.load(deps.storage)?;
-        let actual_power = STAKED_TOTAL.may_load(deps.storage)?.unwrap_or_default();
+        let actual_power = load_total_power(deps.storage)?;
         match threshold {
             ActiveThreshold::AbsoluteCount { count } => to_json_binary(&IsActiveResponse {
                 active: actual_power >= count,
@@ -493,8 +1282,7 @@ pub fn query_is_active(deps: Deps) -> StdResult<Binary> {
                 // 10^15. this bounds percent between [0, 10^17].
                 //
                 // total_potential_power is bounded between [0, 2^128]
-                // as it tracks the balances of a cw20 token which has
-                // a max supply of 2^128.
+                // as it is a Uint128.
                 //
                 // with our precision factor being 10^9:
                 //
@@ -510,15 +1298,7 @@ pub fn query_is_active(deps: Deps) -> StdResult<Binary> {
                 // rounding is rounding down, so the whole thing can
                 // be safely unwrapped at the end of the day thank you
                 // for coming to my ted talk.
-                let total_potential_power: cosmwasm_std::SupplyResponse =
-                    deps.querier
-                        .query(&cosmwasm_std::QueryRequest::Bank(BankQuery::Supply {
-                            denom,
-                        }))?;
-                let total_power = total_potential_power
-                    .amount
-                    .amount
-                    .full_mul(PRECISION_FACTOR);
+                let total_power = total_potential_power(deps)?.full_mul(PRECISION_FACTOR);
                 // under the hood decimals are `atomics / 10^decimal_places`.
                 // cosmwasm doesn't give us a Decimal * Uint256
                 // implementation so we take the decimal apart and
@@ -540,6 +1320,22 @@ pub fn query_is_active(deps: Deps) -> StdResult<Binary> {
     }
 }
 
+/// Returns the voting power the whole supply of the denom in the
+/// config and of each active additional denom, at its current rate,
+/// would have if it were staked.
+fn total_potential_power(deps: Deps) -> StdResult<Uint128> {
+    let denom = DENOM.load(deps.storage)?;
+    let mut power = deps.querier.query_supply(denom)?.amount;
+    for denom in STAKEABLE_DENOMS.range(deps.storage, None, None, Order::Ascending) {
+        let (denom, StakeableDenom { rate, active }) = denom?;
+        if active {
+            let supply = deps.querier.query_supply(denom)?.amount;
+            power = power.checked_add(supply * rate)?;
+        }
+    }
+    Ok(power)
+}
+
 pub fn query_active_threshold(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&ActiveThresholdResponse {
         active_threshold: ACTIVE_THRESHOLD.may_load(deps.storage)?,
@@ -26,27 +26,60 @@ pub enum ContractError {
     #[error("Initial governance token balances must not be empty")]
     InitialBalancesError {},
 
+    #[error("({denom}) is already the denom staked by this contract")]
+    ConfigDenom { denom: String },
+
+    #[error("Can only cancel less than or equal to the amount of your pending claims")]
+    InvalidCancelAmount {},
+
+    #[error("Slash percent must be greater than zero and at most one")]
+    InvalidSlashPercent {},
+
+    #[error("Instant unstake penalty must be between zero and one")]
+    InvalidPenalty {},
+
+    #[error("Instant unstaking is not enabled")]
+    InstantUnstakeDisabled {},
+
     #[error("Can only unstake less than or equal to the amount you have staked")]
     InvalidUnstakeAmount {},
 
     #[error("Factory contract did not implment the required TokenFactoryCallback interface")]
     NoFactoryCallback {},
 
+    #[error("Burning penalties requires a cw-tokenfactory-issuer contract")]
+    NoTokenIssuer {},
+
     #[error("Nothing to claim")]
     NothingToClaim {},
 
+    #[error("Nothing to slash")]
+    NothingToSlash {},
+
     #[error("Too many outstanding claims. Claim some tokens before unstaking more.")]
     TooManyClaims {},
 
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("({denom}) is not a denom that may be staked")]
+    UnknownDenom { denom: String },
+
+    #[error("Slashed tokens of additional denoms may only be sent to the DAO")]
+    UnsupportedSlashDestination {},
+
     #[error("Got a submessage reply with unknown id: {id}")]
     UnknownReplyId { id: u64 },
 
     #[error("Factory message must serialize to WasmMsg::Execute")]
     UnsupportedFactoryMsg {},
 
+    #[error("Denom rate must be greater than zero")]
+    ZeroRate {},
+
+    #[error("Amount being cancelled must be non-zero")]
+    ZeroCancel {},
+
     #[error("Amount being unstaked must be non-zero")]
     ZeroUnstake {},
 }
@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{Binary, Decimal, Uint128};
 use cw_utils::Duration;
 use dao_dao_macros::{active_query, native_token_query, voting_module_query};
 use dao_interface::token::NewTokenInfo;
@@ -37,20 +37,83 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Stakes tokens with the contract to get voting power in the DAO
+    /// Stakes tokens with the contract to get voting power in the
+    /// DAO. Exactly one stakeable denom must be sent.
     Stake {},
-    /// Unstakes tokens so that they begin unbonding
-    Unstake { amount: Uint128 },
+    /// Unstakes tokens so that they begin unbonding. DENOM is the
+    /// additional denom to unstake, or `None` for the denom in the
+    /// config.
+    Unstake {
+        amount: Uint128,
+        #[serde(default)]
+        denom: Option<String>,
+    },
     /// Updates the contract configuration
     UpdateConfig { duration: Option<Duration> },
-    /// Claims unstaked tokens that have completed the unbonding period
-    Claim {},
+    /// Claims unstaked tokens of DENOM that have completed the
+    /// unbonding period. If DENOM is `None`, claims the denom in the
+    /// config.
+    Claim {
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    /// Unstakes tokens of DENOM, or of the denom in the config if
+    /// `None`, without waiting for the unbonding period in exchange
+    /// for paying the instant unstake penalty. Penalties in
+    /// additional denoms are always sent to the DAO.
+    InstantUnstake {
+        amount: Uint128,
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    /// Cancels pending claims for AMOUNT tokens of DENOM, or of the
+    /// denom in the config if `None`, and stakes them again. The most
+    /// recently created claims are cancelled first.
+    CancelClaim {
+        amount: Uint128,
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    /// Withdraws the sender's share of penalties paid by stakers who
+    /// unstaked instantly
+    ClaimPenaltyRewards {},
+    /// Sets or removes the instant unstake penalty. Only callable by
+    /// the DAO.
+    UpdateInstantUnstake {
+        config: Option<crate::state::InstantUnstakeConfig>,
+    },
     /// Sets the active threshold to a new value. Only the
     /// instantiator of this contract (a DAO most likely) may call this
     /// method.
     UpdateActiveThreshold {
         new_threshold: Option<ActiveThreshold>,
     },
+    /// Slashes PERCENT of ADDR's staked balance of DENOM, or of the
+    /// denom in the config if `None`, and of each of their
+    /// outstanding claims for it. Slashed tokens are sent to
+    /// DESTINATION, which must be the DAO for additional denoms. Only
+    /// callable by the DAO.
+    Slash {
+        addr: String,
+        percent: Decimal,
+        destination: crate::state::PenaltyDestination,
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    /// Adds a denom that may be staked in addition to the one in the
+    /// config, or updates its rate if it has already been added. Each
+    /// staked token of DENOM has RATE voting power. Tokens that are
+    /// already staked keep their old rate until `ReweighStake` is
+    /// executed for them. Only callable by the DAO.
+    AddDenom { denom: String, rate: Decimal },
+    /// Stops an additional denom from being staked. Tokens already
+    /// staked keep their voting power until they are unstaked. Only
+    /// callable by the DAO.
+    RemoveDenom { denom: String },
+    /// Applies the current rate of the additional denom DENOM to
+    /// ADDR's stake of it. Fires stake or unstake hooks with the
+    /// change in voting power. Callable by anyone.
+    ReweighStake { addr: String, denom: String },
     /// Adds a hook that fires on staking / unstaking
     AddHook { addr: String },
     /// Removes a hook that fires on staking / unstaking
@@ -66,11 +129,21 @@ pub enum QueryMsg {
     #[returns(crate::state::Config)]
     GetConfig {},
     #[returns(cw_controllers::ClaimsResponse)]
-    Claims { address: String },
+    Claims {
+        address: String,
+        /// The additional denom to get claims for, or `None` for the
+        /// denom in the config.
+        #[serde(default)]
+        denom: Option<String>,
+    },
+    /// Lists staked balances of DENOM, or of the denom in the config
+    /// if `None`. Balances are in tokens, not voting power.
     #[returns(ListStakersResponse)]
     ListStakers {
         start_after: Option<String>,
         limit: Option<u32>,
+        #[serde(default)]
+        denom: Option<String>,
     },
     #[returns(ActiveThresholdResponse)]
     ActiveThreshold {},
@@ -78,6 +151,21 @@ pub enum QueryMsg {
     GetHooks {},
     #[returns(Option<cosmwasm_std::Addr>)]
     TokenContract {},
+    #[returns(Uint128)]
+    PendingPenaltyRewards { address: String },
+    /// Lists denoms that may be staked in addition to the one in the
+    /// config, including removed ones.
+    #[returns(Vec<StakeableDenomResponse>)]
+    StakeableDenoms {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct StakeableDenomResponse {
+    pub denom: String,
+    pub stakeable_denom: crate::state::StakeableDenom,
 }
 
 #[cw_serde]
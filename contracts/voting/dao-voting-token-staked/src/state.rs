@@ -1,8 +1,8 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_controllers::Claims;
+use cosmwasm_std::{Addr, Decimal, Uint128, Uint256};
+use cw_controllers::Claim;
 use cw_hooks::Hooks;
-use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 use cw_utils::Duration;
 use dao_voting::threshold::ActiveThreshold;
 
@@ -11,6 +11,32 @@ use crate::msg::TokenInfo;
 #[cw_serde]
 pub struct Config {
     pub unstaking_duration: Option<Duration>,
+    /// If set, stakers may skip the unstaking duration by paying a
+    /// penalty.
+    #[serde(default)]
+    pub instant_unstake: Option<InstantUnstakeConfig>,
+}
+
+#[cw_serde]
+pub struct InstantUnstakeConfig {
+    /// The fraction of instantly unstaked tokens that is withheld as
+    /// a penalty. Must be between zero and one.
+    pub penalty: Decimal,
+    /// Where withheld tokens are sent.
+    pub destination: PenaltyDestination,
+}
+
+#[cw_serde]
+pub enum PenaltyDestination {
+    /// Sends the penalty to the DAO treasury.
+    Dao {},
+    /// Splits the penalty between the remaining stakers in proportion
+    /// to their stake. Stakers withdraw their share with
+    /// `ClaimPenaltyRewards`.
+    Stakers {},
+    /// Burns the penalty via the cw-tokenfactory-issuer contract. This
+    /// contract must be given a burner allowance on the issuer.
+    Burn {},
 }
 
 /// The configuration of this voting contract
@@ -38,10 +64,90 @@ pub const STAKED_TOTAL: SnapshotItem<Uint128> = SnapshotItem::new(
     Strategy::EveryBlock,
 );
 
+/// A native denom that may be staked in addition to `DENOM`.
+#[cw_serde]
+pub struct StakeableDenom {
+    /// The voting power of one staked token of this denom.
+    pub rate: Decimal,
+    /// False if the denom has been removed. Removed denoms may not be
+    /// staked, but those already staked keep their voting power and
+    /// may be unstaked and claimed as usual.
+    pub active: bool,
+}
+
+/// Additional denoms that may be staked. Denoms are never removed
+/// from this map so that stakes of removed denoms may still be
+/// unstaked and claimed.
+pub const STAKEABLE_DENOMS: SnapshotMap<&str, StakeableDenom> = SnapshotMap::new(
+    "stakeable_denoms",
+    "stakeable_denoms__checkpoints",
+    "stakeable_denoms__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Keeps track of staked balances of additional denoms, keyed by
+/// (denom, staker), over time
+pub const DENOM_STAKED_BALANCES: SnapshotMap<(&str, &Addr), Uint128> = SnapshotMap::new(
+    "denom_staked_balances",
+    "denom_staked_balances__checkpoints",
+    "denom_staked_balances__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Keeps track of the staked total of each additional denom over time
+pub const DENOM_STAKED_TOTALS: SnapshotMap<&str, Uint128> = SnapshotMap::new(
+    "denom_staked_totals",
+    "denom_staked_totals__checkpoints",
+    "denom_staked_totals__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The voting power of each staker's stake of each additional denom,
+/// keyed by (denom, staker). This is the rate in effect when the
+/// tokens were staked, or when the stake was last reweighed, times
+/// the staked balance.
+pub const DENOM_STAKED_POWER: Map<(&str, &Addr), Uint128> = Map::new("denom_staked_power");
+
+/// Keeps track of the voting power of each staker, across all staked
+/// denoms, over time. Stakers without an entry have as much voting
+/// power as they have of `DENOM` staked.
+pub const STAKED_POWER: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "staked_power",
+    "staked_power__checkpoints",
+    "staked_power__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Keeps track of the total voting power over time. Falls back to
+/// `STAKED_TOTAL` if unset.
+pub const TOTAL_POWER: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_power",
+    "total_power__checkpoints",
+    "total_power__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Outstanding claims for additional denoms, keyed by (denom, staker)
+pub const DENOM_CLAIMS: Map<(&str, &Addr), Vec<Claim>> = Map::new("denom_claims");
+
 /// The maximum number of claims that may be outstanding.
 pub const MAX_CLAIMS: u64 = 100;
 
-pub const CLAIMS: Claims = Claims::new("claims");
+/// Outstanding claims for the denom in the config. This has the same
+/// layout as `cw_controllers::Claims`, which does not support
+/// cancelling a pending claim.
+pub const CLAIMS: Map<&Addr, Vec<Claim>> = Map::new("claims");
+
+/// Penalties distributed to stakers per staked token, multiplied by
+/// `PENALTY_SCALE_FACTOR`
+pub const PENALTY_PER_TOKEN: Item<Uint256> = Item::new("penalty_per_token");
+
+/// The value of `PENALTY_PER_TOKEN` when a staker's penalty rewards
+/// were last settled
+pub const USER_PENALTY_PER_TOKEN: Map<&Addr, Uint256> = Map::new("user_penalty_per_token");
+
+/// Settled penalty rewards that stakers have yet to withdraw
+pub const PENDING_PENALTY_REWARDS: Map<&Addr, Uint128> = Map::new("pending_penalty_rewards");
 
 /// The minimum amount of staked tokens for the DAO to be active
 pub const ACTIVE_THRESHOLD: Item<ActiveThreshold> = Item::new("active_threshold");
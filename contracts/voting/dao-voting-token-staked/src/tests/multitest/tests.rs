@@ -1,9 +1,10 @@
 use crate::contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::error::ContractError;
 use crate::msg::{
     ExecuteMsg, GetHooksResponse, InstantiateMsg, ListStakersResponse, MigrateMsg, QueryMsg,
-    StakerBalanceResponse, TokenInfo,
+    StakeableDenomResponse, StakerBalanceResponse, TokenInfo,
 };
-use crate::state::Config;
+use crate::state::{Config, InstantUnstakeConfig, PenaltyDestination, StakeableDenom};
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
 use cosmwasm_std::{coins, Addr, Coin, Decimal, Empty, Uint128};
 use cw_controllers::ClaimsResponse;
@@ -133,6 +134,7 @@ fn unstake_tokens(
         staking_addr,
         &ExecuteMsg::Unstake {
             amount: Uint128::new(amount),
+            denom: None,
         },
         &[],
     )
@@ -142,7 +144,7 @@ fn claim(app: &mut App, staking_addr: Addr, sender: &str) -> anyhow::Result<AppR
     app.execute_contract(
         Addr::unchecked(sender),
         staking_addr,
-        &ExecuteMsg::Claim {},
+        &ExecuteMsg::Claim { denom: None },
         &[],
     )
 }
@@ -193,7 +195,13 @@ fn get_config(app: &mut App, staking_addr: Addr) -> Config {
 
 fn get_claims(app: &mut App, staking_addr: Addr, address: String) -> ClaimsResponse {
     app.wrap()
-        .query_wasm_smart(staking_addr, &QueryMsg::Claims { address })
+        .query_wasm_smart(
+            staking_addr,
+            &QueryMsg::Claims {
+                address,
+                denom: None,
+            },
+        )
         .unwrap()
 }
 
@@ -626,6 +634,7 @@ fn test_update_config_as_owner() {
     assert_eq!(
         Config {
             unstaking_duration: Some(Duration::Height(10)),
+            instant_unstake: None,
         },
         config
     );
@@ -757,6 +766,7 @@ fn test_query_get_config() {
         config,
         Config {
             unstaking_duration: Some(Duration::Height(5)),
+            instant_unstake: None,
         }
     )
 }
@@ -900,6 +910,7 @@ fn test_query_list_stakers() {
             &QueryMsg::ListStakers {
                 start_after: None,
                 limit: None,
+                denom: None,
             },
         )
         .unwrap();
@@ -927,6 +938,7 @@ fn test_query_list_stakers() {
             &QueryMsg::ListStakers {
                 start_after: Some(ADDR1.to_string()),
                 limit: None,
+                denom: None,
             },
         )
         .unwrap();
@@ -948,6 +960,7 @@ fn test_query_list_stakers() {
             &QueryMsg::ListStakers {
                 start_after: Some(ADDR2.to_string()),
                 limit: None,
+                denom: None,
             },
         )
         .unwrap();
@@ -1361,6 +1374,611 @@ fn test_staking_hooks() {
     assert_eq!("stake_hook", res.events.last().unwrap().attributes[1].value);
 }
 
+#[test]
+fn test_instant_unstake_and_cancel_claim() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 300, DENOM).unwrap();
+    app.update_block(next_block);
+
+    let instant_unstake = |app: &mut App, sender: &str, amount: u128| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            addr.clone(),
+            &ExecuteMsg::InstantUnstake {
+                amount: Uint128::new(amount),
+                denom: None,
+            },
+            &[],
+        )
+    };
+    let update_instant_unstake =
+        |app: &mut App, sender: &str, penalty: &str, destination: PenaltyDestination| {
+            app.execute_contract(
+                Addr::unchecked(sender),
+                addr.clone(),
+                &ExecuteMsg::UpdateInstantUnstake {
+                    config: Some(InstantUnstakeConfig {
+                        penalty: penalty.parse().unwrap(),
+                        destination,
+                    }),
+                },
+                &[],
+            )
+        };
+    let cancel_claim = |app: &mut App, sender: &str, amount: u128| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            addr.clone(),
+            &ExecuteMsg::CancelClaim {
+                amount: Uint128::new(amount),
+                denom: None,
+            },
+            &[],
+        )
+    };
+
+    // Instant unstaking is disabled by default.
+    let err: ContractError = instant_unstake(&mut app, ADDR1, 100)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InstantUnstakeDisabled {});
+
+    // Only the DAO may configure it, with a penalty of at most one.
+    let err: ContractError =
+        update_instant_unstake(&mut app, ADDR1, "0.1", PenaltyDestination::Dao {})
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError =
+        update_instant_unstake(&mut app, DAO_ADDR, "1.5", PenaltyDestination::Dao {})
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, ContractError::InvalidPenalty {});
+
+    // There is no issuer contract to burn an existing token with.
+    let err: ContractError =
+        update_instant_unstake(&mut app, DAO_ADDR, "0.1", PenaltyDestination::Burn {})
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, ContractError::NoTokenIssuer {});
+
+    // Penalties go to the remaining stakers.
+    update_instant_unstake(&mut app, DAO_ADDR, "0.1", PenaltyDestination::Stakers {}).unwrap();
+    instant_unstake(&mut app, ADDR1, 100).unwrap();
+    assert_eq!(get_balance(&mut app, ADDR1, DENOM), Uint128::new(9990));
+    let pending: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::PendingPenaltyRewards {
+                address: ADDR2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending, Uint128::new(10));
+
+    app.execute_contract(
+        Addr::unchecked(ADDR2),
+        addr.clone(),
+        &ExecuteMsg::ClaimPenaltyRewards {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_balance(&mut app, ADDR2, DENOM), Uint128::new(9710));
+
+    // ADDR1 unstaked before the penalty was paid and earned nothing.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            addr.clone(),
+            &ExecuteMsg::ClaimPenaltyRewards {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NothingToClaim {});
+
+    // Penalties go to the DAO.
+    update_instant_unstake(&mut app, DAO_ADDR, "0.1", PenaltyDestination::Dao {}).unwrap();
+    instant_unstake(&mut app, ADDR2, 100).unwrap();
+    assert_eq!(get_balance(&mut app, ADDR2, DENOM), Uint128::new(9800));
+    assert_eq!(get_balance(&mut app, DAO_ADDR, DENOM), Uint128::new(10010));
+
+    // Penalties round up, so small unstakes still pay them.
+    instant_unstake(&mut app, ADDR2, 5).unwrap();
+    assert_eq!(get_balance(&mut app, ADDR2, DENOM), Uint128::new(9804));
+    assert_eq!(get_balance(&mut app, DAO_ADDR, DENOM), Uint128::new(10011));
+
+    // Cancel part of two pending claims, most recent first.
+    unstake_tokens(&mut app, addr.clone(), ADDR2, 50).unwrap();
+    unstake_tokens(&mut app, addr.clone(), ADDR2, 30).unwrap();
+    app.update_block(next_block);
+    cancel_claim(&mut app, ADDR2, 60).unwrap();
+    let claims = get_claims(&mut app, addr.clone(), ADDR2.to_string());
+    assert_eq!(claims.claims.len(), 1);
+    assert_eq!(claims.claims[0].amount, Uint128::new(20));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(175));
+
+    let err: ContractError = cancel_claim(&mut app, ADDR2, 30)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidCancelAmount {});
+
+    // Released claims can't be cancelled.
+    app.update_block(|b| b.height += 5);
+    let err: ContractError = cancel_claim(&mut app, ADDR2, 20)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidCancelAmount {});
+    claim(&mut app, addr, ADDR2).unwrap();
+    assert_eq!(get_balance(&mut app, ADDR2, DENOM), Uint128::new(9824));
+}
+
+#[test]
+fn test_multiple_denoms() {
+    const LP_DENOM: &str = "ulp";
+
+    let mut app = mock_app();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: ADDR2.to_string(),
+        amount: coins(1000, LP_DENOM),
+    }))
+    .unwrap();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+
+    let add_denom = |app: &mut App, sender: &str, denom: &str, rate: &str| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            addr.clone(),
+            &ExecuteMsg::AddDenom {
+                denom: denom.to_string(),
+                rate: rate.parse().unwrap(),
+            },
+            &[],
+        )
+    };
+    let unstake_lp = |app: &mut App, amount: u128, denom: &str| {
+        app.execute_contract(
+            Addr::unchecked(ADDR2),
+            addr.clone(),
+            &ExecuteMsg::Unstake {
+                amount: Uint128::new(amount),
+                denom: Some(denom.to_string()),
+            },
+            &[],
+        )
+    };
+
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+
+    // LP shares can't be staked until the DAO adds them.
+    stake_tokens(&mut app, addr.clone(), ADDR2, 300, LP_DENOM).unwrap_err();
+
+    let err: ContractError = add_denom(&mut app, ADDR1, LP_DENOM, "0.5")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = add_denom(&mut app, DAO_ADDR, DENOM, "0.5")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ConfigDenom {
+            denom: DENOM.to_string()
+        }
+    );
+    let err: ContractError = add_denom(&mut app, DAO_ADDR, LP_DENOM, "0")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroRate {});
+
+    add_denom(&mut app, DAO_ADDR, LP_DENOM, "0.5").unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 300, LP_DENOM).unwrap();
+    app.update_block(next_block);
+
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(250));
+
+    // Rate changes apply to staked tokens once they are reweighed.
+    // Until then stakers and the total keep their old voting power.
+    let height = app.block_info().height;
+    add_denom(&mut app, DAO_ADDR, LP_DENOM, "2").unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(150));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(250));
+
+    let reweigh = |app: &mut App, denom: &str| {
+        app.execute_contract(
+            Addr::unchecked(ADDR1),
+            addr.clone(),
+            &ExecuteMsg::ReweighStake {
+                addr: ADDR2.to_string(),
+                denom: denom.to_string(),
+            },
+            &[],
+        )
+    };
+    let err: ContractError = reweigh(&mut app, DENOM).unwrap_err().downcast().unwrap();
+    assert_eq!(
+        err,
+        ContractError::ConfigDenom {
+            denom: DENOM.to_string()
+        }
+    );
+    reweigh(&mut app, LP_DENOM).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(600));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(700));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), Some(height));
+    assert_eq!(resp.power, Uint128::new(250));
+
+    let stakers: ListStakersResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::ListStakers {
+                start_after: None,
+                limit: None,
+                denom: Some(LP_DENOM.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stakers.stakers,
+        vec![StakerBalanceResponse {
+            address: ADDR2.to_string(),
+            balance: Uint128::new(300),
+        }]
+    );
+
+    // Unstaking the governance token or an unknown denom fails.
+    unstake_tokens(&mut app, addr.clone(), ADDR2, 100).unwrap_err();
+    let err: ContractError = unstake_lp(&mut app, 100, INVALID_DENOM)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::UnknownDenom {
+            denom: INVALID_DENOM.to_string()
+        }
+    );
+
+    unstake_lp(&mut app, 100, LP_DENOM).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(400));
+    let claims: ClaimsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::Claims {
+                address: ADDR2.to_string(),
+                denom: Some(LP_DENOM.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(claims.claims.len(), 1);
+    assert_eq!(claims.claims[0].amount, Uint128::new(100));
+    assert!(get_claims(&mut app, addr.clone(), ADDR2.to_string())
+        .claims
+        .is_empty());
+
+    // Removed denoms can't be staked, but keep their voting power.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::RemoveDenom {
+            denom: LP_DENOM.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, LP_DENOM).unwrap_err();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(400));
+
+    let denoms: Vec<StakeableDenomResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::StakeableDenoms {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        denoms,
+        vec![StakeableDenomResponse {
+            denom: LP_DENOM.to_string(),
+            stakeable_denom: StakeableDenom {
+                rate: Decimal::from_ratio(2u128, 1u128),
+                active: false,
+            },
+        }]
+    );
+
+    app.update_block(|b| b.height += 5);
+    app.execute_contract(
+        Addr::unchecked(ADDR2),
+        addr,
+        &ExecuteMsg::Claim {
+            denom: Some(LP_DENOM.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_balance(&mut app, ADDR2, LP_DENOM), Uint128::new(800));
+}
+
+// Active thresholds count voting power from every denom, and
+// additional denoms may be unstaked instantly with the penalty going
+// to the DAO.
+#[test]
+fn test_additional_denom_active_threshold_and_instant_unstake() {
+    const LP_DENOM: &str = "ulp";
+
+    let mut app = mock_app();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: ADDR2.to_string(),
+        amount: coins(1000, LP_DENOM),
+    }))
+    .unwrap();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: ODD_DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::AddDenom {
+            denom: LP_DENOM.to_string(),
+            rate: "0.01".parse().unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let update_threshold = |app: &mut App, threshold: ActiveThreshold| {
+        app.execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            addr.clone(),
+            &ExecuteMsg::UpdateActiveThreshold {
+                new_threshold: Some(threshold),
+            },
+            &[],
+        )
+    };
+    let is_active = |app: &App| -> bool {
+        let resp: IsActiveResponse = app
+            .wrap()
+            .query_wasm_smart(addr.clone(), &QueryMsg::IsActive {})
+            .unwrap();
+        resp.active
+    };
+
+    // 5 uodd and 1000 ulp at 0.01 have 15 voting power between them.
+    let err: ContractError = update_threshold(
+        &mut app,
+        ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(16),
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ActiveThresholdError(
+            dao_voting::threshold::ActiveThresholdError::InvalidAbsoluteCount {}
+        )
+    );
+    update_threshold(
+        &mut app,
+        ActiveThreshold::AbsoluteCount {
+            count: Uint128::new(10),
+        },
+    )
+    .unwrap();
+    assert!(!is_active(&app));
+
+    stake_tokens(&mut app, addr.clone(), ADDR2, 1000, LP_DENOM).unwrap();
+    app.update_block(next_block);
+    assert!(is_active(&app));
+
+    update_threshold(
+        &mut app,
+        ActiveThreshold::Percentage {
+            percent: Decimal::percent(60),
+        },
+    )
+    .unwrap();
+    assert!(is_active(&app));
+    update_threshold(
+        &mut app,
+        ActiveThreshold::Percentage {
+            percent: Decimal::percent(70),
+        },
+    )
+    .unwrap();
+    assert!(!is_active(&app));
+
+    // The penalty goes to the DAO even though stakers of the denom in
+    // the config would otherwise receive it.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        addr.clone(),
+        &ExecuteMsg::UpdateInstantUnstake {
+            config: Some(InstantUnstakeConfig {
+                penalty: Decimal::percent(10),
+                destination: PenaltyDestination::Stakers {},
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+    let instant_unstake = |app: &mut App, amount: u128| {
+        app.execute_contract(
+            Addr::unchecked(ADDR2),
+            addr.clone(),
+            &ExecuteMsg::InstantUnstake {
+                amount: Uint128::new(amount),
+                denom: Some(LP_DENOM.to_string()),
+            },
+            &[],
+        )
+    };
+    let err: ContractError = instant_unstake(&mut app, 1001)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidUnstakeAmount {});
+
+    instant_unstake(&mut app, 100).unwrap();
+    app.update_block(next_block);
+    assert_eq!(get_balance(&mut app, ADDR2, LP_DENOM), Uint128::new(90));
+    assert_eq!(get_balance(&mut app, DAO_ADDR, LP_DENOM), Uint128::new(10));
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(9));
+    let resp = get_total_power_at_height(&mut app, addr, None);
+    assert_eq!(resp.power, Uint128::new(9));
+}
+
+#[test]
+fn test_slash() {
+    let mut app = mock_app();
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 100, DENOM).unwrap();
+    unstake_tokens(&mut app, addr.clone(), ADDR1, 40).unwrap();
+    app.update_block(next_block);
+
+    let slash = |app: &mut App, sender: &str, percent: &str, destination: PenaltyDestination| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            addr.clone(),
+            &ExecuteMsg::Slash {
+                addr: ADDR1.to_string(),
+                percent: percent.parse().unwrap(),
+                destination,
+                denom: None,
+            },
+            &[],
+        )
+    };
+
+    let err: ContractError = slash(&mut app, ADDR2, "0.5", PenaltyDestination::Dao {})
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = slash(&mut app, DAO_ADDR, "0", PenaltyDestination::Dao {})
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSlashPercent {});
+
+    // Half of the stake and of the pending claim go to the DAO.
+    slash(&mut app, DAO_ADDR, "0.5", PenaltyDestination::Dao {}).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(30));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(130));
+    let claims = get_claims(&mut app, addr.clone(), ADDR1.to_string());
+    assert_eq!(claims.claims[0].amount, Uint128::new(20));
+    assert_eq!(get_balance(&mut app, DAO_ADDR, DENOM), Uint128::new(10050));
+
+    // The rest goes to the remaining stakers.
+    slash(&mut app, DAO_ADDR, "1", PenaltyDestination::Stakers {}).unwrap();
+    app.update_block(next_block);
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::zero());
+    assert!(get_claims(&mut app, addr.clone(), ADDR1.to_string())
+        .claims
+        .is_empty());
+    let pending: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            addr.clone(),
+            &QueryMsg::PendingPenaltyRewards {
+                address: ADDR2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pending, Uint128::new(50));
+
+    let err: ContractError = slash(&mut app, DAO_ADDR, "1", PenaltyDestination::Dao {})
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NothingToSlash {});
+}
+
 #[test]
 pub fn test_migrate_update_version() {
     let mut deps = mock_dependencies();
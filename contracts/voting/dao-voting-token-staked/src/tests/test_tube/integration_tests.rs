@@ -72,6 +72,7 @@ fn test_stake_unstake_new_denom() {
     // Unstake 50 tokens
     let unstake_msg = ExecuteMsg::Unstake {
         amount: Uint128::new(50),
+        denom: None,
     };
     vp_contract
         .execute(&unstake_msg, &[], &accounts[0])
@@ -86,13 +87,13 @@ fn test_stake_unstake_new_denom() {
 
     // Can't claim before unstaking period (2 seconds)
     vp_contract
-        .execute(&ExecuteMsg::Claim {}, &[], &accounts[0])
+        .execute(&ExecuteMsg::Claim { denom: None }, &[], &accounts[0])
         .unwrap_err();
 
     // Pass time, unstaking duration is set to 2 seconds
     app.increase_time(5);
     vp_contract
-        .execute(&ExecuteMsg::Claim {}, &[], &accounts[0])
+        .execute(&ExecuteMsg::Claim { denom: None }, &[], &accounts[0])
         .unwrap();
 }
 
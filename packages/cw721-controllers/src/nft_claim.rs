@@ -85,6 +85,31 @@ impl<'a> NftClaims<'a> {
         Ok(to_send)
     }
 
+    /// Removes ADDR's claims for any of TOKEN_IDS, whether or not
+    /// they have matured, and returns the token IDs that were
+    /// removed.
+    pub fn remove_nft_claims(
+        &self,
+        storage: &mut dyn Storage,
+        addr: &Addr,
+        token_ids: &[String],
+    ) -> StdResult<Vec<String>> {
+        let mut removed = vec![];
+        self.0.update(storage, addr, |nft_claims| -> StdResult<_> {
+            let (_removed, remaining): (Vec<_>, _) =
+                nft_claims.unwrap_or_default().into_iter().partition(|c| {
+                    if token_ids.contains(&c.token_id) {
+                        removed.push(c.token_id.clone());
+                        true
+                    } else {
+                        false
+                    }
+                });
+            Ok(remaining)
+        })?;
+        Ok(removed)
+    }
+
     pub fn query_claims<Q: CustomQuery>(
         &self,
         deps: Deps<Q>,
@@ -402,4 +427,43 @@ mod test {
 
         assert_eq!(queried_claims.nft_claims.len(), 0);
     }
+
+    #[test]
+    fn test_remove_nft_claims() {
+        let mut deps = mock_dependencies();
+        let claims = NftClaims::new("claims");
+
+        claims
+            .create_nft_claims(
+                deps.as_mut().storage,
+                &Addr::unchecked("addr"),
+                vec![
+                    TEST_BAYC_TOKEN_ID.to_string(),
+                    TEST_CRYPTO_PUNKS_TOKEN_ID.to_string(),
+                ],
+                Expiration::AtHeight(10),
+            )
+            .unwrap();
+
+        let removed = claims
+            .remove_nft_claims(
+                deps.as_mut().storage,
+                &Addr::unchecked("addr"),
+                &[TEST_BAYC_TOKEN_ID.to_string(), "unknown".to_string()],
+            )
+            .unwrap();
+        assert_eq!(removed, vec![TEST_BAYC_TOKEN_ID.to_string()]);
+
+        let saved_claims = claims
+            .0
+            .load(deps.as_mut().storage, &Addr::unchecked("addr"))
+            .unwrap();
+        assert_eq!(
+            saved_claims,
+            vec![NftClaim::new(
+                TEST_CRYPTO_PUNKS_TOKEN_ID.to_string(),
+                Expiration::AtHeight(10)
+            )]
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::CustomMsg;
+use cw_utils::Expiration;
 
 #[cw_serde]
 pub struct MetadataExt {
@@ -7,6 +8,12 @@ pub struct MetadataExt {
     pub role: Option<String>,
     /// The voting weight of this role
     pub weight: u64,
+    /// Optional expiration after which this token no longer
+    /// contributes to its owner's weight, for example the end of an
+    /// elected term. Must be a time. The weight is removed once
+    /// `ExpireTokens` is executed after the expiration
+    #[serde(default)]
+    pub expires_at: Option<Expiration>,
 }
 
 #[cw_serde]
@@ -28,6 +35,22 @@ pub enum ExecuteExt {
         token_id: String,
         role: Option<String>,
     },
+    /// Renews or extends a token by updating its expiration. Restores
+    /// the weight of tokens that have expired. Must be called by
+    /// minter / admin
+    RenewToken {
+        token_id: String,
+        expires_at: Option<Expiration>,
+    },
+    /// Removes the weight of up to LIMIT (default 10, at most 30)
+    /// expired tokens from their owners and the total weight.
+    /// Callable by anyone
+    ExpireTokens { limit: Option<u32> },
+    /// Sets whether tokens are soulbound. Soulbound tokens may only
+    /// be transferred or sent by the minter, others may also be
+    /// transferred or sent by their owners. Must be called by minter
+    /// / admin
+    UpdateSoulbound { soulbound: bool },
 }
 impl CustomMsg for ExecuteExt {}
 
@@ -52,5 +75,30 @@ pub enum QueryExt {
     /// Shows all registered hooks.
     #[returns(cw_controllers::HooksResponse)]
     Hooks {},
+    /// Whether tokens are soulbound
+    #[returns(bool)]
+    Soulbound {},
+    /// Total weight of tokens with a given role at a given height
+    #[returns(cw4::TotalWeightResponse)]
+    RoleTotalWeight {
+        role: String,
+        at_height: Option<u64>,
+    },
+    /// Returns the weight of a certain member's tokens with a given
+    /// role
+    #[returns(cw4::MemberResponse)]
+    RoleMember {
+        role: String,
+        addr: String,
+        at_height: Option<u64>,
+    },
+    /// Returns a list of members holding tokens with a given role,
+    /// and the weight of those tokens
+    #[returns(cw4::MemberListResponse)]
+    ListRoleMembers {
+        role: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 impl CustomMsg for QueryExt {}
@@ -347,8 +347,8 @@ pub fn active_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
 /// enum QueryMsg {
 ///     Dao {},
 ///     Info {},
-///     ProposalCreationPolicy {},
-///     ProposalHooks {},
+///     NextProposalId {},
+///     QuorumReached { proposal_id: u64 },
 /// }
 /// ```
 ///
@@ -392,6 +392,11 @@ pub fn proposal_module_query(metadata: TokenStream, input: TokenStream) -> Token
             /// next proposal created.
             #[returns(::std::primitive::u64)]
             NextProposalId {},
+            /// Returns true if the votes cast on a proposal meet its
+            /// quorum. Used by deposit modules to settle deposits of
+            /// closed proposals.
+            #[returns(::std::primitive::bool)]
+            QuorumReached { proposal_id: u64 },
         }
         }
         .into(),
@@ -12,13 +12,13 @@ pub enum DaoHooks {
     /// Called when a member is added or removed
     /// to a cw4-groups or cw721-roles contract.
     MemberChangedHook(MemberChangedHookMsg),
-    /// Called when NFTs are staked or unstaked.
+    /// Called when NFTs are staked, unstaked, or slashed.
     NftStakeChangeHook(NftStakeChangedHookMsg),
     /// Pre-propose hooks
     PreProposeHook(PreProposeHookMsg),
     /// Called when a proposal status changes.
     ProposalHook(ProposalHookMsg),
-    /// Called when tokens are staked or unstaked.
+    /// Called when tokens are staked, unstaked, or slashed.
     StakeChangeHook(StakeChangedHookMsg),
     /// Called when a vote is cast.
     VoteHook(VoteHookMsg),
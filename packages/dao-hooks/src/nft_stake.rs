@@ -5,8 +5,21 @@ use cw_hooks::Hooks;
 /// An enum representing NFT staking hooks.
 #[cw_serde]
 pub enum NftStakeChangedHookMsg {
-    Stake { addr: Addr, token_id: String },
-    Unstake { addr: Addr, token_ids: Vec<String> },
+    Stake {
+        addr: Addr,
+        token_id: String,
+    },
+    Unstake {
+        addr: Addr,
+        token_ids: Vec<String>,
+    },
+    /// The DAO confiscated TOKEN_IDS staked by ADDR. Always sent
+    /// after an `Unstake` of the same tokens, so receivers that only
+    /// track stake may ignore it.
+    Slash {
+        addr: Addr,
+        token_ids: Vec<String>,
+    },
 }
 
 /// Prepares NftStakeChangedHookMsg::Stake hook SubMsgs,
@@ -52,6 +65,36 @@ pub fn unstake_nft_hook_msgs(
     })
 }
 
+/// Prepares NftStakeChangedHookMsg::Unstake hook SubMsgs followed by
+/// NftStakeChangedHookMsg::Slash hook SubMsgs, containing the address
+/// and the token_ids confiscated.
+pub fn slash_nft_hook_msgs(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    addr: Addr,
+    token_ids: Vec<String>,
+) -> StdResult<Vec<SubMsg>> {
+    let mut msgs = vec![];
+    for msg in [
+        NftStakeChangedHookMsg::Unstake {
+            addr: addr.clone(),
+            token_ids: token_ids.clone(),
+        },
+        NftStakeChangedHookMsg::Slash { addr, token_ids },
+    ] {
+        let msg = to_json_binary(&NftStakeChangedExecuteMsg::NftStakeChangeHook(msg))?;
+        msgs.extend(hooks.prepare_hooks(storage, |a| {
+            let execute = WasmMsg::Execute {
+                contract_addr: a.into_string(),
+                msg: msg.clone(),
+                funds: vec![],
+            };
+            Ok(SubMsg::new(execute))
+        })?);
+    }
+    Ok(msgs)
+}
+
 #[cw_serde]
 pub enum NftStakeChangedExecuteMsg {
     NftStakeChangeHook(NftStakeChangedHookMsg),
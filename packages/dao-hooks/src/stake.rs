@@ -5,8 +5,21 @@ use cw_hooks::Hooks;
 /// An enum representing staking hooks.
 #[cw_serde]
 pub enum StakeChangedHookMsg {
-    Stake { addr: Addr, amount: Uint128 },
-    Unstake { addr: Addr, amount: Uint128 },
+    Stake {
+        addr: Addr,
+        amount: Uint128,
+    },
+    Unstake {
+        addr: Addr,
+        amount: Uint128,
+    },
+    /// The DAO slashed AMOUNT of ADDR's staked balance. Always sent
+    /// after an `Unstake` of the same amount, so receivers that only
+    /// track stake may ignore it.
+    Slash {
+        addr: Addr,
+        amount: Uint128,
+    },
 }
 
 /// Prepares StakeChangedHookMsg::Stake hook SubMsgs,
@@ -51,6 +64,36 @@ pub fn unstake_hook_msgs(
     })
 }
 
+/// Prepares StakeChangedHookMsg::Unstake hook SubMsgs followed by
+/// StakeChangedHookMsg::Slash hook SubMsgs, containing the address
+/// and the staked amount slashed.
+pub fn slash_hook_msgs(
+    hooks: Hooks,
+    storage: &dyn Storage,
+    addr: Addr,
+    amount: Uint128,
+) -> StdResult<Vec<SubMsg>> {
+    let mut msgs = vec![];
+    for msg in [
+        StakeChangedHookMsg::Unstake {
+            addr: addr.clone(),
+            amount,
+        },
+        StakeChangedHookMsg::Slash { addr, amount },
+    ] {
+        let msg = to_json_binary(&StakeChangedExecuteMsg::StakeChangeHook(msg))?;
+        msgs.extend(hooks.prepare_hooks(storage, |a| {
+            let execute = WasmMsg::Execute {
+                contract_addr: a.to_string(),
+                msg: msg.clone(),
+                funds: vec![],
+            };
+            Ok(SubMsg::new(execute))
+        })?);
+    }
+    Ok(msgs)
+}
+
 #[cw_serde]
 pub enum StakeChangedExecuteMsg {
     StakeChangeHook(StakeChangedHookMsg),
@@ -19,6 +19,11 @@ pub enum Query {
     /// next proposal created.
     #[returns(::std::primitive::u64)]
     NextProposalId {},
+    /// Returns true if the votes cast on a proposal meet its
+    /// quorum. Used by deposit modules to settle deposits of closed
+    /// proposals.
+    #[returns(::std::primitive::bool)]
+    QuorumReached { proposal_id: u64 },
 }
 
 mod tests {
@@ -34,6 +39,7 @@ mod tests {
             Query::Dao {} => (),
             Query::Info {} => (),
             Query::NextProposalId {} => (),
+            Query::QuorumReached { .. } => (),
         }
     }
 }
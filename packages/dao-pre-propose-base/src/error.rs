@@ -1,6 +1,6 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, StdError, Uint128};
 use cw_denom::DenomError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use thiserror::Error;
 
 use cw_hooks::HookError;
@@ -32,6 +32,40 @@ pub enum PreProposeError {
     #[error("You must be a member of this DAO (have voting power) to create a proposal")]
     NotMember {},
 
+    #[error("You must have at least ({min}) voting power to create a proposal")]
+    InsufficientVotingPower { min: Uint128 },
+
+    #[error("You must be on the submission allowlist or hold an NFT from the configured collection to create a proposal")]
+    NotAllowedToSubmit {},
+
+    #[error("You may not create another proposal until ({until})")]
+    CooldownActive { until: Expiration },
+
+    #[error("You may not have more than ({max}) open proposals")]
+    TooManyOpenProposals { max: u32 },
+
+    #[error("Maximum open proposals must be greater than zero")]
+    ZeroMaxOpenProposals {},
+
+    #[error("Proposals may have at most ({max}) messages, but this one has ({count})")]
+    TooManyMessages { max: u32, count: u32 },
+
+    #[error("Proposals may not migrate or change the admin of ({contract})")]
+    ProtectedContract { contract: Addr },
+
+    #[error("Proposals may not send messages to ({contract})")]
+    DeniedContract { contract: Addr },
+
+    #[error("Proposals may spend at most ({limit}{denom}), but this one spends ({amount}{denom})")]
+    SpendLimitExceeded {
+        denom: String,
+        limit: Uint128,
+        amount: Uint128,
+    },
+
+    #[error("The spend of message ({index}) can not be checked against the spend limits")]
+    UncheckableSpend { index: u32 },
+
     #[error("No denomination for withdrawal. specify a denomination to withdraw")]
     NoWithdrawalDenom {},
 
@@ -44,6 +78,45 @@ pub enum PreProposeError {
     #[error("Proposal not found")]
     ProposalNotFound {},
 
+    #[error("Pending proposal has not expired")]
+    NotExpired {},
+
+    #[error("Pending proposal has expired")]
+    Expired {},
+
+    #[error("No template named ({name})")]
+    TemplateNotFound { name: String },
+
+    #[error("Invalid template: {reason}")]
+    InvalidTemplate { reason: String },
+
+    #[error("Invalid value for template parameter ({name}): {reason}")]
+    InvalidTemplateParam { name: String, reason: String },
+
+    #[error("Minimum sponsors must be greater than zero")]
+    ZeroMinSponsors {},
+
+    #[error("This module does not support co-sponsorship")]
+    SponsorshipUnsupported {},
+
+    #[error("Sponsorship not found")]
+    SponsorshipNotFound {},
+
+    #[error("Proposal is not seeking sponsors")]
+    NotSeekingSponsors {},
+
+    #[error("Proposal stopped seeking sponsors at ({expiration})")]
+    SponsorshipExpired { expiration: Expiration },
+
+    #[error("You may not sponsor your own proposal")]
+    SponsorIsProposer {},
+
+    #[error("You have already sponsored this proposal")]
+    AlreadySponsored {},
+
+    #[error("You must have had voting power when this proposal was submitted to sponsor it")]
+    NoSponsorPower {},
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -1,32 +1,51 @@
 use cosmwasm_schema::schemars::JsonSchema;
+use std::collections::BTreeMap;
+
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg,
-    WasmMsg,
+    from_json, to_json_binary, Addr, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Deps, DepsMut,
+    Empty, Env, IbcMsg, MessageInfo, Order, Response, StdError, StdResult, Storage, SubMsg,
+    Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
+use cw20::Cw20ExecuteMsg;
 
+use cw721::{Cw721QueryMsg, TokensResponse};
 use cw_denom::UncheckedDenom;
+use cw_storage_plus::Bound;
+use cw_utils::{Duration, Expiration};
 use dao_interface::voting::{Query as CwCoreQuery, VotingPowerAtHeightResponse};
 use dao_voting::{
-    deposit::{DepositRefundPolicy, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, DepositRefundPolicy, UncheckedDepositInfo, UncheckedDepositOption,
+    },
+    proposal::ProposalMsgs,
     status::Status,
 };
 use serde::Serialize;
 
 use crate::{
     error::PreProposeError,
-    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
-    state::{Config, PreProposeContract},
+    msg::{
+        DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg, SponsorshipHookExecuteMsg,
+        SponsorshipHookMsg, UncheckedMessagePolicy, UncheckedSubmissionPolicy,
+    },
+    state::{
+        Config, PreProposeContract, Sponsorship, SponsorshipConfig, SponsorshipStatus,
+        SubmitterInfo,
+    },
 };
 
 const CONTRACT_NAME: &str = "crates.io::dao-pre-propose-base";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 where
-    ProposalMessage: Serialize,
+    ProposalMessage: Serialize + ProposalMsgs,
     QueryExt: JsonSchema,
 {
     pub fn instantiate(
@@ -87,6 +106,23 @@ where
                 deposit_info,
                 open_proposal_submission,
             } => self.execute_update_config(deps, info, deposit_info, open_proposal_submission),
+            ExecuteMsg::UpdateDepositOptions { options } => {
+                self.execute_update_deposit_options(deps, info, options)
+            }
+            ExecuteMsg::UpdateSubmissionPolicy { policy } => {
+                self.execute_update_submission_policy(deps, info, policy)
+            }
+            ExecuteMsg::UpdateSubmissionAllowlist { to_add, to_remove } => {
+                self.execute_update_submission_allowlist(deps, info, to_add, to_remove)
+            }
+            ExecuteMsg::UpdateMessagePolicy { policy } => {
+                self.execute_update_message_policy(deps, info, policy)
+            }
+            ExecuteMsg::UpdateSponsorshipConfig { config } => {
+                self.execute_update_sponsorship_config(deps, info, config)
+            }
+            ExecuteMsg::Sponsor { id } => self.execute_sponsor(deps, env, info, id),
+            ExecuteMsg::ExpireSponsorship { id } => self.execute_expire_sponsorship(deps, env, id),
             ExecuteMsg::Withdraw { denom } => {
                 self.execute_withdraw(deps.as_ref(), env, info, denom)
             }
@@ -96,10 +132,16 @@ where
             ExecuteMsg::RemoveProposalSubmittedHook { address } => {
                 self.execute_remove_proposal_submitted_hook(deps, info, address)
             }
+            ExecuteMsg::AddSponsorshipHook { address } => {
+                self.execute_add_sponsorship_hook(deps, info, address)
+            }
+            ExecuteMsg::RemoveSponsorshipHook { address } => {
+                self.execute_remove_sponsorship_hook(deps, info, address)
+            }
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+            } => self.execute_proposal_completed_hook(deps, info, proposal_id, new_status),
 
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
@@ -112,17 +154,101 @@ where
         info: MessageInfo,
         msg: ProposalMessage,
     ) -> Result<Response, PreProposeError> {
-        self.check_can_submit(deps.as_ref(), info.sender.clone())?;
+        self.execute_propose_with_deposit(deps, env, info, msg, None)
+    }
 
-        let config = self.config.load(deps.storage)?;
+    /// Creates a proposal like `execute_propose`. If DEPOSIT is set,
+    /// it is taken instead of the module's configured deposit and
+    /// deposit options. If co-sponsorship is required, the proposal
+    /// seeks sponsors instead of being created.
+    pub fn execute_propose_with_deposit(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ProposalMessage,
+        deposit: Option<CheckedDepositInfo>,
+    ) -> Result<Response, PreProposeError> {
+        self.check_proposal_msgs(deps.as_ref(), &msg)?;
+        self.check_can_submit(deps.as_ref(), &env.block, info.sender.clone())?;
+        self.record_submission(deps.storage, &env.block, &info.sender)?;
 
-        let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-            deposit_info.check_native_deposit_paid(&info)?;
-            deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
-        } else {
-            vec![]
+        let (deposit_info, deposit_messages) = match deposit {
+            Some(deposit) => {
+                deposit.check_native_deposit_paid(&info)?;
+                let messages =
+                    deposit.get_take_deposit_messages(&info.sender, &env.contract.address)?;
+                (Some(deposit), messages)
+            }
+            None => self.take_deposit(deps.as_ref(), &env, &info)?,
         };
 
+        let msg = to_json_binary(&msg)?;
+
+        if let Some(config) = self.sponsorship_config.may_load(deps.storage)? {
+            let id = self
+                .sponsorships
+                .keys(deps.storage, None, None, Order::Descending)
+                .next()
+                .transpose()?
+                .unwrap_or_default()
+                + 1;
+            let expiration = config.window.after(&env.block);
+            self.sponsorships.save(
+                deps.storage,
+                id,
+                &Sponsorship {
+                    id,
+                    status: SponsorshipStatus::Seeking {},
+                    proposer: info.sender.clone(),
+                    msg,
+                    deposit: deposit_info,
+                    config,
+                    sponsors: vec![],
+                    power: Uint128::zero(),
+                    start_height: env.block.height,
+                    expiration,
+                },
+            )?;
+
+            let hooks_msgs = self.sponsorship_hook_msgs(
+                deps.storage,
+                SponsorshipHookMsg::SponsorshipRequested {
+                    id,
+                    proposer: info.sender.clone(),
+                },
+            )?;
+
+            return Ok(Response::default()
+                .add_attribute("method", "execute_propose")
+                .add_attribute("sender", info.sender)
+                .add_attribute("sponsorship_id", id.to_string())
+                .add_attribute("expiration", expiration.to_string())
+                .add_submessages(hooks_msgs)
+                .add_messages(deposit_messages));
+        }
+
+        let (_, messages) = self.create_proposal(deps, &info.sender, msg, deposit_info)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "execute_propose")
+            .add_attribute("sender", info.sender)
+            .add_submessages(messages)
+            .add_messages(deposit_messages))
+    }
+
+    /// Returns the messages that create a proposal in the proposal
+    /// module with the serialized propose message MSG and fire
+    /// proposal submitted hooks, along with the ID of the proposal
+    /// that will be created. DEPOSIT is recorded as having been paid
+    /// by PROPOSER.
+    fn create_proposal(
+        &self,
+        deps: DepsMut,
+        proposer: &Addr,
+        msg: Binary,
+        deposit: Option<CheckedDepositInfo>,
+    ) -> Result<(u64, Vec<SubMsg>), PreProposeError> {
         let proposal_module = self.proposal_module.load(deps.storage)?;
 
         // Snapshot the deposit using the ID of the proposal that we
@@ -131,15 +257,12 @@ where
             &proposal_module,
             &dao_interface::proposal::Query::NextProposalId {},
         )?;
-        self.deposits.save(
-            deps.storage,
-            next_id,
-            &(config.deposit_info, info.sender.clone()),
-        )?;
+        self.deposits
+            .save(deps.storage, next_id, &(deposit, proposer.clone()))?;
 
         let propose_messsage = WasmMsg::Execute {
             contract_addr: proposal_module.into_string(),
-            msg: to_json_binary(&msg)?,
+            msg: msg.clone(),
             funds: vec![],
         };
 
@@ -148,22 +271,179 @@ where
             .prepare_hooks(deps.storage, |a| {
                 let execute = WasmMsg::Execute {
                     contract_addr: a.into_string(),
-                    msg: to_json_binary(&msg)?,
+                    msg: msg.clone(),
                     funds: vec![],
                 };
                 Ok(SubMsg::new(execute))
             })?;
 
+        // It's important that the propose message is first. Otherwise,
+        // a hook receiver could create a proposal before us and
+        // invalidate our `NextProposalId {}` query.
+        let mut messages = vec![SubMsg::new(propose_messsage)];
+        messages.extend(hooks_msgs);
+        Ok((next_id, messages))
+    }
+
+    /// Returns the messages that fire sponsorship hooks with MSG.
+    fn sponsorship_hook_msgs(
+        &self,
+        storage: &dyn Storage,
+        msg: SponsorshipHookMsg,
+    ) -> StdResult<Vec<SubMsg>> {
+        let msg = to_json_binary(&SponsorshipHookExecuteMsg::SponsorshipHook(msg))?;
+        self.sponsorship_hooks.prepare_hooks(storage, |a| {
+            Ok(SubMsg::new(WasmMsg::Execute {
+                contract_addr: a.into_string(),
+                msg: msg.clone(),
+                funds: vec![],
+            }))
+        })
+    }
+
+    pub fn execute_update_sponsorship_config(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        config: Option<SponsorshipConfig>,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        match config {
+            Some(config) => {
+                if config.min_sponsors == 0 {
+                    return Err(PreProposeError::ZeroMinSponsors {});
+                }
+                self.sponsorship_config.save(deps.storage, &config)?;
+            }
+            None => self.sponsorship_config.remove(deps.storage),
+        }
+
         Ok(Response::default()
-            .add_attribute("method", "execute_propose")
-            .add_attribute("sender", info.sender)
-            // It's important that the propose message is
-            // first. Otherwise, a hook receiver could create a
-            // proposal before us and invalidate our `NextProposalId
-            // {}` query.
-            .add_message(propose_messsage)
-            .add_submessages(hooks_msgs)
-            .add_messages(deposit_messages))
+            .add_attribute("method", "update_sponsorship_config")
+            .add_attribute("sender", info.sender))
+    }
+
+    pub fn execute_sponsor(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let mut sponsorship = self
+            .sponsorships
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::SponsorshipNotFound {})?;
+        if sponsorship.status != (SponsorshipStatus::Seeking {}) {
+            return Err(PreProposeError::NotSeekingSponsors {});
+        }
+        if sponsorship.expiration.is_expired(&env.block) {
+            return Err(PreProposeError::SponsorshipExpired {
+                expiration: sponsorship.expiration,
+            });
+        }
+        if info.sender == sponsorship.proposer {
+            return Err(PreProposeError::SponsorIsProposer {});
+        }
+        if sponsorship.sponsors.contains(&info.sender) {
+            return Err(PreProposeError::AlreadySponsored {});
+        }
+
+        // Measure voting power at submission so that sponsors can not
+        // be manufactured after the fact.
+        let dao = self.dao.load(deps.storage)?;
+        let voting_power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+            dao.into_string(),
+            &CwCoreQuery::VotingPowerAtHeight {
+                address: info.sender.to_string(),
+                height: Some(sponsorship.start_height),
+            },
+        )?;
+        if voting_power.power.is_zero() {
+            return Err(PreProposeError::NoSponsorPower {});
+        }
+
+        sponsorship.sponsors.push(info.sender.clone());
+        sponsorship.power = sponsorship
+            .power
+            .checked_add(voting_power.power)
+            .map_err(StdError::from)?;
+
+        let mut messages = self.sponsorship_hook_msgs(
+            deps.storage,
+            SponsorshipHookMsg::Sponsored {
+                id,
+                sponsor: info.sender.clone(),
+                power: voting_power.power,
+            },
+        )?;
+
+        let met = sponsorship.sponsors.len() >= sponsorship.config.min_sponsors as usize
+            && sponsorship.power >= sponsorship.config.min_power;
+        let mut response = Response::default()
+            .add_attribute("method", "sponsor")
+            .add_attribute("sponsor", info.sender)
+            .add_attribute("sponsorship_id", id.to_string())
+            .add_attribute("power", sponsorship.power.to_string());
+
+        if met {
+            let (proposal_id, create_messages) = self.create_proposal(
+                deps.branch(),
+                &sponsorship.proposer,
+                sponsorship.msg.clone(),
+                sponsorship.deposit.clone(),
+            )?;
+            sponsorship.status = SponsorshipStatus::Sponsored { proposal_id };
+            messages.extend(create_messages);
+            messages.extend(self.sponsorship_hook_msgs(
+                deps.storage,
+                SponsorshipHookMsg::SponsorshipMet { id, proposal_id },
+            )?);
+            response = response.add_attribute("proposal_id", proposal_id.to_string());
+        }
+
+        self.sponsorships.save(deps.storage, id, &sponsorship)?;
+
+        Ok(response.add_submessages(messages))
+    }
+
+    pub fn execute_expire_sponsorship(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let mut sponsorship = self
+            .sponsorships
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::SponsorshipNotFound {})?;
+        if sponsorship.status != (SponsorshipStatus::Seeking {}) {
+            return Err(PreProposeError::NotSeekingSponsors {});
+        }
+        if !sponsorship.expiration.is_expired(&env.block) {
+            return Err(PreProposeError::NotExpired {});
+        }
+
+        sponsorship.status = SponsorshipStatus::Expired {};
+        self.sponsorships.save(deps.storage, id, &sponsorship)?;
+        self.record_completion(deps.storage, &sponsorship.proposer)?;
+
+        let refund_messages = match sponsorship.deposit {
+            Some(ref deposit) => deposit.get_return_deposit_message(&sponsorship.proposer)?,
+            None => vec![],
+        };
+        let hooks_msgs = self
+            .sponsorship_hook_msgs(deps.storage, SponsorshipHookMsg::SponsorshipExpired { id })?;
+
+        Ok(Response::default()
+            .add_attribute("method", "expire_sponsorship")
+            .add_attribute("sponsorship_id", id.to_string())
+            .add_messages(refund_messages)
+            .add_submessages(hooks_msgs))
     }
 
     pub fn execute_update_config(
@@ -194,6 +474,94 @@ where
         }
     }
 
+    pub fn execute_update_deposit_options(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        options: Vec<UncheckedDepositOption>,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let options = options
+            .into_iter()
+            .map(|o| o.into_checked(deps.as_ref(), dao.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.deposit_options.save(deps.storage, &options)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_deposit_options")
+            .add_attribute("sender", info.sender)
+            .add_attribute("options", options.len().to_string()))
+    }
+
+    pub fn execute_update_submission_policy(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        policy: UncheckedSubmissionPolicy,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let policy = policy.into_checked(deps.as_ref())?;
+        self.submission_policy.save(deps.storage, &policy)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_submission_policy")
+            .add_attribute("sender", info.sender))
+    }
+
+    pub fn execute_update_submission_allowlist(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        for address in to_add {
+            let addr = deps.api.addr_validate(&address)?;
+            self.submission_allowlist
+                .save(deps.storage, addr, &Empty {})?;
+        }
+        for address in to_remove {
+            let addr = deps.api.addr_validate(&address)?;
+            self.submission_allowlist.remove(deps.storage, addr);
+        }
+
+        Ok(Response::default()
+            .add_attribute("method", "update_submission_allowlist")
+            .add_attribute("sender", info.sender))
+    }
+
+    pub fn execute_update_message_policy(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        policy: UncheckedMessagePolicy,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let policy = policy.into_checked(deps.as_ref())?;
+        self.message_policy.save(deps.storage, &policy)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_message_policy")
+            .add_attribute("sender", info.sender))
+    }
+
     pub fn execute_withdraw(
         &self,
         deps: Deps,
@@ -269,9 +637,43 @@ where
         Ok(Response::default())
     }
 
+    pub fn execute_add_sponsorship_hook(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let addr = deps.api.addr_validate(&address)?;
+        self.sponsorship_hooks.add_hook(deps.storage, addr)?;
+
+        Ok(Response::default())
+    }
+
+    pub fn execute_remove_sponsorship_hook(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+
+        let addr = deps.api.addr_validate(&address)?;
+        self.sponsorship_hooks.remove_hook(deps.storage, addr)?;
+
+        Ok(Response::default())
+    }
+
     pub fn execute_proposal_completed_hook(
         &self,
-        deps: Deps,
+        deps: DepsMut,
         info: MessageInfo,
         id: u64,
         new_status: Status,
@@ -295,27 +697,30 @@ where
 
         match self.deposits.may_load(deps.storage, id)? {
             Some((deposit_info, proposer)) => {
+                self.record_completion(deps.storage, &proposer)?;
+
                 let messages = if let Some(ref deposit_info) = deposit_info {
-                    // Determine if refund can be issued
-                    let should_refund_to_proposer =
-                        match (new_status, deposit_info.clone().refund_policy) {
-                            // If policy is refund only passed props, refund for executed status
-                            (Status::Executed, DepositRefundPolicy::OnlyPassed) => true,
-                            // Don't refund other statuses for OnlyPassed policy
-                            (_, DepositRefundPolicy::OnlyPassed) => false,
-                            // Refund if the refund policy is always refund
-                            (_, DepositRefundPolicy::Always) => true,
-                            // Don't refund if the refund is never refund
-                            (_, DepositRefundPolicy::Never) => false,
-                        };
-
-                    if should_refund_to_proposer {
-                        deposit_info.get_return_deposit_message(&proposer)?
-                    } else {
-                        // If the proposer doesn't get the deposit, the DAO does.
-                        let dao = self.dao.load(deps.storage)?;
-                        deposit_info.get_return_deposit_message(&dao)?
-                    }
+                    // Only slashing deposits of closed proposals
+                    // depends on quorum, so only ask about it then.
+                    let quorum_reached = match (new_status, &deposit_info.refund_policy) {
+                        (Status::Closed, DepositRefundPolicy::Slash { .. }) => deps
+                            .querier
+                            .query_wasm_smart(
+                                &proposal_module,
+                                &dao_interface::proposal::Query::QuorumReached { proposal_id: id },
+                            )
+                            // Proposal modules without the query are
+                            // treated as not knowing.
+                            .ok(),
+                        _ => None,
+                    };
+                    let dao = self.dao.load(deps.storage)?;
+                    deposit_info.get_completion_messages(
+                        new_status,
+                        quorum_reached,
+                        &proposer,
+                        &dao,
+                    )?
                 } else {
                     // No deposit info for this proposal. Nothing to do.
                     vec![]
@@ -338,21 +743,232 @@ where
         }
     }
 
-    pub fn check_can_submit(&self, deps: Deps, who: Addr) -> Result<(), PreProposeError> {
+    /// Determines which deposit, if any, the sender of `info` is
+    /// paying to create a proposal and returns it along with the
+    /// messages needed to take it.
+    pub fn take_deposit(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> Result<(Option<CheckedDepositInfo>, Vec<CosmosMsg>), PreProposeError> {
         let config = self.config.load(deps.storage)?;
+        match config.deposit_info {
+            Some(deposit_info) => {
+                let options = self
+                    .deposit_options
+                    .may_load(deps.storage)?
+                    .unwrap_or_default();
+                let paid = deposit_info.select_paid(deps, info, &env.contract.address, &options)?;
+                let messages =
+                    paid.get_take_deposit_messages(&info.sender, &env.contract.address)?;
+                Ok((Some(paid), messages))
+            }
+            None => Ok((None, vec![])),
+        }
+    }
+
+    /// Checks that the messages of MSG, a proposal being submitted,
+    /// follow the module's message policy. At most one of a
+    /// proposal's choices is executed, so each is checked on its own.
+    pub fn check_proposal_msgs(
+        &self,
+        deps: Deps,
+        msg: &impl ProposalMsgs,
+    ) -> Result<(), PreProposeError> {
+        for msgs in msg.proposal_msgs() {
+            self.check_msgs(deps, msgs)?;
+        }
+        Ok(())
+    }
 
-        if !config.open_proposal_submission {
+    /// Checks that MSGS, the messages of a proposal being submitted,
+    /// follow the module's message policy.
+    pub fn check_msgs(&self, deps: Deps, msgs: &[CosmosMsg<Empty>]) -> Result<(), PreProposeError> {
+        let policy = match self.message_policy.may_load(deps.storage)? {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        if let Some(max) = policy.max_msgs {
+            if msgs.len() > max as usize {
+                return Err(PreProposeError::TooManyMessages {
+                    max,
+                    count: msgs.len() as u32,
+                });
+            }
+        }
+
+        let mut spent: BTreeMap<String, Uint128> = BTreeMap::new();
+        for (index, msg) in msgs.iter().enumerate() {
+            let (contract, admin_change) = match msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                    (Some(contract_addr), false)
+                }
+                CosmosMsg::Wasm(WasmMsg::Migrate { contract_addr, .. })
+                | CosmosMsg::Wasm(WasmMsg::UpdateAdmin { contract_addr, .. })
+                | CosmosMsg::Wasm(WasmMsg::ClearAdmin { contract_addr }) => {
+                    (Some(contract_addr), true)
+                }
+                _ => (None, false),
+            };
+
+            if let Some(contract) = contract {
+                if let Some(denied) = policy
+                    .denied_contracts
+                    .iter()
+                    .find(|c| c.as_str() == contract)
+                {
+                    return Err(PreProposeError::DeniedContract {
+                        contract: denied.clone(),
+                    });
+                }
+                if admin_change {
+                    if let Some(protected) = policy
+                        .protected_contracts
+                        .iter()
+                        .find(|c| c.as_str() == contract)
+                    {
+                        return Err(PreProposeError::ProtectedContract {
+                            contract: protected.clone(),
+                        });
+                    }
+                }
+            }
+
+            if policy.spend_limits.is_empty() {
+                continue;
+            }
+            let funds = spent_by(msg).ok_or(PreProposeError::UncheckableSpend {
+                index: index as u32,
+            })?;
+            for coin in funds {
+                let total = spent.entry(coin.denom).or_default();
+                *total = total.checked_add(coin.amount).map_err(StdError::from)?;
+            }
+        }
+
+        for limit in &policy.spend_limits {
+            let amount = spent.get(limit.denom.as_str()).copied().unwrap_or_default();
+            if amount > limit.amount {
+                return Err(PreProposeError::SpendLimitExceeded {
+                    denom: limit.denom.clone(),
+                    limit: limit.amount,
+                    amount,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that WHO may submit a proposal given the module's
+    /// configuration and submission policy.
+    pub fn check_can_submit(
+        &self,
+        deps: Deps,
+        block: &BlockInfo,
+        who: Addr,
+    ) -> Result<(), PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        let policy = self
+            .submission_policy
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+
+        if !config.open_proposal_submission || policy.min_voting_power.is_some() {
             let dao = self.dao.load(deps.storage)?;
             let voting_power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
                 dao.into_string(),
                 &CwCoreQuery::VotingPowerAtHeight {
-                    address: who.into_string(),
+                    address: who.to_string(),
                     height: None,
                 },
             )?;
-            if voting_power.power.is_zero() {
+            if !config.open_proposal_submission && voting_power.power.is_zero() {
                 return Err(PreProposeError::NotMember {});
             }
+            if let Some(min) = policy.min_voting_power {
+                if voting_power.power < min {
+                    return Err(PreProposeError::InsufficientVotingPower { min });
+                }
+            }
+        }
+
+        if policy.allowlist || policy.nft_collection.is_some() {
+            let allowed = (policy.allowlist
+                && self.submission_allowlist.has(deps.storage, who.clone()))
+                || match policy.nft_collection {
+                    Some(collection) => {
+                        let held: TokensResponse = deps.querier.query_wasm_smart(
+                            collection,
+                            &Cw721QueryMsg::Tokens {
+                                owner: who.to_string(),
+                                start_after: None,
+                                limit: Some(1),
+                            },
+                        )?;
+                        !held.tokens.is_empty()
+                    }
+                    None => false,
+                };
+            if !allowed {
+                return Err(PreProposeError::NotAllowedToSubmit {});
+            }
+        }
+
+        if let Some(submitter) = self.submitters.may_load(deps.storage, who)? {
+            if let Some(cooldown) = policy.cooldown {
+                let until = match cooldown {
+                    Duration::Height(blocks) => {
+                        Expiration::AtHeight(submitter.last_submission_height + blocks)
+                    }
+                    Duration::Time(seconds) => {
+                        Expiration::AtTime(submitter.last_submission_time.plus_seconds(seconds))
+                    }
+                };
+                if !until.is_expired(block) {
+                    return Err(PreProposeError::CooldownActive { until });
+                }
+            }
+            if let Some(max) = policy.max_open_proposals {
+                if submitter.open_proposals >= max {
+                    return Err(PreProposeError::TooManyOpenProposals { max });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records that WHO has submitted a proposal, starting their
+    /// cooldown and counting the proposal as open until
+    /// `record_completion` is called.
+    pub fn record_submission(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        who: &Addr,
+    ) -> StdResult<()> {
+        self.submitters
+            .update(storage, who.clone(), |submitter| -> StdResult<_> {
+                let submitter = submitter.unwrap_or_default();
+                Ok(SubmitterInfo {
+                    open_proposals: submitter.open_proposals + 1,
+                    last_submission_height: block.height,
+                    last_submission_time: block.time,
+                })
+            })?;
+        Ok(())
+    }
+
+    /// Records that one of WHO's open proposals has completed.
+    pub fn record_completion(&self, storage: &mut dyn Storage, who: &Addr) -> StdResult<()> {
+        if let Some(mut submitter) = self.submitters.may_load(storage, who.clone())? {
+            // proposals submitted before submissions were tracked
+            // are not counted.
+            submitter.open_proposals = submitter.open_proposals.saturating_sub(1);
+            self.submitters.save(storage, who.clone(), &submitter)?;
         }
         Ok(())
     }
@@ -371,10 +987,120 @@ where
                     proposer,
                 })
             }
+            QueryMsg::DepositOptions {} => to_json_binary(
+                &self
+                    .deposit_options
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            ),
+            QueryMsg::SubmissionPolicy {} => to_json_binary(
+                &self
+                    .submission_policy
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            ),
+            QueryMsg::SubmissionAllowlist { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+                let start_after = start_after
+                    .map(|a| deps.api.addr_validate(&a))
+                    .transpose()?;
+                let allowlist = self
+                    .submission_allowlist
+                    .keys(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit as usize)
+                    .collect::<StdResult<Vec<Addr>>>()?;
+                to_json_binary(&allowlist)
+            }
+            QueryMsg::Submitter { address } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_json_binary(
+                    &self
+                        .submitters
+                        .may_load(deps.storage, address)?
+                        .unwrap_or_default(),
+                )
+            }
+            QueryMsg::MessagePolicy {} => to_json_binary(
+                &self
+                    .message_policy
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            ),
+            QueryMsg::SponsorshipConfig {} => {
+                to_json_binary(&self.sponsorship_config.may_load(deps.storage)?)
+            }
+            QueryMsg::Sponsorship { id } => {
+                to_json_binary(&self.sponsorships.load(deps.storage, id)?)
+            }
+            QueryMsg::Sponsorships { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+                let sponsorships = self
+                    .sponsorships
+                    .range(
+                        deps.storage,
+                        start_after.map(Bound::exclusive),
+                        None,
+                        Order::Ascending,
+                    )
+                    .take(limit as usize)
+                    .map(|r| r.map(|(_, s)| s))
+                    .collect::<StdResult<Vec<Sponsorship>>>()?;
+                to_json_binary(&sponsorships)
+            }
             QueryMsg::ProposalSubmittedHooks {} => {
                 to_json_binary(&self.proposal_submitted_hooks.query_hooks(deps)?)
             }
+            QueryMsg::SponsorshipHooks {} => {
+                to_json_binary(&self.sponsorship_hooks.query_hooks(deps)?)
+            }
             QueryMsg::QueryExtension { .. } => Ok(Binary::default()),
         }
     }
 }
+
+/// Returns the funds MSG spends from the DAO, with cw20 tokens keyed
+/// by their contract's address, or None if its spend can not be
+/// determined. Messages moving funds in ways that are not understood,
+/// for example stargate, staking, and distribution messages, can not
+/// be valued.
+fn spent_by(msg: &CosmosMsg<Empty>) -> Option<Vec<Coin>> {
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. })
+        | CosmosMsg::Bank(BankMsg::Burn { amount }) => Some(amount.clone()),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let mut spent = funds.clone();
+            if let Ok(
+                Cw20ExecuteMsg::Transfer { amount, .. }
+                | Cw20ExecuteMsg::Send { amount, .. }
+                | Cw20ExecuteMsg::Burn { amount }
+                | Cw20ExecuteMsg::IncreaseAllowance { amount, .. },
+            ) = from_json::<Cw20ExecuteMsg>(msg)
+            {
+                spent.push(Coin {
+                    denom: contract_addr.clone(),
+                    amount,
+                });
+            }
+            Some(spent)
+        }
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => Some(funds.clone()),
+        CosmosMsg::Wasm(WasmMsg::Migrate { .. })
+        | CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. })
+        | CosmosMsg::Wasm(WasmMsg::ClearAdmin { .. }) => Some(vec![]),
+        CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => Some(vec![amount.clone()]),
+        CosmosMsg::Ibc(IbcMsg::SendPacket { .. })
+        | CosmosMsg::Ibc(IbcMsg::CloseChannel { .. })
+        | CosmosMsg::Gov(_)
+        | CosmosMsg::Custom(_) => Some(vec![]),
+        _ => None,
+    }
+}
@@ -1,10 +1,17 @@
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Deps, Uint128};
 use cw_denom::UncheckedDenom;
+use cw_utils::Duration;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    deposit::{CheckedDepositInfo, UncheckedDepositInfo, UncheckedDepositOption},
     status::Status,
 };
 
+use crate::{
+    error::PreProposeError,
+    state::{MessagePolicy, SponsorshipConfig, SubmissionPolicy},
+};
+
 #[cw_serde]
 pub struct InstantiateMsg<InstantiateExt> {
     /// Information about the deposit requirements for this
@@ -19,6 +26,64 @@ pub struct InstantiateMsg<InstantiateExt> {
     pub extension: InstantiateExt,
 }
 
+/// A submission policy whose NFT collection address has not been
+/// validated. See `crate::state::SubmissionPolicy`.
+#[cw_serde]
+#[derive(Default)]
+pub struct UncheckedSubmissionPolicy {
+    pub cooldown: Option<Duration>,
+    pub max_open_proposals: Option<u32>,
+    pub min_voting_power: Option<Uint128>,
+    pub allowlist: bool,
+    pub nft_collection: Option<String>,
+}
+
+impl UncheckedSubmissionPolicy {
+    pub fn into_checked(self, deps: Deps) -> Result<SubmissionPolicy, PreProposeError> {
+        if self.max_open_proposals == Some(0) {
+            return Err(PreProposeError::ZeroMaxOpenProposals {});
+        }
+        Ok(SubmissionPolicy {
+            cooldown: self.cooldown,
+            max_open_proposals: self.max_open_proposals,
+            min_voting_power: self.min_voting_power,
+            allowlist: self.allowlist,
+            nft_collection: self
+                .nft_collection
+                .map(|c| deps.api.addr_validate(&c))
+                .transpose()?,
+        })
+    }
+}
+
+/// A message policy whose contract addresses have not been
+/// validated. See `crate::state::MessagePolicy`.
+#[cw_serde]
+#[derive(Default)]
+pub struct UncheckedMessagePolicy {
+    pub protected_contracts: Vec<String>,
+    pub denied_contracts: Vec<String>,
+    pub spend_limits: Vec<Coin>,
+    pub max_msgs: Option<u32>,
+}
+
+impl UncheckedMessagePolicy {
+    pub fn into_checked(self, deps: Deps) -> Result<MessagePolicy, PreProposeError> {
+        let validate = |addrs: Vec<String>| {
+            addrs
+                .iter()
+                .map(|a| deps.api.addr_validate(a))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(MessagePolicy {
+            protected_contracts: validate(self.protected_contracts)?,
+            denied_contracts: validate(self.denied_contracts)?,
+            spend_limits: self.spend_limits,
+            max_msgs: self.max_msgs,
+        })
+    }
+}
+
 #[cw_serde]
 pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     /// Creates a new proposal in the pre-propose module. MSG will be
@@ -34,6 +99,54 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
         open_proposal_submission: bool,
     },
 
+    /// Sets the deposits that proposers may pay instead of the one
+    /// configured in `deposit_info`, replacing any previously
+    /// set. Options are ignored if no deposit is configured, and
+    /// share its refund policy. Like `UpdateConfig`, this only
+    /// applies to proposals created after it is executed. Only the
+    /// DAO may execute this message.
+    UpdateDepositOptions {
+        options: Vec<UncheckedDepositOption>,
+    },
+
+    /// Sets the policy limiting who may submit proposals and how
+    /// often, replacing any previously set. Only the DAO may execute
+    /// this message.
+    UpdateSubmissionPolicy { policy: UncheckedSubmissionPolicy },
+
+    /// Adds and removes addresses from the submission
+    /// allowlist. The allowlist only has an effect if enabled in the
+    /// submission policy. Only the DAO may execute this message.
+    UpdateSubmissionAllowlist {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+
+    /// Sets the policy that the messages of submitted proposals must
+    /// follow, replacing any previously set. Only the DAO may execute
+    /// this message.
+    UpdateMessagePolicy { policy: UncheckedMessagePolicy },
+
+    /// Sets the co-sponsorship requirements for new proposals. If
+    /// set, submitted proposals are only created in the proposal
+    /// module once they have been sponsored by enough members. If
+    /// `None`, proposals are created as soon as they are
+    /// submitted. Proposals already seeking sponsors keep the
+    /// requirements they were submitted with. Only the DAO may
+    /// execute this message.
+    UpdateSponsorshipConfig { config: Option<SponsorshipConfig> },
+
+    /// Sponsors the proposal seeking sponsors identified by ID. The
+    /// sender must have had voting power when the proposal was
+    /// submitted and may not be its proposer. Creates the proposal
+    /// if this sponsorship meets its requirements.
+    Sponsor { id: u64 },
+
+    /// Expires the proposal identified by ID if its sponsorship
+    /// window has ended without it gaining enough sponsors, and
+    /// returns its deposit to the proposer. May be called by anyone.
+    ExpireSponsorship { id: u64 },
+
     /// Withdraws funds inside of this contract to the message
     /// sender. The contracts entire balance for the specifed DENOM is
     /// withdrawn to the message sender. Only the DAO may call this
@@ -77,6 +190,14 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     /// Removes a proposal submitted hook. Only the DAO may call this method.
     RemoveProposalSubmittedHook { address: String },
 
+    /// Adds a sponsorship hook. Fires when a proposal starts seeking
+    /// sponsors, is sponsored, or expires. Only the DAO may call
+    /// this method.
+    AddSponsorshipHook { address: String },
+
+    /// Removes a sponsorship hook. Only the DAO may call this method.
+    RemoveSponsorshipHook { address: String },
+
     /// Handles proposal hook fired by the associated proposal
     /// module when a proposal is completed (ie executed or rejected).
     /// By default, the base contract will return deposits
@@ -109,9 +230,47 @@ where
     /// PROPOSAL_ID.
     #[returns(DepositInfoResponse)]
     DepositInfo { proposal_id: u64 },
+    /// Gets the deposits that may be paid instead of the one in the
+    /// module's configuration.
+    #[returns(Vec<dao_voting::deposit::CheckedDepositOption>)]
+    DepositOptions {},
+    /// Gets the module's submission policy.
+    #[returns(crate::state::SubmissionPolicy)]
+    SubmissionPolicy {},
+    /// Lists addresses on the submission allowlist.
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    SubmissionAllowlist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the submission history of ADDRESS, used to enforce the
+    /// submission policy.
+    #[returns(crate::state::SubmitterInfo)]
+    Submitter { address: String },
+    /// Gets the policy that the messages of submitted proposals must
+    /// follow.
+    #[returns(crate::state::MessagePolicy)]
+    MessagePolicy {},
+    /// Gets the module's co-sponsorship requirements. Returns `None`
+    /// if proposals do not need sponsors.
+    #[returns(Option<crate::state::SponsorshipConfig>)]
+    SponsorshipConfig {},
+    /// Gets the proposal seeking or having sought sponsors identified
+    /// by ID.
+    #[returns(crate::state::Sponsorship)]
+    Sponsorship { id: u64 },
+    /// Lists proposals seeking or having sought sponsors.
+    #[returns(Vec<crate::state::Sponsorship>)]
+    Sponsorships {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     /// Returns list of proposal submitted hooks.
     #[returns(cw_hooks::HooksResponse)]
     ProposalSubmittedHooks {},
+    /// Returns list of sponsorship hooks.
+    #[returns(cw_hooks::HooksResponse)]
+    SponsorshipHooks {},
     /// Extension for queries. The default implementation will do
     /// nothing if queried for will return `Binary::default()`.
     #[returns(cosmwasm_std::Binary)]
@@ -120,8 +279,34 @@ where
 
 #[cw_serde]
 pub struct DepositInfoResponse {
-    /// The deposit that has been paid for the specified proposal.
+    /// The deposit that has been paid for the specified proposal. If
+    /// deposit options are configured, this is the option the
+    /// proposer chose to pay.
     pub deposit_info: Option<CheckedDepositInfo>,
     /// The address that created the proposal.
     pub proposer: cosmwasm_std::Addr,
 }
+
+/// Messages sent to sponsorship hook receivers.
+#[cw_serde]
+pub enum SponsorshipHookMsg {
+    /// A proposal has been submitted and is seeking sponsors.
+    SponsorshipRequested { id: u64, proposer: Addr },
+    /// A member has sponsored a proposal.
+    Sponsored {
+        id: u64,
+        sponsor: Addr,
+        /// The sponsor's voting power.
+        power: Uint128,
+    },
+    /// A proposal gained enough sponsors and has been created in the
+    /// proposal module.
+    SponsorshipMet { id: u64, proposal_id: u64 },
+    /// A proposal did not gain enough sponsors and has expired.
+    SponsorshipExpired { id: u64 },
+}
+
+#[cw_serde]
+pub enum SponsorshipHookExecuteMsg {
+    SponsorshipHook(SponsorshipHookMsg),
+}
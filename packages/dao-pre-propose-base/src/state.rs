@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Timestamp, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
 
-use dao_voting::deposit::CheckedDepositInfo;
+use dao_voting::deposit::{CheckedDepositInfo, CheckedDepositOption};
 
 #[cw_serde]
 pub struct Config {
@@ -18,6 +19,126 @@ pub struct Config {
     pub open_proposal_submission: bool,
 }
 
+/// Limits on who may submit proposals and how often. Checked in
+/// addition to `open_proposal_submission`.
+#[cw_serde]
+#[derive(Default)]
+pub struct SubmissionPolicy {
+    /// The minimum amount of time or blocks between proposals
+    /// submitted by the same address.
+    pub cooldown: Option<Duration>,
+    /// The maximum number of proposals an address may have open at
+    /// once. A proposal is open until it is executed, closed, or
+    /// vetoed.
+    pub max_open_proposals: Option<u32>,
+    /// The minimum voting power an address must have in the DAO at
+    /// the time of submission.
+    pub min_voting_power: Option<Uint128>,
+    /// If true, addresses on the submission allowlist may submit
+    /// proposals.
+    pub allowlist: bool,
+    /// If set, addresses holding an NFT from this cw721 collection
+    /// may submit proposals.
+    ///
+    /// If `allowlist` is true or this is set, only addresses that
+    /// are on the allowlist or hold an NFT from the collection may
+    /// submit proposals.
+    pub nft_collection: Option<Addr>,
+}
+
+/// Information about an address's past submissions, used to enforce
+/// the submission policy.
+#[cw_serde]
+#[derive(Default)]
+pub struct SubmitterInfo {
+    /// The number of proposals created by this address that have not
+    /// yet completed.
+    pub open_proposals: u32,
+    /// The block height of this address's last submission.
+    pub last_submission_height: u64,
+    /// The block time of this address's last submission.
+    pub last_submission_time: Timestamp,
+}
+
+/// Limits on the messages of submitted proposals. Proposals whose
+/// messages break a rule are rejected before voting starts.
+#[cw_serde]
+#[derive(Default)]
+pub struct MessagePolicy {
+    /// Contracts that proposals may not migrate, or update or clear
+    /// the admin of.
+    pub protected_contracts: Vec<Addr>,
+    /// Contracts that proposals may not send any wasm message to.
+    pub denied_contracts: Vec<Addr>,
+    /// The maximum total amount of each listed denom that a
+    /// proposal's messages may spend, counting bank sends and burns,
+    /// IBC transfers, funds attached to wasm messages, and cw20
+    /// transfers, sends, burns, and allowances. cw20 tokens are
+    /// listed by their contract's address. While any limit is set,
+    /// proposals with messages whose spend can not be determined,
+    /// such as stargate, staking, and distribution messages, are
+    /// rejected.
+    pub spend_limits: Vec<Coin>,
+    /// The maximum number of messages a proposal may have.
+    pub max_msgs: Option<u32>,
+}
+
+/// Requirements for proposals to be co-sponsored before they are
+/// created in the proposal module. Proposals that do not gain enough
+/// sponsors before the window ends expire and their deposits are
+/// returned.
+#[cw_serde]
+pub struct SponsorshipConfig {
+    /// The number of distinct members, not counting the proposer,
+    /// that must sponsor a proposal.
+    pub min_sponsors: u32,
+    /// The combined voting power sponsors must have had when the
+    /// proposal was submitted.
+    pub min_power: Uint128,
+    /// How long a proposal may seek sponsors for.
+    pub window: Duration,
+}
+
+#[cw_serde]
+pub enum SponsorshipStatus {
+    /// The proposal is seeking sponsors.
+    Seeking {},
+    /// The proposal gained enough sponsors and was created.
+    Sponsored {
+        /// The ID of the created proposal.
+        proposal_id: u64,
+    },
+    /// The proposal did not gain enough sponsors before its window
+    /// ended.
+    Expired {},
+}
+
+/// A proposal seeking or having sought sponsors.
+#[cw_serde]
+pub struct Sponsorship {
+    pub id: u64,
+    pub status: SponsorshipStatus,
+    /// The address that submitted the proposal.
+    pub proposer: Addr,
+    /// The serialized message that will be sent to the proposal
+    /// module to create the proposal.
+    pub msg: Binary,
+    /// The deposit paid by the proposer.
+    pub deposit: Option<CheckedDepositInfo>,
+    /// Snapshot of the sponsorship requirements at the time of
+    /// submission.
+    pub config: SponsorshipConfig,
+    /// The members that have sponsored the proposal.
+    pub sponsors: Vec<Addr>,
+    /// The combined voting power of the sponsors.
+    pub power: Uint128,
+    /// The block height the proposal was submitted at. Sponsors'
+    /// voting power is measured at this height.
+    pub start_height: u64,
+    /// When the proposal stops seeking sponsors.
+    pub expiration: Expiration,
+}
+
 pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> {
     /// The proposal module that this module is associated with.
     pub proposal_module: Item<'static, Addr>,
@@ -26,10 +147,33 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
     pub dao: Item<'static, Addr>,
     /// The configuration for this module.
     pub config: Item<'static, Config>,
-    /// Map between proposal IDs and (deposit, proposer) pairs.
+    /// Deposits that may be paid instead of the one in `config`. Not
+    /// set if no alternatives have been configured.
+    pub deposit_options: Item<'static, Vec<CheckedDepositOption>>,
+    /// Map between proposal IDs and (deposit, proposer) pairs. The
+    /// deposit is the one actually paid by the proposer.
     pub deposits: Map<'static, u64, (Option<CheckedDepositInfo>, Addr)>,
     /// Consumers of proposal submitted hooks.
     pub proposal_submitted_hooks: Hooks<'static>,
+    /// Limits on proposal submission. Not set if no policy has been
+    /// configured.
+    pub submission_policy: Item<'static, SubmissionPolicy>,
+    /// Addresses that may submit proposals if the submission policy
+    /// enables the allowlist.
+    pub submission_allowlist: Map<'static, Addr, Empty>,
+    /// Submission history for addresses that have created proposals.
+    pub submitters: Map<'static, Addr, SubmitterInfo>,
+    /// Limits on the messages of submitted proposals. Not set if no
+    /// policy has been configured.
+    pub message_policy: Item<'static, MessagePolicy>,
+    /// Co-sponsorship requirements. Not set if proposals are created
+    /// as soon as they are submitted.
+    pub sponsorship_config: Item<'static, SponsorshipConfig>,
+    /// Proposals submitted while co-sponsorship was required, keyed
+    /// by sponsorship ID.
+    pub sponsorships: Map<'static, u64, Sponsorship>,
+    /// Consumers of sponsorship hooks.
+    pub sponsorship_hooks: Hooks<'static>,
 
     // These types are used in associated functions, but not
     // assocaited data. To stop the compiler complaining about unused
@@ -43,19 +187,36 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 {
+    #[allow(clippy::too_many_arguments)]
     const fn new(
         proposal_key: &'static str,
         dao_key: &'static str,
         config_key: &'static str,
+        deposit_options_key: &'static str,
         deposits_key: &'static str,
         proposal_submitted_hooks_key: &'static str,
+        submission_policy_key: &'static str,
+        submission_allowlist_key: &'static str,
+        submitters_key: &'static str,
+        message_policy_key: &'static str,
+        sponsorship_config_key: &'static str,
+        sponsorships_key: &'static str,
+        sponsorship_hooks_key: &'static str,
     ) -> Self {
         Self {
             proposal_module: Item::new(proposal_key),
             dao: Item::new(dao_key),
             config: Item::new(config_key),
+            deposit_options: Item::new(deposit_options_key),
             deposits: Map::new(deposits_key),
             proposal_submitted_hooks: Hooks::new(proposal_submitted_hooks_key),
+            submission_policy: Item::new(submission_policy_key),
+            submission_allowlist: Map::new(submission_allowlist_key),
+            submitters: Map::new(submitters_key),
+            message_policy: Item::new(message_policy_key),
+            sponsorship_config: Item::new(sponsorship_config_key),
+            sponsorships: Map::new(sponsorships_key),
+            sponsorship_hooks: Hooks::new(sponsorship_hooks_key),
             execute_type: PhantomData,
             instantiate_type: PhantomData,
             query_type: PhantomData,
@@ -75,8 +236,16 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> Default
             "proposal_module",
             "dao",
             "config",
+            "deposit_options",
             "deposits",
             "proposal_submitted_hooks",
+            "submission_policy",
+            "submission_allowlist",
+            "submitters",
+            "message_policy",
+            "sponsorship_config",
+            "sponsorships",
+            "sponsorship_hooks",
         )
     }
 }
@@ -1,14 +1,17 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Addr, CosmosMsg, Deps, MessageInfo, StdError, StdResult, Uint128, WasmMsg,
+    coins, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal, Deps, MessageInfo, StdError,
+    StdResult, Uint128, WasmMsg,
 };
-use cw_utils::{must_pay, PaymentError};
+use cw_utils::{must_pay, one_coin, PaymentError};
 
 use dao_interface::voting::DenomResponse;
 use thiserror::Error;
 
 use cw_denom::{CheckedDenom, DenomError, UncheckedDenom};
 
+use crate::status::Status;
+
 /// Error type for deposit methods.
 #[derive(Error, Debug, PartialEq)]
 pub enum DepositError {
@@ -26,6 +29,17 @@ pub enum DepositError {
 
     #[error("invalid deposit amount. got ({actual}), expected ({expected})")]
     InvalidDeposit { actual: Uint128, expected: Uint128 },
+
+    #[error("slash percentages must be between 0 and 1 (inclusive)")]
+    InvalidSlashPercentage,
+
+    #[error("({denom}) is not accepted for proposal deposits")]
+    UnacceptedDenom { denom: String },
+
+    #[error(
+        "no deposit paid. send an accepted native deposit or approve an accepted cw20 deposit"
+    )]
+    NoDepositPaid,
 }
 
 // The voting module token type to expect.
@@ -63,6 +77,19 @@ pub struct UncheckedDepositInfo {
     pub refund_policy: DepositRefundPolicy,
 }
 
+/// An alternative to the token and amount configured in a
+/// `UncheckedDepositInfo`. Proposers may pay any one of the accepted
+/// deposits, and the refund policy of the deposit info applies to
+/// all of them.
+#[cw_serde]
+pub struct UncheckedDepositOption {
+    /// The token to be used for the deposit.
+    pub denom: DepositToken,
+    /// The number of tokens that must be deposited. Must be a
+    /// positive, non-zero number.
+    pub amount: Uint128,
+}
+
 #[cw_serde]
 pub enum DepositRefundPolicy {
     /// Deposits should always be refunded.
@@ -71,6 +98,36 @@ pub enum DepositRefundPolicy {
     OnlyPassed,
     /// Deposits should never be refunded.
     Never,
+    /// Deposits are refunded for passed proposals. For other
+    /// proposals, a percentage of the deposit depending on how the
+    /// proposal ended is slashed and sent to `destination`, and the
+    /// remainder refunded. Setting `no_quorum` higher than `rejected`
+    /// punishes spam that nobody votes on more than proposals which
+    /// were voted on and failed.
+    Slash {
+        /// Percentage slashed when a proposal is rejected, or when
+        /// the proposal module does not report if quorum was reached.
+        rejected: Decimal,
+        /// Percentage slashed when a proposal is vetoed.
+        vetoed: Decimal,
+        /// Percentage slashed when a proposal is closed without
+        /// reaching quorum.
+        no_quorum: Decimal,
+        /// Where slashed funds are sent.
+        destination: SlashDestination,
+    },
+}
+
+/// Where slashed proposal deposits are sent.
+#[cw_serde]
+pub enum SlashDestination {
+    /// Slashed deposits are sent to the DAO's treasury.
+    Dao,
+    /// Slashed deposits are burned.
+    Burn,
+    /// Slashed deposits are sent to an address, for example a
+    /// community pool.
+    Address { address: String },
 }
 
 /// Counterpart to the `DepositInfo` struct which has been
@@ -91,6 +148,16 @@ pub struct CheckedDepositInfo {
     pub refund_policy: DepositRefundPolicy,
 }
 
+/// Counterpart to the `UncheckedDepositOption` struct which has been
+/// processed.
+#[cw_serde]
+pub struct CheckedDepositOption {
+    /// The token to be used for the deposit.
+    pub denom: CheckedDenom,
+    /// The number of tokens that must be deposited.
+    pub amount: Uint128,
+}
+
 impl UncheckedDepositInfo {
     /// Converts deposit info into checked deposit info.
     pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<CheckedDepositInfo, DepositError> {
@@ -106,7 +173,50 @@ impl UncheckedDepositInfo {
             return Err(DepositError::ZeroDeposit);
         }
 
-        let denom = match denom {
+        if let DepositRefundPolicy::Slash {
+            rejected,
+            vetoed,
+            no_quorum,
+            ref destination,
+        } = refund_policy
+        {
+            if [rejected, vetoed, no_quorum]
+                .iter()
+                .any(|p| *p > Decimal::one())
+            {
+                return Err(DepositError::InvalidSlashPercentage);
+            }
+            if let SlashDestination::Address { address } = destination {
+                deps.api.addr_validate(address)?;
+            }
+        }
+
+        Ok(CheckedDepositInfo {
+            denom: denom.into_checked(deps, dao)?,
+            amount,
+            refund_policy,
+        })
+    }
+}
+
+impl UncheckedDepositOption {
+    /// Converts a deposit option into a checked deposit option.
+    pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<CheckedDepositOption, DepositError> {
+        if self.amount.is_zero() {
+            return Err(DepositError::ZeroDeposit);
+        }
+        Ok(CheckedDepositOption {
+            denom: self.denom.into_checked(deps, dao)?,
+            amount: self.amount,
+        })
+    }
+}
+
+impl DepositToken {
+    /// Resolves the token to a checked denom, querying the DAO's
+    /// voting module if needed.
+    pub fn into_checked(self, deps: Deps, dao: Addr) -> Result<CheckedDenom, DepositError> {
+        let denom = match self {
             DepositToken::Token { denom } => denom.into_checked(deps),
             DepositToken::VotingModuleToken { token_type } => {
                 let voting_module: Addr = deps
@@ -142,12 +252,7 @@ impl UncheckedDepositInfo {
                 }
             }
         }?;
-
-        Ok(CheckedDepositInfo {
-            denom,
-            amount,
-            refund_policy,
-        })
+        Ok(denom)
     }
 }
 
@@ -178,6 +283,63 @@ impl CheckedDepositInfo {
         }
     }
 
+    /// Determines which accepted deposit the sender of `info` is
+    /// paying, where the accepted deposits are this one and
+    /// `options`. Returns the deposit being paid, with this deposit's
+    /// refund policy.
+    ///
+    /// If native tokens were sent, the deposit paid is the one in
+    /// the sent denomination. Otherwise, it is the first cw20 deposit
+    /// that the sender has allowed `spender` to take. With no
+    /// options, this is equivalent to `check_native_deposit_paid`.
+    pub fn select_paid(
+        &self,
+        deps: Deps,
+        info: &MessageInfo,
+        spender: &Addr,
+        options: &[CheckedDepositOption],
+    ) -> Result<CheckedDepositInfo, DepositError> {
+        if options.is_empty() {
+            self.check_native_deposit_paid(info)?;
+            return Ok(self.clone());
+        }
+
+        let mut accepted = std::iter::once((&self.denom, self.amount))
+            .chain(options.iter().map(|o| (&o.denom, o.amount)));
+        let (denom, amount) = if info.funds.is_empty() {
+            let mut paid = None;
+            for (denom, amount) in accepted {
+                if let CheckedDenom::Cw20(token) = denom {
+                    let allowance: cw20::AllowanceResponse = deps.querier.query_wasm_smart(
+                        token,
+                        &cw20::Cw20QueryMsg::Allowance {
+                            owner: info.sender.to_string(),
+                            spender: spender.to_string(),
+                        },
+                    )?;
+                    if allowance.allowance >= amount {
+                        paid = Some((denom, amount));
+                        break;
+                    }
+                }
+            }
+            paid.ok_or(DepositError::NoDepositPaid)?
+        } else {
+            let sent = one_coin(info)?;
+            accepted
+                .find(|(denom, _)| matches!(denom, CheckedDenom::Native(d) if *d == sent.denom))
+                .ok_or(DepositError::UnacceptedDenom { denom: sent.denom })?
+        };
+
+        let deposit = CheckedDepositInfo {
+            denom: denom.clone(),
+            amount,
+            refund_policy: self.refund_policy.clone(),
+        };
+        deposit.check_native_deposit_paid(info)?;
+        Ok(deposit)
+    }
+
     pub fn get_take_deposit_messages(
         &self,
         depositor: &Addr,
@@ -213,6 +375,76 @@ impl CheckedDepositInfo {
         Ok(take_deposit_msg)
     }
 
+    /// Returns the messages that settle this deposit once the proposal
+    /// it was paid for has completed with `status`, according to the
+    /// deposit's refund policy. `quorum_reached` is only considered
+    /// for closed proposals and, if unknown, the proposal is treated
+    /// as having been rejected with quorum.
+    pub fn get_completion_messages(
+        &self,
+        status: Status,
+        quorum_reached: Option<bool>,
+        proposer: &Addr,
+        dao: &Addr,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        let passed = status == Status::Executed;
+        match &self.refund_policy {
+            DepositRefundPolicy::Always => self.get_return_deposit_message(proposer),
+            DepositRefundPolicy::OnlyPassed if passed => self.get_return_deposit_message(proposer),
+            // If the proposer doesn't get the deposit, the DAO does.
+            DepositRefundPolicy::OnlyPassed | DepositRefundPolicy::Never => {
+                self.get_return_deposit_message(dao)
+            }
+            DepositRefundPolicy::Slash {
+                rejected,
+                vetoed,
+                no_quorum,
+                destination,
+            } => {
+                let percentage = match (status, quorum_reached) {
+                    (Status::Executed, _) => Decimal::zero(),
+                    (Status::Vetoed, _) => *vetoed,
+                    (_, Some(false)) => *no_quorum,
+                    _ => *rejected,
+                };
+                let slashed = self.amount * percentage;
+                let refunded = self.amount - slashed;
+
+                let mut messages = vec![];
+                if !refunded.is_zero() {
+                    messages.push(self.denom.get_transfer_to_message(proposer, refunded)?);
+                }
+                if !slashed.is_zero() {
+                    messages.push(match destination {
+                        SlashDestination::Dao => {
+                            self.denom.get_transfer_to_message(dao, slashed)?
+                        }
+                        SlashDestination::Address { address } => self
+                            .denom
+                            .get_transfer_to_message(&Addr::unchecked(address), slashed)?,
+                        SlashDestination::Burn => self.get_burn_message(slashed)?,
+                    });
+                }
+                Ok(messages)
+            }
+        }
+    }
+
+    fn get_burn_message(&self, amount: Uint128) -> StdResult<CosmosMsg> {
+        Ok(match &self.denom {
+            CheckedDenom::Native(denom) => BankMsg::Burn {
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+            CheckedDenom::Cw20(address) => WasmMsg::Execute {
+                contract_addr: address.to_string(),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Burn { amount })?,
+                funds: vec![],
+            }
+            .into(),
+        })
+    }
+
     pub fn get_return_deposit_message(&self, depositor: &Addr) -> StdResult<Vec<CosmosMsg>> {
         // Should get caught in `into_checked()`, but to be pedantic.
         if self.amount.is_zero() {
@@ -317,6 +549,98 @@ pub mod tests {
         assert_eq!(err, DepositError::Payment(PaymentError::NoFunds {}));
     }
 
+    #[test]
+    fn test_select_paid_native_option() {
+        let deps = cosmwasm_std::testing::mock_dependencies();
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
+            amount: Uint128::new(100),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        };
+        let options = vec![CheckedDepositOption {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(50),
+        }];
+        let spender = Addr::unchecked("contract");
+
+        let paid = deposit_info
+            .select_paid(
+                deps.as_ref(),
+                &mock_info("ekez", &coins(50, NATIVE_DENOM)),
+                &spender,
+                &options,
+            )
+            .unwrap();
+        assert_eq!(
+            paid,
+            CheckedDepositInfo {
+                denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+                amount: Uint128::new(50),
+                refund_policy: DepositRefundPolicy::OnlyPassed,
+            }
+        );
+
+        let err = deposit_info
+            .select_paid(
+                deps.as_ref(),
+                &mock_info("ekez", &coins(40, NATIVE_DENOM)),
+                &spender,
+                &options,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DepositError::InvalidDeposit {
+                actual: Uint128::new(40),
+                expected: Uint128::new(50)
+            }
+        );
+
+        let err = deposit_info
+            .select_paid(
+                deps.as_ref(),
+                &mock_info("ekez", &coins(50, "unotekez")),
+                &spender,
+                &options,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DepositError::UnacceptedDenom {
+                denom: "unotekez".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_select_paid_no_options() {
+        let deps = cosmwasm_std::testing::mock_dependencies();
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+        };
+        let paid = deposit_info
+            .select_paid(
+                deps.as_ref(),
+                &mock_info("ekez", &coins(10, NATIVE_DENOM)),
+                &Addr::unchecked("contract"),
+                &[],
+            )
+            .unwrap();
+        assert_eq!(paid, deposit_info);
+
+        let err = deposit_info
+            .select_paid(
+                deps.as_ref(),
+                &mock_info("ekez", &[]),
+                &Addr::unchecked("contract"),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(err, DepositError::Payment(PaymentError::NoFunds {}));
+    }
+
     #[test]
     fn test_get_take_deposit_messages() {
         // Does nothing if a native token is being used.
@@ -384,6 +708,70 @@ pub mod tests {
         assert_eq!(messages, vec![]);
     }
 
+    #[test]
+    fn test_get_completion_messages_slash() {
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(100),
+            refund_policy: DepositRefundPolicy::Slash {
+                rejected: Decimal::percent(10),
+                vetoed: Decimal::percent(50),
+                no_quorum: Decimal::one(),
+                destination: SlashDestination::Burn,
+            },
+        };
+        let proposer = Addr::unchecked("ekez");
+        let dao = Addr::unchecked("dao");
+        let messages = |status, quorum_reached| {
+            deposit_info
+                .get_completion_messages(status, quorum_reached, &proposer, &dao)
+                .unwrap()
+        };
+        let refund = |amount| -> CosmosMsg {
+            BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(amount, NATIVE_DENOM),
+            }
+            .into()
+        };
+        let burn = |amount| -> CosmosMsg {
+            BankMsg::Burn {
+                amount: coins(amount, NATIVE_DENOM),
+            }
+            .into()
+        };
+
+        assert_eq!(messages(Status::Executed, None), vec![refund(100)]);
+        assert_eq!(
+            messages(Status::Closed, Some(true)),
+            vec![refund(90), burn(10)]
+        );
+        // Unknown quorum is treated as a rejection.
+        assert_eq!(messages(Status::Closed, None), vec![refund(90), burn(10)]);
+        assert_eq!(messages(Status::Vetoed, None), vec![refund(50), burn(50)]);
+        assert_eq!(messages(Status::Closed, Some(false)), vec![burn(100)]);
+    }
+
+    #[test]
+    fn test_invalid_slash_percentage() {
+        let deps = cosmwasm_std::testing::mock_dependencies();
+        let err = UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Slash {
+                rejected: Decimal::percent(101),
+                vetoed: Decimal::zero(),
+                no_quorum: Decimal::zero(),
+                destination: SlashDestination::Dao,
+            },
+        }
+        .into_checked(deps.as_ref(), Addr::unchecked("dao"))
+        .unwrap_err();
+        assert_eq!(err, DepositError::InvalidSlashPercentage);
+    }
+
     #[test]
     fn test_get_return_deposit_message_cw20() {
         let mut deposit_info = CheckedDepositInfo {
@@ -1,7 +1,10 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{CosmosMsg, Empty, StdError, StdResult, Uint128};
 
-use crate::threshold::{validate_quorum, PercentageThreshold, ThresholdError};
+use crate::{
+    threshold::{validate_percentage, validate_quorum, PercentageThreshold, ThresholdError},
+    voting::does_vote_count_pass,
+};
 
 /// Maximum number of choices for multiple choice votes. Chosen
 /// in order to impose a bound on state / queries.
@@ -11,23 +14,87 @@ const NONE_OPTION_DESCRIPTION: &str = "None of the above";
 /// Determines how many choices may be selected.
 #[cw_serde]
 pub enum VotingStrategy {
-    SingleChoice { quorum: PercentageThreshold },
+    SingleChoice {
+        quorum: PercentageThreshold,
+    },
+    /// Voters may approve any number of options, and each approved
+    /// option receives the voter's full voting power. Up to `winners`
+    /// options with the most approvals pass, so long as they have
+    /// more approvals than "None of the above". The messages of all
+    /// passing options are executed.
+    Approval {
+        quorum: PercentageThreshold,
+        winners: u32,
+    },
+    /// Voters rank options from most to least preferred, and the
+    /// winner is found by instant runoff. Each round counts every
+    /// ballot towards its highest ranked remaining option, and the
+    /// options with the fewest votes are eliminated until one option
+    /// has a majority of the remaining votes. "None of the above"
+    /// takes part in elimination like any other option.
+    RankedChoice {
+        quorum: PercentageThreshold,
+    },
 }
 
 impl VotingStrategy {
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
             VotingStrategy::SingleChoice { quorum } => validate_quorum(quorum),
+            VotingStrategy::Approval { quorum, winners } => {
+                if *winners == 0 {
+                    return Err(ThresholdError::ZeroWinners {});
+                }
+                validate_quorum(quorum)
+            }
+            VotingStrategy::RankedChoice { quorum } => validate_quorum(quorum),
         }
     }
 
     pub fn get_quorum(&self) -> PercentageThreshold {
         match self {
             VotingStrategy::SingleChoice { quorum } => *quorum,
+            VotingStrategy::Approval { quorum, .. } => *quorum,
+            VotingStrategy::RankedChoice { quorum } => *quorum,
+        }
+    }
+
+    /// Returns true if voters may select more than one option.
+    pub fn is_multi_select(&self) -> bool {
+        match self {
+            VotingStrategy::SingleChoice { .. } => false,
+            VotingStrategy::Approval { .. } | VotingStrategy::RankedChoice { .. } => true,
         }
     }
 }
 
+/// Requires the winning option of a single choice proposal to receive
+/// a minimum share of the votes cast, so that a plurality in a many
+/// way split is not enough to pass.
+#[cw_serde]
+pub struct WinnerThreshold {
+    /// The share of the votes cast, including votes for "None of the
+    /// above", that the winning option must receive.
+    pub threshold: PercentageThreshold,
+    /// If true, closing a proposal that was rejected only because its
+    /// winning option did not reach the threshold creates a runoff
+    /// proposal between the two standard options with the most
+    /// votes.
+    pub runoff: bool,
+}
+
+impl WinnerThreshold {
+    pub fn validate(&self) -> Result<(), ThresholdError> {
+        validate_percentage(&self.threshold)
+    }
+
+    /// Returns true if `votes` meets the threshold out of `cast`
+    /// votes.
+    pub fn is_met(&self, votes: Uint128, cast: Uint128) -> bool {
+        does_vote_count_pass(votes, cast, self.threshold)
+    }
+}
+
 /// A multiple choice vote, picking the desired option
 #[cw_serde]
 #[derive(Copy)]
@@ -42,12 +109,124 @@ impl std::fmt::Display for MultipleChoiceVote {
     }
 }
 
+/// A vote selecting several options at once, cast on proposals that
+/// use approval or ranked choice voting.
+#[cw_serde]
+pub struct MultiSelectVote {
+    /// The selected options. Each option may be selected at most
+    /// once. For ranked choice voting, options are listed from most
+    /// to least preferred. For approval voting, "None of the above"
+    /// may not be selected alongside other options.
+    pub option_ids: Vec<u32>,
+}
+
+impl MultiSelectVote {
+    /// Checks that this vote selects at least one option, that all
+    /// selected options exist, that no option is selected twice, and,
+    /// unless voting is ranked, that "None of the above" is not
+    /// selected alongside other options.
+    pub fn validate(
+        &self,
+        strategy: &VotingStrategy,
+        options: &[CheckedMultipleChoiceOption],
+    ) -> bool {
+        let ranked = matches!(strategy, VotingStrategy::RankedChoice { .. });
+        if self.option_ids.is_empty() {
+            return false;
+        }
+        let mut seen = vec![false; options.len()];
+        for id in &self.option_ids {
+            match options.get(*id as usize) {
+                Some(option) => {
+                    if seen[*id as usize] {
+                        return false;
+                    }
+                    if !ranked
+                        && option.option_type == MultipleChoiceOptionType::None
+                        && self.option_ids.len() > 1
+                    {
+                        return false;
+                    }
+                    seen[*id as usize] = true;
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns true if both votes select the same options. Order is
+    /// only significant for ranked choice voting.
+    pub fn selects_same(&self, strategy: &VotingStrategy, other: &MultiSelectVote) -> bool {
+        if let VotingStrategy::RankedChoice { .. } = strategy {
+            return self.option_ids == other.option_ids;
+        }
+        let mut a = self.option_ids.clone();
+        let mut b = other.option_ids.clone();
+        a.sort_unstable();
+        b.sort_unstable();
+        a == b
+    }
+}
+
+impl std::fmt::Display for MultiSelectVote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids = self
+            .option_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", ids.join(","))
+    }
+}
+
 // Holds the vote weights for each option
 #[cw_serde]
+#[derive(Default)]
 pub struct MultipleChoiceVotes {
     // Vote counts is a vector of integers indicating the vote weight for each option
     // (the index corresponds to the option).
     pub vote_weights: Vec<Uint128>,
+    /// The total voting power of ballots cast with
+    /// `MultiSelectVote`s. A multi-select ballot adds its weight to
+    /// every option it selects, so the sum of `vote_weights`
+    /// overstates participation. Always zero for single choice
+    /// voting.
+    #[serde(default)]
+    pub multi_select_power: Uint128,
+    /// The winner of the instant runoff over a ranked choice
+    /// proposal's ballots. For ranked choice voting `vote_weights`
+    /// holds only first preferences, and running the runoff needs
+    /// every ranking, so proposal modules store rankings separately
+    /// and cache the result here whenever a ballot is cast. None if
+    /// no ballots have been cast or the runoff ended in a tie.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ranked_choice_winner: Option<u32>,
+}
+
+/// The voting power of all ballots that ranked options in the same
+/// order.
+#[cw_serde]
+pub struct RankingTally {
+    /// Option ids from most to least preferred.
+    pub ranking: Vec<u32>,
+    /// The voting power behind this ranking.
+    pub power: Uint128,
+}
+
+/// The count of one round of an instant runoff.
+#[cw_serde]
+pub struct RankedChoiceRound {
+    /// The votes for each option in this round, indexed by option
+    /// id. Options eliminated in earlier rounds have zero votes.
+    pub tallies: Vec<Uint128>,
+    /// The voting power of ballots that rank no remaining option.
+    pub exhausted: Uint128,
+    /// The options eliminated at the end of this round.
+    pub eliminated: Vec<u32>,
+    /// The option with a majority of the remaining votes, if this is
+    /// the final round and it was not a tie.
+    pub winner: Option<u32>,
 }
 
 impl MultipleChoiceVotes {
@@ -56,6 +235,130 @@ impl MultipleChoiceVotes {
         self.vote_weights.iter().sum()
     }
 
+    /// The total voting power that has been cast on the proposal,
+    /// counting each ballot once regardless of how many options it
+    /// selects.
+    pub fn participation(&self, strategy: &VotingStrategy) -> Uint128 {
+        if strategy.is_multi_select() {
+            self.multi_select_power
+        } else {
+            self.total()
+        }
+    }
+
+    /// Add a multi-select vote to the tally. Every selected option
+    /// receives the full weight.
+    pub fn add_multi_select_vote(
+        &mut self,
+        vote: &MultiSelectVote,
+        weight: Uint128,
+    ) -> StdResult<()> {
+        for option_id in &vote.option_ids {
+            self.add_vote(
+                MultipleChoiceVote {
+                    option_id: *option_id,
+                },
+                weight,
+            )?;
+        }
+        self.multi_select_power = self
+            .multi_select_power
+            .checked_add(weight)
+            .map_err(StdError::overflow)?;
+        Ok(())
+    }
+
+    /// Remove a multi-select vote from the tally.
+    pub fn remove_multi_select_vote(
+        &mut self,
+        vote: &MultiSelectVote,
+        weight: Uint128,
+    ) -> StdResult<()> {
+        for option_id in &vote.option_ids {
+            self.remove_vote(
+                MultipleChoiceVote {
+                    option_id: *option_id,
+                },
+                weight,
+            )?;
+        }
+        self.multi_select_power = self
+            .multi_select_power
+            .checked_sub(weight)
+            .map_err(StdError::overflow)?;
+        Ok(())
+    }
+
+    /// Add a ranked vote to the tally. Only the first preference
+    /// counts towards `vote_weights`. The full ranking must be stored
+    /// by the caller and `ranked_choice_winner` updated.
+    pub fn add_ranked_vote(&mut self, vote: &MultiSelectVote, weight: Uint128) -> StdResult<()> {
+        self.add_vote(
+            MultipleChoiceVote {
+                option_id: vote.option_ids[0],
+            },
+            weight,
+        )?;
+        self.multi_select_power = self
+            .multi_select_power
+            .checked_add(weight)
+            .map_err(StdError::overflow)?;
+        Ok(())
+    }
+
+    /// Remove a ranked vote from the tally.
+    pub fn remove_ranked_vote(&mut self, vote: &MultiSelectVote, weight: Uint128) -> StdResult<()> {
+        self.remove_vote(
+            MultipleChoiceVote {
+                option_id: vote.option_ids[0],
+            },
+            weight,
+        )?;
+        self.multi_select_power = self
+            .multi_select_power
+            .checked_sub(weight)
+            .map_err(StdError::overflow)?;
+        Ok(())
+    }
+
+    /// Add a ballot to the tally in the way `strategy` counts it.
+    pub fn add_ballot(
+        &mut self,
+        strategy: &VotingStrategy,
+        vote: &MultiSelectVote,
+        weight: Uint128,
+    ) -> StdResult<()> {
+        match strategy {
+            VotingStrategy::SingleChoice { .. } => self.add_vote(
+                MultipleChoiceVote {
+                    option_id: vote.option_ids[0],
+                },
+                weight,
+            ),
+            VotingStrategy::Approval { .. } => self.add_multi_select_vote(vote, weight),
+            VotingStrategy::RankedChoice { .. } => self.add_ranked_vote(vote, weight),
+        }
+    }
+
+    /// Remove a ballot from the tally in the way `strategy` counts it.
+    pub fn remove_ballot(
+        &mut self,
+        strategy: &VotingStrategy,
+        vote: &MultiSelectVote,
+        weight: Uint128,
+    ) -> StdResult<()> {
+        match strategy {
+            VotingStrategy::SingleChoice { .. } => self.remove_vote(
+                MultipleChoiceVote {
+                    option_id: vote.option_ids[0],
+                },
+                weight,
+            ),
+            VotingStrategy::Approval { .. } => self.remove_multi_select_vote(vote, weight),
+            VotingStrategy::RankedChoice { .. } => self.remove_ranked_vote(vote, weight),
+        }
+    }
+
     // Add a vote to the tally
     pub fn add_vote(&mut self, vote: MultipleChoiceVote, weight: Uint128) -> StdResult<()> {
         self.vote_weights[vote.option_id as usize] = self.vote_weights[vote.option_id as usize]
@@ -76,10 +379,87 @@ impl MultipleChoiceVotes {
     pub fn zero(num_choices: usize) -> Self {
         Self {
             vote_weights: vec![Uint128::zero(); num_choices],
+            ..Default::default()
         }
     }
 }
 
+/// Runs an instant runoff over a proposal's ranked ballots, grouped
+/// by ranking, and returns the count of every round. The last round
+/// either has a winner or, if every remaining option is tied, does
+/// not.
+///
+/// In each round every ballot counts towards its highest ranked
+/// remaining option. An option with more than half of the
+/// non-exhausted votes, or the last remaining option, wins.
+/// Otherwise all options tied for the fewest votes are
+/// eliminated.
+pub fn instant_runoff(num_options: usize, rankings: &[RankingTally]) -> Vec<RankedChoiceRound> {
+    let mut remaining = vec![true; num_options];
+    let mut rounds = vec![];
+    loop {
+        let mut tallies = vec![Uint128::zero(); num_options];
+        let mut exhausted = Uint128::zero();
+        for tally in rankings {
+            match tally.ranking.iter().find(|id| remaining[**id as usize]) {
+                Some(id) => tallies[*id as usize] += tally.power,
+                None => exhausted += tally.power,
+            }
+        }
+        let counted: Uint128 = tallies.iter().sum();
+        let options: Vec<usize> = (0..num_options).filter(|i| remaining[*i]).collect();
+
+        let winner = if options.len() == 1 {
+            Some(options[0])
+        } else {
+            options
+                .iter()
+                .copied()
+                .find(|i| tallies[*i] > counted - tallies[*i])
+        };
+        if let Some(winner) = winner {
+            rounds.push(RankedChoiceRound {
+                tallies,
+                exhausted,
+                eliminated: vec![],
+                winner: Some(winner as u32),
+            });
+            return rounds;
+        }
+
+        let fewest = options
+            .iter()
+            .map(|i| tallies[*i])
+            .min()
+            .unwrap_or_default();
+        let eliminated: Vec<u32> = options
+            .iter()
+            .filter(|i| tallies[**i] == fewest)
+            .map(|i| *i as u32)
+            .collect();
+        // Every remaining option is tied, so there is no way to
+        // continue.
+        if eliminated.len() == options.len() {
+            rounds.push(RankedChoiceRound {
+                tallies,
+                exhausted,
+                eliminated: vec![],
+                winner: None,
+            });
+            return rounds;
+        }
+        for id in &eliminated {
+            remaining[*id as usize] = false;
+        }
+        rounds.push(RankedChoiceRound {
+            tallies,
+            exhausted,
+            eliminated,
+            winner: None,
+        });
+    }
+}
+
 /// Represents the type of Multiple choice option. "None of the above" has a special
 /// type for example.
 #[cw_serde]
@@ -196,6 +576,7 @@ mod test {
     fn test_multiple_choice_votes() {
         let mut votes = MultipleChoiceVotes {
             vote_weights: vec![Uint128::new(10), Uint128::new(100)],
+            ..Default::default()
         };
         let total = votes.total();
         assert_eq!(total, Uint128::new(110));
@@ -216,6 +597,229 @@ mod test {
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
     }
 
+    #[test]
+    fn test_multi_select_votes() {
+        let mut votes = MultipleChoiceVotes::zero(3);
+        let vote = MultiSelectVote {
+            option_ids: vec![0, 1],
+        };
+        votes
+            .add_multi_select_vote(&vote, Uint128::new(10))
+            .unwrap();
+        votes
+            .add_multi_select_vote(
+                &MultiSelectVote {
+                    option_ids: vec![1],
+                },
+                Uint128::new(5),
+            )
+            .unwrap();
+
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::new(10), Uint128::new(15), Uint128::zero()]
+        );
+        assert_eq!(votes.total(), Uint128::new(25));
+        assert_eq!(
+            votes.participation(&VotingStrategy::Approval {
+                quorum: PercentageThreshold::Majority {},
+                winners: 1,
+            }),
+            Uint128::new(15)
+        );
+
+        votes
+            .remove_multi_select_vote(&vote, Uint128::new(10))
+            .unwrap();
+        assert_eq!(
+            votes.vote_weights,
+            vec![Uint128::zero(), Uint128::new(5), Uint128::zero()]
+        );
+        assert_eq!(votes.multi_select_power, Uint128::new(5));
+        assert_eq!("0,1", vote.to_string());
+    }
+
+    #[test]
+    fn test_validate_multi_select_vote() {
+        let options = MultipleChoiceOptions {
+            options: vec![
+                MultipleChoiceOption {
+                    description: "multiple choice option 1".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+                MultipleChoiceOption {
+                    description: "multiple choice option 2".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+            ],
+        }
+        .into_checked()
+        .unwrap()
+        .options;
+
+        let approval = VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            winners: 1,
+        };
+        let ranked = VotingStrategy::RankedChoice {
+            quorum: PercentageThreshold::Majority {},
+        };
+        let valid =
+            |option_ids: Vec<u32>| MultiSelectVote { option_ids }.validate(&approval, &options);
+
+        assert!(valid(vec![0]));
+        assert!(valid(vec![1, 0]));
+        assert!(valid(vec![2]));
+        // Empty.
+        assert!(!valid(vec![]));
+        // Out of range.
+        assert!(!valid(vec![0, 3]));
+        // Duplicate.
+        assert!(!valid(vec![1, 1]));
+        // "None of the above" alongside another option.
+        assert!(!valid(vec![0, 2]));
+        // Unless "None of the above" is ranked.
+        assert!(MultiSelectVote {
+            option_ids: vec![0, 2, 1]
+        }
+        .validate(&ranked, &options));
+        assert!(!MultiSelectVote {
+            option_ids: vec![0, 2, 0]
+        }
+        .validate(&ranked, &options));
+    }
+
+    #[test]
+    fn test_instant_runoff() {
+        let mut votes = MultipleChoiceVotes::zero(4);
+        let ranking = |ranking: Vec<u32>, power: u128| RankingTally {
+            ranking,
+            power: Uint128::new(power),
+        };
+
+        // Option 3 leads the first round, but voters who prefer
+        // options 0 and 1 prefer option 2 over it.
+        let rankings = vec![
+            ranking(vec![3], 40),
+            ranking(vec![2, 3], 30),
+            ranking(vec![0, 2], 20),
+            ranking(vec![1], 5),
+            ranking(vec![1, 2], 5),
+        ];
+        for tally in &rankings {
+            votes
+                .add_ranked_vote(
+                    &MultiSelectVote {
+                        option_ids: tally.ranking.clone(),
+                    },
+                    tally.power,
+                )
+                .unwrap();
+        }
+
+        assert_eq!(
+            votes.vote_weights,
+            vec![
+                Uint128::new(20),
+                Uint128::new(10),
+                Uint128::new(30),
+                Uint128::new(40)
+            ]
+        );
+        assert_eq!(votes.multi_select_power, Uint128::new(100));
+
+        let rounds = instant_runoff(4, &rankings);
+        assert_eq!(
+            rounds,
+            vec![
+                RankedChoiceRound {
+                    tallies: votes.vote_weights.clone(),
+                    exhausted: Uint128::zero(),
+                    eliminated: vec![1],
+                    winner: None,
+                },
+                RankedChoiceRound {
+                    tallies: vec![
+                        Uint128::new(20),
+                        Uint128::zero(),
+                        Uint128::new(35),
+                        Uint128::new(40)
+                    ],
+                    exhausted: Uint128::new(5),
+                    eliminated: vec![0],
+                    winner: None,
+                },
+                RankedChoiceRound {
+                    tallies: vec![
+                        Uint128::zero(),
+                        Uint128::zero(),
+                        Uint128::new(55),
+                        Uint128::new(40)
+                    ],
+                    exhausted: Uint128::new(5),
+                    eliminated: vec![],
+                    winner: Some(2),
+                },
+            ]
+        );
+
+        votes
+            .remove_ranked_vote(
+                &MultiSelectVote {
+                    option_ids: vec![1],
+                },
+                Uint128::new(5),
+            )
+            .unwrap();
+        assert_eq!(votes.vote_weights[1], Uint128::new(5));
+        assert_eq!(votes.multi_select_power, Uint128::new(95));
+    }
+
+    #[test]
+    fn test_instant_runoff_tie() {
+        let rounds = instant_runoff(3, &[]);
+        assert_eq!(rounds.len(), 1);
+        assert_eq!(rounds[0].winner, None);
+
+        // Option 2 is eliminated, after which options 0 and 1 are
+        // tied.
+        let rankings = [
+            RankingTally {
+                ranking: vec![0],
+                power: Uint128::new(10),
+            },
+            RankingTally {
+                ranking: vec![1],
+                power: Uint128::new(10),
+            },
+        ];
+        let rounds = instant_runoff(3, &rankings);
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].eliminated, vec![2]);
+        assert_eq!(rounds[1].winner, None);
+        assert_eq!(rounds[1].eliminated, Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_validate_voting_strategy() {
+        assert_eq!(
+            VotingStrategy::Approval {
+                quorum: PercentageThreshold::Majority {},
+                winners: 0,
+            }
+            .validate(),
+            Err(ThresholdError::ZeroWinners {})
+        );
+        assert!(VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            winners: 2,
+        }
+        .validate()
+        .is_ok());
+    }
+
     #[test]
     fn test_into_checked() {
         let options = vec![
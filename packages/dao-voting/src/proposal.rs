@@ -68,3 +68,34 @@ pub struct MultipleChoiceProposeMsg {
     /// An optional vote cast by the proposer.
     pub vote: Option<MultipleChoiceAutoVote>,
 }
+
+/// Implemented by propose messages so that pre-propose modules may
+/// check the messages of a proposal before creating it.
+pub trait ProposalMsgs {
+    /// The messages of the proposal, one list for each of its
+    /// choices. At most one choice is executed, so each list is
+    /// checked on its own.
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]>;
+}
+
+impl ProposalMsgs for Empty {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        vec![]
+    }
+}
+
+impl ProposalMsgs for SingleChoiceProposeMsg {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        vec![&self.msgs]
+    }
+}
+
+impl ProposalMsgs for MultipleChoiceProposeMsg {
+    fn proposal_msgs(&self) -> Vec<&[CosmosMsg<Empty>]> {
+        self.choices
+            .options
+            .iter()
+            .map(|option| option.msgs.as_slice())
+            .collect()
+    }
+}
@@ -40,3 +40,40 @@ impl std::fmt::Display for Status {
         }
     }
 }
+
+impl Status {
+    /// Returns a key identifying this status' variant. Unlike the
+    /// `Display` implementation this ignores any data carried by the
+    /// variant, so all `VetoTimelock` statuses share the same key.
+    /// Used to index proposals by status.
+    pub fn index_key(&self) -> &'static str {
+        match self {
+            Status::Open => "open",
+            Status::Rejected => "rejected",
+            Status::Passed => "passed",
+            Status::Executed => "executed",
+            Status::Closed => "closed",
+            Status::ExecutionFailed => "execution_failed",
+            Status::VetoTimelock { .. } => "veto_timelock",
+            Status::Vetoed => "vetoed",
+        }
+    }
+
+    /// Proposal statuses are only written to storage on vote,
+    /// execute, close, and veto events, so a proposal whose current
+    /// status is `self` may still be stored with an earlier
+    /// status. Returns the index keys of every status such a proposal
+    /// may be stored with.
+    pub fn stored_index_keys(&self) -> Vec<&'static str> {
+        match self {
+            // Open proposals may pass or be rejected once they
+            // expire or once enough votes have been cast.
+            Status::Rejected => vec![Status::Open.index_key(), self.index_key()],
+            Status::VetoTimelock { .. } => vec![Status::Open.index_key(), self.index_key()],
+            // Passed proposals may have been stored while open or
+            // while their veto timelock was pending.
+            Status::Passed => vec![Status::Open.index_key(), "veto_timelock", self.index_key()],
+            _ => vec![self.index_key()],
+        }
+    }
+}
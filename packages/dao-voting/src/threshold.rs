@@ -62,6 +62,9 @@ pub enum ThresholdError {
 
     #[error("Not possible to reach required (passing) threshold")]
     UnreachableThreshold {},
+
+    #[error("Approval voting must have at least one winner")]
+    ZeroWinners {},
 }
 
 /// A percentage of voting power that must vote yes for a proposal to
@@ -121,7 +124,7 @@ pub enum Threshold {
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
-fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
+pub fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
     if let PercentageThreshold::Percent(percent) = percent {
         if percent.is_zero() {
             Err(ThresholdError::ZeroThreshold {})
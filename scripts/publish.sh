@@ -135,6 +135,10 @@ cd contracts/pre-propose/dao-pre-propose-approval-single
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/pre-propose/dao-pre-propose-approval-multiple
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/pre-propose/dao-pre-propose-approver
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
@@ -153,6 +157,10 @@ cd contracts/proposal/dao-proposal-condorcet
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/pre-propose/dao-pre-propose-condorcet
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/staking/cw20-stake
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
@@ -183,6 +191,10 @@ cd contracts/voting/dao-voting-cw721-staked
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/voting/dao-voting-inactivity-decay
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/voting/dao-voting-token-staked
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"